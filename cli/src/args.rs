@@ -171,6 +171,65 @@ pub enum ProgramCommand {
         switchboard_feed: Option<String>,
         #[arg(long, help = "Weight when no feed is available")]
         no_feed_weight: Option<u128>,
+        #[arg(
+            long,
+            help = "Max change in weight from the previous epoch in basis points"
+        )]
+        max_weight_change_bps: Option<u64>,
+        #[arg(
+            long,
+            help = "Max slots since the last switchboard update before the feed is stale, 0 uses the default"
+        )]
+        max_stale_slots: Option<u64>,
+    },
+    AdminSetStMintOracleFeeds {
+        #[arg(long, help = "Vault address")]
+        vault: String,
+        #[arg(long, value_delimiter = ',', help = "Switchboard feed addresses")]
+        switchboard_feeds: Vec<String>,
+        #[arg(long, value_delimiter = ',', help = "Pyth price account addresses")]
+        pyth_feeds: Vec<String>,
+        #[arg(
+            long,
+            default_value_t = 500,
+            help = "Max spread between the feeds in basis points of the median"
+        )]
+        max_feed_divergence_bps: u16,
+    },
+    AdminRetireVault {
+        #[arg(long, help = "Vault address")]
+        vault: String,
+        #[arg(long, help = "Epoch the vault is retired from")]
+        retired_epoch: u64,
+    },
+    AdminSetVaultRewardAsset {
+        #[arg(long, help = "Vault address")]
+        vault: String,
+        #[arg(
+            long,
+            help = "Reward asset type: 0 - JitoSOL, 1 - SPL stake pool, 2 - SOL"
+        )]
+        reward_asset_type: u8,
+        #[arg(
+            long,
+            help = "Stake pool address, only for the SPL stake pool reward asset"
+        )]
+        stake_pool: Option<String>,
+    },
+    AdminSetVaultRewardMultiplier {
+        #[arg(long, help = "Vault address")]
+        vault: String,
+        #[arg(
+            long,
+            help = "Reward multiplier in basis points, replacing the ST mint's. 0 removes the override"
+        )]
+        reward_multiplier_bps: u64,
+    },
+    AdminRetireStMint {
+        #[arg(long, help = "ST mint address")]
+        st_mint: String,
+        #[arg(long, help = "Epoch the ST mint is retired from")]
+        retired_epoch: u64,
     },
     AdminSetWeight {
         #[arg(long, help = "Vault address")]
@@ -191,7 +250,61 @@ pub enum ProgramCommand {
         valid_slots_after_consensus: Option<u64>,
         #[arg(long, help = "Starting valid epoch")]
         starting_valid_epoch: Option<u64>,
+        #[arg(
+            long,
+            help = "Share of total stake weight needed for consensus in basis points"
+        )]
+        consensus_threshold_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Slots after ballot box creation where votes are committed, 0 disables commit-reveal"
+        )]
+        commit_window_slots: Option<u64>,
+        #[arg(
+            long,
+            help = "Slots after consensus where late votes keep their full rewards"
+        )]
+        late_vote_grace_slots: Option<u64>,
+        #[arg(
+            long,
+            help = "Share of rewards in basis points forfeited to the DAO by the latest votes, 0 disables late vote decay"
+        )]
+        late_vote_max_decay_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Epochs after the stall where anyone can finalize the plurality ballot, 0 disables the plurality fallback"
+        )]
+        plurality_fallback_epochs: Option<u64>,
+        #[arg(
+            long,
+            help = "Share of total stake weight the plurality ballot needs in basis points"
+        )]
+        plurality_min_stake_weight_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Lamports paid from the account payer to the signer of each useful crank step, 0 disables crank bounties"
+        )]
+        crank_bounty_lamports: Option<u64>,
+        #[arg(long, help = "Maximum crank bounties paid per epoch")]
+        max_crank_bounties_per_epoch: Option<u64>,
+        #[arg(
+            long,
+            help = "Balance in lamports that routing refills the account payer to from the DAO base fee group, 0 disables top-ups"
+        )]
+        account_payer_target_balance: Option<u64>,
+        #[arg(
+            long,
+            help = "Stake weight an operator needs to be counted in the epoch snapshot, 0 disables the minimum"
+        )]
+        min_operator_stake_weight: Option<u128>,
+        #[arg(
+            long,
+            help = "Highest operator fee in basis points an operator can charge and still be active in the epoch snapshot, 0 disables the maximum"
+        )]
+        max_operator_fee_bps: Option<u16>,
     },
+    AdminCreateConfigExtension,
+    AdminCreateFeeSchedule,
     AdminSetConfigFees {
         #[arg(long, help = "New block engine fee in basis points")]
         new_block_engine_fee_bps: Option<u16>,
@@ -205,14 +318,42 @@ pub enum ProgramCommand {
         ncn_fee_group: Option<u8>,
         #[arg(long, help = "New NCN fee in basis points")]
         new_ncn_fee_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Fee schedule action, 0 to append, 1 to replace or 2 to cancel an entry"
+        )]
+        fee_schedule_action: Option<u8>,
+        #[arg(long, help = "Activation epoch of the fee schedule entry")]
+        activation_epoch: Option<u64>,
     },
-    AdminSetNewAdmin {
+    AdminProposeNewAdmin {
         #[arg(long, help = "New admin address")]
         new_admin: String,
-        #[arg(long, help = "Set fee admin")]
+        #[arg(long, help = "Propose fee admin")]
         set_fee_admin: bool,
-        #[arg(long, help = "Set tie breaker admin")]
+        #[arg(long, help = "Propose tie breaker admin")]
         set_tie_breaker_admin: bool,
+        #[arg(long, help = "Propose pause admin")]
+        set_pause_admin: bool,
+    },
+    AdminCancelNewAdmin,
+    AdminAcceptNewAdmin {
+        #[arg(long, help = "Accept fee admin")]
+        set_fee_admin: bool,
+        #[arg(long, help = "Accept tie breaker admin")]
+        set_tie_breaker_admin: bool,
+        #[arg(long, help = "Accept pause admin")]
+        set_pause_admin: bool,
+    },
+    AdminSetPause {
+        #[arg(long, help = "Pause voting")]
+        pause_voting: bool,
+        #[arg(long, help = "Pause setting merkle roots")]
+        pause_set_merkle_root: bool,
+        #[arg(long, help = "Pause routing rewards")]
+        pause_routing: bool,
+        #[arg(long, help = "Pause distributing rewards")]
+        pause_distribution: bool,
     },
     AdminFundAccountPayer {
         #[arg(long, help = "Amount of SOL to fund")]
@@ -264,6 +405,8 @@ pub enum ProgramCommand {
         meta_merkle_root: String,
     },
 
+    FinalizeStalledBallot,
+
     CreateBaseRewardRouter,
 
     CreateNcnRewardRouter {
@@ -289,6 +432,8 @@ pub enum ProgramCommand {
         ncn_fee_group: u8,
     },
 
+    SweepDust,
+
     /// Getters
     GetNcn,
     GetNcnOperatorState {
@@ -321,6 +466,10 @@ pub enum ProgramCommand {
         #[arg(long, env = "OPERATOR", help = "Operator Account Address")]
         operator: String,
     },
+    GetOperatorRecord {
+        #[arg(long, env = "OPERATOR", help = "Operator Account Address")]
+        operator: String,
+    },
     GetBallotBox,
     GetBaseRewardRouter,
     GetBaseRewardReceiverAddress,
@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, HashSet};
 use std::mem::size_of;
 use std::{fmt, time::Duration};
 
@@ -12,16 +13,19 @@ use jito_restaking_core::{
 };
 use jito_tip_router_core::{
     account_payer::AccountPayer,
-    ballot_box::BallotBox,
+    ballot_box::{Ballot, BallotBox, BallotBoxPage},
     base_fee_group::BaseFeeGroup,
-    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter, BaseRewardRouterPage},
     config::Config as TipRouterConfig,
+    config_extension::ConfigExtension,
     constants::JITOSOL_POOL_ADDRESS,
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    fee_schedule::FeeSchedule,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    operator_record::OperatorRecord,
     vault_registry::VaultRegistry,
     weight_table::WeightTable,
 };
@@ -36,7 +40,7 @@ use solana_client::{
     rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
-use solana_sdk::{account::Account, pubkey::Pubkey};
+use solana_sdk::{account::Account, hash::Hash, pubkey::Pubkey};
 use spl_associated_token_account::get_associated_token_address;
 use spl_stake_pool::{find_withdraw_authority_program_address, state::StakePool};
 use tokio::time::sleep;
@@ -99,6 +103,36 @@ pub async fn get_tip_router_config(handler: &CliHandler) -> Result<TipRouterConf
     Ok(*account)
 }
 
+/// The NCN's config extension, `None` if it has not been initialized
+pub async fn get_config_extension(handler: &CliHandler) -> Result<Option<ConfigExtension>> {
+    let (address, _, _) =
+        ConfigExtension::find_program_address(&handler.tip_router_program_id, handler.ncn()?);
+
+    let account = get_account(handler, &address).await?;
+
+    let Some(account) = account else {
+        return Ok(None);
+    };
+
+    let account = ConfigExtension::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(Some(*account))
+}
+
+/// The NCN's fee schedule, `None` if it has not been initialized
+pub async fn get_fee_schedule(handler: &CliHandler) -> Result<Option<FeeSchedule>> {
+    let (address, _, _) =
+        FeeSchedule::find_program_address(&handler.tip_router_program_id, handler.ncn()?);
+
+    let account = get_account(handler, &address).await?;
+
+    let Some(account) = account else {
+        return Ok(None);
+    };
+
+    let account = FeeSchedule::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(Some(*account))
+}
+
 pub async fn get_vault_registry(handler: &CliHandler) -> Result<VaultRegistry> {
     let (address, _, _) =
         VaultRegistry::find_program_address(&handler.tip_router_program_id, handler.ncn()?);
@@ -182,6 +216,27 @@ pub async fn get_operator_snapshot(
     Ok(*account)
 }
 
+pub async fn get_operator_record(
+    handler: &CliHandler,
+    operator: &Pubkey,
+) -> Result<OperatorRecord> {
+    let (address, _, _) = OperatorRecord::find_program_address(
+        &handler.tip_router_program_id,
+        handler.ncn()?,
+        operator,
+    );
+
+    let account = get_account(handler, &address).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Account not found"));
+    }
+    let account = account.unwrap();
+
+    let account = OperatorRecord::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
 pub async fn get_ballot_box(handler: &CliHandler, epoch: u64) -> Result<BallotBox> {
     let (address, _, _) =
         BallotBox::find_program_address(&handler.tip_router_program_id, handler.ncn()?, epoch);
@@ -197,6 +252,33 @@ pub async fn get_ballot_box(handler: &CliHandler, epoch: u64) -> Result<BallotBo
     Ok(*account)
 }
 
+pub async fn get_ballot_box_pages(
+    handler: &CliHandler,
+    epoch: u64,
+    page_count: u8,
+) -> Result<Vec<BallotBoxPage>> {
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, handler.ncn()?, epoch);
+
+    let mut pages = Vec::with_capacity(page_count as usize);
+    for page in 1..=page_count {
+        let (address, _, _) =
+            BallotBoxPage::find_program_address(&handler.tip_router_program_id, &ballot_box, page);
+
+        let account = get_account(handler, &address).await?;
+
+        if account.is_none() {
+            return Err(anyhow::anyhow!("Account not found"));
+        }
+        let account = account.unwrap();
+
+        let account = BallotBoxPage::try_from_slice_unchecked(account.data.as_slice())?;
+        pages.push(*account);
+    }
+
+    Ok(pages)
+}
+
 pub async fn get_base_reward_router(handler: &CliHandler, epoch: u64) -> Result<BaseRewardRouter> {
     let (address, _, _) = BaseRewardRouter::find_program_address(
         &handler.tip_router_program_id,
@@ -215,6 +297,39 @@ pub async fn get_base_reward_router(handler: &CliHandler, epoch: u64) -> Result<
     Ok(*account)
 }
 
+pub async fn get_base_reward_router_pages(
+    handler: &CliHandler,
+    epoch: u64,
+    page_count: u8,
+) -> Result<Vec<BaseRewardRouterPage>> {
+    let (base_reward_router, _, _) = BaseRewardRouter::find_program_address(
+        &handler.tip_router_program_id,
+        handler.ncn()?,
+        epoch,
+    );
+
+    let mut pages = Vec::with_capacity(page_count as usize);
+    for page in 1..=page_count {
+        let (address, _, _) = BaseRewardRouterPage::find_program_address(
+            &handler.tip_router_program_id,
+            &base_reward_router,
+            page,
+        );
+
+        let account = get_account(handler, &address).await?;
+
+        if account.is_none() {
+            return Err(anyhow::anyhow!("Account not found"));
+        }
+        let account = account.unwrap();
+
+        let account = BaseRewardRouterPage::try_from_slice_unchecked(account.data.as_slice())?;
+        pages.push(*account);
+    }
+
+    Ok(pages)
+}
+
 pub async fn get_base_reward_receiver(
     handler: &CliHandler,
     epoch: u64,
@@ -319,12 +434,14 @@ pub async fn get_ncn_reward_receiver_rewards(
 pub async fn get_total_rewards_to_be_distributed(handler: &CliHandler, epoch: u64) -> Result<u64> {
     let all_operators = {
         let ballot_box = get_ballot_box(handler, epoch).await?;
-        let winning_ballot = ballot_box.get_winning_ballot_tally()?;
+        let ballot_box_pages =
+            get_ballot_box_pages(handler, epoch, ballot_box.page_count()).await?;
+        let ballot_box_pages: Vec<&BallotBoxPage> = ballot_box_pages.iter().collect();
+        let winning_ballot = ballot_box.get_winning_ballot_tally(&ballot_box_pages)?;
         let winning_ballot_index = winning_ballot.index();
 
         ballot_box
-            .operator_votes()
-            .iter()
+            .all_operator_votes(&ballot_box_pages)
             .filter_map(|vote| {
                 if vote.ballot_index() == winning_ballot_index {
                     Some(*vote.operator())
@@ -590,8 +707,11 @@ pub async fn get_operator_vault_ticket(
 }
 
 pub async fn get_stake_pool(handler: &CliHandler) -> Result<StakePool> {
-    let stake_pool = JITOSOL_POOL_ADDRESS;
-    let account = get_account(handler, &stake_pool).await?;
+    get_stake_pool_at(handler, &JITOSOL_POOL_ADDRESS).await
+}
+
+pub async fn get_stake_pool_at(handler: &CliHandler, stake_pool: &Pubkey) -> Result<StakePool> {
+    let account = get_account(handler, stake_pool).await?;
 
     if account.is_none() {
         return Err(anyhow::anyhow!("Stake Pool account not found"));
@@ -614,9 +734,16 @@ pub struct StakePoolAccounts {
 }
 
 pub async fn get_stake_pool_accounts(handler: &CliHandler) -> Result<StakePoolAccounts> {
+    get_stake_pool_accounts_at(handler, &JITOSOL_POOL_ADDRESS).await
+}
+
+pub async fn get_stake_pool_accounts_at(
+    handler: &CliHandler,
+    stake_pool_address: &Pubkey,
+) -> Result<StakePoolAccounts> {
     let stake_pool_program_id = spl_stake_pool::id();
-    let stake_pool_address = JITOSOL_POOL_ADDRESS;
-    let stake_pool = get_stake_pool(handler).await?;
+    let stake_pool_address = *stake_pool_address;
+    let stake_pool = get_stake_pool_at(handler, &stake_pool_address).await?;
 
     let (stake_pool_withdraw_authority, _) =
         find_withdraw_authority_program_address(&spl_stake_pool::id(), &stake_pool_address);
@@ -810,7 +937,10 @@ pub async fn get_total_epoch_rent_cost(handler: &CliHandler) -> Result<u64> {
 
     let fee_group_count = {
         let config = get_tip_router_config(handler).await?;
-        let current_fees = config.fee_config.current_fees(current_epoch);
+        let fee_schedule = get_fee_schedule(handler).await?;
+        let current_fees = config
+            .fee_config
+            .current_fees(fee_schedule.as_ref(), current_epoch);
         let mut fee_group_count = 0;
         for group in NcnFeeGroup::all_groups() {
             let fee = current_fees.ncn_fee_bps(group)?;
@@ -839,9 +969,17 @@ pub async fn get_total_epoch_rent_cost(handler: &CliHandler) -> Result<u64> {
     rent_cost += client
         .get_minimum_balance_for_rent_exemption(BallotBox::SIZE)
         .await?;
+    rent_cost += client
+        .get_minimum_balance_for_rent_exemption(BallotBoxPage::SIZE)
+        .await?
+        * BallotBox::pages_required(operator_count)? as u64;
     rent_cost += client
         .get_minimum_balance_for_rent_exemption(BaseRewardRouter::SIZE)
         .await?;
+    rent_cost += client
+        .get_minimum_balance_for_rent_exemption(BaseRewardRouterPage::SIZE)
+        .await?
+        * BaseRewardRouter::pages_required(operator_count)? as u64;
     // Base Reward Receiver
     rent_cost += client.get_minimum_balance_for_rent_exemption(0).await?;
     rent_cost += client
@@ -1163,3 +1301,121 @@ impl fmt::Display for NcnTickets {
         Ok(())
     }
 }
+
+/// Operator votes grouped by the snapshot recorded in each voter's operator snapshot, to tell
+/// whether diverging ballots come from a different snapshot slot, bank hash or computation
+pub struct BallotDivergence {
+    pub epoch: u64,
+    pub snapshots: BTreeMap<(u64, [u8; 32]), Vec<(Pubkey, Ballot)>>,
+}
+
+impl BallotDivergence {
+    pub async fn fetch(handler: &CliHandler, epoch: u64) -> Result<Self> {
+        let ballot_box = get_ballot_box(handler, epoch).await?;
+        let ballot_box_pages =
+            get_ballot_box_pages(handler, epoch, ballot_box.page_count()).await?;
+        let ballot_box_pages: Vec<&BallotBoxPage> = ballot_box_pages.iter().collect();
+
+        let mut snapshots: BTreeMap<(u64, [u8; 32]), Vec<(Pubkey, Ballot)>> = BTreeMap::new();
+        for vote in ballot_box.all_operator_votes(&ballot_box_pages) {
+            if vote.is_empty() || !vote.has_voted() {
+                continue;
+            }
+
+            let ballot = if vote.is_abstain() {
+                Ballot::abstain()
+            } else {
+                *ballot_box
+                    .all_ballot_tallies(&ballot_box_pages)
+                    .nth(vote.ballot_index() as usize)
+                    .ok_or_else(|| anyhow::anyhow!("Ballot tally not found"))?
+                    .ballot()
+            };
+
+            let snapshot = get_operator_snapshot(handler, vote.operator(), epoch)
+                .await
+                .map(|snapshot| (snapshot.vote_snapshot_slot(), *snapshot.vote_bank_hash()))
+                .unwrap_or((0, [0; 32]));
+
+            snapshots
+                .entry(snapshot)
+                .or_default()
+                .push((*vote.operator(), ballot));
+        }
+
+        Ok(Self { epoch, snapshots })
+    }
+
+    fn reported_snapshots(&self) -> impl Iterator<Item = &(u64, [u8; 32])> {
+        self.snapshots.keys().filter(|(slot, _)| *slot != 0)
+    }
+
+    fn unique_roots(&self) -> HashSet<[u8; 32]> {
+        self.snapshots
+            .values()
+            .flatten()
+            .filter(|(_, ballot)| !ballot.is_abstain())
+            .map(|(_, ballot)| ballot.root())
+            .collect()
+    }
+
+    pub fn diagnosis(&self) -> &str {
+        if self.unique_roots().len() <= 1 {
+            return "No divergence";
+        }
+
+        let slots: HashSet<u64> = self.reported_snapshots().map(|(slot, _)| *slot).collect();
+        if slots.len() > 1 {
+            return "Operators disagreed on the snapshot slot";
+        }
+
+        if self.reported_snapshots().count() > 1 {
+            return "Operators disagreed on the bank hash at the same slot";
+        }
+
+        if self.snapshots.contains_key(&(0, [0; 32])) {
+            return "Not all operators reported their snapshot";
+        }
+
+        "Operators computed different roots from the same snapshot"
+    }
+}
+
+impl fmt::Display for BallotDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Ballot Divergence -------------")?;
+        writeln!(f, "  Epoch:                        {}", self.epoch)?;
+        writeln!(
+            f,
+            "  Snapshots:                    {}",
+            self.reported_snapshots().count()
+        )?;
+        writeln!(
+            f,
+            "  Meta Merkle Roots:            {}",
+            self.unique_roots().len()
+        )?;
+        writeln!(f, "  Diagnosis:                    {}", self.diagnosis())?;
+
+        for ((slot, bank_hash), votes) in self.snapshots.iter() {
+            if *slot == 0 {
+                writeln!(f, "\nSnapshot Not Reported:")?;
+            } else {
+                writeln!(f, "\nSnapshot Slot {}:", slot)?;
+                writeln!(
+                    f,
+                    "  Bank Hash:                    {}",
+                    Hash::new_from_array(*bank_hash)
+                )?;
+            }
+
+            for (operator, ballot) in votes {
+                writeln!(f, "  Operator:                     {}", operator)?;
+                writeln!(f, "    Ballot:                     {}", ballot)?;
+            }
+        }
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
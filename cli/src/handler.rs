@@ -5,27 +5,35 @@ use crate::{
     args::{Args, ProgramCommand},
     getters::{
         get_account_payer, get_all_operators_in_ncn, get_all_tickets, get_all_vaults_in_ncn,
-        get_ballot_box, get_base_reward_receiver, get_base_reward_router, get_current_slot,
-        get_epoch_snapshot, get_epoch_state, get_is_epoch_completed, get_ncn,
-        get_ncn_operator_state, get_ncn_reward_receiver, get_ncn_reward_router,
-        get_ncn_vault_ticket, get_operator_snapshot, get_stake_pool, get_tip_router_config,
-        get_total_epoch_rent_cost, get_total_rewards_to_be_distributed, get_vault_ncn_ticket,
-        get_vault_operator_delegation, get_vault_registry, get_weight_table,
+        get_ballot_box, get_ballot_box_pages, get_base_reward_receiver, get_base_reward_router,
+        get_config_extension, get_current_slot, get_epoch_snapshot, get_epoch_state,
+        get_is_epoch_completed, get_ncn, get_ncn_operator_state, get_ncn_reward_receiver,
+        get_ncn_reward_router, get_ncn_vault_ticket, get_operator_record, get_operator_snapshot,
+        get_stake_pool, get_tip_router_config, get_total_epoch_rent_cost,
+        get_total_rewards_to_be_distributed, get_vault_ncn_ticket, get_vault_operator_delegation,
+        get_vault_registry, get_weight_table, BallotDivergence,
     },
     instructions::{
-        admin_create_config, admin_fund_account_payer, admin_register_st_mint,
-        admin_set_config_fees, admin_set_new_admin, admin_set_parameters, admin_set_weight,
-        crank_switchboard, create_and_add_test_operator, create_and_add_test_vault,
-        create_ballot_box, create_base_reward_router, create_epoch_snapshot, create_epoch_state,
-        create_ncn_reward_router, create_operator_snapshot, create_test_ncn, create_vault_registry,
-        create_weight_table, distribute_base_ncn_rewards, register_vault, route_base_rewards,
+        admin_accept_new_admin, admin_cancel_new_admin, admin_create_config,
+        admin_create_config_extension, admin_create_fee_schedule, admin_fund_account_payer,
+        admin_propose_new_admin, admin_register_st_mint, admin_retire_st_mint, admin_retire_vault,
+        admin_set_config_fees, admin_set_parameters, admin_set_pause,
+        admin_set_st_mint_oracle_feeds, admin_set_vault_reward_asset,
+        admin_set_vault_reward_multiplier, admin_set_weight, crank_sweep_dust, crank_switchboard,
+        create_and_add_test_operator, create_and_add_test_vault, create_ballot_box,
+        create_ballot_box_pages, create_base_reward_router, create_base_reward_router_pages,
+        create_epoch_snapshot, create_epoch_state, create_ncn_reward_router,
+        create_operator_snapshot, create_test_ncn, create_vault_registry, create_weight_table,
+        distribute_base_ncn_rewards, finalize_stalled_ballot, register_vault, route_base_rewards,
         route_ncn_rewards, set_weight, snapshot_vault_operator_delegation,
     },
     keeper::keeper_loop::startup_keeper,
 };
 use anyhow::{anyhow, Result};
 use jito_tip_router_core::{
-    account_payer::AccountPayer, base_reward_router::BaseRewardReceiver, ncn_fee_group::NcnFeeGroup,
+    account_payer::AccountPayer, base_reward_router::BaseRewardReceiver,
+    constants::SWITCHBOARD_MAX_STALE_SLOTS, ncn_fee_group::NcnFeeGroup,
+    reward_asset::RewardAssetType,
 };
 use log::info;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
@@ -175,6 +183,8 @@ impl CliHandler {
                 reward_multiplier_bps,
                 switchboard_feed,
                 no_feed_weight,
+                max_weight_change_bps,
+                max_stale_slots,
             } => {
                 let vault = Pubkey::from_str(&vault).expect("error parsing vault");
                 let switchboard = switchboard_feed
@@ -188,9 +198,70 @@ impl CliHandler {
                     reward_multiplier_bps,
                     switchboard,
                     no_feed_weight,
+                    max_weight_change_bps,
+                    max_stale_slots,
                 )
                 .await
             }
+            ProgramCommand::AdminSetStMintOracleFeeds {
+                vault,
+                switchboard_feeds,
+                pyth_feeds,
+                max_feed_divergence_bps,
+            } => {
+                let vault = Pubkey::from_str(&vault).expect("error parsing vault");
+                let switchboard_feeds: Vec<Pubkey> = switchboard_feeds
+                    .iter()
+                    .map(|s| Pubkey::from_str(s).expect("error parsing switchboard feed"))
+                    .collect();
+                let pyth_feeds: Vec<Pubkey> = pyth_feeds
+                    .iter()
+                    .map(|s| Pubkey::from_str(s).expect("error parsing pyth feed"))
+                    .collect();
+                admin_set_st_mint_oracle_feeds(
+                    self,
+                    &vault,
+                    &switchboard_feeds,
+                    &pyth_feeds,
+                    max_feed_divergence_bps,
+                )
+                .await
+            }
+            ProgramCommand::AdminRetireVault {
+                vault,
+                retired_epoch,
+            } => {
+                let vault = Pubkey::from_str(&vault).expect("error parsing vault");
+                admin_retire_vault(self, &vault, retired_epoch).await
+            }
+            ProgramCommand::AdminSetVaultRewardAsset {
+                vault,
+                reward_asset_type,
+                stake_pool,
+            } => {
+                let vault = Pubkey::from_str(&vault).expect("error parsing vault");
+                let reward_asset_type = RewardAssetType::try_from(reward_asset_type)?;
+                let stake_pool = stake_pool
+                    .map(|stake_pool| {
+                        Pubkey::from_str(&stake_pool).expect("error parsing stake pool")
+                    })
+                    .unwrap_or_default();
+                admin_set_vault_reward_asset(self, &vault, reward_asset_type, &stake_pool).await
+            }
+            ProgramCommand::AdminSetVaultRewardMultiplier {
+                vault,
+                reward_multiplier_bps,
+            } => {
+                let vault = Pubkey::from_str(&vault).expect("error parsing vault");
+                admin_set_vault_reward_multiplier(self, &vault, reward_multiplier_bps).await
+            }
+            ProgramCommand::AdminRetireStMint {
+                st_mint,
+                retired_epoch,
+            } => {
+                let st_mint = Pubkey::from_str(&st_mint).expect("error parsing st mint");
+                admin_retire_st_mint(self, &st_mint, retired_epoch).await
+            }
             ProgramCommand::AdminSetWeight { vault, weight } => {
                 let vault = Pubkey::from_str(&vault).expect("error parsing vault");
                 admin_set_weight(self, &vault, self.epoch, weight).await
@@ -210,6 +281,17 @@ impl CliHandler {
                 epochs_after_consensus_before_close,
                 valid_slots_after_consensus,
                 starting_valid_epoch,
+                consensus_threshold_bps,
+                commit_window_slots,
+                late_vote_grace_slots,
+                late_vote_max_decay_bps,
+                plurality_fallback_epochs,
+                plurality_min_stake_weight_bps,
+                crank_bounty_lamports,
+                max_crank_bounties_per_epoch,
+                account_payer_target_balance,
+                min_operator_stake_weight,
+                max_operator_fee_bps,
             } => {
                 admin_set_parameters(
                     self,
@@ -217,18 +299,45 @@ impl CliHandler {
                     epochs_after_consensus_before_close,
                     valid_slots_after_consensus,
                     starting_valid_epoch,
+                    consensus_threshold_bps,
+                    commit_window_slots,
+                    late_vote_grace_slots,
+                    late_vote_max_decay_bps,
+                    plurality_fallback_epochs,
+                    plurality_min_stake_weight_bps,
+                    crank_bounty_lamports,
+                    max_crank_bounties_per_epoch,
+                    account_payer_target_balance,
+                    min_operator_stake_weight,
+                    max_operator_fee_bps,
                 )
                 .await?;
                 let config = get_tip_router_config(self).await?;
-                info!("\n\n--- Parameters Set ---\nepochs_before_stall: {}\nepochs_after_consensus_before_close: {}\nvalid_slots_after_consensus: {}\nstarting_valid_epoch: {}\n",
+                let config_extension = get_config_extension(self).await?;
+                info!("\n\n--- Parameters Set ---\nepochs_before_stall: {}\nepochs_after_consensus_before_close: {}\nvalid_slots_after_consensus: {}\nstarting_valid_epoch: {}\nconsensus_threshold_bps: {}\ncommit_window_slots: {}\nlate_vote_grace_slots: {}\nlate_vote_max_decay_bps: {}\nplurality_fallback_epochs: {}\nplurality_min_stake_weight_bps: {}\ncrank_bounty_lamports: {}\nmax_crank_bounties_per_epoch: {}\naccount_payer_target_balance: {}\nmin_operator_stake_weight: {}\nmax_operator_fee_bps: {}\n",
                     config.epochs_before_stall(),
                     config.epochs_after_consensus_before_close(),
                     config.valid_slots_after_consensus(),
-                    config.starting_valid_epoch()
+                    config.starting_valid_epoch(),
+                    config.consensus_threshold_bps(),
+                    config.commit_window_slots(),
+                    config.late_vote_grace_slots(),
+                    config.late_vote_max_decay_bps(),
+                    config.plurality_fallback_epochs(),
+                    config.plurality_min_stake_weight_bps(),
+                    config_extension.map_or(0, |extension| extension.crank_bounty_lamports()),
+                    config_extension.map_or(0, |extension| extension.max_crank_bounties_per_epoch()),
+                    config_extension.map_or(0, |extension| extension.account_payer_target_balance()),
+                    config_extension.map_or(0, |extension| extension.min_operator_stake_weight()),
+                    config_extension.map_or(0, |extension| extension.max_operator_fee_bps())
                 );
 
                 Ok(())
             }
+            ProgramCommand::AdminCreateConfigExtension {} => {
+                admin_create_config_extension(self).await
+            }
+            ProgramCommand::AdminCreateFeeSchedule {} => admin_create_fee_schedule(self).await,
             ProgramCommand::AdminSetConfigFees {
                 new_block_engine_fee_bps,
                 base_fee_group,
@@ -236,6 +345,8 @@ impl CliHandler {
                 new_base_fee_bps,
                 ncn_fee_group,
                 new_ncn_fee_bps,
+                fee_schedule_action,
+                activation_epoch,
             } => {
                 admin_set_config_fees(
                     self,
@@ -245,16 +356,50 @@ impl CliHandler {
                     new_base_fee_bps,
                     ncn_fee_group,
                     new_ncn_fee_bps,
+                    fee_schedule_action,
+                    activation_epoch,
                 )
                 .await
             }
-            ProgramCommand::AdminSetNewAdmin {
+            ProgramCommand::AdminProposeNewAdmin {
                 new_admin,
                 set_fee_admin,
                 set_tie_breaker_admin,
+                set_pause_admin,
             } => {
                 let new_admin = Pubkey::from_str(&new_admin).expect("error parsing new admin");
-                admin_set_new_admin(self, &new_admin, set_fee_admin, set_tie_breaker_admin).await
+                admin_propose_new_admin(
+                    self,
+                    &new_admin,
+                    set_fee_admin,
+                    set_tie_breaker_admin,
+                    set_pause_admin,
+                )
+                .await
+            }
+            ProgramCommand::AdminCancelNewAdmin {} => admin_cancel_new_admin(self).await,
+            ProgramCommand::AdminAcceptNewAdmin {
+                set_fee_admin,
+                set_tie_breaker_admin,
+                set_pause_admin,
+            } => {
+                admin_accept_new_admin(self, set_fee_admin, set_tie_breaker_admin, set_pause_admin)
+                    .await
+            }
+            ProgramCommand::AdminSetPause {
+                pause_voting,
+                pause_set_merkle_root,
+                pause_routing,
+                pause_distribution,
+            } => {
+                admin_set_pause(
+                    self,
+                    pause_voting,
+                    pause_set_merkle_root,
+                    pause_routing,
+                    pause_distribution,
+                )
+                .await
             }
             ProgramCommand::AdminFundAccountPayer { amount_in_sol } => {
                 admin_fund_account_payer(self, amount_in_sol).await
@@ -274,7 +419,7 @@ impl CliHandler {
             ProgramCommand::CrankSwitchboard { switchboard_feed } => {
                 let switchboard_feed =
                     Pubkey::from_str(&switchboard_feed).expect("error parsing switchboard feed");
-                crank_switchboard(self, &switchboard_feed).await
+                crank_switchboard(self, &switchboard_feed, SWITCHBOARD_MAX_STALE_SLOTS).await
             }
             ProgramCommand::SetWeight { vault } => {
                 let vault = Pubkey::from_str(&vault).expect("error parsing vault");
@@ -292,7 +437,13 @@ impl CliHandler {
                 snapshot_vault_operator_delegation(self, &vault, &operator, self.epoch).await
             }
 
-            ProgramCommand::CreateBallotBox {} => create_ballot_box(self, self.epoch).await,
+            ProgramCommand::CreateBallotBox {} => {
+                create_ballot_box(self, self.epoch).await?;
+                create_ballot_box_pages(self, self.epoch).await
+            }
+            ProgramCommand::FinalizeStalledBallot {} => {
+                finalize_stalled_ballot(self, self.epoch).await
+            }
             ProgramCommand::OperatorCastVote {
                 operator,
                 meta_merkle_root,
@@ -310,7 +461,8 @@ impl CliHandler {
             }
 
             ProgramCommand::CreateBaseRewardRouter {} => {
-                create_base_reward_router(self, self.epoch).await
+                create_base_reward_router(self, self.epoch).await?;
+                create_base_reward_router_pages(self, self.epoch).await
             }
 
             ProgramCommand::CreateNcnRewardRouter {
@@ -345,6 +497,8 @@ impl CliHandler {
                 distribute_base_ncn_rewards(self, &operator, ncn_fee_group, self.epoch).await
             }
 
+            ProgramCommand::SweepDust {} => crank_sweep_dust(self, self.epoch).await,
+
             // Getters
             ProgramCommand::GetNcn {} => {
                 let ncn = get_ncn(self).await?;
@@ -468,9 +622,24 @@ impl CliHandler {
                 info!("{}", operator_snapshot);
                 Ok(())
             }
+            ProgramCommand::GetOperatorRecord { operator } => {
+                let operator = Pubkey::from_str(&operator).expect("error parsing operator");
+                let operator_record = get_operator_record(self, &operator).await?;
+                info!("{}", operator_record);
+                Ok(())
+            }
             ProgramCommand::GetBallotBox {} => {
                 let ballot_box = get_ballot_box(self, self.epoch).await?;
                 info!("{}", ballot_box);
+
+                let ballot_box_pages =
+                    get_ballot_box_pages(self, self.epoch, ballot_box.page_count()).await?;
+                for ballot_box_page in ballot_box_pages.iter() {
+                    info!("{}", ballot_box_page);
+                }
+
+                let ballot_divergence = BallotDivergence::fetch(self, self.epoch).await?;
+                info!("{}", ballot_divergence);
                 Ok(())
             }
             ProgramCommand::GetBaseRewardReceiverAddress {} => {
@@ -4,15 +4,18 @@ use crate::{
     getters::{
         get_account, get_all_operators_in_ncn, get_all_sorted_operators_for_vault,
         get_all_vaults_in_ncn, get_ballot_box, get_base_reward_receiver_rewards,
-        get_base_reward_router, get_current_slot, get_epoch_snapshot,
-        get_ncn_reward_receiver_rewards, get_ncn_reward_router, get_operator,
-        get_operator_snapshot, get_stake_pool_accounts, get_tip_router_config, get_vault,
-        get_vault_config, get_vault_registry, get_vault_update_state_tracker, get_weight_table,
+        get_base_reward_router, get_base_reward_router_pages, get_current_epoch, get_current_slot,
+        get_epoch_snapshot, get_epoch_state, get_ncn_reward_receiver_rewards,
+        get_ncn_reward_router, get_operator, get_operator_snapshot, get_stake_pool_accounts,
+        get_stake_pool_accounts_at, get_tip_router_config, get_vault, get_vault_config,
+        get_vault_registry, get_vault_update_state_tracker, get_weight_table,
     },
     handler::CliHandler,
+    keeper::keeper_metrics::emit_weight_change_rejected,
     log::boring_progress_bar,
 };
 use anyhow::{anyhow, Ok, Result};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_restaking_client::instructions::{
     InitializeNcnBuilder, InitializeNcnOperatorStateBuilder, InitializeNcnVaultTicketBuilder,
     InitializeOperatorBuilder, InitializeOperatorVaultTicketBuilder, NcnWarmupOperatorBuilder,
@@ -25,36 +28,53 @@ use jito_restaking_core::{
 };
 use jito_tip_router_client::{
     instructions::{
-        AdminRegisterStMintBuilder, AdminSetConfigFeesBuilder, AdminSetNewAdminBuilder,
-        AdminSetParametersBuilder, AdminSetTieBreakerBuilder, AdminSetWeightBuilder,
-        CastVoteBuilder, CloseEpochAccountBuilder, DistributeBaseNcnRewardRouteBuilder,
-        DistributeBaseRewardsBuilder, DistributeNcnOperatorRewardsBuilder,
-        DistributeNcnVaultRewardsBuilder, InitializeBallotBoxBuilder,
+        AdminAcceptNewAdminBuilder, AdminCancelNewAdminBuilder,
+        AdminInitializeConfigExtensionBuilder, AdminInitializeFeeScheduleBuilder,
+        AdminProposeNewAdminBuilder, AdminRegisterStMintBuilder, AdminRetireStMintBuilder,
+        AdminRetireVaultBuilder, AdminSetConfigFeesBuilder, AdminSetParametersBuilder,
+        AdminSetPauseBuilder, AdminSetStMintOracleFeedsBuilder, AdminSetTieBreakerBuilder,
+        AdminSetVaultRewardAssetBuilder, AdminSetVaultRewardMultiplierBuilder,
+        AdminSetWeightBuilder, CastVoteBuilder, CloseEpochAccountBuilder,
+        DistributeBaseNcnRewardRouteBuilder, DistributeBaseRewardsBuilder,
+        DistributeNcnOperatorRewardsBuilder, DistributeNcnVaultRewardsBuilder,
+        FinalizeStalledBallotBuilder, InitializeBallotBoxBuilder,
         InitializeBaseRewardRouterBuilder,
         InitializeConfigBuilder as InitializeTipRouterConfigBuilder,
         InitializeEpochSnapshotBuilder, InitializeEpochStateBuilder,
-        InitializeNcnRewardRouterBuilder, InitializeOperatorSnapshotBuilder,
-        InitializeVaultRegistryBuilder, InitializeWeightTableBuilder, ReallocBallotBoxBuilder,
-        ReallocBaseRewardRouterBuilder, ReallocEpochStateBuilder, ReallocOperatorSnapshotBuilder,
-        ReallocVaultRegistryBuilder, ReallocWeightTableBuilder, RegisterVaultBuilder,
-        RouteBaseRewardsBuilder, RouteNcnRewardsBuilder, SnapshotVaultOperatorDelegationBuilder,
-        SwitchboardSetWeightBuilder,
+        InitializeNcnRewardRouterBuilder, InitializeOperatorRecordBuilder,
+        InitializeOperatorSnapshotBuilder, InitializeVaultRegistryBuilder,
+        InitializeWeightTableBuilder, MedianSetWeightBuilder, ReallocBallotBoxBuilder,
+        ReallocBallotBoxPageBuilder, ReallocBaseRewardRouterBuilder,
+        ReallocBaseRewardRouterPageBuilder, ReallocEpochStateBuilder,
+        ReallocOperatorSnapshotBuilder, ReallocVaultRegistryBuilder, ReallocWeightTableBuilder,
+        RegisterVaultBuilder, RouteBaseRewardsBuilder, RouteNcnRewardsBuilder,
+        SnapshotVaultOperatorDelegationBuilder, SweepDustBuilder, SwitchboardSetWeightBuilder,
     },
     types::ConfigAdminRole,
 };
 use jito_tip_router_core::{
     account_payer::AccountPayer,
-    ballot_box::BallotBox,
+    ballot_box::{BallotBox, BallotBoxPage},
     base_fee_group::BaseFeeGroup,
-    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter, BaseRewardRouterPage},
     config::Config as TipRouterConfig,
-    constants::{MAX_REALLOC_BYTES, SWITCHBOARD_QUEUE},
+    config_extension::ConfigExtension,
+    constants::{
+        MAX_DUST_LAMPORTS, MAX_ORACLE_FEEDS, MAX_REALLOC_BYTES, PAUSE_DISTRIBUTION, PAUSE_ROUTING,
+        PAUSE_SET_MERKLE_ROOT, PAUSE_VOTING, SWITCHBOARD_QUEUE,
+    },
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    error::TipRouterError,
+    fee_schedule::FeeSchedule,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
-    vault_registry::VaultRegistry,
+    operator_record::OperatorRecord,
+    oracle_feed::OracleFeedType,
+    reward_asset::RewardAssetType,
+    tie_breaker_committee::TieBreakerCommittee,
+    vault_registry::{StMintEntry, VaultRegistry},
     weight_table::WeightTable,
 };
 use jito_vault_client::{
@@ -73,12 +93,12 @@ use jito_vault_core::{
     vault_update_state_tracker::VaultUpdateStateTracker,
 };
 use log::info;
-use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_client::{client_error::ClientError, rpc_config::RpcSendTransactionConfig};
 
 use solana_sdk::{
     clock::DEFAULT_SLOTS_PER_EPOCH,
     compute_budget::ComputeBudgetInstruction,
-    instruction::Instruction,
+    instruction::{AccountMeta, Instruction, InstructionError},
     native_token::sol_to_lamports,
     program_pack::Pack,
     pubkey::Pubkey,
@@ -87,7 +107,7 @@ use solana_sdk::{
     signer::Signer,
     system_instruction::{create_account, transfer},
     system_program,
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
 use spl_associated_token_account::get_associated_token_address;
 use switchboard_on_demand_client::{CrossbarClient, FetchUpdateParams, PullFeed, QueueAccountData};
@@ -173,6 +193,8 @@ pub async fn admin_register_st_mint(
     reward_multiplier_bps: u64,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    max_weight_change_bps: Option<u64>,
+    max_stale_slots: Option<u64>,
 ) -> Result<()> {
     let keypair = handler.keypair()?;
 
@@ -205,6 +227,14 @@ pub async fn admin_register_st_mint(
         register_st_mint_builder.no_feed_weight(no_feed_weight);
     }
 
+    if let Some(max_weight_change_bps) = max_weight_change_bps {
+        register_st_mint_builder.max_weight_change_bps(max_weight_change_bps);
+    }
+
+    if let Some(max_stale_slots) = max_stale_slots {
+        register_st_mint_builder.max_stale_slots(max_stale_slots);
+    }
+
     let register_st_mint_ix = register_st_mint_builder.instruction();
 
     send_and_log_transaction(
@@ -222,6 +252,241 @@ pub async fn admin_register_st_mint(
                 switchboard_feed.unwrap_or_default()
             ),
             format!("No Feed Weight: {:?}", no_feed_weight.unwrap_or_default()),
+            format!(
+                "Max Weight Change BPS: {:?}",
+                max_weight_change_bps.unwrap_or_default()
+            ),
+            format!("Max Stale Slots: {:?}", max_stale_slots.unwrap_or_default()),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_set_st_mint_oracle_feeds(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    switchboard_feeds: &[Pubkey],
+    pyth_feeds: &[Pubkey],
+    max_feed_divergence_bps: u16,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let vault_account = get_vault(handler, vault).await?;
+
+    let feeds: Vec<(Pubkey, OracleFeedType)> = switchboard_feeds
+        .iter()
+        .map(|feed| (*feed, OracleFeedType::Switchboard))
+        .chain(pyth_feeds.iter().map(|feed| (*feed, OracleFeedType::Pyth)))
+        .collect();
+
+    if feeds.len() > MAX_ORACLE_FEEDS {
+        return Err(anyhow!(
+            "At most {} oracle feeds can be registered",
+            MAX_ORACLE_FEEDS
+        ));
+    }
+
+    let mut oracle_feeds = [Pubkey::default(); MAX_ORACLE_FEEDS];
+    let mut oracle_feed_types = [OracleFeedType::Switchboard as u8; MAX_ORACLE_FEEDS];
+    for (i, (feed, feed_type)) in feeds.iter().enumerate() {
+        oracle_feeds[i] = *feed;
+        oracle_feed_types[i] = *feed_type as u8;
+    }
+
+    let set_oracle_feeds_ix = AdminSetStMintOracleFeedsBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .vault_registry(vault_registry)
+        .admin(keypair.pubkey())
+        .st_mint(vault_account.supported_mint)
+        .oracle_feeds(oracle_feeds)
+        .oracle_feed_types(oracle_feed_types)
+        .max_feed_divergence_bps(max_feed_divergence_bps)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[set_oracle_feeds_ix],
+        &[],
+        "Set ST Mint Oracle Feeds",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("ST Mint: {:?}", vault_account.supported_mint),
+            format!("Oracle Feeds: {:?}", feeds),
+            format!("Max Feed Divergence BPS: {:?}", max_feed_divergence_bps),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_retire_vault(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    retired_epoch: u64,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let retire_vault_ix = AdminRetireVaultBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .vault_registry(vault_registry)
+        .vault(*vault)
+        .admin(keypair.pubkey())
+        .retired_epoch(retired_epoch)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[retire_vault_ix],
+        &[],
+        "Retired Vault",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Vault: {:?}", vault),
+            format!("Retired Epoch: {:?}", retired_epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_set_vault_reward_asset(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    reward_asset_type: RewardAssetType,
+    stake_pool: &Pubkey,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let set_vault_reward_asset_ix = AdminSetVaultRewardAssetBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .vault_registry(vault_registry)
+        .vault(*vault)
+        .admin(keypair.pubkey())
+        .reward_asset_type(reward_asset_type as u8)
+        .stake_pool(*stake_pool)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[set_vault_reward_asset_ix],
+        &[],
+        "Set Vault Reward Asset",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Vault: {:?}", vault),
+            format!("Reward Asset: {:?}", reward_asset_type),
+            format!("Stake Pool: {:?}", stake_pool),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_set_vault_reward_multiplier(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    reward_multiplier_bps: u64,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let set_vault_reward_multiplier_ix = AdminSetVaultRewardMultiplierBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .vault_registry(vault_registry)
+        .vault(*vault)
+        .admin(keypair.pubkey())
+        .reward_multiplier_bps(reward_multiplier_bps)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[set_vault_reward_multiplier_ix],
+        &[],
+        "Set Vault Reward Multiplier",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Vault: {:?}", vault),
+            format!("Reward Multiplier BPS: {:?}", reward_multiplier_bps),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_retire_st_mint(
+    handler: &CliHandler,
+    st_mint: &Pubkey,
+    retired_epoch: u64,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let retire_st_mint_ix = AdminRetireStMintBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .vault_registry(vault_registry)
+        .st_mint(*st_mint)
+        .admin(keypair.pubkey())
+        .retired_epoch(retired_epoch)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[retire_st_mint_ix],
+        &[],
+        "Retired ST Mint",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("ST Mint: {:?}", st_mint),
+            format!("Retired Epoch: {:?}", retired_epoch),
         ],
     )
     .await?;
@@ -301,14 +566,26 @@ pub async fn admin_set_tie_breaker(
     let (ballot_box, _, _) =
         BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
+    let (tie_breaker_committee, _, _) =
+        TieBreakerCommittee::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let ballot_box_page_count = get_ballot_box(handler, epoch).await?.page_count();
+
     let set_tie_breaker_ix = AdminSetTieBreakerBuilder::new()
         .epoch_state(epoch_state)
         .config(ncn_config)
         .ballot_box(ballot_box)
         .ncn(ncn)
         .tie_breaker_admin(keypair.pubkey())
+        .tie_breaker_committee(tie_breaker_committee)
         .meta_merkle_root(meta_merkle_root)
         .epoch(epoch)
+        .add_remaining_accounts(&get_ballot_box_page_metas(
+            handler,
+            &ballot_box,
+            ballot_box_page_count,
+            true,
+        ))
         .instruction();
 
     send_and_log_transaction(
@@ -327,98 +604,396 @@ pub async fn admin_set_tie_breaker(
     Ok(())
 }
 
-pub async fn admin_set_new_admin(
+pub async fn finalize_stalled_ballot(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let ballot_box_page_count = get_ballot_box(handler, epoch).await?.page_count();
+
+    let finalize_stalled_ballot_ix = FinalizeStalledBallotBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ballot_box(ballot_box)
+        .ncn(ncn)
+        .epoch_snapshot(epoch_snapshot)
+        .epoch(epoch)
+        .add_remaining_accounts(&get_ballot_box_page_metas(
+            handler,
+            &ballot_box,
+            ballot_box_page_count,
+            false,
+        ))
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[finalize_stalled_ballot_ix],
+        &[],
+        "Finalize Stalled Ballot",
+        &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Only one admin proposal can be pending at a time, so exactly one role must be selected
+fn admin_role(
+    set_fee_admin: bool,
+    set_tie_breaker_admin: bool,
+    set_pause_admin: bool,
+) -> Result<ConfigAdminRole> {
+    match (set_fee_admin, set_tie_breaker_admin, set_pause_admin) {
+        (true, false, false) => Ok(ConfigAdminRole::FeeAdmin),
+        (false, true, false) => Ok(ConfigAdminRole::TieBreakerAdmin),
+        (false, false, true) => Ok(ConfigAdminRole::PauseAdmin),
+        _ => Err(anyhow!("Exactly one admin role must be selected")),
+    }
+}
+
+pub async fn admin_propose_new_admin(
     handler: &CliHandler,
     new_admin: &Pubkey,
     set_fee_admin: bool,
     set_tie_breaker_admin: bool,
+    set_pause_admin: bool,
 ) -> Result<()> {
     let keypair = handler.keypair()?;
     let ncn = *handler.ncn()?;
 
     let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    let roles = [
-        (set_fee_admin, ConfigAdminRole::FeeAdmin),
-        (set_tie_breaker_admin, ConfigAdminRole::TieBreakerAdmin),
-    ];
+    let role = admin_role(set_fee_admin, set_tie_breaker_admin, set_pause_admin)?;
 
-    for (should_set, role) in roles.iter() {
-        if !should_set {
-            continue;
-        }
+    let mut ix = AdminProposeNewAdminBuilder::new();
+    ix.config(config_pda)
+        .ncn(ncn)
+        .ncn_admin(keypair.pubkey())
+        .new_admin(*new_admin)
+        .role(role);
 
-        let mut ix = AdminSetNewAdminBuilder::new();
-        ix.config(config_pda)
-            .ncn(ncn)
-            .ncn_admin(keypair.pubkey())
-            .new_admin(*new_admin)
-            .role(*role);
+    send_and_log_transaction(
+        handler,
+        &[ix.instruction()],
+        &[],
+        "Admin Propose New Admin",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("New Admin: {:?}", new_admin),
+            format!("Role: {:?}", role),
+        ],
+    )
+    .await?;
 
-        send_and_log_transaction(
-            handler,
-            &[ix.instruction()],
-            &[],
-            "Admin Set New Admin",
-            &[
-                format!("NCN: {:?}", ncn),
-                format!("New Admin: {:?}", new_admin),
-                format!("Role: {:?}", role),
-            ],
-        )
-        .await?;
+    Ok(())
+}
+
+pub async fn admin_cancel_new_admin(handler: &CliHandler) -> Result<()> {
+    let keypair = handler.keypair()?;
+    let ncn = *handler.ncn()?;
+
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+
+    let mut ix = AdminCancelNewAdminBuilder::new();
+    ix.config(config_pda).ncn(ncn).ncn_admin(keypair.pubkey());
+
+    send_and_log_transaction(
+        handler,
+        &[ix.instruction()],
+        &[],
+        "Admin Cancel New Admin",
+        &[format!("NCN: {:?}", ncn)],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_accept_new_admin(
+    handler: &CliHandler,
+    set_fee_admin: bool,
+    set_tie_breaker_admin: bool,
+    set_pause_admin: bool,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+    let ncn = *handler.ncn()?;
+
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+
+    let role = admin_role(set_fee_admin, set_tie_breaker_admin, set_pause_admin)?;
+
+    let mut ix = AdminAcceptNewAdminBuilder::new();
+    ix.config(config_pda)
+        .ncn(ncn)
+        .new_admin(keypair.pubkey())
+        .role(role);
+
+    send_and_log_transaction(
+        handler,
+        &[ix.instruction()],
+        &[],
+        "Admin Accept New Admin",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("New Admin: {:?}", keypair.pubkey()),
+            format!("Role: {:?}", role),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_set_pause(
+    handler: &CliHandler,
+    pause_voting: bool,
+    pause_set_merkle_root: bool,
+    pause_routing: bool,
+    pause_distribution: bool,
+) -> Result<()> {
+    let keypair = handler.keypair()?;
+    let ncn = *handler.ncn()?;
+
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+
+    let mut paused = 0;
+    if pause_voting {
+        paused |= PAUSE_VOTING;
+    }
+    if pause_set_merkle_root {
+        paused |= PAUSE_SET_MERKLE_ROOT;
     }
+    if pause_routing {
+        paused |= PAUSE_ROUTING;
+    }
+    if pause_distribution {
+        paused |= PAUSE_DISTRIBUTION;
+    }
+
+    let mut ix = AdminSetPauseBuilder::new();
+    ix.config(config_pda)
+        .ncn(ncn)
+        .pause_admin(keypair.pubkey())
+        .paused(paused);
+
+    send_and_log_transaction(
+        handler,
+        &[ix.instruction()],
+        &[],
+        "Admin Set Pause",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Pause Voting: {:?}", pause_voting),
+            format!("Pause Set Merkle Root: {:?}", pause_set_merkle_root),
+            format!("Pause Routing: {:?}", pause_routing),
+            format!("Pause Distribution: {:?}", pause_distribution),
+        ],
+    )
+    .await?;
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn admin_set_parameters(
     handler: &CliHandler,
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
     starting_valid_epoch: Option<u64>,
+    consensus_threshold_bps: Option<u16>,
+    commit_window_slots: Option<u64>,
+    late_vote_grace_slots: Option<u64>,
+    late_vote_max_decay_bps: Option<u16>,
+    plurality_fallback_epochs: Option<u64>,
+    plurality_min_stake_weight_bps: Option<u16>,
+    crank_bounty_lamports: Option<u64>,
+    max_crank_bounties_per_epoch: Option<u64>,
+    account_payer_target_balance: Option<u64>,
+    min_operator_stake_weight: Option<u128>,
+    max_operator_fee_bps: Option<u16>,
 ) -> Result<()> {
     let keypair = handler.keypair()?;
     let ncn = *handler.ncn()?;
 
     let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+    let config_extension =
+        ConfigExtension::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
     let mut ix = AdminSetParametersBuilder::new();
-    ix.config(config_pda).ncn(ncn).ncn_admin(keypair.pubkey());
+    ix.config(config_pda)
+        .ncn(ncn)
+        .ncn_admin(keypair.pubkey())
+        .config_extension(config_extension);
+
+    if let Some(epochs) = epochs_before_stall {
+        ix.epochs_before_stall(epochs);
+    }
+
+    if let Some(epochs) = epochs_after_consensus_before_close {
+        ix.epochs_after_consensus_before_close(epochs);
+    }
+
+    if let Some(slots) = valid_slots_after_consensus {
+        ix.valid_slots_after_consensus(slots);
+    }
+
+    if let Some(epoch) = starting_valid_epoch {
+        ix.starting_valid_epoch(epoch);
+    }
+
+    if let Some(bps) = consensus_threshold_bps {
+        ix.consensus_threshold_bps(bps);
+    }
+
+    if let Some(slots) = commit_window_slots {
+        ix.commit_window_slots(slots);
+    }
+
+    if let Some(slots) = late_vote_grace_slots {
+        ix.late_vote_grace_slots(slots);
+    }
+
+    if let Some(bps) = late_vote_max_decay_bps {
+        ix.late_vote_max_decay_bps(bps);
+    }
+
+    if let Some(epochs) = plurality_fallback_epochs {
+        ix.plurality_fallback_epochs(epochs);
+    }
+
+    if let Some(bps) = plurality_min_stake_weight_bps {
+        ix.plurality_min_stake_weight_bps(bps);
+    }
+
+    if let Some(lamports) = crank_bounty_lamports {
+        ix.crank_bounty_lamports(lamports);
+    }
+
+    if let Some(count) = max_crank_bounties_per_epoch {
+        ix.max_crank_bounties_per_epoch(count);
+    }
+
+    if let Some(lamports) = account_payer_target_balance {
+        ix.account_payer_target_balance(lamports);
+    }
+
+    if let Some(stake_weight) = min_operator_stake_weight {
+        ix.min_operator_stake_weight(stake_weight);
+    }
+
+    if let Some(bps) = max_operator_fee_bps {
+        ix.max_operator_fee_bps(bps);
+    }
+
+    send_and_log_transaction(
+        handler,
+        &[ix.instruction()],
+        &[],
+        "Set Parameters",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Epochs Before Stall: {:?}", epochs_before_stall),
+            format!(
+                "Epochs After Consensus Before Close: {:?}",
+                epochs_after_consensus_before_close
+            ),
+            format!(
+                "Valid Slots After Consensus: {:?}",
+                valid_slots_after_consensus
+            ),
+            format!("Consensus Threshold Bps: {:?}", consensus_threshold_bps),
+            format!("Commit Window Slots: {:?}", commit_window_slots),
+            format!("Late Vote Grace Slots: {:?}", late_vote_grace_slots),
+            format!("Late Vote Max Decay Bps: {:?}", late_vote_max_decay_bps),
+            format!("Plurality Fallback Epochs: {:?}", plurality_fallback_epochs),
+            format!(
+                "Plurality Min Stake Weight Bps: {:?}",
+                plurality_min_stake_weight_bps
+            ),
+            format!("Crank Bounty Lamports: {:?}", crank_bounty_lamports),
+            format!(
+                "Max Crank Bounties Per Epoch: {:?}",
+                max_crank_bounties_per_epoch
+            ),
+            format!(
+                "Account Payer Target Balance: {:?}",
+                account_payer_target_balance
+            ),
+            format!("Min Operator Stake Weight: {:?}", min_operator_stake_weight),
+            format!("Max Operator Fee BPS: {:?}", max_operator_fee_bps),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Result<()> {
+    let keypair = handler.keypair()?;
+    let ncn = *handler.ncn()?;
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let transfer_ix = transfer(&keypair.pubkey(), &account_payer, sol_to_lamports(amount));
+
+    send_and_log_transaction(
+        handler,
+        &[transfer_ix],
+        &[],
+        "Fund Account Payer",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Amount: {:?} SOL", amount),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn admin_create_config_extension(handler: &CliHandler) -> Result<()> {
+    let keypair = handler.keypair()?;
+    let ncn = *handler.ncn()?;
 
-    if let Some(epochs) = epochs_before_stall {
-        ix.epochs_before_stall(epochs);
-    }
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    if let Some(epochs) = epochs_after_consensus_before_close {
-        ix.epochs_after_consensus_before_close(epochs);
-    }
+    let (config_extension, _, _) =
+        ConfigExtension::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    if let Some(slots) = valid_slots_after_consensus {
-        ix.valid_slots_after_consensus(slots);
-    }
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    if let Some(epoch) = starting_valid_epoch {
-        ix.starting_valid_epoch(epoch);
-    }
+    let initialize_config_extension_ix = AdminInitializeConfigExtensionBuilder::new()
+        .config(config)
+        .config_extension(config_extension)
+        .ncn(ncn)
+        .ncn_admin(keypair.pubkey())
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .instruction();
 
     send_and_log_transaction(
         handler,
-        &[ix.instruction()],
+        &[initialize_config_extension_ix],
         &[],
-        "Set Parameters",
+        "Created Config Extension",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Epochs Before Stall: {:?}", epochs_before_stall),
-            format!(
-                "Epochs After Consensus Before Close: {:?}",
-                epochs_after_consensus_before_close
-            ),
-            format!(
-                "Valid Slots After Consensus: {:?}",
-                valid_slots_after_consensus
-            ),
+            format!("Config Extension: {:?}", config_extension),
         ],
     )
     .await?;
@@ -426,23 +1001,36 @@ pub async fn admin_set_parameters(
     Ok(())
 }
 
-pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Result<()> {
+pub async fn admin_create_fee_schedule(handler: &CliHandler) -> Result<()> {
     let keypair = handler.keypair()?;
     let ncn = *handler.ncn()?;
 
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (fee_schedule, _, _) =
+        FeeSchedule::find_program_address(&handler.tip_router_program_id, &ncn);
+
     let (account_payer, _, _) =
         AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let transfer_ix = transfer(&keypair.pubkey(), &account_payer, sol_to_lamports(amount));
+    let initialize_fee_schedule_ix = AdminInitializeFeeScheduleBuilder::new()
+        .config(config)
+        .fee_schedule(fee_schedule)
+        .ncn(ncn)
+        .fee_admin(keypair.pubkey())
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .instruction();
 
     send_and_log_transaction(
         handler,
-        &[transfer_ix],
+        &[initialize_fee_schedule_ix],
         &[],
-        "Fund Account Payer",
+        "Created Fee Schedule",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Amount: {:?} SOL", amount),
+            format!("Fee Schedule: {:?}", fee_schedule),
         ],
     )
     .await?;
@@ -450,6 +1038,7 @@ pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Resu
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn admin_set_config_fees(
     handler: &CliHandler,
     new_block_engine_fee_bps: Option<u16>,
@@ -458,14 +1047,20 @@ pub async fn admin_set_config_fees(
     new_base_fee_bps: Option<u16>,
     ncn_fee_group: Option<u8>,
     new_ncn_fee_bps: Option<u16>,
+    fee_schedule_action: Option<u8>,
+    activation_epoch: Option<u64>,
 ) -> Result<()> {
     let keypair = handler.keypair()?;
     let ncn = *handler.ncn()?;
 
     let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+    let fee_schedule = FeeSchedule::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
     let mut ix = AdminSetConfigFeesBuilder::new();
-    ix.config(config_pda).ncn(ncn).ncn_admin(keypair.pubkey());
+    ix.config(config_pda)
+        .ncn(ncn)
+        .ncn_admin(keypair.pubkey())
+        .fee_schedule(fee_schedule);
 
     if let Some(fee) = new_block_engine_fee_bps {
         ix.new_block_engine_fee_bps(fee);
@@ -492,6 +1087,14 @@ pub async fn admin_set_config_fees(
         ix.new_ncn_fee_bps(fee);
     }
 
+    if let Some(action) = fee_schedule_action {
+        ix.fee_schedule_action(action);
+    }
+
+    if let Some(epoch) = activation_epoch {
+        ix.activation_epoch(epoch);
+    }
+
     send_and_log_transaction(
         handler,
         &[ix.instruction()],
@@ -505,6 +1108,8 @@ pub async fn admin_set_config_fees(
             format!("New Base Fee BPS: {:?}", new_base_fee_bps),
             format!("NCN Fee Group: {:?}", ncn_fee_group),
             format!("New NCN Fee BPS: {:?}", new_ncn_fee_bps),
+            format!("Fee Schedule Action: {:?}", fee_schedule_action),
+            format!("Activation Epoch: {:?}", activation_epoch),
         ],
     )
     .await?;
@@ -655,6 +1260,9 @@ pub async fn create_epoch_state(handler: &CliHandler, epoch: u64) -> Result<()>
     // Number of reallocations needed based on BallotBox::SIZE
     let num_reallocs = (EpochState::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
 
+    let (config_extension, _, _) =
+        ConfigExtension::find_program_address(&handler.tip_router_program_id, &ncn);
+
     // Realloc ballot box
     let realloc_ballot_box_ix = ReallocEpochStateBuilder::new()
         .config(config)
@@ -663,6 +1271,7 @@ pub async fn create_epoch_state(handler: &CliHandler, epoch: u64) -> Result<()>
         .epoch(epoch)
         .account_payer(account_payer)
         .system_program(system_program::id())
+        .config_extension(config_extension)
         .instruction();
 
     let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
@@ -770,7 +1379,26 @@ pub async fn create_weight_table(handler: &CliHandler, epoch: u64) -> Result<()>
     Ok(())
 }
 
-pub async fn crank_switchboard(handler: &CliHandler, switchboard_feed: &Pubkey) -> Result<()> {
+pub async fn is_switchboard_feed_stale(
+    handler: &CliHandler,
+    switchboard_feed: &Pubkey,
+    max_stale_slots: u64,
+) -> Result<bool> {
+    let feed = PullFeed::load_data(handler.rpc_client(), switchboard_feed)
+        .await
+        .map_err(|e| anyhow!("Failed to load switchboard feed: {:?}", e))?;
+
+    let current_slot = get_current_slot(handler).await?;
+    let stale_slot = feed.result.slot.saturating_add(max_stale_slots);
+
+    Ok(current_slot > stale_slot)
+}
+
+pub async fn crank_switchboard(
+    handler: &CliHandler,
+    switchboard_feed: &Pubkey,
+    max_stale_slots: u64,
+) -> Result<()> {
     async fn wait_for_x_slots_after_epoch(handler: &CliHandler, slots: u64) -> Result<()> {
         loop {
             let current_slot = handler.rpc_client().get_slot().await?;
@@ -792,6 +1420,14 @@ pub async fn crank_switchboard(handler: &CliHandler, switchboard_feed: &Pubkey)
 
     wait_for_x_slots_after_epoch(handler, 400).await?;
 
+    if !is_switchboard_feed_stale(handler, switchboard_feed, max_stale_slots).await? {
+        log::info!(
+            "Switchboard feed {:?} is not stale, skipping crank",
+            switchboard_feed
+        );
+        return Ok(());
+    }
+
     // STATIC PUBKEY
     let queue_key = SWITCHBOARD_QUEUE;
 
@@ -847,6 +1483,11 @@ pub async fn set_weight_with_st_mint(
     let vault_registry = get_vault_registry(handler).await?;
 
     let mint_entry = vault_registry.get_mint_entry(st_mint)?;
+
+    if mint_entry.has_oracle_feeds() {
+        return median_set_weight(handler, &mint_entry, epoch).await;
+    }
+
     let switchboard_feed = mint_entry.switchboard_feed();
 
     let (epoch_state, _, _) =
@@ -855,8 +1496,14 @@ pub async fn set_weight_with_st_mint(
     let (weight_table, _, _) =
         WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
+    let (previous_weight_table, _, _) = WeightTable::find_program_address(
+        &handler.tip_router_program_id,
+        &ncn,
+        epoch.saturating_sub(1),
+    );
+
     // Crank Switchboard
-    let result = crank_switchboard(handler, switchboard_feed).await;
+    let result = crank_switchboard(handler, switchboard_feed, mint_entry.max_stale_slots()).await;
     if let Err(e) = result {
         log::error!(
             "\n\nFailed to crank switchboard - will need manual crank at {}\n\nError:\n{:?}\n",
@@ -871,6 +1518,7 @@ pub async fn set_weight_with_st_mint(
     let set_weight_ix = SwitchboardSetWeightBuilder::new()
         .ncn(ncn)
         .weight_table(weight_table)
+        .previous_weight_table(previous_weight_table)
         .epoch_state(epoch_state)
         .st_mint(*st_mint)
         .switchboard_feed(*switchboard_feed)
@@ -894,12 +1542,91 @@ pub async fn set_weight_with_st_mint(
     Ok(())
 }
 
+pub async fn median_set_weight(
+    handler: &CliHandler,
+    mint_entry: &StMintEntry,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+    let st_mint = mint_entry.st_mint();
+    let oracle_feeds = mint_entry.registered_oracle_feeds();
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (previous_weight_table, _, _) = WeightTable::find_program_address(
+        &handler.tip_router_program_id,
+        &ncn,
+        epoch.saturating_sub(1),
+    );
+
+    // Crank Switchboard
+    for oracle_feed in oracle_feeds.iter() {
+        if oracle_feed.feed_type()? != OracleFeedType::Switchboard {
+            continue;
+        }
+
+        let result =
+            crank_switchboard(handler, oracle_feed.feed(), mint_entry.max_stale_slots()).await;
+        if let Err(e) = result {
+            log::error!(
+                "\n\nFailed to crank switchboard - will need manual crank at {}\n\nError:\n{:?}\n",
+                format!(
+                    "https://ondemand.switchboard.xyz/solana/mainnet/feed/{}",
+                    oracle_feed.feed()
+                ),
+                e
+            );
+        }
+    }
+
+    let oracle_feed_metas: Vec<AccountMeta> = oracle_feeds
+        .iter()
+        .map(|oracle_feed| AccountMeta::new_readonly(*oracle_feed.feed(), false))
+        .collect();
+
+    let set_weight_ix = MedianSetWeightBuilder::new()
+        .ncn(ncn)
+        .weight_table(weight_table)
+        .previous_weight_table(previous_weight_table)
+        .epoch_state(epoch_state)
+        .st_mint(*st_mint)
+        .epoch(epoch)
+        .add_remaining_accounts(&oracle_feed_metas)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[set_weight_ix],
+        &[],
+        "Set Weight Using Median Of Oracle Feeds",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Epoch: {:?}", epoch),
+            format!("ST Mint: {:?}", st_mint),
+            format!(
+                "Oracle Feeds: {:?}",
+                oracle_feeds.iter().map(|f| f.feed()).collect::<Vec<_>>()
+            ),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
 pub async fn create_epoch_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
     let ncn = *handler.ncn()?;
 
     let (config, _, _) =
         TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
+    let (fee_schedule, _, _) =
+        FeeSchedule::find_program_address(&handler.tip_router_program_id, &ncn);
+
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
@@ -917,6 +1644,7 @@ pub async fn create_epoch_snapshot(handler: &CliHandler, epoch: u64) -> Result<(
     let initialize_epoch_snapshot_ix = InitializeEpochSnapshotBuilder::new()
         .epoch_marker(epoch_marker)
         .config(config)
+        .fee_schedule(fee_schedule)
         .ncn(ncn)
         .epoch_state(epoch_state)
         .weight_table(weight_table)
@@ -971,6 +1699,36 @@ pub async fn create_operator_snapshot(
     let (epoch_marker, _, _) =
         EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
 
+    let (operator_record, _, _) =
+        OperatorRecord::find_program_address(&handler.tip_router_program_id, &ncn, &operator);
+
+    let operator_record_account = get_account(handler, &operator_record).await?;
+
+    // The operator record outlives epochs, it only needs to be created once
+    if operator_record_account.is_none() {
+        let initialize_operator_record_ix = InitializeOperatorRecordBuilder::new()
+            .config(config)
+            .operator_record(operator_record)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .instruction();
+
+        send_and_log_transaction(
+            handler,
+            &[initialize_operator_record_ix],
+            &[],
+            "Initialized Operator Record",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Operator: {:?}", operator),
+            ],
+        )
+        .await?;
+    }
+
     let operator_snapshot_account = get_account(handler, &operator_snapshot).await?;
 
     // Skip if operator snapshot already exists
@@ -1007,6 +1765,9 @@ pub async fn create_operator_snapshot(
     // Number of reallocations needed based on OperatorSnapshot::SIZE
     let num_reallocs = (OperatorSnapshot::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
 
+    let (config_extension, _, _) =
+        ConfigExtension::find_program_address(&handler.tip_router_program_id, &ncn);
+
     // Realloc operator snapshot
     let realloc_operator_snapshot_ix = ReallocOperatorSnapshotBuilder::new()
         .config(config)
@@ -1019,6 +1780,7 @@ pub async fn create_operator_snapshot(
         .operator_snapshot(operator_snapshot)
         .account_payer(account_payer)
         .system_program(system_program::id())
+        .config_extension(config_extension)
         .epoch(epoch)
         .instruction();
 
@@ -1087,6 +1849,9 @@ pub async fn snapshot_vault_operator_delegation(
         epoch,
     );
 
+    let (config_extension, _, _) =
+        ConfigExtension::find_program_address(&handler.tip_router_program_id, &ncn);
+
     let snapshot_vault_operator_delegation_ix = SnapshotVaultOperatorDelegationBuilder::new()
         .config(config)
         .epoch_state(epoch_state)
@@ -1100,6 +1865,7 @@ pub async fn snapshot_vault_operator_delegation(
         .weight_table(weight_table)
         .epoch_snapshot(epoch_snapshot)
         .operator_snapshot(operator_snapshot)
+        .config_extension(config_extension)
         .epoch(epoch)
         .instruction();
 
@@ -1199,6 +1965,68 @@ pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
     Ok(())
 }
 
+pub async fn create_ballot_box_pages(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let page_count = get_ballot_box(handler, epoch).await?.page_count();
+    let pages_required =
+        BallotBox::pages_required(get_epoch_state(handler, epoch).await?.operator_count())?;
+
+    // Number of instructions needed to create and reallocate a page to BallotBoxPage::SIZE
+    let num_reallocs = (BallotBoxPage::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64;
+
+    for page in page_count + 1..=pages_required {
+        let (ballot_box_page, _, _) =
+            BallotBoxPage::find_program_address(&handler.tip_router_program_id, &ballot_box, page);
+
+        let realloc_ballot_box_page_ix = ReallocBallotBoxPageBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ballot_box(ballot_box)
+            .ballot_box_page(ballot_box_page)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .page(page)
+            .instruction();
+
+        let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize + 1);
+        realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
+        for _ in 0..num_reallocs {
+            realloc_ixs.push(realloc_ballot_box_page_ix.clone());
+        }
+
+        send_and_log_transaction(
+            handler,
+            &realloc_ixs,
+            &[],
+            "Reallocated Ballot Box Page",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Epoch: {:?}", epoch),
+                format!("Page: {:?}", page),
+                format!("Number of reallocations: {:?}", num_reallocs),
+            ],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
 pub async fn operator_cast_vote(
     handler: &CliHandler,
     operator: &Pubkey,
@@ -1230,6 +2058,11 @@ pub async fn operator_cast_vote(
         epoch,
     );
 
+    let (operator_record, _, _) =
+        OperatorRecord::find_program_address(&handler.tip_router_program_id, &ncn, &operator);
+
+    let ballot_box_page_count = get_ballot_box(handler, epoch).await?.page_count();
+
     let cast_vote_ix = CastVoteBuilder::new()
         .config(config)
         .epoch_state(epoch_state)
@@ -1239,8 +2072,21 @@ pub async fn operator_cast_vote(
         .operator_snapshot(operator_snapshot)
         .operator(operator)
         .operator_voter(keypair.pubkey())
+        .operator_record(operator_record)
         .meta_merkle_root(meta_merkle_root)
         .epoch(epoch)
+        .abstain(false)
+        // Votes cast from the CLI are not computed from a snapshot, nor report its tip
+        // distribution account count
+        .snapshot_slot(0)
+        .bank_hash([0; 32])
+        .tip_distribution_account_count(0)
+        .add_remaining_accounts(&get_ballot_box_page_metas(
+            handler,
+            &ballot_box,
+            ballot_box_page_count,
+            true,
+        ))
         .instruction();
 
     send_and_log_transaction(
@@ -1305,35 +2151,100 @@ pub async fn create_base_reward_router(handler: &CliHandler, epoch: u64) -> Resu
     // Number of reallocations needed based on BaseRewardRouter::SIZE
     let num_reallocs = (BaseRewardRouter::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
 
-    let realloc_base_reward_router_ix = ReallocBaseRewardRouterBuilder::new()
-        .config(TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0)
-        .epoch_state(epoch_state)
-        .base_reward_router(base_reward_router)
-        .ncn(ncn)
-        .epoch(epoch)
-        .account_payer(account_payer)
-        .system_program(system_program::id())
-        .instruction();
+    let realloc_base_reward_router_ix = ReallocBaseRewardRouterBuilder::new()
+        .config(TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0)
+        .epoch_state(epoch_state)
+        .base_reward_router(base_reward_router)
+        .ncn(ncn)
+        .epoch(epoch)
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .instruction();
+
+    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
+    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
+    for _ in 0..num_reallocs {
+        realloc_ixs.push(realloc_base_reward_router_ix.clone());
+    }
+
+    send_and_log_transaction(
+        handler,
+        &realloc_ixs,
+        &[],
+        "Reallocated Base Reward Router",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Epoch: {:?}", epoch),
+            format!("Number of reallocations: {:?}", num_reallocs),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn create_base_reward_router_pages(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_router, _, _) =
+        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let page_count = get_base_reward_router(handler, epoch).await?.page_count();
+    let pages_required =
+        BaseRewardRouter::pages_required(get_epoch_state(handler, epoch).await?.operator_count())?;
+
+    // Number of instructions needed to create and reallocate a page to BaseRewardRouterPage::SIZE
+    let num_reallocs = (BaseRewardRouterPage::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64;
+
+    for page in page_count + 1..=pages_required {
+        let (base_reward_router_page, _, _) = BaseRewardRouterPage::find_program_address(
+            &handler.tip_router_program_id,
+            &base_reward_router,
+            page,
+        );
+
+        let realloc_base_reward_router_page_ix = ReallocBaseRewardRouterPageBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .base_reward_router(base_reward_router)
+            .base_reward_router_page(base_reward_router_page)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .page(page)
+            .instruction();
+
+        let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize + 1);
+        realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
+        for _ in 0..num_reallocs {
+            realloc_ixs.push(realloc_base_reward_router_page_ix.clone());
+        }
 
-    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
-    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
-    for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_base_reward_router_ix.clone());
+        send_and_log_transaction(
+            handler,
+            &realloc_ixs,
+            &[],
+            "Reallocated Base Reward Router Page",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Epoch: {:?}", epoch),
+                format!("Page: {:?}", page),
+                format!("Number of reallocations: {:?}", num_reallocs),
+            ],
+        )
+        .await?;
     }
 
-    send_and_log_transaction(
-        handler,
-        &realloc_ixs,
-        &[],
-        "Reallocated Base Reward Router",
-        &[
-            format!("NCN: {:?}", ncn),
-            format!("Epoch: {:?}", epoch),
-            format!("Number of reallocations: {:?}", num_reallocs),
-        ],
-    )
-    .await?;
-
     Ok(())
 }
 
@@ -1429,6 +2340,26 @@ pub async fn route_base_rewards(handler: &CliHandler, epoch: u64) -> Result<()>
     let (base_reward_receiver, _, _) =
         BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (config_extension, _, _) =
+        ConfigExtension::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    // Ballot box pages are followed by the base reward router pages
+    let mut page_metas = get_ballot_box_page_metas(
+        handler,
+        &ballot_box,
+        get_ballot_box(handler, epoch).await?.page_count(),
+        false,
+    );
+    page_metas.extend(get_base_reward_router_page_metas(
+        handler,
+        &base_reward_router,
+        get_base_reward_router(handler, epoch).await?.page_count(),
+        true,
+    ));
+
     // Using max iterations defined in BaseRewardRouter
     let max_iterations: u16 = BaseRewardRouter::MAX_ROUTE_BASE_ITERATIONS;
 
@@ -1442,8 +2373,12 @@ pub async fn route_base_rewards(handler: &CliHandler, epoch: u64) -> Result<()>
             .ballot_box(ballot_box)
             .base_reward_router(base_reward_router)
             .base_reward_receiver(base_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .config_extension(config_extension)
             .max_iterations(max_iterations)
             .epoch(epoch)
+            .add_remaining_accounts(&page_metas)
             .instruction();
 
         let instructions = vec![
@@ -1485,6 +2420,9 @@ pub async fn route_ncn_rewards(
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
     let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
         &handler.tip_router_program_id,
         &operator,
@@ -1515,6 +2453,7 @@ pub async fn route_ncn_rewards(
     while still_routing {
         let route_ncn_rewards_ix = RouteNcnRewardsBuilder::new()
             .epoch_state(epoch_state)
+            .config(config)
             .ncn(ncn)
             .operator(operator)
             .operator_snapshot(operator_snapshot)
@@ -1592,6 +2531,8 @@ pub async fn distribute_base_ncn_rewards(
         epoch,
     );
 
+    let base_reward_router_page_count = get_base_reward_router(handler, epoch).await?.page_count();
+
     let distribute_base_ncn_rewards_ix = DistributeBaseNcnRewardRouteBuilder::new()
         .epoch_state(epoch_state)
         .config(ncn_config)
@@ -1604,6 +2545,12 @@ pub async fn distribute_base_ncn_rewards(
         .system_program(system_program::id())
         .ncn_fee_group(ncn_fee_group.group)
         .epoch(epoch)
+        .add_remaining_accounts(&get_base_reward_router_page_metas(
+            handler,
+            &base_reward_router,
+            base_reward_router_page_count,
+            true,
+        ))
         .instruction();
 
     send_and_log_transaction(
@@ -1739,16 +2686,41 @@ pub async fn distribute_ncn_vault_rewards(
         epoch,
     );
 
-    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let reward_asset = *get_vault_registry(handler)
+        .await?
+        .get_vault_entry(vault)?
+        .reward_asset();
+
+    // SOL rewards skip the stake pool, the JitoSOL pool accounts are passed but unused
+    let (stake_pool_accounts, vault_mint) = match reward_asset.asset_type()? {
+        RewardAssetType::JitoSol => {
+            let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+            let pool_mint = stake_pool_accounts.stake_pool.pool_mint;
+            (stake_pool_accounts, pool_mint)
+        }
+        RewardAssetType::StakePool => {
+            let stake_pool_accounts =
+                get_stake_pool_accounts_at(handler, reward_asset.stake_pool()).await?;
+            let pool_mint = stake_pool_accounts.stake_pool.pool_mint;
+            (stake_pool_accounts, pool_mint)
+        }
+        RewardAssetType::Sol => (
+            get_stake_pool_accounts(handler).await?,
+            spl_token::native_mint::id(),
+        ),
+    };
 
     let vault = *vault;
-    let vault_ata = get_associated_token_address(&vault, &stake_pool_accounts.stake_pool.pool_mint);
+    let vault_ata = get_associated_token_address(&vault, &vault_mint);
 
     let create_vault_ata_ix =
         spl_associated_token_account::instruction::create_associated_token_account_idempotent(
             &keypair.pubkey(),
             &vault,
-            &stake_pool_accounts.stake_pool.pool_mint,
+            &vault_mint,
             &handler.token_program_id,
         );
 
@@ -1771,6 +2743,7 @@ pub async fn distribute_ncn_vault_rewards(
         .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
         .token_program(handler.token_program_id)
         .system_program(system_program::id())
+        .vault_registry(vault_registry)
         .ncn_fee_group(ncn_fee_group.group)
         .epoch(epoch)
         .instruction();
@@ -1907,6 +2880,54 @@ pub async fn distribute_ncn_operator_rewards(
     Ok(())
 }
 
+pub async fn sweep_dust(
+    handler: &CliHandler,
+    reward_router: &Pubkey,
+    reward_receiver: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let config_account = get_tip_router_config(handler).await?;
+    let dao_wallet = *config_account
+        .fee_config
+        .base_fee_wallet(BaseFeeGroup::dao())?;
+
+    let ix = SweepDustBuilder::new()
+        .epoch_state(epoch_state)
+        .config(config)
+        .ncn(ncn)
+        .reward_router(*reward_router)
+        .reward_receiver(*reward_receiver)
+        .dao_wallet(dao_wallet)
+        .system_program(system_program::id())
+        .epoch(epoch)
+        .instruction();
+
+    send_and_log_transaction(
+        handler,
+        &[ix],
+        &[],
+        "Swept Dust",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Reward Router: {:?}", reward_router),
+            format!("Reward Receiver: {:?}", reward_receiver),
+            format!("DAO Wallet: {:?}", dao_wallet),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
 pub async fn close_epoch_account(
     handler: &CliHandler,
     ncn: Pubkey,
@@ -1926,16 +2947,43 @@ pub async fn close_epoch_account(
     let (config, _, _) =
         TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let account_already_closed = get_account(handler, &account_to_close)
-        .await?
-        .map_or(true, |account| {
-            account.data.is_empty() || account.lamports == 0
-        });
+    let account = get_account(handler, &account_to_close).await?;
+
+    let account_already_closed = account.as_ref().map_or(true, |account| {
+        account.data.is_empty() || account.lamports == 0
+    });
     if account_already_closed {
         info!("Account already closed: {:?}", account_to_close);
         return Ok(());
     }
 
+    // Operator snapshots record the operator's participation from the ballot box as they close
+    let mut receiver_to_close = receiver_to_close;
+    let mut remaining_accounts = vec![];
+    let operator = account
+        .filter(|account| account.data.first() == Some(&OperatorSnapshot::DISCRIMINATOR))
+        .and_then(|account| {
+            OperatorSnapshot::try_from_slice_unchecked(account.data.as_slice())
+                .ok()
+                .map(|operator_snapshot| *operator_snapshot.operator())
+        });
+    if let Some(operator) = operator {
+        let (ballot_box, _, _) =
+            BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+        let ballot_box_page_count = get_ballot_box(handler, epoch).await?.page_count();
+
+        receiver_to_close = Some(
+            OperatorRecord::find_program_address(&handler.tip_router_program_id, &ncn, &operator).0,
+        );
+        remaining_accounts.push(AccountMeta::new_readonly(ballot_box, false));
+        remaining_accounts.extend(get_ballot_box_page_metas(
+            handler,
+            &ballot_box,
+            ballot_box_page_count,
+            false,
+        ));
+    }
+
     let config_account = get_tip_router_config(handler).await?;
     let dao_wallet = *config_account
         .fee_config
@@ -1957,6 +3005,8 @@ pub async fn close_epoch_account(
         ix.receiver_to_close(Some(receiver_to_close));
     }
 
+    ix.add_remaining_accounts(&remaining_accounts);
+
     send_and_log_transaction(
         handler,
         &[ix.instruction()],
@@ -1975,6 +3025,51 @@ pub async fn close_epoch_account(
 }
 
 // --------------------- MIDDLEWARE ------------------------------
+/// Pages chained to an account are passed as remaining accounts, in page order
+fn get_page_metas(
+    page_count: u8,
+    is_writable: bool,
+    find_page_address: impl Fn(u8) -> Pubkey,
+) -> Vec<AccountMeta> {
+    (1..=page_count)
+        .map(|page| {
+            let address = find_page_address(page);
+            if is_writable {
+                AccountMeta::new(address, false)
+            } else {
+                AccountMeta::new_readonly(address, false)
+            }
+        })
+        .collect()
+}
+
+pub fn get_ballot_box_page_metas(
+    handler: &CliHandler,
+    ballot_box: &Pubkey,
+    page_count: u8,
+    is_writable: bool,
+) -> Vec<AccountMeta> {
+    get_page_metas(page_count, is_writable, |page| {
+        BallotBoxPage::find_program_address(&handler.tip_router_program_id, ballot_box, page).0
+    })
+}
+
+pub fn get_base_reward_router_page_metas(
+    handler: &CliHandler,
+    base_reward_router: &Pubkey,
+    page_count: u8,
+    is_writable: bool,
+) -> Vec<AccountMeta> {
+    get_page_metas(page_count, is_writable, |page| {
+        BaseRewardRouterPage::find_program_address(
+            &handler.tip_router_program_id,
+            base_reward_router,
+            page,
+        )
+        .0
+    })
+}
+
 pub const CREATE_TIMEOUT_MS: u64 = 2000;
 pub const CREATE_GET_RETRIES: u64 = 3;
 pub async fn check_created(handler: &CliHandler, address: &Pubkey) -> Result<()> {
@@ -2226,6 +3321,7 @@ pub async fn get_or_create_ballot_box(handler: &CliHandler, epoch: u64) -> Resul
         create_ballot_box(handler, epoch).await?;
         check_created(handler, &ballot_box).await?;
     }
+    create_ballot_box_pages(handler, epoch).await?;
     get_ballot_box(handler, epoch).await
 }
 
@@ -2244,6 +3340,7 @@ pub async fn get_or_create_base_reward_router(
         create_base_reward_router(handler, epoch).await?;
         check_created(handler, &base_reward_router).await?;
     }
+    create_base_reward_router_pages(handler, epoch).await?;
     get_base_reward_router(handler, epoch).await
 }
 
@@ -2277,6 +3374,9 @@ pub async fn get_or_create_ncn_reward_router(
 pub async fn crank_register_vaults(handler: &CliHandler) -> Result<()> {
     let all_ncn_vaults = get_all_vaults_in_ncn(handler).await?;
     let vault_registry = get_vault_registry(handler).await?;
+    let current_epoch = get_current_epoch(handler).await?;
+
+    // Includes retired vaults, which stay retired
     let all_registered_vaults: Vec<Pubkey> = vault_registry
         .get_valid_vault_entries()
         .iter()
@@ -2289,9 +3389,21 @@ pub async fn crank_register_vaults(handler: &CliHandler) -> Result<()> {
         .copied()
         .collect();
 
-    //TODO check if ST mint is registered first
-
     for vault in vaults_to_register.iter() {
+        let vault_account = get_vault(handler, vault).await?;
+        let st_mint_is_active = vault_registry
+            .get_mint_entry(&vault_account.supported_mint)
+            .is_ok_and(|entry| entry.is_active(current_epoch));
+
+        if !st_mint_is_active {
+            log::info!(
+                "Skipping vault: {:?}, ST mint {:?} is not registered or is retired",
+                vault,
+                vault_account.supported_mint
+            );
+            continue;
+        }
+
         let result = register_vault(handler, vault).await;
 
         if let Err(err) = result {
@@ -2320,6 +3432,10 @@ pub async fn crank_set_weight(handler: &CliHandler, epoch: u64) -> Result<()> {
         let result = set_weight_with_st_mint(handler, &st_mint, epoch).await;
 
         if let Err(err) = result {
+            if is_tip_router_error(&err, TipRouterError::WeightChangeExceedsLimit) {
+                emit_weight_change_rejected(&st_mint, epoch).await;
+            }
+
             log::error!(
                 "Failed to set weight for st_mint: {:?} in epoch: {:?} with error: {:?}",
                 st_mint,
@@ -2337,7 +3453,7 @@ pub async fn crank_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
 
     let operators = get_all_operators_in_ncn(handler).await?;
     let all_vaults: Vec<Pubkey> = vault_registry
-        .get_valid_vault_entries()
+        .get_active_vault_entries(epoch)
         .iter()
         .map(|entry| *entry.vault())
         .collect();
@@ -2414,6 +3530,24 @@ pub async fn crank_vote(handler: &CliHandler, epoch: u64, test_vote: bool) -> Re
         return Ok(());
     }
 
+    // Anyone can finalize the plurality ballot once voting has stalled past the fallback window
+    let config = get_tip_router_config(handler).await?;
+    if config.is_plurality_fallback_enabled() {
+        let current_epoch = get_current_epoch(handler).await?;
+        let fallback_epoch = epoch.saturating_add(config.epochs_before_plurality_fallback()?);
+
+        if current_epoch >= fallback_epoch {
+            match finalize_stalled_ballot(handler, epoch).await {
+                Ok(()) => return Ok(()),
+                Err(err) => log::error!(
+                    "Failed to finalize stalled ballot for epoch: {:?} with error: {:?}",
+                    epoch,
+                    err
+                ),
+            }
+        }
+    }
+
     if test_vote {
         crank_test_vote(handler, epoch).await?;
     }
@@ -2486,6 +3620,10 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
     let fees = epoch_snapshot.fees();
 
     let base_reward_router = get_or_create_base_reward_router(handler, epoch).await?;
+    let base_reward_router_pages =
+        get_base_reward_router_pages(handler, epoch, base_reward_router.page_count()).await?;
+    let base_reward_router_pages: Vec<&BaseRewardRouterPage> =
+        base_reward_router_pages.iter().collect();
 
     let base_reward_receiver_rewards = get_base_reward_receiver_rewards(handler, epoch).await?;
     if base_reward_receiver_rewards > 0 {
@@ -2529,7 +3667,8 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
                 continue;
             }
 
-            let result = base_reward_router.ncn_fee_group_reward_route(operator);
+            let result =
+                base_reward_router.ncn_fee_group_reward_route(&base_reward_router_pages, operator);
 
             if result.is_err() {
                 log::info!(
@@ -2541,7 +3680,7 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
             }
 
             if base_reward_router
-                .ncn_fee_group_reward_route(operator)?
+                .ncn_fee_group_reward_route(&base_reward_router_pages, operator)?
                 .rewards(group)?
                 != 0
             {
@@ -2626,6 +3765,79 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
         }
     }
 
+    // Whatever is left untracked once everything is distributed is rounding dust
+    let result = crank_sweep_dust(handler, epoch).await;
+    if let Err(err) = result {
+        log::error!(
+            "Failed to sweep dust in epoch: {:?} with error: {:?}",
+            epoch,
+            err
+        );
+    }
+
+    Ok(())
+}
+
+/// Sweeps the rounding dust left in the base and NCN reward receivers to the DAO wallet
+pub async fn crank_sweep_dust(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (base_reward_router, _, _) =
+        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let mut receivers = vec![(
+        base_reward_router,
+        base_reward_receiver,
+        get_base_reward_receiver_rewards(handler, epoch).await,
+    )];
+
+    let operators = get_all_operators_in_ncn(handler).await?;
+    for operator in operators.iter() {
+        for group in NcnFeeGroup::all_groups() {
+            let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+                &handler.tip_router_program_id,
+                group,
+                operator,
+                &ncn,
+                epoch,
+            );
+            let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+                &handler.tip_router_program_id,
+                group,
+                operator,
+                &ncn,
+                epoch,
+            );
+
+            receivers.push((
+                ncn_reward_router,
+                ncn_reward_receiver,
+                get_ncn_reward_receiver_rewards(handler, group, operator, epoch).await,
+            ));
+        }
+    }
+
+    for (reward_router, reward_receiver, receiver_rewards) in receivers {
+        // Missing receivers and larger balances, which are routed instead, are skipped
+        let receiver_rewards = receiver_rewards.unwrap_or(0);
+        if receiver_rewards == 0 || receiver_rewards > MAX_DUST_LAMPORTS {
+            continue;
+        }
+
+        let result = sweep_dust(handler, &reward_router, &reward_receiver, epoch).await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to sweep dust from receiver: {:?} in epoch: {:?} with error: {:?}",
+                reward_receiver,
+                epoch,
+                err
+            );
+        }
+    }
+
     Ok(())
 }
 
@@ -2682,10 +3894,34 @@ pub async fn crank_close_epoch_accounts(handler: &CliHandler, epoch: u64) -> Res
         }
     }
 
-    // Close Base Reward Router
+    // Close Base Reward Router Pages
     let (base_reward_router, _, _) =
         BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
+    if let Some(base_reward_router_account) = get_base_reward_router(handler, epoch).await.ok() {
+        for page in 1..=base_reward_router_account.page_count() {
+            let (base_reward_router_page, _, _) = BaseRewardRouterPage::find_program_address(
+                &handler.tip_router_program_id,
+                &base_reward_router,
+                page,
+            );
+
+            let result =
+                close_epoch_account(handler, ncn, epoch, base_reward_router_page, None).await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to close base reward router page: {:?} in epoch: {:?} with error: {:?}",
+                    base_reward_router_page,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    // Close Base Reward Router
+
     let (base_reward_receiver, _, _) =
         BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
@@ -2707,21 +3943,6 @@ pub async fn crank_close_epoch_accounts(handler: &CliHandler, epoch: u64) -> Res
         );
     }
 
-    // Close Ballot Box
-    let (ballot_box, _, _) =
-        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let result = close_epoch_account(handler, ncn, epoch, ballot_box, None).await;
-
-    if let Err(err) = result {
-        log::error!(
-            "Failed to close ballot box: {:?} in epoch: {:?} with error: {:?}",
-            ballot_box,
-            epoch,
-            err
-        );
-    }
-
     // Close Operator Snapshots
     for operator in all_operators.iter() {
         let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
@@ -2743,6 +3964,44 @@ pub async fn crank_close_epoch_accounts(handler: &CliHandler, epoch: u64) -> Res
         }
     }
 
+    // Close Ballot Box Pages
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    if let Some(ballot_box_account) = get_ballot_box(handler, epoch).await.ok() {
+        for page in 1..=ballot_box_account.page_count() {
+            let (ballot_box_page, _, _) = BallotBoxPage::find_program_address(
+                &handler.tip_router_program_id,
+                &ballot_box,
+                page,
+            );
+
+            let result = close_epoch_account(handler, ncn, epoch, ballot_box_page, None).await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to close ballot box page: {:?} in epoch: {:?} with error: {:?}",
+                    ballot_box_page,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    // Close Ballot Box
+
+    let result = close_epoch_account(handler, ncn, epoch, ballot_box, None).await;
+
+    if let Err(err) = result {
+        log::error!(
+            "Failed to close ballot box: {:?} in epoch: {:?} with error: {:?}",
+            ballot_box,
+            epoch,
+            err
+        );
+    }
+
     // Close Epoch Snapshot
     let (epoch_snapshot, _, _) =
         EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
@@ -3300,12 +4559,23 @@ pub async fn send_transactions(
     let result = client.send_and_confirm_transaction(&tx).await;
 
     if let Err(e) = result {
-        return Err(anyhow!("\nError: \n\n{:?}\n\n", e));
+        return Err(anyhow::Error::from(e).context("Failed to send transaction"));
     }
 
     Ok(result?)
 }
 
+/// Whether a transaction sent by `send_transactions` failed with `tip_router_error`
+fn is_tip_router_error(err: &anyhow::Error, tip_router_error: TipRouterError) -> bool {
+    let expected_code = tip_router_error as u32;
+    matches!(
+        err.downcast_ref::<ClientError>()
+            .and_then(ClientError::get_transaction_error),
+        Some(TransactionError::InstructionError(_, InstructionError::Custom(code)))
+            if code == expected_code
+    )
+}
+
 pub fn log_transaction(title: &str, signature: Signature, log_items: &[String]) {
     let mut log_message = format!(
         "\n\n---------- {} ----------\nSignature: {:?}",
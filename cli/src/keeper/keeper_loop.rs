@@ -1,18 +1,21 @@
 use crate::{
-    getters::get_guaranteed_epoch_and_slot,
+    getters::{get_guaranteed_epoch_and_slot, get_tip_router_config},
     handler::CliHandler,
     instructions::{
         crank_close_epoch_accounts, crank_distribute, crank_register_vaults, crank_set_weight,
         crank_snapshot, crank_vote, create_epoch_state,
     },
     keeper::{
-        keeper_metrics::{emit_epoch_metrics, emit_error, emit_ncn_metrics},
+        keeper_metrics::{emit_epoch_metrics, emit_error, emit_ncn_metrics, emit_paused},
         keeper_state::KeeperState,
     },
     log::{boring_progress_bar, progress_bar},
 };
 use anyhow::{Ok, Result};
-use jito_tip_router_core::epoch_state::State;
+use jito_tip_router_core::{
+    constants::{PAUSE_DISTRIBUTION, PAUSE_ROUTING, PAUSE_VOTING},
+    epoch_state::State,
+};
 use log::info;
 
 pub async fn progress_epoch(
@@ -63,6 +66,15 @@ pub async fn check_and_timeout_error<T>(
     }
 }
 
+/// Pause flags that would make cranking the given state fail
+pub const fn pause_flags_for_state(state: State) -> u8 {
+    match state {
+        State::Vote => PAUSE_VOTING,
+        State::Distribute => PAUSE_ROUTING | PAUSE_DISTRIBUTION,
+        _ => 0,
+    }
+}
+
 pub async fn timeout_error(duration_ms: u64) {
     progress_bar(duration_ms).await;
 }
@@ -225,6 +237,39 @@ pub async fn run_keeper(
             .await;
         }
 
+        {
+            let current_state = state.current_state().expect("cannot get current state");
+            info!(
+                "\n\nG. Check Pause [{:?}] - {}\n",
+                current_state, current_epoch
+            );
+
+            let result = get_tip_router_config(handler).await;
+
+            if check_and_timeout_error(
+                "Check Pause".to_string(),
+                &result,
+                error_timeout_ms,
+                state.epoch,
+            )
+            .await
+            {
+                continue;
+            }
+
+            let config = result.unwrap();
+            if config.is_paused(pause_flags_for_state(current_state)) {
+                // Back off instead of sending transactions that will fail
+                info!(
+                    "\n\nPAUSED: skipping {:?} for {} ( paused flags {:#06b} )\n\n",
+                    current_state, current_epoch, config.paused
+                );
+                emit_paused(current_state, config.paused, state.epoch).await;
+                timeout_error(error_timeout_ms).await;
+                continue;
+            }
+        }
+
         {
             let current_state = state.current_state().expect("cannot get current state");
             info!(
@@ -1,22 +1,23 @@
 use anyhow::Result;
 use jito_tip_router_core::{
     account_payer::AccountPayer,
+    ballot_box::BallotBoxPage,
     base_fee_group::{BaseFeeGroup, BaseFeeGroupType},
     constants::MAX_OPERATORS,
-    epoch_state::AccountStatus,
+    epoch_state::{AccountStatus, State},
     ncn_fee_group::{NcnFeeGroup, NcnFeeGroupType},
 };
 use solana_metrics::datapoint_info;
-use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::{hash::Hash, native_token::lamports_to_sol, pubkey::Pubkey};
 
 use crate::{
     getters::{
         get_account_payer, get_all_operators_in_ncn, get_all_tickets, get_all_vaults_in_ncn,
-        get_ballot_box, get_base_reward_receiver, get_base_reward_router,
-        get_current_epoch_and_slot, get_epoch_snapshot, get_epoch_state, get_is_epoch_completed,
-        get_ncn_reward_receiver, get_ncn_reward_router, get_operator, get_operator_snapshot,
-        get_tip_router_config, get_vault, get_vault_config, get_vault_operator_delegation,
-        get_vault_registry, get_weight_table,
+        get_ballot_box, get_ballot_box_pages, get_base_reward_receiver, get_base_reward_router,
+        get_config_extension, get_current_epoch_and_slot, get_epoch_snapshot, get_epoch_state,
+        get_fee_schedule, get_is_epoch_completed, get_ncn_reward_receiver, get_ncn_reward_router,
+        get_operator, get_operator_snapshot, get_tip_router_config, get_vault, get_vault_config,
+        get_vault_operator_delegation, get_vault_registry, get_weight_table,
     },
     handler::CliHandler,
 };
@@ -39,6 +40,23 @@ pub async fn emit_error(title: String, error: String, message: String, keeper_ep
     );
 }
 
+pub async fn emit_paused(state: State, paused: u8, keeper_epoch: u64) {
+    datapoint_info!(
+        "tr-beta-paused",
+        ("state", format!("{:?}", state), String),
+        ("paused", paused, i64),
+        ("keeper-epoch", keeper_epoch, i64),
+    );
+}
+
+pub async fn emit_weight_change_rejected(st_mint: &Pubkey, epoch: u64) {
+    datapoint_info!(
+        "tr-beta-weight-change-rejected",
+        ("st-mint", st_mint.to_string(), String),
+        ("epoch", epoch, i64),
+    );
+}
+
 pub async fn emit_ncn_metrics(handler: &CliHandler) -> Result<()> {
     emit_ncn_metrics_tickets(handler).await?;
     emit_ncn_metrics_vault_operator_delegation(handler).await?;
@@ -260,7 +278,18 @@ pub async fn emit_ncn_metrics_vault_registry(handler: &CliHandler) -> Result<()>
         ("current-epoch", current_epoch, i64),
         ("current-slot", current_slot, i64),
         ("st-mints", vault_registry.st_mint_count(), i64),
-        ("vaults", vault_registry.vault_count(), i64)
+        ("vaults", vault_registry.vault_count(), i64),
+        (
+            "active-st-mints",
+            vault_registry.active_st_mint_count(current_epoch),
+            i64
+        ),
+        (
+            "active-vaults",
+            vault_registry.active_vault_count(current_epoch),
+            i64
+        ),
+        ("removed-vaults", vault_registry.removed_vault_count(), i64),
     );
 
     for vault in vault_registry.vault_list {
@@ -277,6 +306,7 @@ pub async fn emit_ncn_metrics_vault_registry(handler: &CliHandler) -> Result<()>
             ("vault", vault.vault().to_string(), String),
             ("st-mint", vault.st_mint().to_string(), String),
             ("index", vault.vault_index(), i64),
+            ("retired-epoch", vault.retired_epoch(), i64),
             (
                 "tokens-deposited",
                 format_token_amount(vault_account.tokens_deposited()),
@@ -314,6 +344,23 @@ pub async fn emit_ncn_metrics_vault_registry(handler: &CliHandler) -> Result<()>
                 st_mint.reward_multiplier_bps(),
                 i64
             ),
+            (
+                "oracle-feed-count",
+                st_mint.registered_oracle_feeds().len(),
+                i64
+            ),
+            (
+                "max-feed-divergence-bps",
+                st_mint.max_feed_divergence_bps(),
+                i64
+            ),
+            (
+                "max-weight-change-bps",
+                st_mint.max_weight_change_bps(),
+                i64
+            ),
+            ("max-stale-slots", st_mint.max_stale_slots(), i64),
+            ("retired-epoch", st_mint.retired_epoch(), i64),
         );
     }
 
@@ -324,8 +371,10 @@ pub async fn emit_ncn_metrics_config(handler: &CliHandler) -> Result<()> {
     let (current_epoch, current_slot) = get_current_epoch_and_slot(handler).await?;
 
     let config = get_tip_router_config(handler).await?;
+    let config_extension = get_config_extension(handler).await?;
+    let fee_schedule = get_fee_schedule(handler).await?;
     let fee_config = config.fee_config;
-    let current_fees = fee_config.current_fees(current_epoch);
+    let current_fees = fee_config.current_fees(fee_schedule.as_ref(), current_epoch);
 
     datapoint_info!(
         "tr-beta-em-config",
@@ -343,12 +392,57 @@ pub async fn emit_ncn_metrics_config(handler: &CliHandler) -> Result<()> {
             config.valid_slots_after_consensus(),
             i64
         ),
+        (
+            "consensus-threshold-bps",
+            config.consensus_threshold_bps(),
+            i64
+        ),
+        ("commit-window-slots", config.commit_window_slots(), i64),
+        ("late-vote-grace-slots", config.late_vote_grace_slots(), i64),
+        (
+            "late-vote-max-decay-bps",
+            config.late_vote_max_decay_bps(),
+            i64
+        ),
+        (
+            "plurality-fallback-epochs",
+            config.plurality_fallback_epochs(),
+            i64
+        ),
+        (
+            "plurality-min-stake-weight-bps",
+            config.plurality_min_stake_weight_bps(),
+            i64
+        ),
+        (
+            "crank-bounty-lamports",
+            config_extension.map_or(0, |extension| extension.crank_bounty_lamports()),
+            i64
+        ),
+        (
+            "max-crank-bounties-per-epoch",
+            config_extension.map_or(0, |extension| extension.max_crank_bounties_per_epoch()),
+            i64
+        ),
+        (
+            "account-payer-target-balance",
+            config_extension.map_or(0, |extension| extension.account_payer_target_balance()),
+            i64
+        ),
         ("fee-admin", config.fee_admin.to_string(), String),
         (
             "tie-breaker-admin",
             config.tie_breaker_admin.to_string(),
             String
         ),
+        ("pending-admin", config.pending_admin.to_string(), String),
+        (
+            "pending-admin-expiry-epoch",
+            config.pending_admin_expiry_epoch(),
+            i64
+        ),
+        ("pause-admin", config.pause_admin().to_string(), String),
+        ("paused", config.paused, i64),
         // Fees
         (
             "block-engine-fee-bps",
@@ -526,6 +620,11 @@ pub async fn emit_epoch_metrics_base_rewards(handler: &CliHandler, epoch: u64) -
                 format_token_amount(base_reward_router.base_fee_group_reward(BaseFeeGroup::dao())?),
                 f64
             ),
+            (
+                "account-payer-top-up",
+                format_token_amount(base_reward_router.account_payer_top_up()),
+                f64
+            ),
             (
                 "lst-rewards",
                 format_token_amount(base_reward_router.ncn_fee_group_rewards(NcnFeeGroup::lst())?),
@@ -685,16 +784,39 @@ pub async fn emit_epoch_metrics_ballot_box(handler: &CliHandler, epoch: u64) ->
     if let Ok(ballot_box) = ballot_box_result {
         if let Ok(epoch_snapshot) = epoch_snapshot_result {
             let total_stake_weight = epoch_snapshot.stake_weights().stake_weight();
+            let ballot_box_pages =
+                get_ballot_box_pages(handler, epoch, ballot_box.page_count()).await?;
+            let ballot_box_pages: Vec<&BallotBoxPage> = ballot_box_pages.iter().collect();
 
-            for operator_vote in ballot_box.operator_votes() {
-                if operator_vote.is_empty() {
+            for operator_vote in ballot_box.all_operator_votes(&ballot_box_pages) {
+                // Committed votes are not tied to a ballot until revealed
+                if operator_vote.is_empty() || !operator_vote.has_voted() {
                     continue;
                 }
 
                 let ballot_index = operator_vote.ballot_index();
-                let ballot_tally = ballot_box.ballot_tallies()[ballot_index as usize];
-                let vote = format!("{:?}", ballot_tally.ballot().root());
-                ballot_tally.stake_weights().stake_weight();
+
+                // Abstained votes have no ballot tally
+                let (vote, ballot_stake_weight) = if operator_vote.is_abstain() {
+                    ("Abstain".to_string(), 0)
+                } else {
+                    let ballot_tally = ballot_box
+                        .all_ballot_tallies(&ballot_box_pages)
+                        .nth(ballot_index as usize)
+                        .ok_or_else(|| anyhow::anyhow!("Ballot tally not found"))?;
+
+                    (
+                        format!("{:?}", ballot_tally.ballot().root()),
+                        ballot_tally.stake_weights().stake_weight(),
+                    )
+                };
+
+                // The snapshot an operator voted from is recorded in its operator snapshot
+                let (snapshot_slot, bank_hash) =
+                    get_operator_snapshot(handler, operator_vote.operator(), epoch)
+                        .await
+                        .map(|snapshot| (snapshot.vote_snapshot_slot(), *snapshot.vote_bank_hash()))
+                        .unwrap_or((0, [0; 32]));
 
                 datapoint_info!(
                     "tr-beta-ee-ballot-box-votes",
@@ -704,6 +826,13 @@ pub async fn emit_epoch_metrics_ballot_box(handler: &CliHandler, epoch: u64) ->
                     ("operator", operator_vote.operator().to_string(), String),
                     ("slot-voted", operator_vote.slot_voted(), i64),
                     ("ballot-index", ballot_index, i64),
+                    ("abstain", operator_vote.is_abstain(), bool),
+                    ("snapshot-slot", snapshot_slot, i64),
+                    (
+                        "bank-hash",
+                        Hash::new_from_array(bank_hash).to_string(),
+                        String
+                    ),
                     (
                         "operator-stake-weight",
                         format_stake_weight(operator_vote.stake_weights().stake_weight()),
@@ -711,7 +840,7 @@ pub async fn emit_epoch_metrics_ballot_box(handler: &CliHandler, epoch: u64) ->
                     ),
                     (
                         "ballot-stake-weight",
-                        format_stake_weight(ballot_tally.stake_weights().stake_weight()),
+                        format_stake_weight(ballot_stake_weight),
                         f64
                     ),
                     (
@@ -723,7 +852,7 @@ pub async fn emit_epoch_metrics_ballot_box(handler: &CliHandler, epoch: u64) ->
                 );
             }
 
-            for tally in ballot_box.ballot_tallies() {
+            for tally in ballot_box.all_ballot_tallies(&ballot_box_pages) {
                 if !tally.is_valid() {
                     continue;
                 }
@@ -753,7 +882,9 @@ pub async fn emit_epoch_metrics_ballot_box(handler: &CliHandler, epoch: u64) ->
 
             let (winning_ballot_string, winning_stake_weight, winning_tally) = {
                 if ballot_box.has_winning_ballot() {
-                    let ballot_tally = ballot_box.get_winning_ballot_tally().unwrap();
+                    let ballot_tally = ballot_box
+                        .get_winning_ballot_tally(&ballot_box_pages)
+                        .unwrap();
                     (
                         format!("{:?}", ballot_tally.ballot().root()),
                         ballot_tally.stake_weights().stake_weight(),
@@ -771,6 +902,11 @@ pub async fn emit_epoch_metrics_ballot_box(handler: &CliHandler, epoch: u64) ->
                 ("keeper-epoch", epoch, i64),
                 ("unique-ballots", ballot_box.unique_ballots(), i64),
                 ("operators-voted", ballot_box.operators_voted(), i64),
+                (
+                    "operators-abstained",
+                    ballot_box.operators_abstained(&ballot_box_pages),
+                    i64
+                ),
                 ("has-winning-ballot", ballot_box.has_winning_ballot(), bool),
                 ("winning-ballot", winning_ballot_string, String),
                 (
@@ -779,6 +915,11 @@ pub async fn emit_epoch_metrics_ballot_box(handler: &CliHandler, epoch: u64) ->
                     f64
                 ),
                 ("winning-tally", winning_tally, i64),
+                (
+                    "winning-tip-distribution-account-count",
+                    ballot_box.winning_tip_distribution_account_count(),
+                    i64
+                ),
                 (
                     "total-stake-weight",
                     format_stake_weight(total_stake_weight),
@@ -1021,6 +1162,12 @@ pub async fn emit_epoch_metrics_state(handler: &CliHandler, epoch: u64) -> Resul
         ("current-state", current_state as u8, i64),
         ("operator-count", state.operator_count(), i64),
         ("vault-count", state.vault_count(), i64),
+        ("crank-bounties-paid", state.crank_bounties_paid(), i64),
+        (
+            "crank-bounty-lamports-paid",
+            state.crank_bounty_lamports_paid(),
+            i64
+        ),
         (
             "slot-consensus-reached",
             state.slot_consensus_reached(),
@@ -1076,6 +1223,14 @@ pub async fn emit_epoch_metrics_state(handler: &CliHandler, epoch: u64) -> Resul
             state.upload_progress().total(),
             i64
         ),
+        (
+            "upload-progress-missing",
+            state
+                .upload_progress()
+                .total()
+                .saturating_sub(state.upload_progress().tally()),
+            i64
+        ),
         (
             "total-distribution-progress-tally",
             state.total_distribution_progress().tally(),
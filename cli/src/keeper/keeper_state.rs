@@ -12,6 +12,7 @@ use jito_tip_router_core::{
     ballot_box::BallotBox,
     base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
     config::Config as TipRouterConfig,
+    constants::MAX_DUST_LAMPORTS,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::{EpochState, State},
     ncn_fee_group::NcnFeeGroup,
@@ -376,8 +377,8 @@ impl KeeperState {
             let total_rewards_to_be_distributed =
                 get_total_rewards_to_be_distributed(handler, self.epoch).await?;
 
-            // If dust rewards, then stall
-            if total_rewards_to_be_distributed < 10_000 {
+            // Dust is swept rather than distributed, so only dust left means a stall
+            if total_rewards_to_be_distributed <= MAX_DUST_LAMPORTS {
                 return Ok(true);
             }
         }
@@ -0,0 +1,100 @@
+pub use jito_tip_router_core::event::{
+    AccountClosed, ConsensusReached, CrankBountyPaid, MerkleRootSet, RewardsDistributed,
+    RewardsRouted, StalledBallotFinalized, TieBreakerSet, TipRouterEvent, VoteCast, EVENT_PREFIX,
+};
+use solana_program::pubkey::Pubkey;
+
+const PROGRAM: &str = "Program ";
+const PROGRAM_DATA: &str = "Program data: ";
+
+/// Decodes the payload of a `Program data:` log line, returning `None` if it is not an event
+fn decode_program_data(encoded: &str) -> Option<TipRouterEvent> {
+    let fields = encoded
+        .split_whitespace()
+        .map(base64::decode)
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    let fields: Vec<&[u8]> = fields.iter().map(Vec::as_slice).collect();
+
+    TipRouterEvent::from_log_data(&fields)
+}
+
+/// Decodes every event the tip router program at `program_id` emitted in a transaction's log
+/// messages, in order. Log data written by other programs, including programs the tip router
+/// invokes, is skipped.
+pub fn decode_events<S: AsRef<str>>(program_id: &Pubkey, logs: &[S]) -> Vec<TipRouterEvent> {
+    let program_id = program_id.to_string();
+    let mut invoke_stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs.iter().map(AsRef::as_ref) {
+        if let Some(encoded) = log.strip_prefix(PROGRAM_DATA) {
+            if invoke_stack.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+
+            if let Some(event) = decode_program_data(encoded) {
+                events.push(event);
+            }
+        } else if let Some(rest) = log.strip_prefix(PROGRAM) {
+            let mut parts = rest.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some(program), Some("invoke")) => invoke_stack.push(program),
+                (Some(_), Some("success" | "failed:")) => {
+                    invoke_stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_events_only_from_tip_router() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+
+        let event = TipRouterEvent::CrankBountyPaid(CrankBountyPaid {
+            ncn: Pubkey::new_unique(),
+            epoch: 1,
+            cranker: Pubkey::new_unique(),
+            lamports: 5_000,
+            slot: 100,
+        });
+        let data = format!(
+            "{}{} {}",
+            PROGRAM_DATA,
+            base64::encode(EVENT_PREFIX),
+            base64::encode(event.try_to_vec().unwrap())
+        );
+
+        let logs = vec![
+            // Emitted by another program before the tip router runs
+            format!("Program {} invoke [1]", other_program_id),
+            data.clone(),
+            format!("Program {} success", other_program_id),
+            format!("Program {} invoke [1]", program_id),
+            data.clone(),
+            // Emitted by a program the tip router invokes
+            format!("Program {} invoke [2]", other_program_id),
+            data.clone(),
+            format!("Program {} success", other_program_id),
+            "Program log: not an event".to_string(),
+            data,
+            format!("Program {} success", program_id),
+        ];
+
+        assert_eq!(
+            decode_events(&program_id, &logs),
+            vec![event.clone(), event]
+        );
+    }
+}
@@ -24,8 +24,10 @@ pub slot_created: u64,
 pub total_rewards: u64,
 pub reward_pool: u64,
 pub rewards_processed: u64,
+pub page_count: u8,
+pub account_payer_top_up: u64,
 #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-pub reserved: [u8; 128],
+pub reserved: [u8; 119],
 pub last_ncn_group_index: u8,
 pub last_vote_index: u16,
 pub last_rewards_to_process: u64,
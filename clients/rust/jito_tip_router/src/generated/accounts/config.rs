@@ -52,8 +52,27 @@ pub reserved: [u8; 119],
     pub bump: u8,
     pub epochs_after_consensus_before_close: u64,
     pub starting_valid_epoch: u64,
+    pub consensus_threshold_bps: u16,
+    pub commit_window_slots: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_admin: Pubkey,
+    pub pending_admin_role: u8,
+    pub pending_admin_expiry_epoch: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pause_admin: Pubkey,
+    pub paused: u8,
+    pub late_vote_grace_slots: u64,
+    pub late_vote_max_decay_bps: u16,
+    pub plurality_fallback_epochs: u64,
+    pub plurality_min_stake_weight_bps: u16,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 111],
+    pub reserved: [u8; 7],
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
 }
 
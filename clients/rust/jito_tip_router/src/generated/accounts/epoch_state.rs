@@ -67,8 +67,17 @@ pub reserved: [u8; 1024],
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub ncn_distribution_progress: [Progress; 2048],
     pub is_closing: bool,
+    pub ballot_box_page_progress: Progress,
+    pub base_reward_router_page_progress: Progress,
+    pub open_page_count: u64,
+    pub was_plurality_set: bool,
+    pub crank_bounty_lamports: u64,
+    pub max_crank_bounties: u64,
+    pub crank_bounties_paid: u64,
+    pub crank_bounty_lamports_paid: u64,
+    pub dust_swept: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 1023],
+    pub reserved: [u8; 926],
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
 }
 
@@ -7,45 +7,69 @@
 
 <<<<<<< HEAD
   pub(crate) mod r#ballot_box;
+  pub(crate) mod r#ballot_box_page;
   pub(crate) mod r#base_reward_router;
+  pub(crate) mod r#base_reward_router_page;
   pub(crate) mod r#config;
+  pub(crate) mod r#config_extension;
   pub(crate) mod r#epoch_snapshot;
   pub(crate) mod r#epoch_state;
+  pub(crate) mod r#fee_schedule;
   pub(crate) mod r#ncn_reward_router;
+  pub(crate) mod r#operator_record;
   pub(crate) mod r#operator_snapshot;
+  pub(crate) mod r#tie_breaker_committee;
   pub(crate) mod r#vault_registry;
   pub(crate) mod r#weight_table;
 
   pub use self::r#ballot_box::*;
+  pub use self::r#ballot_box_page::*;
   pub use self::r#base_reward_router::*;
+  pub use self::r#base_reward_router_page::*;
   pub use self::r#config::*;
+  pub use self::r#config_extension::*;
   pub use self::r#epoch_snapshot::*;
   pub use self::r#epoch_state::*;
+  pub use self::r#fee_schedule::*;
   pub use self::r#ncn_reward_router::*;
+  pub use self::r#operator_record::*;
   pub use self::r#operator_snapshot::*;
+  pub use self::r#tie_breaker_committee::*;
   pub use self::r#vault_registry::*;
   pub use self::r#weight_table::*;
 
 =======
 pub(crate) mod r#ballot_box;
+pub(crate) mod r#ballot_box_page;
 pub(crate) mod r#base_reward_router;
+pub(crate) mod r#base_reward_router_page;
 pub(crate) mod r#config;
+pub(crate) mod r#config_extension;
 pub(crate) mod r#epoch_marker;
 pub(crate) mod r#epoch_snapshot;
 pub(crate) mod r#epoch_state;
+pub(crate) mod r#fee_schedule;
 pub(crate) mod r#ncn_reward_router;
+pub(crate) mod r#operator_record;
 pub(crate) mod r#operator_snapshot;
+pub(crate) mod r#tie_breaker_committee;
 pub(crate) mod r#vault_registry;
 pub(crate) mod r#weight_table;
 
 pub use self::r#ballot_box::*;
+pub use self::r#ballot_box_page::*;
 pub use self::r#base_reward_router::*;
+pub use self::r#base_reward_router_page::*;
 pub use self::r#config::*;
+pub use self::r#config_extension::*;
 pub use self::r#epoch_marker::*;
 pub use self::r#epoch_snapshot::*;
 pub use self::r#epoch_state::*;
+pub use self::r#fee_schedule::*;
 pub use self::r#ncn_reward_router::*;
+pub use self::r#operator_record::*;
 pub use self::r#operator_snapshot::*;
+pub use self::r#tie_breaker_committee::*;
 pub use self::r#vault_registry::*;
 pub use self::r#weight_table::*;
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
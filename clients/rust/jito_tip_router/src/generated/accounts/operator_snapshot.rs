@@ -32,8 +32,11 @@ pub vault_operator_delegation_count: u64,
 pub vault_operator_delegations_registered: u64,
 pub valid_operator_vault_delegations: u64,
 pub stake_weights: StakeWeights,
+pub inactive_reason: u8,
+pub vote_snapshot_slot: u64,
+pub vote_bank_hash: [u8; 32],
 #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-pub reserved: [u8; 256],
+pub reserved: [u8; 215],
 #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
 pub vault_operator_stake_weight: [VaultOperatorStakeWeight; 64],
 }
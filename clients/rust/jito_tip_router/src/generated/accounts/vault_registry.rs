@@ -19,8 +19,9 @@ pub discriminator: u64,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub ncn: Pubkey,
 pub bump: u8,
+pub removed_vault_count: u64,
 #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-pub reserved: [u8; 127],
+pub reserved: [u8; 119],
 #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
 pub st_mint_list: [StMintEntry; 64],
 #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
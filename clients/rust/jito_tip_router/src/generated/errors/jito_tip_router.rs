@@ -283,6 +283,180 @@ pub enum JitoTipRouterError {
     /// 8785 - Marker exists
     #[error("Marker exists")]
     MarkerExists = 0x2251,
+    /// 8786 - Invalid consensus threshold
+    #[error("Invalid consensus threshold")]
+    InvalidConsensusThreshold = 0x2252,
+    /// 8787 - Invalid commit window
+    #[error("Invalid commit window")]
+    InvalidCommitWindow = 0x2253,
+    /// 8788 - Votes must be committed and revealed
+    #[error("Votes must be committed and revealed")]
+    CommitRevealRequired = 0x2254,
+    /// 8789 - Commit-reveal voting is not enabled
+    #[error("Commit-reveal voting is not enabled")]
+    CommitRevealNotEnabled = 0x2255,
+    /// 8790 - Commit window is closed
+    #[error("Commit window is closed")]
+    CommitWindowClosed = 0x2256,
+    /// 8791 - Commit window is still open
+    #[error("Commit window is still open")]
+    CommitWindowOpen = 0x2257,
+    /// 8792 - Vote commitment not found
+    #[error("Vote commitment not found")]
+    VoteCommitmentNotFound = 0x2258,
+    /// 8793 - Revealed vote does not match commitment
+    #[error("Revealed vote does not match commitment")]
+    InvalidVoteCommitment = 0x2259,
+    /// 8794 - No pending admin
+    #[error("No pending admin")]
+    NoPendingAdmin = 0x225a,
+    /// 8795 - Pending admin does not match
+    #[error("Pending admin does not match")]
+    PendingAdminMismatch = 0x225b,
+    /// 8796 - Pending admin proposal has expired
+    #[error("Pending admin proposal has expired")]
+    PendingAdminExpired = 0x225c,
+    /// 8797 - Voting is paused
+    #[error("Voting is paused")]
+    VotingPaused = 0x225d,
+    /// 8798 - Setting merkle roots is paused
+    #[error("Setting merkle roots is paused")]
+    SetMerkleRootPaused = 0x225e,
+    /// 8799 - Routing rewards is paused
+    #[error("Routing rewards is paused")]
+    RoutingPaused = 0x225f,
+    /// 8800 - Distributing rewards is paused
+    #[error("Distributing rewards is paused")]
+    DistributionPaused = 0x2260,
+    /// 8801 - Invalid pause flags
+    #[error("Invalid pause flags")]
+    InvalidPauseFlags = 0x2261,
+    /// 8802 - Incorrect pause admin
+    #[error("Incorrect pause admin")]
+    IncorrectPauseAdmin = 0x2262,
+    /// 8803 - Invalid oracle feed type
+    #[error("Invalid oracle feed type")]
+    InvalidOracleFeedType = 0x2263,
+    /// 8804 - No oracle feeds registered
+    #[error("No oracle feeds registered")]
+    NoOracleFeeds = 0x2264,
+    /// 8805 - Oracle feed not registered
+    #[error("Oracle feed not registered")]
+    OracleFeedNotRegistered = 0x2265,
+    /// 8806 - Bad pyth feed
+    #[error("Bad pyth feed")]
+    BadPythFeed = 0x2266,
+    /// 8807 - Stale pyth feed
+    #[error("Stale pyth feed")]
+    StalePythFeed = 0x2267,
+    /// 8808 - Oracle feeds diverge beyond tolerance
+    #[error("Oracle feeds diverge beyond tolerance")]
+    OracleFeedsDiverged = 0x2268,
+    /// 8809 - Invalid feed divergence tolerance
+    #[error("Invalid feed divergence tolerance")]
+    InvalidFeedDivergence = 0x2269,
+    /// 8810 - Weight change from the previous epoch exceeds the limit
+    #[error("Weight change from the previous epoch exceeds the limit")]
+    WeightChangeExceedsLimit = 0x226a,
+    /// 8811 - Invalid previous weight table
+    #[error("Invalid previous weight table")]
+    InvalidPreviousWeightTable = 0x226b,
+    /// 8812 - Retired epoch must be in the future
+    #[error("Retired epoch must be in the future")]
+    InvalidRetiredEpoch = 0x226c,
+    /// 8813 - Vault is retired
+    #[error("Vault is retired")]
+    VaultRetired = 0x226d,
+    /// 8814 - ST mint is retired
+    #[error("ST mint is retired")]
+    StMintRetired = 0x226e,
+    /// 8815 - ST mint has vaults that are not retired
+    #[error("ST mint has vaults that are not retired")]
+    StMintHasActiveVaults = 0x226f,
+    /// 8816 - Too many pages required
+    #[error("Too many pages required")]
+    TooManyPages = 0x2270,
+    /// 8817 - Page is out of order or not required
+    #[error("Page is out of order or not required")]
+    InvalidPage = 0x2271,
+    /// 8818 - Invalid tie breaker committee members or threshold
+    #[error("Invalid tie breaker committee members or threshold")]
+    InvalidTieBreakerCommittee = 0x2272,
+    /// 8819 - Signer is not a tie breaker committee member
+    #[error("Signer is not a tie breaker committee member")]
+    TieBreakerCommitteeMemberInvalid = 0x2273,
+    /// 8820 - Cannot close account - No operator record or ballot box provided
+    #[error("Cannot close account - No operator record or ballot box provided")]
+    CannotCloseAccountNoOperatorRecordProvided = 0x2274,
+    /// 8821 - Cannot close ballot box - Operator snapshots need to be closed first
+    #[error("Cannot close ballot box - Operator snapshots need to be closed first")]
+    CannotCloseBallotBoxOperatorSnapshotsOpen = 0x2275,
+    /// 8822 - Invalid late vote decay
+    #[error("Invalid late vote decay")]
+    InvalidLateVoteDecay = 0x2276,
+    /// 8823 - Invalid plurality fallback
+    #[error("Invalid plurality fallback")]
+    InvalidPluralityFallback = 0x2277,
+    /// 8824 - Plurality fallback is disabled
+    #[error("Plurality fallback is disabled")]
+    PluralityFallbackDisabled = 0x2278,
+    /// 8825 - No single ballot has the most stake weight
+    #[error("No single ballot has the most stake weight")]
+    NoPluralityBallot = 0x2279,
+    /// 8826 - Plurality ballot is below the minimum stake weight
+    #[error("Plurality ballot is below the minimum stake weight")]
+    PluralityBelowStakeFloor = 0x227a,
+    /// 8827 - Invalid crank bounty
+    #[error("Invalid crank bounty")]
+    InvalidCrankBounty = 0x227b,
+    /// 8828 - Merkle root batch does not match its accounts
+    #[error("Merkle root batch does not match its accounts")]
+    InvalidMerkleRootBatch = 0x227c,
+    /// 8829 - Invalid reward asset
+    #[error("Invalid reward asset")]
+    InvalidRewardAsset = 0x227d,
+    /// 8830 - Invalid max operator fee
+    #[error("Invalid max operator fee")]
+    InvalidMaxOperatorFee = 0x227e,
+    /// 8831 - Invalid operator inactive reason
+    #[error("Invalid operator inactive reason")]
+    InvalidOperatorInactiveReason = 0x227f,
+    /// 8832 - Untracked rewards are above the dust threshold
+    #[error("Untracked rewards are above the dust threshold")]
+    UntrackedRewardsAboveDust = 0x2280,
+    /// 8833 - Invalid reward router
+    #[error("Invalid reward router")]
+    InvalidRewardRouter = 0x2281,
+    /// 8834 - Invalid fee schedule action
+    #[error("Invalid fee schedule action")]
+    InvalidFeeScheduleAction = 0x2282,
+    /// 8835 - Invalid fee schedule activation epoch
+    #[error("Invalid fee schedule activation epoch")]
+    InvalidFeeScheduleEpoch = 0x2283,
+    /// 8836 - Fee schedule is full
+    #[error("Fee schedule is full")]
+    FeeScheduleFull = 0x2284,
+    /// 8837 - Fee schedule entry not found
+    #[error("Fee schedule entry not found")]
+    FeeScheduleEntryNotFound = 0x2285,
+    /// 8838 - Rewards are not fully routed and distributed
+    #[error("Rewards are not fully routed and distributed")]
+    RewardsNotDistributed = 0x2286,
+    /// 8839 - Tie breaker committee is initialized, the committee sets the tie breaker
+    #[error("Tie breaker committee is initialized, the committee sets the tie breaker")]
+    TieBreakerCommitteeActive = 0x2287,
+    /// 8840 - Another admin proposal is pending, cancel it first
+    #[error("Another admin proposal is pending, cancel it first")]
+    PendingAdminExists = 0x2288,
+    /// 8841 - Operator index is out of bounds
+    #[error("Operator index is out of bounds")]
+    OperatorIndexOutOfBounds = 0x2289,
+    /// 8842 - Duplicate oracle feed
+    #[error("Duplicate oracle feed")]
+    DuplicateOracleFeed = 0x228a,
+    /// 8843 - Invalid max stale slots
+    #[error("Invalid max stale slots")]
+    InvalidMaxStaleSlots = 0x228b,
 }
 
 impl solana_program::program_error::PrintProgramError for JitoTipRouterError {
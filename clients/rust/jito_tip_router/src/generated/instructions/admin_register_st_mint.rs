@@ -94,6 +94,8 @@ pub struct AdminRegisterStMintInstructionArgs {
                 pub reward_multiplier_bps: u64,
                 pub switchboard_feed: Option<Pubkey>,
                 pub no_feed_weight: Option<u128>,
+                pub max_weight_change_bps: Option<u64>,
+                pub max_stale_slots: Option<u64>,
       }
 
 
@@ -117,6 +119,8 @@ pub struct AdminRegisterStMintBuilder {
                 reward_multiplier_bps: Option<u64>,
                 switchboard_feed: Option<Pubkey>,
                 no_feed_weight: Option<u128>,
+                max_weight_change_bps: Option<u64>,
+                max_stale_slots: Option<u64>,
         __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -170,6 +174,18 @@ impl AdminRegisterStMintBuilder {
       pub fn no_feed_weight(&mut self, no_feed_weight: u128) -> &mut Self {
         self.no_feed_weight = Some(no_feed_weight);
         self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn max_weight_change_bps(&mut self, max_weight_change_bps: u64) -> &mut Self {
+          self.max_weight_change_bps = Some(max_weight_change_bps);
+          self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn max_stale_slots(&mut self, max_stale_slots: u64) -> &mut Self {
+          self.max_stale_slots = Some(max_stale_slots);
+          self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -197,6 +213,8 @@ impl AdminRegisterStMintBuilder {
                                                                   reward_multiplier_bps: self.reward_multiplier_bps.clone().expect("reward_multiplier_bps is not set"),
                                                                   switchboard_feed: self.switchboard_feed.clone(),
                                                                   no_feed_weight: self.no_feed_weight.clone(),
+                                                                  max_weight_change_bps: self.max_weight_change_bps.clone(),
+                                                                  max_stale_slots: self.max_stale_slots.clone(),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -361,6 +379,8 @@ impl<'a, 'b> AdminRegisterStMintCpiBuilder<'a, 'b> {
                                 reward_multiplier_bps: None,
                                 switchboard_feed: None,
                                 no_feed_weight: None,
+                                max_weight_change_bps: None,
+                                max_stale_slots: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -411,6 +431,18 @@ impl<'a, 'b> AdminRegisterStMintCpiBuilder<'a, 'b> {
       pub fn no_feed_weight(&mut self, no_feed_weight: u128) -> &mut Self {
         self.instruction.no_feed_weight = Some(no_feed_weight);
         self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn max_weight_change_bps(&mut self, max_weight_change_bps: u64) -> &mut Self {
+          self.instruction.max_weight_change_bps = Some(max_weight_change_bps);
+          self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn max_stale_slots(&mut self, max_stale_slots: u64) -> &mut Self {
+          self.instruction.max_stale_slots = Some(max_stale_slots);
+          self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -439,6 +471,8 @@ impl<'a, 'b> AdminRegisterStMintCpiBuilder<'a, 'b> {
                                                                   reward_multiplier_bps: self.instruction.reward_multiplier_bps.clone().expect("reward_multiplier_bps is not set"),
                                                                   switchboard_feed: self.instruction.switchboard_feed.clone(),
                                                                   no_feed_weight: self.instruction.no_feed_weight.clone(),
+                                                                  max_weight_change_bps: self.instruction.max_weight_change_bps.clone(),
+                                                                  max_stale_slots: self.instruction.max_stale_slots.clone(),
                                     };
         let instruction = AdminRegisterStMintCpi {
         __program: self.instruction.__program,
@@ -470,6 +504,8 @@ struct AdminRegisterStMintCpiBuilderInstruction<'a, 'b> {
                 reward_multiplier_bps: Option<u64>,
                 switchboard_feed: Option<Pubkey>,
                 no_feed_weight: Option<u128>,
+                max_weight_change_bps: Option<u64>,
+                max_stale_slots: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -1,387 +0,0 @@
-//! This code was AUTOGENERATED using the kinobi library.
-//! Please DO NOT EDIT THIS FILE, instead use visitors
-//! to add features, then rerun kinobi to update it.
-//!
-//! <https://github.com/kinobi-so/kinobi>
-//!
-
-use borsh::BorshDeserialize;
-use borsh::BorshSerialize;
-use crate::generated::types::ConfigAdminRole;
-
-/// Accounts.
-pub struct AdminSetNewAdmin {
-      
-              
-          pub config: solana_program::pubkey::Pubkey,
-          
-              
-          pub ncn: solana_program::pubkey::Pubkey,
-          
-              
-          pub ncn_admin: solana_program::pubkey::Pubkey,
-          
-              
-          pub new_admin: solana_program::pubkey::Pubkey,
-      }
-
-impl AdminSetNewAdmin {
-  pub fn instruction(&self, args: AdminSetNewAdminInstructionArgs) -> solana_program::instruction::Instruction {
-    self.instruction_with_remaining_accounts(args, &[])
-  }
-  #[allow(clippy::vec_init_then_push)]
-  pub fn instruction_with_remaining_accounts(&self, args: AdminSetNewAdminInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
-                            accounts.push(solana_program::instruction::AccountMeta::new(
-            self.config,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.ncn,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.ncn_admin,
-            true
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.new_admin,
-            false
-          ));
-                      accounts.extend_from_slice(remaining_accounts);
-    let mut data = AdminSetNewAdminInstructionData::new().try_to_vec().unwrap();
-          let mut args = args.try_to_vec().unwrap();
-      data.append(&mut args);
-    
-    solana_program::instruction::Instruction {
-      program_id: crate::JITO_TIP_ROUTER_ID,
-      accounts,
-      data,
-    }
-  }
-}
-
-#[derive(BorshDeserialize, BorshSerialize)]
-pub struct AdminSetNewAdminInstructionData {
-            discriminator: u8,
-            }
-
-impl AdminSetNewAdminInstructionData {
-  pub fn new() -> Self {
-    Self {
-                        discriminator: 30,
-                                }
-  }
-}
-
-impl Default for AdminSetNewAdminInstructionData {
-  fn default() -> Self {
-    Self::new()
-  }
-}
-
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct AdminSetNewAdminInstructionArgs {
-                  pub role: ConfigAdminRole,
-      }
-
-
-/// Instruction builder for `AdminSetNewAdmin`.
-///
-/// ### Accounts:
-///
-                ///   0. `[writable]` config
-          ///   1. `[]` ncn
-                ///   2. `[signer]` ncn_admin
-          ///   3. `[]` new_admin
-#[derive(Clone, Debug, Default)]
-pub struct AdminSetNewAdminBuilder {
-            config: Option<solana_program::pubkey::Pubkey>,
-                ncn: Option<solana_program::pubkey::Pubkey>,
-                ncn_admin: Option<solana_program::pubkey::Pubkey>,
-                new_admin: Option<solana_program::pubkey::Pubkey>,
-                        role: Option<ConfigAdminRole>,
-        __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
-}
-
-impl AdminSetNewAdminBuilder {
-  pub fn new() -> Self {
-    Self::default()
-  }
-            #[inline(always)]
-    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.config = Some(config);
-                    self
-    }
-            #[inline(always)]
-    pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.ncn = Some(ncn);
-                    self
-    }
-            #[inline(always)]
-    pub fn ncn_admin(&mut self, ncn_admin: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.ncn_admin = Some(ncn_admin);
-                    self
-    }
-            #[inline(always)]
-    pub fn new_admin(&mut self, new_admin: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.new_admin = Some(new_admin);
-                    self
-    }
-                    #[inline(always)]
-      pub fn role(&mut self, role: ConfigAdminRole) -> &mut Self {
-        self.role = Some(role);
-        self
-      }
-        /// Add an additional account to the instruction.
-  #[inline(always)]
-  pub fn add_remaining_account(&mut self, account: solana_program::instruction::AccountMeta) -> &mut Self {
-    self.__remaining_accounts.push(account);
-    self
-  }
-  /// Add additional accounts to the instruction.
-  #[inline(always)]
-  pub fn add_remaining_accounts(&mut self, accounts: &[solana_program::instruction::AccountMeta]) -> &mut Self {
-    self.__remaining_accounts.extend_from_slice(accounts);
-    self
-  }
-  #[allow(clippy::clone_on_copy)]
-  pub fn instruction(&self) -> solana_program::instruction::Instruction {
-    let accounts = AdminSetNewAdmin {
-                              config: self.config.expect("config is not set"),
-                                        ncn: self.ncn.expect("ncn is not set"),
-                                        ncn_admin: self.ncn_admin.expect("ncn_admin is not set"),
-                                        new_admin: self.new_admin.expect("new_admin is not set"),
-                      };
-          let args = AdminSetNewAdminInstructionArgs {
-                                                              role: self.role.clone().expect("role is not set"),
-                                    };
-    
-    accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
-  }
-}
-
-  /// `admin_set_new_admin` CPI accounts.
-  pub struct AdminSetNewAdminCpiAccounts<'a, 'b> {
-          
-                    
-              pub config: &'b solana_program::account_info::AccountInfo<'a>,
-                
-                    
-              pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
-                
-                    
-              pub ncn_admin: &'b solana_program::account_info::AccountInfo<'a>,
-                
-                    
-              pub new_admin: &'b solana_program::account_info::AccountInfo<'a>,
-            }
-
-/// `admin_set_new_admin` CPI instruction.
-pub struct AdminSetNewAdminCpi<'a, 'b> {
-  /// The program to invoke.
-  pub __program: &'b solana_program::account_info::AccountInfo<'a>,
-      
-              
-          pub config: &'b solana_program::account_info::AccountInfo<'a>,
-          
-              
-          pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
-          
-              
-          pub ncn_admin: &'b solana_program::account_info::AccountInfo<'a>,
-          
-              
-          pub new_admin: &'b solana_program::account_info::AccountInfo<'a>,
-            /// The arguments for the instruction.
-    pub __args: AdminSetNewAdminInstructionArgs,
-  }
-
-impl<'a, 'b> AdminSetNewAdminCpi<'a, 'b> {
-  pub fn new(
-    program: &'b solana_program::account_info::AccountInfo<'a>,
-          accounts: AdminSetNewAdminCpiAccounts<'a, 'b>,
-              args: AdminSetNewAdminInstructionArgs,
-      ) -> Self {
-    Self {
-      __program: program,
-              config: accounts.config,
-              ncn: accounts.ncn,
-              ncn_admin: accounts.ncn_admin,
-              new_admin: accounts.new_admin,
-                    __args: args,
-          }
-  }
-  #[inline(always)]
-  pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
-    self.invoke_signed_with_remaining_accounts(&[], &[])
-  }
-  #[inline(always)]
-  pub fn invoke_with_remaining_accounts(&self, remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]) -> solana_program::entrypoint::ProgramResult {
-    self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
-  }
-  #[inline(always)]
-  pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program::entrypoint::ProgramResult {
-    self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
-  }
-  #[allow(clippy::clone_on_copy)]
-  #[allow(clippy::vec_init_then_push)]
-  pub fn invoke_signed_with_remaining_accounts(
-    &self,
-    signers_seeds: &[&[&[u8]]],
-    remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
-  ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
-                            accounts.push(solana_program::instruction::AccountMeta::new(
-            *self.config.key,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.ncn.key,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.ncn_admin.key,
-            true
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.new_admin.key,
-            false
-          ));
-                      remaining_accounts.iter().for_each(|remaining_account| {
-      accounts.push(solana_program::instruction::AccountMeta {
-          pubkey: *remaining_account.0.key,
-          is_signer: remaining_account.1,
-          is_writable: remaining_account.2,
-      })
-    });
-    let mut data = AdminSetNewAdminInstructionData::new().try_to_vec().unwrap();
-          let mut args = self.__args.try_to_vec().unwrap();
-      data.append(&mut args);
-    
-    let instruction = solana_program::instruction::Instruction {
-      program_id: crate::JITO_TIP_ROUTER_ID,
-      accounts,
-      data,
-    };
-    let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
-    account_infos.push(self.__program.clone());
-                  account_infos.push(self.config.clone());
-                        account_infos.push(self.ncn.clone());
-                        account_infos.push(self.ncn_admin.clone());
-                        account_infos.push(self.new_admin.clone());
-              remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
-
-    if signers_seeds.is_empty() {
-      solana_program::program::invoke(&instruction, &account_infos)
-    } else {
-      solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
-    }
-  }
-}
-
-/// Instruction builder for `AdminSetNewAdmin` via CPI.
-///
-/// ### Accounts:
-///
-                ///   0. `[writable]` config
-          ///   1. `[]` ncn
-                ///   2. `[signer]` ncn_admin
-          ///   3. `[]` new_admin
-#[derive(Clone, Debug)]
-pub struct AdminSetNewAdminCpiBuilder<'a, 'b> {
-  instruction: Box<AdminSetNewAdminCpiBuilderInstruction<'a, 'b>>,
-}
-
-impl<'a, 'b> AdminSetNewAdminCpiBuilder<'a, 'b> {
-  pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
-    let instruction = Box::new(AdminSetNewAdminCpiBuilderInstruction {
-      __program: program,
-              config: None,
-              ncn: None,
-              ncn_admin: None,
-              new_admin: None,
-                                            role: None,
-                    __remaining_accounts: Vec::new(),
-    });
-    Self { instruction }
-  }
-      #[inline(always)]
-    pub fn config(&mut self, config: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.config = Some(config);
-                    self
-    }
-      #[inline(always)]
-    pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.ncn = Some(ncn);
-                    self
-    }
-      #[inline(always)]
-    pub fn ncn_admin(&mut self, ncn_admin: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.ncn_admin = Some(ncn_admin);
-                    self
-    }
-      #[inline(always)]
-    pub fn new_admin(&mut self, new_admin: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.new_admin = Some(new_admin);
-                    self
-    }
-                    #[inline(always)]
-      pub fn role(&mut self, role: ConfigAdminRole) -> &mut Self {
-        self.instruction.role = Some(role);
-        self
-      }
-        /// Add an additional account to the instruction.
-  #[inline(always)]
-  pub fn add_remaining_account(&mut self, account: &'b solana_program::account_info::AccountInfo<'a>, is_writable: bool, is_signer: bool) -> &mut Self {
-    self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
-    self
-  }
-  /// Add additional accounts to the instruction.
-  ///
-  /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
-  /// and a `bool` indicating whether the account is a signer or not.
-  #[inline(always)]
-  pub fn add_remaining_accounts(&mut self, accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]) -> &mut Self {
-    self.instruction.__remaining_accounts.extend_from_slice(accounts);
-    self
-  }
-  #[inline(always)]
-  pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
-    self.invoke_signed(&[])
-  }
-  #[allow(clippy::clone_on_copy)]
-  #[allow(clippy::vec_init_then_push)]
-  pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program::entrypoint::ProgramResult {
-          let args = AdminSetNewAdminInstructionArgs {
-                                                              role: self.instruction.role.clone().expect("role is not set"),
-                                    };
-        let instruction = AdminSetNewAdminCpi {
-        __program: self.instruction.__program,
-                  
-          config: self.instruction.config.expect("config is not set"),
-                  
-          ncn: self.instruction.ncn.expect("ncn is not set"),
-                  
-          ncn_admin: self.instruction.ncn_admin.expect("ncn_admin is not set"),
-                  
-          new_admin: self.instruction.new_admin.expect("new_admin is not set"),
-                          __args: args,
-            };
-    instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
-  }
-}
-
-#[derive(Clone, Debug)]
-struct AdminSetNewAdminCpiBuilderInstruction<'a, 'b> {
-  __program: &'b solana_program::account_info::AccountInfo<'a>,
-            config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                ncn_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                new_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                        role: Option<ConfigAdminRole>,
-        /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
-  __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
-}
-
@@ -19,6 +19,7 @@ pub struct AdminSetParameters {
           
               
           pub ncn_admin: solana_program::pubkey::Pubkey,
+          pub config_extension: solana_program::pubkey::Pubkey,
       }
 
 impl AdminSetParameters {
@@ -27,7 +28,7 @@ impl AdminSetParameters {
   }
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: AdminSetParametersInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             self.config,
             false
@@ -39,6 +40,10 @@ impl AdminSetParameters {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.ncn_admin,
             true
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            self.config_extension,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = AdminSetParametersInstructionData::new().try_to_vec().unwrap();
@@ -86,6 +91,17 @@ pub struct AdminSetParametersInstructionArgs {
     pub epochs_before_stall: Option<u64>,
     pub epochs_after_consensus_before_close: Option<u64>,
     pub valid_slots_after_consensus: Option<u64>,
+    pub consensus_threshold_bps: Option<u16>,
+    pub commit_window_slots: Option<u64>,
+    pub late_vote_grace_slots: Option<u64>,
+    pub late_vote_max_decay_bps: Option<u16>,
+    pub plurality_fallback_epochs: Option<u64>,
+    pub plurality_min_stake_weight_bps: Option<u16>,
+    pub crank_bounty_lamports: Option<u64>,
+    pub max_crank_bounties_per_epoch: Option<u64>,
+    pub account_payer_target_balance: Option<u64>,
+    pub min_operator_stake_weight: Option<u128>,
+    pub max_operator_fee_bps: Option<u16>,
 }
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
 
@@ -96,12 +112,14 @@ pub struct AdminSetParametersInstructionArgs {
                 ///   0. `[writable]` config
           ///   1. `[]` ncn
                 ///   2. `[signer]` ncn_admin
+                ///   3. `[writable]` config_extension
 #[derive(Clone, Debug, Default)]
 pub struct AdminSetParametersBuilder {
 <<<<<<< HEAD
             config: Option<solana_program::pubkey::Pubkey>,
                 ncn: Option<solana_program::pubkey::Pubkey>,
                 ncn_admin: Option<solana_program::pubkey::Pubkey>,
+                config_extension: Option<solana_program::pubkey::Pubkey>,
                         epochs_before_stall: Option<u64>,
                 epochs_after_consensus_before_close: Option<u64>,
                 valid_slots_after_consensus: Option<u64>,
@@ -110,10 +128,22 @@ pub struct AdminSetParametersBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
     ncn: Option<solana_program::pubkey::Pubkey>,
     ncn_admin: Option<solana_program::pubkey::Pubkey>,
+    config_extension: Option<solana_program::pubkey::Pubkey>,
     starting_valid_epoch: Option<u64>,
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
+    consensus_threshold_bps: Option<u16>,
+    commit_window_slots: Option<u64>,
+    late_vote_grace_slots: Option<u64>,
+    late_vote_max_decay_bps: Option<u16>,
+    plurality_fallback_epochs: Option<u64>,
+    plurality_min_stake_weight_bps: Option<u16>,
+    crank_bounty_lamports: Option<u64>,
+    max_crank_bounties_per_epoch: Option<u64>,
+    account_payer_target_balance: Option<u64>,
+    min_operator_stake_weight: Option<u128>,
+    max_operator_fee_bps: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
 }
@@ -136,6 +166,11 @@ impl AdminSetParametersBuilder {
     pub fn ncn_admin(&mut self, ncn_admin: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.ncn_admin = Some(ncn_admin);
                     self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.config_extension = Some(config_extension);
+                    self
     }
 <<<<<<< HEAD
                     /// `[optional argument]`
@@ -186,6 +221,7 @@ impl AdminSetParametersBuilder {
                               config: self.config.expect("config is not set"),
                                         ncn: self.ncn.expect("ncn is not set"),
                                         ncn_admin: self.ncn_admin.expect("ncn_admin is not set"),
+                                        config_extension: self.config_extension.expect("config_extension is not set"),
                       };
           let args = AdminSetParametersInstructionArgs {
                                                               epochs_before_stall: self.epochs_before_stall.clone(),
@@ -197,6 +233,75 @@ impl AdminSetParametersBuilder {
   }
 =======
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn consensus_threshold_bps(&mut self, consensus_threshold_bps: u16) -> &mut Self {
+        self.consensus_threshold_bps = Some(consensus_threshold_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn commit_window_slots(&mut self, commit_window_slots: u64) -> &mut Self {
+        self.commit_window_slots = Some(commit_window_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn late_vote_grace_slots(&mut self, late_vote_grace_slots: u64) -> &mut Self {
+        self.late_vote_grace_slots = Some(late_vote_grace_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn late_vote_max_decay_bps(&mut self, late_vote_max_decay_bps: u16) -> &mut Self {
+        self.late_vote_max_decay_bps = Some(late_vote_max_decay_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn plurality_fallback_epochs(&mut self, plurality_fallback_epochs: u64) -> &mut Self {
+        self.plurality_fallback_epochs = Some(plurality_fallback_epochs);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn plurality_min_stake_weight_bps(
+        &mut self,
+        plurality_min_stake_weight_bps: u16,
+    ) -> &mut Self {
+        self.plurality_min_stake_weight_bps = Some(plurality_min_stake_weight_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn crank_bounty_lamports(&mut self, crank_bounty_lamports: u64) -> &mut Self {
+        self.crank_bounty_lamports = Some(crank_bounty_lamports);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_crank_bounties_per_epoch(&mut self, max_crank_bounties_per_epoch: u64) -> &mut Self {
+        self.max_crank_bounties_per_epoch = Some(max_crank_bounties_per_epoch);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn account_payer_target_balance(&mut self, account_payer_target_balance: u64) -> &mut Self {
+        self.account_payer_target_balance = Some(account_payer_target_balance);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn min_operator_stake_weight(&mut self, min_operator_stake_weight: u128) -> &mut Self {
+        self.min_operator_stake_weight = Some(min_operator_stake_weight);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_operator_fee_bps(&mut self, max_operator_fee_bps: u16) -> &mut Self {
+        self.max_operator_fee_bps = Some(max_operator_fee_bps);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -221,12 +326,24 @@ impl AdminSetParametersBuilder {
             config: self.config.expect("config is not set"),
             ncn: self.ncn.expect("ncn is not set"),
             ncn_admin: self.ncn_admin.expect("ncn_admin is not set"),
+            config_extension: self.config_extension.expect("config_extension is not set"),
         };
         let args = AdminSetParametersInstructionArgs {
             starting_valid_epoch: self.starting_valid_epoch.clone(),
             epochs_before_stall: self.epochs_before_stall.clone(),
             epochs_after_consensus_before_close: self.epochs_after_consensus_before_close.clone(),
             valid_slots_after_consensus: self.valid_slots_after_consensus.clone(),
+            consensus_threshold_bps: self.consensus_threshold_bps.clone(),
+            commit_window_slots: self.commit_window_slots.clone(),
+            late_vote_grace_slots: self.late_vote_grace_slots.clone(),
+            late_vote_max_decay_bps: self.late_vote_max_decay_bps.clone(),
+            plurality_fallback_epochs: self.plurality_fallback_epochs.clone(),
+            plurality_min_stake_weight_bps: self.plurality_min_stake_weight_bps.clone(),
+            crank_bounty_lamports: self.crank_bounty_lamports.clone(),
+            max_crank_bounties_per_epoch: self.max_crank_bounties_per_epoch.clone(),
+            account_payer_target_balance: self.account_payer_target_balance.clone(),
+            min_operator_stake_weight: self.min_operator_stake_weight.clone(),
+            max_operator_fee_bps: self.max_operator_fee_bps.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -245,6 +362,7 @@ impl AdminSetParametersBuilder {
                 
                     
               pub ncn_admin: &'b solana_program::account_info::AccountInfo<'a>,
+              pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             }
 
 /// `admin_set_parameters` CPI instruction.
@@ -260,6 +378,7 @@ pub struct AdminSetParametersCpi<'a, 'b> {
           
               
           pub ncn_admin: &'b solana_program::account_info::AccountInfo<'a>,
+          pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: AdminSetParametersInstructionArgs,
   }
@@ -275,6 +394,7 @@ impl<'a, 'b> AdminSetParametersCpi<'a, 'b> {
               config: accounts.config,
               ncn: accounts.ncn,
               ncn_admin: accounts.ncn_admin,
+              config_extension: accounts.config_extension,
                     __args: args,
           }
   }
@@ -297,7 +417,7 @@ impl<'a, 'b> AdminSetParametersCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             *self.config.key,
             false
@@ -309,6 +429,10 @@ impl<'a, 'b> AdminSetParametersCpi<'a, 'b> {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.ncn_admin.key,
             true
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.config_extension.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_program::instruction::AccountMeta {
@@ -326,11 +450,12 @@ impl<'a, 'b> AdminSetParametersCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.config.clone());
                         account_infos.push(self.ncn.clone());
                         account_infos.push(self.ncn_admin.clone());
+                        account_infos.push(self.config_extension.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -348,6 +473,7 @@ impl<'a, 'b> AdminSetParametersCpi<'a, 'b> {
                 ///   0. `[writable]` config
           ///   1. `[]` ncn
                 ///   2. `[signer]` ncn_admin
+                ///   3. `[writable]` config_extension
 #[derive(Clone, Debug)]
 pub struct AdminSetParametersCpiBuilder<'a, 'b> {
   instruction: Box<AdminSetParametersCpiBuilderInstruction<'a, 'b>>,
@@ -361,6 +487,7 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
               config: None,
               ncn: None,
               ncn_admin: None,
+              config_extension: None,
                                             epochs_before_stall: None,
                                 epochs_after_consensus_before_close: None,
                                 valid_slots_after_consensus: None,
@@ -379,10 +506,22 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
             config: None,
             ncn: None,
             ncn_admin: None,
+            config_extension: None,
             starting_valid_epoch: None,
             epochs_before_stall: None,
             epochs_after_consensus_before_close: None,
             valid_slots_after_consensus: None,
+            consensus_threshold_bps: None,
+            commit_window_slots: None,
+            late_vote_grace_slots: None,
+            late_vote_max_decay_bps: None,
+            plurality_fallback_epochs: None,
+            plurality_min_stake_weight_bps: None,
+            crank_bounty_lamports: None,
+            max_crank_bounties_per_epoch: None,
+            account_payer_target_balance: None,
+            min_operator_stake_weight: None,
+            max_operator_fee_bps: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -397,6 +536,11 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
     pub fn ncn_admin(&mut self, ncn_admin: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.ncn_admin = Some(ncn_admin);
                     self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.config_extension = Some(config_extension);
+                    self
     }
 <<<<<<< HEAD
                     /// `[optional argument]`
@@ -458,6 +602,75 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
                                     };
 =======
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn consensus_threshold_bps(&mut self, consensus_threshold_bps: u16) -> &mut Self {
+        self.instruction.consensus_threshold_bps = Some(consensus_threshold_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn commit_window_slots(&mut self, commit_window_slots: u64) -> &mut Self {
+        self.instruction.commit_window_slots = Some(commit_window_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn late_vote_grace_slots(&mut self, late_vote_grace_slots: u64) -> &mut Self {
+        self.instruction.late_vote_grace_slots = Some(late_vote_grace_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn late_vote_max_decay_bps(&mut self, late_vote_max_decay_bps: u16) -> &mut Self {
+        self.instruction.late_vote_max_decay_bps = Some(late_vote_max_decay_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn plurality_fallback_epochs(&mut self, plurality_fallback_epochs: u64) -> &mut Self {
+        self.instruction.plurality_fallback_epochs = Some(plurality_fallback_epochs);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn plurality_min_stake_weight_bps(
+        &mut self,
+        plurality_min_stake_weight_bps: u16,
+    ) -> &mut Self {
+        self.instruction.plurality_min_stake_weight_bps = Some(plurality_min_stake_weight_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn crank_bounty_lamports(&mut self, crank_bounty_lamports: u64) -> &mut Self {
+        self.instruction.crank_bounty_lamports = Some(crank_bounty_lamports);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_crank_bounties_per_epoch(&mut self, max_crank_bounties_per_epoch: u64) -> &mut Self {
+        self.instruction.max_crank_bounties_per_epoch = Some(max_crank_bounties_per_epoch);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn account_payer_target_balance(&mut self, account_payer_target_balance: u64) -> &mut Self {
+        self.instruction.account_payer_target_balance = Some(account_payer_target_balance);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn min_operator_stake_weight(&mut self, min_operator_stake_weight: u128) -> &mut Self {
+        self.instruction.min_operator_stake_weight = Some(min_operator_stake_weight);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_operator_fee_bps(&mut self, max_operator_fee_bps: u16) -> &mut Self {
+        self.instruction.max_operator_fee_bps = Some(max_operator_fee_bps);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -507,6 +720,17 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
                 .epochs_after_consensus_before_close
                 .clone(),
             valid_slots_after_consensus: self.instruction.valid_slots_after_consensus.clone(),
+            consensus_threshold_bps: self.instruction.consensus_threshold_bps.clone(),
+            commit_window_slots: self.instruction.commit_window_slots.clone(),
+            late_vote_grace_slots: self.instruction.late_vote_grace_slots.clone(),
+            late_vote_max_decay_bps: self.instruction.late_vote_max_decay_bps.clone(),
+            plurality_fallback_epochs: self.instruction.plurality_fallback_epochs.clone(),
+            plurality_min_stake_weight_bps: self.instruction.plurality_min_stake_weight_bps.clone(),
+            crank_bounty_lamports: self.instruction.crank_bounty_lamports.clone(),
+            max_crank_bounties_per_epoch: self.instruction.max_crank_bounties_per_epoch.clone(),
+            account_payer_target_balance: self.instruction.account_payer_target_balance.clone(),
+            min_operator_stake_weight: self.instruction.min_operator_stake_weight.clone(),
+            max_operator_fee_bps: self.instruction.max_operator_fee_bps.clone(),
         };
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
         let instruction = AdminSetParametersCpi {
@@ -517,6 +741,7 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
           ncn: self.instruction.ncn.expect("ncn is not set"),
                   
           ncn_admin: self.instruction.ncn_admin.expect("ncn_admin is not set"),
+          config_extension: self.instruction.config_extension.expect("config_extension is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -530,6 +755,7 @@ struct AdminSetParametersCpiBuilderInstruction<'a, 'b> {
             config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 ncn_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                config_extension: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                         epochs_before_stall: Option<u64>,
                 epochs_after_consensus_before_close: Option<u64>,
                 valid_slots_after_consensus: Option<u64>,
@@ -540,10 +766,22 @@ struct AdminSetParametersCpiBuilderInstruction<'a, 'b> {
     config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    config_extension: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     starting_valid_epoch: Option<u64>,
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
+    consensus_threshold_bps: Option<u16>,
+    commit_window_slots: Option<u64>,
+    late_vote_grace_slots: Option<u64>,
+    late_vote_max_decay_bps: Option<u16>,
+    plurality_fallback_epochs: Option<u64>,
+    plurality_min_stake_weight_bps: Option<u16>,
+    crank_bounty_lamports: Option<u64>,
+    max_crank_bounties_per_epoch: Option<u64>,
+    account_payer_target_balance: Option<u64>,
+    min_operator_stake_weight: Option<u128>,
+    max_operator_fee_bps: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
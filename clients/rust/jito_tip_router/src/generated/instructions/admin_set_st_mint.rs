@@ -88,6 +88,8 @@ pub struct AdminSetStMintInstructionArgs {
                 pub reward_multiplier_bps: Option<u64>,
                 pub switchboard_feed: Option<Pubkey>,
                 pub no_feed_weight: Option<u128>,
+                pub max_weight_change_bps: Option<u64>,
+                pub max_stale_slots: Option<u64>,
       }
 
 
@@ -110,6 +112,8 @@ pub struct AdminSetStMintBuilder {
                 reward_multiplier_bps: Option<u64>,
                 switchboard_feed: Option<Pubkey>,
                 no_feed_weight: Option<u128>,
+                max_weight_change_bps: Option<u64>,
+                max_stale_slots: Option<u64>,
         __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -165,6 +169,18 @@ impl AdminSetStMintBuilder {
       pub fn no_feed_weight(&mut self, no_feed_weight: u128) -> &mut Self {
         self.no_feed_weight = Some(no_feed_weight);
         self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn max_weight_change_bps(&mut self, max_weight_change_bps: u64) -> &mut Self {
+          self.max_weight_change_bps = Some(max_weight_change_bps);
+          self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn max_stale_slots(&mut self, max_stale_slots: u64) -> &mut Self {
+          self.max_stale_slots = Some(max_stale_slots);
+          self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -192,6 +208,8 @@ impl AdminSetStMintBuilder {
                                                                   reward_multiplier_bps: self.reward_multiplier_bps.clone(),
                                                                   switchboard_feed: self.switchboard_feed.clone(),
                                                                   no_feed_weight: self.no_feed_weight.clone(),
+                                                                  max_weight_change_bps: self.max_weight_change_bps.clone(),
+                                                                  max_stale_slots: self.max_stale_slots.clone(),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -343,6 +361,8 @@ impl<'a, 'b> AdminSetStMintCpiBuilder<'a, 'b> {
                                 reward_multiplier_bps: None,
                                 switchboard_feed: None,
                                 no_feed_weight: None,
+                                max_weight_change_bps: None,
+                                max_stale_slots: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -395,6 +415,18 @@ impl<'a, 'b> AdminSetStMintCpiBuilder<'a, 'b> {
       pub fn no_feed_weight(&mut self, no_feed_weight: u128) -> &mut Self {
         self.instruction.no_feed_weight = Some(no_feed_weight);
         self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn max_weight_change_bps(&mut self, max_weight_change_bps: u64) -> &mut Self {
+          self.instruction.max_weight_change_bps = Some(max_weight_change_bps);
+          self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn max_stale_slots(&mut self, max_stale_slots: u64) -> &mut Self {
+          self.instruction.max_stale_slots = Some(max_stale_slots);
+          self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -424,6 +456,8 @@ impl<'a, 'b> AdminSetStMintCpiBuilder<'a, 'b> {
                                                                   reward_multiplier_bps: self.instruction.reward_multiplier_bps.clone(),
                                                                   switchboard_feed: self.instruction.switchboard_feed.clone(),
                                                                   no_feed_weight: self.instruction.no_feed_weight.clone(),
+                                                                  max_weight_change_bps: self.instruction.max_weight_change_bps.clone(),
+                                                                  max_stale_slots: self.instruction.max_stale_slots.clone(),
                                     };
         let instruction = AdminSetStMintCpi {
         __program: self.instruction.__program,
@@ -453,6 +487,8 @@ struct AdminSetStMintCpiBuilderInstruction<'a, 'b> {
                 reward_multiplier_bps: Option<u64>,
                 switchboard_feed: Option<Pubkey>,
                 no_feed_weight: Option<u128>,
+                max_weight_change_bps: Option<u64>,
+                max_stale_slots: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -0,0 +1,507 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+/// Accounts.
+pub struct AdminSetStMintOracleFeeds {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub ncn: solana_program::pubkey::Pubkey,
+
+    pub vault_registry: solana_program::pubkey::Pubkey,
+
+    pub admin: solana_program::pubkey::Pubkey,
+}
+
+impl AdminSetStMintOracleFeeds {
+    pub fn instruction(
+        &self,
+        args: AdminSetStMintOracleFeedsInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AdminSetStMintOracleFeedsInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_registry,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.admin, true,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = AdminSetStMintOracleFeedsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct AdminSetStMintOracleFeedsInstructionData {
+    discriminator: u8,
+}
+
+impl AdminSetStMintOracleFeedsInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 41 }
+    }
+}
+
+impl Default for AdminSetStMintOracleFeedsInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdminSetStMintOracleFeedsInstructionArgs {
+    pub st_mint: Pubkey,
+    pub oracle_feeds: [Pubkey; 3],
+    pub oracle_feed_types: [u8; 3],
+    pub max_feed_divergence_bps: u16,
+}
+
+/// Instruction builder for `AdminSetStMintOracleFeeds`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` ncn
+///   2. `[writable]` vault_registry
+///   3. `[writable, signer]` admin
+#[derive(Clone, Debug, Default)]
+pub struct AdminSetStMintOracleFeedsBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    ncn: Option<solana_program::pubkey::Pubkey>,
+    vault_registry: Option<solana_program::pubkey::Pubkey>,
+    admin: Option<solana_program::pubkey::Pubkey>,
+    st_mint: Option<Pubkey>,
+    oracle_feeds: Option<[Pubkey; 3]>,
+    oracle_feed_types: Option<[u8; 3]>,
+    max_feed_divergence_bps: Option<u16>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AdminSetStMintOracleFeedsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_registry(&mut self, vault_registry: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault_registry = Some(vault_registry);
+        self
+    }
+    #[inline(always)]
+    pub fn admin(&mut self, admin: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+    #[inline(always)]
+    pub fn st_mint(&mut self, st_mint: Pubkey) -> &mut Self {
+        self.st_mint = Some(st_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn oracle_feeds(&mut self, oracle_feeds: [Pubkey; 3]) -> &mut Self {
+        self.oracle_feeds = Some(oracle_feeds);
+        self
+    }
+    #[inline(always)]
+    pub fn oracle_feed_types(&mut self, oracle_feed_types: [u8; 3]) -> &mut Self {
+        self.oracle_feed_types = Some(oracle_feed_types);
+        self
+    }
+    #[inline(always)]
+    pub fn max_feed_divergence_bps(&mut self, max_feed_divergence_bps: u16) -> &mut Self {
+        self.max_feed_divergence_bps = Some(max_feed_divergence_bps);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AdminSetStMintOracleFeeds {
+            config: self.config.expect("config is not set"),
+            ncn: self.ncn.expect("ncn is not set"),
+            vault_registry: self.vault_registry.expect("vault_registry is not set"),
+            admin: self.admin.expect("admin is not set"),
+        };
+        let args = AdminSetStMintOracleFeedsInstructionArgs {
+            st_mint: self.st_mint.clone().expect("st_mint is not set"),
+            oracle_feeds: self.oracle_feeds.clone().expect("oracle_feeds is not set"),
+            oracle_feed_types: self
+                .oracle_feed_types
+                .clone()
+                .expect("oracle_feed_types is not set"),
+            max_feed_divergence_bps: self
+                .max_feed_divergence_bps
+                .clone()
+                .expect("max_feed_divergence_bps is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `admin_set_st_mint_oracle_feeds` CPI accounts.
+pub struct AdminSetStMintOracleFeedsCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `admin_set_st_mint_oracle_feeds` CPI instruction.
+pub struct AdminSetStMintOracleFeedsCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// The arguments for the instruction.
+    pub __args: AdminSetStMintOracleFeedsInstructionArgs,
+}
+
+impl<'a, 'b> AdminSetStMintOracleFeedsCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AdminSetStMintOracleFeedsCpiAccounts<'a, 'b>,
+        args: AdminSetStMintOracleFeedsInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            ncn: accounts.ncn,
+            vault_registry: accounts.vault_registry,
+            admin: accounts.admin,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_registry.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.admin.key,
+            true,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = AdminSetStMintOracleFeedsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.ncn.clone());
+        account_infos.push(self.vault_registry.clone());
+        account_infos.push(self.admin.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AdminSetStMintOracleFeeds` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` ncn
+///   2. `[writable]` vault_registry
+///   3. `[writable, signer]` admin
+#[derive(Clone, Debug)]
+pub struct AdminSetStMintOracleFeedsCpiBuilder<'a, 'b> {
+    instruction: Box<AdminSetStMintOracleFeedsCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AdminSetStMintOracleFeedsCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AdminSetStMintOracleFeedsCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            ncn: None,
+            vault_registry: None,
+            admin: None,
+            st_mint: None,
+            oracle_feeds: None,
+            oracle_feed_types: None,
+            max_feed_divergence_bps: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_registry(
+        &mut self,
+        vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_registry = Some(vault_registry);
+        self
+    }
+    #[inline(always)]
+    pub fn admin(&mut self, admin: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.admin = Some(admin);
+        self
+    }
+    #[inline(always)]
+    pub fn st_mint(&mut self, st_mint: Pubkey) -> &mut Self {
+        self.instruction.st_mint = Some(st_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn oracle_feeds(&mut self, oracle_feeds: [Pubkey; 3]) -> &mut Self {
+        self.instruction.oracle_feeds = Some(oracle_feeds);
+        self
+    }
+    #[inline(always)]
+    pub fn oracle_feed_types(&mut self, oracle_feed_types: [u8; 3]) -> &mut Self {
+        self.instruction.oracle_feed_types = Some(oracle_feed_types);
+        self
+    }
+    #[inline(always)]
+    pub fn max_feed_divergence_bps(&mut self, max_feed_divergence_bps: u16) -> &mut Self {
+        self.instruction.max_feed_divergence_bps = Some(max_feed_divergence_bps);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AdminSetStMintOracleFeedsInstructionArgs {
+            st_mint: self
+                .instruction
+                .st_mint
+                .clone()
+                .expect("st_mint is not set"),
+            oracle_feeds: self
+                .instruction
+                .oracle_feeds
+                .clone()
+                .expect("oracle_feeds is not set"),
+            oracle_feed_types: self
+                .instruction
+                .oracle_feed_types
+                .clone()
+                .expect("oracle_feed_types is not set"),
+            max_feed_divergence_bps: self
+                .instruction
+                .max_feed_divergence_bps
+                .clone()
+                .expect("max_feed_divergence_bps is not set"),
+        };
+        let instruction = AdminSetStMintOracleFeedsCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            ncn: self.instruction.ncn.expect("ncn is not set"),
+
+            vault_registry: self
+                .instruction
+                .vault_registry
+                .expect("vault_registry is not set"),
+
+            admin: self.instruction.admin.expect("admin is not set"),
+
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AdminSetStMintOracleFeedsCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_registry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    st_mint: Option<Pubkey>,
+    oracle_feeds: Option<[Pubkey; 3]>,
+    oracle_feed_types: Option<[u8; 3]>,
+    max_feed_divergence_bps: Option<u16>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -25,6 +25,7 @@ pub struct AdminSetTieBreaker {
           
               
           pub tie_breaker_admin: solana_program::pubkey::Pubkey,
+          pub tie_breaker_committee: solana_program::pubkey::Pubkey,
       }
 
 impl AdminSetTieBreaker {
@@ -33,7 +34,7 @@ impl AdminSetTieBreaker {
   }
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: AdminSetTieBreakerInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false
@@ -53,6 +54,10 @@ impl AdminSetTieBreaker {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.tie_breaker_admin,
             true
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.tie_breaker_committee,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = AdminSetTieBreakerInstructionData::new().try_to_vec().unwrap();
@@ -103,6 +108,7 @@ pub struct AdminSetTieBreakerInstructionArgs {
                 ///   2. `[writable]` ballot_box
           ///   3. `[]` ncn
                 ///   4. `[signer]` tie_breaker_admin
+                ///   5. `[]` tie_breaker_committee
 #[derive(Clone, Debug, Default)]
 pub struct AdminSetTieBreakerBuilder {
             epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -110,6 +116,7 @@ pub struct AdminSetTieBreakerBuilder {
                 ballot_box: Option<solana_program::pubkey::Pubkey>,
                 ncn: Option<solana_program::pubkey::Pubkey>,
                 tie_breaker_admin: Option<solana_program::pubkey::Pubkey>,
+                tie_breaker_committee: Option<solana_program::pubkey::Pubkey>,
                         meta_merkle_root: Option<[u8; 32]>,
                 epoch: Option<u64>,
         __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -143,6 +150,11 @@ impl AdminSetTieBreakerBuilder {
     pub fn tie_breaker_admin(&mut self, tie_breaker_admin: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.tie_breaker_admin = Some(tie_breaker_admin);
                     self
+    }
+            #[inline(always)]
+    pub fn tie_breaker_committee(&mut self, tie_breaker_committee: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.tie_breaker_committee = Some(tie_breaker_committee);
+                    self
     }
                     #[inline(always)]
       pub fn meta_merkle_root(&mut self, meta_merkle_root: [u8; 32]) -> &mut Self {
@@ -174,6 +186,7 @@ impl AdminSetTieBreakerBuilder {
                                         ballot_box: self.ballot_box.expect("ballot_box is not set"),
                                         ncn: self.ncn.expect("ncn is not set"),
                                         tie_breaker_admin: self.tie_breaker_admin.expect("tie_breaker_admin is not set"),
+                                        tie_breaker_committee: self.tie_breaker_committee.expect("tie_breaker_committee is not set"),
                       };
           let args = AdminSetTieBreakerInstructionArgs {
                                                               meta_merkle_root: self.meta_merkle_root.clone().expect("meta_merkle_root is not set"),
@@ -201,6 +214,7 @@ impl AdminSetTieBreakerBuilder {
                 
                     
               pub tie_breaker_admin: &'b solana_program::account_info::AccountInfo<'a>,
+              pub tie_breaker_committee: &'b solana_program::account_info::AccountInfo<'a>,
             }
 
 /// `admin_set_tie_breaker` CPI instruction.
@@ -222,6 +236,7 @@ pub struct AdminSetTieBreakerCpi<'a, 'b> {
           
               
           pub tie_breaker_admin: &'b solana_program::account_info::AccountInfo<'a>,
+          pub tie_breaker_committee: &'b solana_program::account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: AdminSetTieBreakerInstructionArgs,
   }
@@ -239,6 +254,7 @@ impl<'a, 'b> AdminSetTieBreakerCpi<'a, 'b> {
               ballot_box: accounts.ballot_box,
               ncn: accounts.ncn,
               tie_breaker_admin: accounts.tie_breaker_admin,
+              tie_breaker_committee: accounts.tie_breaker_committee,
                     __args: args,
           }
   }
@@ -261,7 +277,7 @@ impl<'a, 'b> AdminSetTieBreakerCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false
@@ -281,6 +297,10 @@ impl<'a, 'b> AdminSetTieBreakerCpi<'a, 'b> {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.tie_breaker_admin.key,
             true
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.tie_breaker_committee.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_program::instruction::AccountMeta {
@@ -298,13 +318,14 @@ impl<'a, 'b> AdminSetTieBreakerCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.epoch_state.clone());
                         account_infos.push(self.config.clone());
                         account_infos.push(self.ballot_box.clone());
                         account_infos.push(self.ncn.clone());
                         account_infos.push(self.tie_breaker_admin.clone());
+                        account_infos.push(self.tie_breaker_committee.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -324,6 +345,7 @@ impl<'a, 'b> AdminSetTieBreakerCpi<'a, 'b> {
                 ///   2. `[writable]` ballot_box
           ///   3. `[]` ncn
                 ///   4. `[signer]` tie_breaker_admin
+                ///   5. `[]` tie_breaker_committee
 #[derive(Clone, Debug)]
 pub struct AdminSetTieBreakerCpiBuilder<'a, 'b> {
   instruction: Box<AdminSetTieBreakerCpiBuilderInstruction<'a, 'b>>,
@@ -338,6 +360,7 @@ impl<'a, 'b> AdminSetTieBreakerCpiBuilder<'a, 'b> {
               ballot_box: None,
               ncn: None,
               tie_breaker_admin: None,
+              tie_breaker_committee: None,
                                             meta_merkle_root: None,
                                 epoch: None,
                     __remaining_accounts: Vec::new(),
@@ -368,6 +391,11 @@ impl<'a, 'b> AdminSetTieBreakerCpiBuilder<'a, 'b> {
     pub fn tie_breaker_admin(&mut self, tie_breaker_admin: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.tie_breaker_admin = Some(tie_breaker_admin);
                     self
+    }
+            #[inline(always)]
+    pub fn tie_breaker_committee(&mut self, tie_breaker_committee: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.tie_breaker_committee = Some(tie_breaker_committee);
+                    self
     }
                     #[inline(always)]
       pub fn meta_merkle_root(&mut self, meta_merkle_root: [u8; 32]) -> &mut Self {
@@ -417,6 +445,7 @@ impl<'a, 'b> AdminSetTieBreakerCpiBuilder<'a, 'b> {
           ncn: self.instruction.ncn.expect("ncn is not set"),
                   
           tie_breaker_admin: self.instruction.tie_breaker_admin.expect("tie_breaker_admin is not set"),
+          tie_breaker_committee: self.instruction.tie_breaker_committee.expect("tie_breaker_committee is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -431,6 +460,7 @@ struct AdminSetTieBreakerCpiBuilderInstruction<'a, 'b> {
                 ballot_box: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 tie_breaker_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                tie_breaker_committee: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                         meta_merkle_root: Option<[u8; 32]>,
                 epoch: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
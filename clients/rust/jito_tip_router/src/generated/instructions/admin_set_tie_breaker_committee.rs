@@ -0,0 +1,478 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+/// Accounts.
+pub struct AdminSetTieBreakerCommittee {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub tie_breaker_committee: solana_program::pubkey::Pubkey,
+
+    pub ncn: solana_program::pubkey::Pubkey,
+
+    pub tie_breaker_admin: solana_program::pubkey::Pubkey,
+}
+
+impl AdminSetTieBreakerCommittee {
+    pub fn instruction(
+        &self,
+        args: AdminSetTieBreakerCommitteeInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AdminSetTieBreakerCommitteeInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tie_breaker_committee,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.tie_breaker_admin,
+            true,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = AdminSetTieBreakerCommitteeInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct AdminSetTieBreakerCommitteeInstructionData {
+    discriminator: u8,
+}
+
+impl AdminSetTieBreakerCommitteeInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 47 }
+    }
+}
+
+impl Default for AdminSetTieBreakerCommitteeInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdminSetTieBreakerCommitteeInstructionArgs {
+    pub members: [Pubkey; 8],
+    pub threshold: u8,
+}
+
+/// Instruction builder for `AdminSetTieBreakerCommittee`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` tie_breaker_committee
+///   2. `[]` ncn
+///   3. `[signer]` tie_breaker_admin
+#[derive(Clone, Debug, Default)]
+pub struct AdminSetTieBreakerCommitteeBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    tie_breaker_committee: Option<solana_program::pubkey::Pubkey>,
+    ncn: Option<solana_program::pubkey::Pubkey>,
+    tie_breaker_admin: Option<solana_program::pubkey::Pubkey>,
+    members: Option<[Pubkey; 8]>,
+    threshold: Option<u8>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AdminSetTieBreakerCommitteeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn tie_breaker_committee(
+        &mut self,
+        tie_breaker_committee: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.tie_breaker_committee = Some(tie_breaker_committee);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn tie_breaker_admin(
+        &mut self,
+        tie_breaker_admin: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.tie_breaker_admin = Some(tie_breaker_admin);
+        self
+    }
+    #[inline(always)]
+    pub fn members(&mut self, members: [Pubkey; 8]) -> &mut Self {
+        self.members = Some(members);
+        self
+    }
+    #[inline(always)]
+    pub fn threshold(&mut self, threshold: u8) -> &mut Self {
+        self.threshold = Some(threshold);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AdminSetTieBreakerCommittee {
+            config: self.config.expect("config is not set"),
+            tie_breaker_committee: self
+                .tie_breaker_committee
+                .expect("tie_breaker_committee is not set"),
+            ncn: self.ncn.expect("ncn is not set"),
+            tie_breaker_admin: self
+                .tie_breaker_admin
+                .expect("tie_breaker_admin is not set"),
+        };
+        let args = AdminSetTieBreakerCommitteeInstructionArgs {
+            members: self.members.clone().expect("members is not set"),
+            threshold: self.threshold.clone().expect("threshold is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `admin_set_tie_breaker_committee` CPI accounts.
+pub struct AdminSetTieBreakerCommitteeCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tie_breaker_committee: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tie_breaker_admin: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `admin_set_tie_breaker_committee` CPI instruction.
+pub struct AdminSetTieBreakerCommitteeCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tie_breaker_committee: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tie_breaker_admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// The arguments for the instruction.
+    pub __args: AdminSetTieBreakerCommitteeInstructionArgs,
+}
+
+impl<'a, 'b> AdminSetTieBreakerCommitteeCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AdminSetTieBreakerCommitteeCpiAccounts<'a, 'b>,
+        args: AdminSetTieBreakerCommitteeInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            tie_breaker_committee: accounts.tie_breaker_committee,
+            ncn: accounts.ncn,
+            tie_breaker_admin: accounts.tie_breaker_admin,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tie_breaker_committee.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.tie_breaker_admin.key,
+            true,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = AdminSetTieBreakerCommitteeInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.tie_breaker_committee.clone());
+        account_infos.push(self.ncn.clone());
+        account_infos.push(self.tie_breaker_admin.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AdminSetTieBreakerCommittee` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` tie_breaker_committee
+///   2. `[]` ncn
+///   3. `[signer]` tie_breaker_admin
+#[derive(Clone, Debug)]
+pub struct AdminSetTieBreakerCommitteeCpiBuilder<'a, 'b> {
+    instruction: Box<AdminSetTieBreakerCommitteeCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AdminSetTieBreakerCommitteeCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AdminSetTieBreakerCommitteeCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            tie_breaker_committee: None,
+            ncn: None,
+            tie_breaker_admin: None,
+            members: None,
+            threshold: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn tie_breaker_committee(
+        &mut self,
+        tie_breaker_committee: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tie_breaker_committee = Some(tie_breaker_committee);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn tie_breaker_admin(
+        &mut self,
+        tie_breaker_admin: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tie_breaker_admin = Some(tie_breaker_admin);
+        self
+    }
+    #[inline(always)]
+    pub fn members(&mut self, members: [Pubkey; 8]) -> &mut Self {
+        self.instruction.members = Some(members);
+        self
+    }
+    #[inline(always)]
+    pub fn threshold(&mut self, threshold: u8) -> &mut Self {
+        self.instruction.threshold = Some(threshold);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AdminSetTieBreakerCommitteeInstructionArgs {
+            members: self
+                .instruction
+                .members
+                .clone()
+                .expect("members is not set"),
+            threshold: self
+                .instruction
+                .threshold
+                .clone()
+                .expect("threshold is not set"),
+        };
+        let instruction = AdminSetTieBreakerCommitteeCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            tie_breaker_committee: self
+                .instruction
+                .tie_breaker_committee
+                .expect("tie_breaker_committee is not set"),
+
+            ncn: self.instruction.ncn.expect("ncn is not set"),
+
+            tie_breaker_admin: self
+                .instruction
+                .tie_breaker_admin
+                .expect("tie_breaker_admin is not set"),
+
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AdminSetTieBreakerCommitteeCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tie_breaker_committee: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tie_breaker_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    members: Option<[Pubkey; 8]>,
+    threshold: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
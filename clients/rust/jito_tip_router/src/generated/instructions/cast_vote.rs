@@ -34,6 +34,7 @@ pub struct CastVote {
           
               
           pub operator_voter: solana_program::pubkey::Pubkey,
+          pub operator_record: solana_program::pubkey::Pubkey,
       }
 
 impl CastVote {
@@ -42,7 +43,7 @@ impl CastVote {
   }
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: CastVoteInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false
@@ -63,7 +64,7 @@ impl CastVote {
             self.epoch_snapshot,
             false
           ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
             self.operator_snapshot,
             false
           ));
@@ -74,6 +75,10 @@ impl CastVote {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.operator_voter,
             true
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            self.operator_record,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = CastVoteInstructionData::new().try_to_vec().unwrap();
@@ -112,6 +117,10 @@ impl Default for CastVoteInstructionData {
 pub struct CastVoteInstructionArgs {
                   pub meta_merkle_root: [u8; 32],
                 pub epoch: u64,
+                pub abstain: bool,
+                pub snapshot_slot: u64,
+                pub bank_hash: [u8; 32],
+                pub tip_distribution_account_count: u64,
       }
 
 
@@ -124,9 +133,10 @@ pub struct CastVoteInstructionArgs {
                 ///   2. `[writable]` ballot_box
           ///   3. `[]` ncn
           ///   4. `[]` epoch_snapshot
-          ///   5. `[]` operator_snapshot
+          ///   5. `[writable]` operator_snapshot
           ///   6. `[]` operator
                 ///   7. `[signer]` operator_voter
+                ///   8. `[writable]` operator_record
 #[derive(Clone, Debug, Default)]
 pub struct CastVoteBuilder {
             epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -137,8 +147,13 @@ pub struct CastVoteBuilder {
                 operator_snapshot: Option<solana_program::pubkey::Pubkey>,
                 operator: Option<solana_program::pubkey::Pubkey>,
                 operator_voter: Option<solana_program::pubkey::Pubkey>,
+                operator_record: Option<solana_program::pubkey::Pubkey>,
                         meta_merkle_root: Option<[u8; 32]>,
                 epoch: Option<u64>,
+                abstain: Option<bool>,
+                snapshot_slot: Option<u64>,
+                bank_hash: Option<[u8; 32]>,
+                tip_distribution_account_count: Option<u64>,
         __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -185,6 +200,11 @@ impl CastVoteBuilder {
     pub fn operator_voter(&mut self, operator_voter: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.operator_voter = Some(operator_voter);
                     self
+    }
+            #[inline(always)]
+    pub fn operator_record(&mut self, operator_record: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.operator_record = Some(operator_record);
+                    self
     }
                     #[inline(always)]
       pub fn meta_merkle_root(&mut self, meta_merkle_root: [u8; 32]) -> &mut Self {
@@ -195,6 +215,26 @@ impl CastVoteBuilder {
       pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
         self
+      }
+                #[inline(always)]
+      pub fn abstain(&mut self, abstain: bool) -> &mut Self {
+        self.abstain = Some(abstain);
+        self
+      }
+                #[inline(always)]
+      pub fn snapshot_slot(&mut self, snapshot_slot: u64) -> &mut Self {
+        self.snapshot_slot = Some(snapshot_slot);
+        self
+      }
+                #[inline(always)]
+      pub fn bank_hash(&mut self, bank_hash: [u8; 32]) -> &mut Self {
+        self.bank_hash = Some(bank_hash);
+        self
+      }
+                #[inline(always)]
+      pub fn tip_distribution_account_count(&mut self, tip_distribution_account_count: u64) -> &mut Self {
+        self.tip_distribution_account_count = Some(tip_distribution_account_count);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -219,10 +259,15 @@ impl CastVoteBuilder {
                                         operator_snapshot: self.operator_snapshot.expect("operator_snapshot is not set"),
                                         operator: self.operator.expect("operator is not set"),
                                         operator_voter: self.operator_voter.expect("operator_voter is not set"),
+                                        operator_record: self.operator_record.expect("operator_record is not set"),
                       };
           let args = CastVoteInstructionArgs {
                                                               meta_merkle_root: self.meta_merkle_root.clone().expect("meta_merkle_root is not set"),
                                                                   epoch: self.epoch.clone().expect("epoch is not set"),
+                                                                  abstain: self.abstain.clone().expect("abstain is not set"),
+                                                                  snapshot_slot: self.snapshot_slot.clone().expect("snapshot_slot is not set"),
+                                                                  bank_hash: self.bank_hash.clone().expect("bank_hash is not set"),
+                                                                  tip_distribution_account_count: self.tip_distribution_account_count.clone().expect("tip_distribution_account_count is not set"),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -255,6 +300,7 @@ impl CastVoteBuilder {
                 
                     
               pub operator_voter: &'b solana_program::account_info::AccountInfo<'a>,
+              pub operator_record: &'b solana_program::account_info::AccountInfo<'a>,
             }
 
 /// `cast_vote` CPI instruction.
@@ -285,6 +331,7 @@ pub struct CastVoteCpi<'a, 'b> {
           
               
           pub operator_voter: &'b solana_program::account_info::AccountInfo<'a>,
+          pub operator_record: &'b solana_program::account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: CastVoteInstructionArgs,
   }
@@ -305,6 +352,7 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
               operator_snapshot: accounts.operator_snapshot,
               operator: accounts.operator,
               operator_voter: accounts.operator_voter,
+              operator_record: accounts.operator_record,
                     __args: args,
           }
   }
@@ -327,7 +375,7 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false
@@ -348,7 +396,7 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
             *self.epoch_snapshot.key,
             false
           ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
             *self.operator_snapshot.key,
             false
           ));
@@ -359,6 +407,10 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.operator_voter.key,
             true
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.operator_record.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_program::instruction::AccountMeta {
@@ -376,7 +428,7 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.epoch_state.clone());
                         account_infos.push(self.config.clone());
@@ -386,6 +438,7 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
                         account_infos.push(self.operator_snapshot.clone());
                         account_infos.push(self.operator.clone());
                         account_infos.push(self.operator_voter.clone());
+                        account_infos.push(self.operator_record.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -405,9 +458,10 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
                 ///   2. `[writable]` ballot_box
           ///   3. `[]` ncn
           ///   4. `[]` epoch_snapshot
-          ///   5. `[]` operator_snapshot
+          ///   5. `[writable]` operator_snapshot
           ///   6. `[]` operator
                 ///   7. `[signer]` operator_voter
+                ///   8. `[writable]` operator_record
 #[derive(Clone, Debug)]
 pub struct CastVoteCpiBuilder<'a, 'b> {
   instruction: Box<CastVoteCpiBuilderInstruction<'a, 'b>>,
@@ -425,8 +479,13 @@ impl<'a, 'b> CastVoteCpiBuilder<'a, 'b> {
               operator_snapshot: None,
               operator: None,
               operator_voter: None,
+              operator_record: None,
                                             meta_merkle_root: None,
                                 epoch: None,
+                                abstain: None,
+                                snapshot_slot: None,
+                                bank_hash: None,
+                                tip_distribution_account_count: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -470,6 +529,11 @@ impl<'a, 'b> CastVoteCpiBuilder<'a, 'b> {
     pub fn operator_voter(&mut self, operator_voter: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.operator_voter = Some(operator_voter);
                     self
+    }
+            #[inline(always)]
+    pub fn operator_record(&mut self, operator_record: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.operator_record = Some(operator_record);
+                    self
     }
                     #[inline(always)]
       pub fn meta_merkle_root(&mut self, meta_merkle_root: [u8; 32]) -> &mut Self {
@@ -480,6 +544,26 @@ impl<'a, 'b> CastVoteCpiBuilder<'a, 'b> {
       pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
         self
+      }
+                #[inline(always)]
+      pub fn abstain(&mut self, abstain: bool) -> &mut Self {
+        self.instruction.abstain = Some(abstain);
+        self
+      }
+                #[inline(always)]
+      pub fn snapshot_slot(&mut self, snapshot_slot: u64) -> &mut Self {
+        self.instruction.snapshot_slot = Some(snapshot_slot);
+        self
+      }
+                #[inline(always)]
+      pub fn bank_hash(&mut self, bank_hash: [u8; 32]) -> &mut Self {
+        self.instruction.bank_hash = Some(bank_hash);
+        self
+      }
+                #[inline(always)]
+      pub fn tip_distribution_account_count(&mut self, tip_distribution_account_count: u64) -> &mut Self {
+        self.instruction.tip_distribution_account_count = Some(tip_distribution_account_count);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -506,6 +590,10 @@ impl<'a, 'b> CastVoteCpiBuilder<'a, 'b> {
           let args = CastVoteInstructionArgs {
                                                               meta_merkle_root: self.instruction.meta_merkle_root.clone().expect("meta_merkle_root is not set"),
                                                                   epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+                                                                  abstain: self.instruction.abstain.clone().expect("abstain is not set"),
+                                                                  snapshot_slot: self.instruction.snapshot_slot.clone().expect("snapshot_slot is not set"),
+                                                                  bank_hash: self.instruction.bank_hash.clone().expect("bank_hash is not set"),
+                                                                  tip_distribution_account_count: self.instruction.tip_distribution_account_count.clone().expect("tip_distribution_account_count is not set"),
                                     };
         let instruction = CastVoteCpi {
         __program: self.instruction.__program,
@@ -525,6 +613,7 @@ impl<'a, 'b> CastVoteCpiBuilder<'a, 'b> {
           operator: self.instruction.operator.expect("operator is not set"),
                   
           operator_voter: self.instruction.operator_voter.expect("operator_voter is not set"),
+          operator_record: self.instruction.operator_record.expect("operator_record is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -542,8 +631,13 @@ struct CastVoteCpiBuilderInstruction<'a, 'b> {
                 operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 operator_voter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                operator_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                         meta_merkle_root: Option<[u8; 32]>,
                 epoch: Option<u64>,
+                abstain: Option<bool>,
+                snapshot_slot: Option<u64>,
+                bank_hash: Option<[u8; 32]>,
+                tip_distribution_account_count: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
 }
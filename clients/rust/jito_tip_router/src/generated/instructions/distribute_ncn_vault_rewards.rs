@@ -64,6 +64,7 @@ pub struct DistributeNcnVaultRewards {
           
               
           pub system_program: solana_program::pubkey::Pubkey,
+          pub vault_registry: solana_program::pubkey::Pubkey,
       }
 
 impl DistributeNcnVaultRewards {
@@ -72,7 +73,7 @@ impl DistributeNcnVaultRewards {
   }
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: DistributeNcnVaultRewardsInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false
@@ -144,6 +145,10 @@ impl DistributeNcnVaultRewards {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault_registry,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = DistributeNcnVaultRewardsInstructionData::new().try_to_vec().unwrap();
@@ -207,6 +212,7 @@ pub struct DistributeNcnVaultRewardsInstructionArgs {
                 ///   15. `[writable]` pool_mint
                 ///   16. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
                 ///   17. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   18. `[]` vault_registry
 #[derive(Clone, Debug, Default)]
 pub struct DistributeNcnVaultRewardsBuilder {
             epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -227,6 +233,7 @@ pub struct DistributeNcnVaultRewardsBuilder {
                 pool_mint: Option<solana_program::pubkey::Pubkey>,
                 token_program: Option<solana_program::pubkey::Pubkey>,
                 system_program: Option<solana_program::pubkey::Pubkey>,
+                vault_registry: Option<solana_program::pubkey::Pubkey>,
                         ncn_fee_group: Option<u8>,
                 epoch: Option<u64>,
         __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -327,6 +334,11 @@ impl DistributeNcnVaultRewardsBuilder {
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.system_program = Some(system_program);
                     self
+    }
+            #[inline(always)]
+    pub fn vault_registry(&mut self, vault_registry: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.vault_registry = Some(vault_registry);
+                    self
     }
                     #[inline(always)]
       pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
@@ -371,6 +383,7 @@ impl DistributeNcnVaultRewardsBuilder {
                                         pool_mint: self.pool_mint.expect("pool_mint is not set"),
                                         token_program: self.token_program.unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         system_program: self.system_program.unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+                                        vault_registry: self.vault_registry.expect("vault_registry is not set"),
                       };
           let args = DistributeNcnVaultRewardsInstructionArgs {
                                                               ncn_fee_group: self.ncn_fee_group.clone().expect("ncn_fee_group is not set"),
@@ -437,6 +450,7 @@ impl DistributeNcnVaultRewardsBuilder {
                 
                     
               pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+              pub vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
             }
 
 /// `distribute_ncn_vault_rewards` CPI instruction.
@@ -497,6 +511,7 @@ pub struct DistributeNcnVaultRewardsCpi<'a, 'b> {
           
               
           pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+          pub vault_registry: &'b solana_program::account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: DistributeNcnVaultRewardsInstructionArgs,
   }
@@ -527,6 +542,7 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
               pool_mint: accounts.pool_mint,
               token_program: accounts.token_program,
               system_program: accounts.system_program,
+              vault_registry: accounts.vault_registry,
                     __args: args,
           }
   }
@@ -549,7 +565,7 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false
@@ -621,6 +637,10 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault_registry.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_program::instruction::AccountMeta {
@@ -638,7 +658,7 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(18 + 1 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(19 + 1 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.epoch_state.clone());
                         account_infos.push(self.config.clone());
@@ -658,6 +678,7 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
                         account_infos.push(self.pool_mint.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.system_program.clone());
+                        account_infos.push(self.vault_registry.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -690,6 +711,7 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
                 ///   15. `[writable]` pool_mint
           ///   16. `[]` token_program
           ///   17. `[]` system_program
+          ///   18. `[]` vault_registry
 #[derive(Clone, Debug)]
 pub struct DistributeNcnVaultRewardsCpiBuilder<'a, 'b> {
   instruction: Box<DistributeNcnVaultRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -717,6 +739,7 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpiBuilder<'a, 'b> {
               pool_mint: None,
               token_program: None,
               system_program: None,
+              vault_registry: None,
                                             ncn_fee_group: None,
                                 epoch: None,
                     __remaining_accounts: Vec::new(),
@@ -812,6 +835,11 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpiBuilder<'a, 'b> {
     pub fn system_program(&mut self, system_program: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.system_program = Some(system_program);
                     self
+    }
+            #[inline(always)]
+    pub fn vault_registry(&mut self, vault_registry: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.vault_registry = Some(vault_registry);
+                    self
     }
                     #[inline(always)]
       pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
@@ -887,6 +915,7 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpiBuilder<'a, 'b> {
           token_program: self.instruction.token_program.expect("token_program is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
+          vault_registry: self.instruction.vault_registry.expect("vault_registry is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -914,6 +943,7 @@ struct DistributeNcnVaultRewardsCpiBuilderInstruction<'a, 'b> {
                 pool_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                vault_registry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                         ncn_fee_group: Option<u8>,
                 epoch: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
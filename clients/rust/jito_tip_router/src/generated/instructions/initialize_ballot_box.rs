@@ -73,7 +73,7 @@ impl InitializeBallotBox {
             self.epoch_marker,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             self.epoch_state,
             false
@@ -142,7 +142,7 @@ pub struct InitializeBallotBoxInstructionArgs {
 /// ### Accounts:
 ///
 <<<<<<< HEAD
-          ///   0. `[]` epoch_state
+          ///   0. `[writable]` epoch_state
           ///   1. `[]` config
                 ///   2. `[writable]` ballot_box
           ///   3. `[]` ncn
@@ -167,7 +167,7 @@ impl InitializeBallotBoxBuilder {
             #[inline(always)]
 =======
 ///   0. `[]` epoch_marker
-///   1. `[]` epoch_state
+///   1. `[writable]` epoch_state
 ///   2. `[]` config
 ///   3. `[writable]` ballot_box
 ///   4. `[]` ncn
@@ -484,7 +484,7 @@ impl<'a, 'b> InitializeBallotBoxCpi<'a, 'b> {
             *self.epoch_marker.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             *self.epoch_state.key,
             false
@@ -574,7 +574,7 @@ impl<'a, 'b> InitializeBallotBoxCpi<'a, 'b> {
 /// ### Accounts:
 ///
 <<<<<<< HEAD
-          ///   0. `[]` epoch_state
+          ///   0. `[writable]` epoch_state
           ///   1. `[]` config
                 ///   2. `[writable]` ballot_box
           ///   3. `[]` ncn
@@ -582,7 +582,7 @@ impl<'a, 'b> InitializeBallotBoxCpi<'a, 'b> {
           ///   5. `[]` system_program
 =======
 ///   0. `[]` epoch_marker
-///   1. `[]` epoch_state
+///   1. `[writable]` epoch_state
 ///   2. `[]` config
 ///   3. `[writable]` ballot_box
 ///   4. `[]` ncn
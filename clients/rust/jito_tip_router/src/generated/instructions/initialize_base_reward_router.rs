@@ -73,7 +73,7 @@ impl InitializeBaseRewardRouter {
             self.epoch_marker,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             self.epoch_state,
             false
@@ -142,7 +142,7 @@ pub struct InitializeBaseRewardRouterInstructionArgs {
 /// ### Accounts:
 ///
 <<<<<<< HEAD
-          ///   0. `[]` epoch_state
+          ///   0. `[writable]` epoch_state
           ///   1. `[]` ncn
                 ///   2. `[writable]` base_reward_router
                 ///   3. `[writable]` base_reward_receiver
@@ -167,7 +167,7 @@ impl InitializeBaseRewardRouterBuilder {
             #[inline(always)]
 =======
 ///   0. `[]` epoch_marker
-///   1. `[]` epoch_state
+///   1. `[writable]` epoch_state
 ///   2. `[]` ncn
 ///   3. `[writable]` base_reward_router
 ///   4. `[writable]` base_reward_receiver
@@ -488,7 +488,7 @@ impl<'a, 'b> InitializeBaseRewardRouterCpi<'a, 'b> {
             *self.epoch_marker.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             *self.epoch_state.key,
             false
@@ -578,7 +578,7 @@ impl<'a, 'b> InitializeBaseRewardRouterCpi<'a, 'b> {
 /// ### Accounts:
 ///
 <<<<<<< HEAD
-          ///   0. `[]` epoch_state
+          ///   0. `[writable]` epoch_state
           ///   1. `[]` ncn
                 ///   2. `[writable]` base_reward_router
                 ///   3. `[writable]` base_reward_receiver
@@ -586,7 +586,7 @@ impl<'a, 'b> InitializeBaseRewardRouterCpi<'a, 'b> {
           ///   5. `[]` system_program
 =======
 ///   0. `[]` epoch_marker
-///   1. `[]` epoch_state
+///   1. `[writable]` epoch_state
 ///   2. `[]` ncn
 ///   3. `[writable]` base_reward_router
 ///   4. `[writable]` base_reward_receiver
@@ -10,39 +10,6 @@ use borsh::BorshSerialize;
 
 /// Accounts.
 pub struct InitializeEpochSnapshot {
-<<<<<<< HEAD
-      
-              
-          pub epoch_state: solana_program::pubkey::Pubkey,
-          
-              
-          pub config: solana_program::pubkey::Pubkey,
-          
-              
-          pub ncn: solana_program::pubkey::Pubkey,
-          
-              
-          pub weight_table: solana_program::pubkey::Pubkey,
-          
-              
-          pub epoch_snapshot: solana_program::pubkey::Pubkey,
-          
-              
-          pub account_payer: solana_program::pubkey::Pubkey,
-          
-              
-          pub system_program: solana_program::pubkey::Pubkey,
-      }
-
-impl InitializeEpochSnapshot {
-  pub fn instruction(&self, args: InitializeEpochSnapshotInstructionArgs) -> solana_program::instruction::Instruction {
-    self.instruction_with_remaining_accounts(args, &[])
-  }
-  #[allow(clippy::vec_init_then_push)]
-  pub fn instruction_with_remaining_accounts(&self, args: InitializeEpochSnapshotInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
-                            accounts.push(solana_program::instruction::AccountMeta::new(
-=======
     pub epoch_marker: solana_program::pubkey::Pubkey,
 
     pub epoch_state: solana_program::pubkey::Pubkey,
@@ -58,6 +25,8 @@ impl InitializeEpochSnapshot {
     pub account_payer: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub fee_schedule: solana_program::pubkey::Pubkey,
 }
 
 impl InitializeEpochSnapshot {
@@ -73,110 +42,84 @@ impl InitializeEpochSnapshot {
         args: InitializeEpochSnapshotInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.epoch_marker,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
->>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             self.epoch_state,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.ncn,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.weight_table,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_snapshot,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.account_payer,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
-            false
-          ));
-                      accounts.extend_from_slice(remaining_accounts);
-    let mut data = InitializeEpochSnapshotInstructionData::new().try_to_vec().unwrap();
-          let mut args = args.try_to_vec().unwrap();
-      data.append(&mut args);
-    
-    solana_program::instruction::Instruction {
-      program_id: crate::JITO_TIP_ROUTER_ID,
-      accounts,
-      data,
-    }
-  }
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.fee_schedule,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = InitializeEpochSnapshotInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        }
+    }
 }
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct InitializeEpochSnapshotInstructionData {
-            discriminator: u8,
-            }
+    discriminator: u8,
+}
 
 impl InitializeEpochSnapshotInstructionData {
-  pub fn new() -> Self {
-    Self {
-                        discriminator: 9,
-                                }
-  }
+    pub fn new() -> Self {
+        Self { discriminator: 9 }
+    }
 }
 
 impl Default for InitializeEpochSnapshotInstructionData {
-  fn default() -> Self {
-    Self::new()
-  }
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeEpochSnapshotInstructionArgs {
-                  pub epoch: u64,
-      }
-
+    pub epoch: u64,
+}
 
 /// Instruction builder for `InitializeEpochSnapshot`.
 ///
 /// ### Accounts:
 ///
-<<<<<<< HEAD
-                ///   0. `[writable]` epoch_state
-          ///   1. `[]` config
-          ///   2. `[]` ncn
-          ///   3. `[]` weight_table
-                ///   4. `[writable]` epoch_snapshot
-                ///   5. `[writable]` account_payer
-                ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
-#[derive(Clone, Debug, Default)]
-pub struct InitializeEpochSnapshotBuilder {
-            epoch_state: Option<solana_program::pubkey::Pubkey>,
-                config: Option<solana_program::pubkey::Pubkey>,
-                ncn: Option<solana_program::pubkey::Pubkey>,
-                weight_table: Option<solana_program::pubkey::Pubkey>,
-                epoch_snapshot: Option<solana_program::pubkey::Pubkey>,
-                account_payer: Option<solana_program::pubkey::Pubkey>,
-                system_program: Option<solana_program::pubkey::Pubkey>,
-                        epoch: Option<u64>,
-        __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
-}
-
-impl InitializeEpochSnapshotBuilder {
-  pub fn new() -> Self {
-    Self::default()
-  }
-            #[inline(always)]
-=======
 ///   0. `[]` epoch_marker
 ///   1. `[writable]` epoch_state
 ///   2. `[]` config
@@ -185,6 +128,7 @@ impl InitializeEpochSnapshotBuilder {
 ///   5. `[writable]` epoch_snapshot
 ///   6. `[writable]` account_payer
 ///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[]` fee_schedule
 #[derive(Clone, Debug, Default)]
 pub struct InitializeEpochSnapshotBuilder {
     epoch_marker: Option<solana_program::pubkey::Pubkey>,
@@ -195,6 +139,7 @@ pub struct InitializeEpochSnapshotBuilder {
     epoch_snapshot: Option<solana_program::pubkey::Pubkey>,
     account_payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    fee_schedule: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -209,131 +154,50 @@ impl InitializeEpochSnapshotBuilder {
         self
     }
     #[inline(always)]
->>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
     pub fn epoch_state(&mut self, epoch_state: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.epoch_state = Some(epoch_state);
-                    self
+        self.epoch_state = Some(epoch_state);
+        self
     }
-            #[inline(always)]
+    #[inline(always)]
     pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.config = Some(config);
-                    self
+        self.config = Some(config);
+        self
     }
-            #[inline(always)]
+    #[inline(always)]
     pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.ncn = Some(ncn);
-                    self
+        self.ncn = Some(ncn);
+        self
     }
-            #[inline(always)]
+    #[inline(always)]
     pub fn weight_table(&mut self, weight_table: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.weight_table = Some(weight_table);
-                    self
+        self.weight_table = Some(weight_table);
+        self
     }
-            #[inline(always)]
+    #[inline(always)]
     pub fn epoch_snapshot(&mut self, epoch_snapshot: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.epoch_snapshot = Some(epoch_snapshot);
-                    self
+        self.epoch_snapshot = Some(epoch_snapshot);
+        self
     }
-            #[inline(always)]
+    #[inline(always)]
     pub fn account_payer(&mut self, account_payer: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.account_payer = Some(account_payer);
-                    self
+        self.account_payer = Some(account_payer);
+        self
     }
-            /// `[optional account, default to '11111111111111111111111111111111']`
-#[inline(always)]
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
-                        self.system_program = Some(system_program);
-                    self
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_schedule(&mut self, fee_schedule: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.fee_schedule = Some(fee_schedule);
+        self
     }
-                    #[inline(always)]
-      pub fn epoch(&mut self, epoch: u64) -> &mut Self {
+    #[inline(always)]
+    pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
         self
-<<<<<<< HEAD
-      }
-        /// Add an additional account to the instruction.
-  #[inline(always)]
-  pub fn add_remaining_account(&mut self, account: solana_program::instruction::AccountMeta) -> &mut Self {
-    self.__remaining_accounts.push(account);
-    self
-  }
-  /// Add additional accounts to the instruction.
-  #[inline(always)]
-  pub fn add_remaining_accounts(&mut self, accounts: &[solana_program::instruction::AccountMeta]) -> &mut Self {
-    self.__remaining_accounts.extend_from_slice(accounts);
-    self
-  }
-  #[allow(clippy::clone_on_copy)]
-  pub fn instruction(&self) -> solana_program::instruction::Instruction {
-    let accounts = InitializeEpochSnapshot {
-                              epoch_state: self.epoch_state.expect("epoch_state is not set"),
-                                        config: self.config.expect("config is not set"),
-                                        ncn: self.ncn.expect("ncn is not set"),
-                                        weight_table: self.weight_table.expect("weight_table is not set"),
-                                        epoch_snapshot: self.epoch_snapshot.expect("epoch_snapshot is not set"),
-                                        account_payer: self.account_payer.expect("account_payer is not set"),
-                                        system_program: self.system_program.unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
-                      };
-          let args = InitializeEpochSnapshotInstructionArgs {
-                                                              epoch: self.epoch.clone().expect("epoch is not set"),
-                                    };
-    
-    accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
-  }
-}
-
-  /// `initialize_epoch_snapshot` CPI accounts.
-  pub struct InitializeEpochSnapshotCpiAccounts<'a, 'b> {
-          
-                    
-              pub epoch_state: &'b solana_program::account_info::AccountInfo<'a>,
-                
-                    
-              pub config: &'b solana_program::account_info::AccountInfo<'a>,
-                
-                    
-              pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
-                
-                    
-              pub weight_table: &'b solana_program::account_info::AccountInfo<'a>,
-                
-                    
-              pub epoch_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
-                
-                    
-              pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
-                
-                    
-              pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
-            }
-
-/// `initialize_epoch_snapshot` CPI instruction.
-pub struct InitializeEpochSnapshotCpi<'a, 'b> {
-  /// The program to invoke.
-  pub __program: &'b solana_program::account_info::AccountInfo<'a>,
-      
-              
-          pub epoch_state: &'b solana_program::account_info::AccountInfo<'a>,
-          
-              
-          pub config: &'b solana_program::account_info::AccountInfo<'a>,
-          
-              
-          pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
-          
-              
-          pub weight_table: &'b solana_program::account_info::AccountInfo<'a>,
-          
-              
-          pub epoch_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
-          
-              
-          pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
-          
-              
-          pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
-            /// The arguments for the instruction.
-=======
     }
     /// Add an additional account to the instruction.
     #[inline(always)]
@@ -366,6 +230,7 @@ pub struct InitializeEpochSnapshotCpi<'a, 'b> {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            fee_schedule: self.fee_schedule.expect("fee_schedule is not set"),
         };
         let args = InitializeEpochSnapshotInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
@@ -392,6 +257,8 @@ pub struct InitializeEpochSnapshotCpiAccounts<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_schedule: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `initialize_epoch_snapshot` CPI instruction.
@@ -414,52 +281,14 @@ pub struct InitializeEpochSnapshotCpi<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
     /// The arguments for the instruction.
->>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
     pub __args: InitializeEpochSnapshotInstructionArgs,
-  }
+}
 
 impl<'a, 'b> InitializeEpochSnapshotCpi<'a, 'b> {
-<<<<<<< HEAD
-  pub fn new(
-    program: &'b solana_program::account_info::AccountInfo<'a>,
-          accounts: InitializeEpochSnapshotCpiAccounts<'a, 'b>,
-              args: InitializeEpochSnapshotInstructionArgs,
-      ) -> Self {
-    Self {
-      __program: program,
-              epoch_state: accounts.epoch_state,
-              config: accounts.config,
-              ncn: accounts.ncn,
-              weight_table: accounts.weight_table,
-              epoch_snapshot: accounts.epoch_snapshot,
-              account_payer: accounts.account_payer,
-              system_program: accounts.system_program,
-                    __args: args,
-          }
-  }
-  #[inline(always)]
-  pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
-    self.invoke_signed_with_remaining_accounts(&[], &[])
-  }
-  #[inline(always)]
-  pub fn invoke_with_remaining_accounts(&self, remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]) -> solana_program::entrypoint::ProgramResult {
-    self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
-  }
-  #[inline(always)]
-  pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program::entrypoint::ProgramResult {
-    self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
-  }
-  #[allow(clippy::clone_on_copy)]
-  #[allow(clippy::vec_init_then_push)]
-  pub fn invoke_signed_with_remaining_accounts(
-    &self,
-    signers_seeds: &[&[&[u8]]],
-    remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
-  ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
-                            accounts.push(solana_program::instruction::AccountMeta::new(
-=======
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: InitializeEpochSnapshotCpiAccounts<'a, 'b>,
@@ -475,6 +304,7 @@ impl<'a, 'b> InitializeEpochSnapshotCpi<'a, 'b> {
             epoch_snapshot: accounts.epoch_snapshot,
             account_payer: accounts.account_payer,
             system_program: accounts.system_program,
+            fee_schedule: accounts.fee_schedule,
             __args: args,
         }
     }
@@ -511,79 +341,62 @@ impl<'a, 'b> InitializeEpochSnapshotCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.epoch_marker.key,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
->>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             *self.epoch_state.key,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.ncn.key,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.weight_table.key,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_snapshot.key,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.account_payer.key,
-            false
-          ));
-                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
-            false
-          ));
-                      remaining_accounts.iter().for_each(|remaining_account| {
-      accounts.push(solana_program::instruction::AccountMeta {
-          pubkey: *remaining_account.0.key,
-          is_signer: remaining_account.1,
-          is_writable: remaining_account.2,
-      })
-    });
-    let mut data = InitializeEpochSnapshotInstructionData::new().try_to_vec().unwrap();
-          let mut args = self.__args.try_to_vec().unwrap();
-      data.append(&mut args);
-    
-    let instruction = solana_program::instruction::Instruction {
-      program_id: crate::JITO_TIP_ROUTER_ID,
-      accounts,
-      data,
-    };
-    let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
-    account_infos.push(self.__program.clone());
-                  account_infos.push(self.epoch_state.clone());
-                        account_infos.push(self.config.clone());
-                        account_infos.push(self.ncn.clone());
-                        account_infos.push(self.weight_table.clone());
-                        account_infos.push(self.epoch_snapshot.clone());
-                        account_infos.push(self.account_payer.clone());
-                        account_infos.push(self.system_program.clone());
-              remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
-
-<<<<<<< HEAD
-    if signers_seeds.is_empty() {
-      solana_program::program::invoke(&instruction, &account_infos)
-    } else {
-      solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
-=======
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.fee_schedule.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = InitializeEpochSnapshotInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::JITO_TIP_ROUTER_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_marker.clone());
         account_infos.push(self.epoch_state.clone());
@@ -593,6 +406,7 @@ impl<'a, 'b> InitializeEpochSnapshotCpi<'a, 'b> {
         account_infos.push(self.epoch_snapshot.clone());
         account_infos.push(self.account_payer.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.fee_schedule.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -602,24 +416,13 @@ impl<'a, 'b> InitializeEpochSnapshotCpi<'a, 'b> {
         } else {
             solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
         }
->>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
     }
-  }
 }
 
 /// Instruction builder for `InitializeEpochSnapshot` via CPI.
 ///
 /// ### Accounts:
 ///
-<<<<<<< HEAD
-                ///   0. `[writable]` epoch_state
-          ///   1. `[]` config
-          ///   2. `[]` ncn
-          ///   3. `[]` weight_table
-                ///   4. `[writable]` epoch_snapshot
-                ///   5. `[writable]` account_payer
-          ///   6. `[]` system_program
-=======
 ///   0. `[]` epoch_marker
 ///   1. `[writable]` epoch_state
 ///   2. `[]` config
@@ -627,40 +430,14 @@ impl<'a, 'b> InitializeEpochSnapshotCpi<'a, 'b> {
 ///   4. `[]` weight_table
 ///   5. `[writable]` epoch_snapshot
 ///   6. `[writable]` account_payer
-///   7. `[]` system_program
->>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
+///   7. `[optional]` system_program
+///   8. `[]` fee_schedule
 #[derive(Clone, Debug)]
 pub struct InitializeEpochSnapshotCpiBuilder<'a, 'b> {
-  instruction: Box<InitializeEpochSnapshotCpiBuilderInstruction<'a, 'b>>,
+    instruction: Box<InitializeEpochSnapshotCpiBuilderInstruction<'a, 'b>>,
 }
 
 impl<'a, 'b> InitializeEpochSnapshotCpiBuilder<'a, 'b> {
-<<<<<<< HEAD
-  pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
-    let instruction = Box::new(InitializeEpochSnapshotCpiBuilderInstruction {
-      __program: program,
-              epoch_state: None,
-              config: None,
-              ncn: None,
-              weight_table: None,
-              epoch_snapshot: None,
-              account_payer: None,
-              system_program: None,
-                                            epoch: None,
-                    __remaining_accounts: Vec::new(),
-    });
-    Self { instruction }
-  }
-      #[inline(always)]
-    pub fn epoch_state(&mut self, epoch_state: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.epoch_state = Some(epoch_state);
-                    self
-    }
-      #[inline(always)]
-    pub fn config(&mut self, config: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.config = Some(config);
-                    self
-=======
     pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
         let instruction = Box::new(InitializeEpochSnapshotCpiBuilderInstruction {
             __program: program,
@@ -672,6 +449,7 @@ impl<'a, 'b> InitializeEpochSnapshotCpiBuilder<'a, 'b> {
             epoch_snapshot: None,
             account_payer: None,
             system_program: None,
+            fee_schedule: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -692,85 +470,110 @@ impl<'a, 'b> InitializeEpochSnapshotCpiBuilder<'a, 'b> {
     ) -> &mut Self {
         self.instruction.epoch_state = Some(epoch_state);
         self
->>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
     }
-      #[inline(always)]
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
     pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.ncn = Some(ncn);
-                    self
-    }
-      #[inline(always)]
-    pub fn weight_table(&mut self, weight_table: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.weight_table = Some(weight_table);
-                    self
-    }
-      #[inline(always)]
-    pub fn epoch_snapshot(&mut self, epoch_snapshot: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.epoch_snapshot = Some(epoch_snapshot);
-                    self
-    }
-      #[inline(always)]
-    pub fn account_payer(&mut self, account_payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.account_payer = Some(account_payer);
-                    self
-    }
-      #[inline(always)]
-    pub fn system_program(&mut self, system_program: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.system_program = Some(system_program);
-                    self
-    }
-                    #[inline(always)]
-      pub fn epoch(&mut self, epoch: u64) -> &mut Self {
+        self.instruction.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn weight_table(
+        &mut self,
+        weight_table: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.weight_table = Some(weight_table);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch_snapshot(
+        &mut self,
+        epoch_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.epoch_snapshot = Some(epoch_snapshot);
+        self
+    }
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.account_payer = Some(account_payer);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_schedule(
+        &mut self,
+        fee_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_schedule = Some(fee_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
         self
-      }
-        /// Add an additional account to the instruction.
-  #[inline(always)]
-  pub fn add_remaining_account(&mut self, account: &'b solana_program::account_info::AccountInfo<'a>, is_writable: bool, is_signer: bool) -> &mut Self {
-    self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
-    self
-  }
-  /// Add additional accounts to the instruction.
-  ///
-  /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
-  /// and a `bool` indicating whether the account is a signer or not.
-  #[inline(always)]
-  pub fn add_remaining_accounts(&mut self, accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]) -> &mut Self {
-    self.instruction.__remaining_accounts.extend_from_slice(accounts);
-    self
-  }
-  #[inline(always)]
-  pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
-    self.invoke_signed(&[])
-  }
-  #[allow(clippy::clone_on_copy)]
-  #[allow(clippy::vec_init_then_push)]
-  pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program::entrypoint::ProgramResult {
-          let args = InitializeEpochSnapshotInstructionArgs {
-                                                              epoch: self.instruction.epoch.clone().expect("epoch is not set"),
-                                    };
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = InitializeEpochSnapshotInstructionArgs {
+            epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+        };
         let instruction = InitializeEpochSnapshotCpi {
-<<<<<<< HEAD
-        __program: self.instruction.__program,
-                  
-          epoch_state: self.instruction.epoch_state.expect("epoch_state is not set"),
-                  
-          config: self.instruction.config.expect("config is not set"),
-                  
-          ncn: self.instruction.ncn.expect("ncn is not set"),
-                  
-          weight_table: self.instruction.weight_table.expect("weight_table is not set"),
-                  
-          epoch_snapshot: self.instruction.epoch_snapshot.expect("epoch_snapshot is not set"),
-                  
-          account_payer: self.instruction.account_payer.expect("account_payer is not set"),
-                  
-          system_program: self.instruction.system_program.expect("system_program is not set"),
-                          __args: args,
-            };
-    instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
-  }
-=======
             __program: self.instruction.__program,
 
             epoch_marker: self
@@ -806,6 +609,12 @@ impl<'a, 'b> InitializeEpochSnapshotCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            fee_schedule: self
+                .instruction
+                .fee_schedule
+                .expect("fee_schedule is not set"),
+
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -813,24 +622,10 @@ impl<'a, 'b> InitializeEpochSnapshotCpiBuilder<'a, 'b> {
             &self.instruction.__remaining_accounts,
         )
     }
->>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
 }
 
 #[derive(Clone, Debug)]
 struct InitializeEpochSnapshotCpiBuilderInstruction<'a, 'b> {
-<<<<<<< HEAD
-  __program: &'b solana_program::account_info::AccountInfo<'a>,
-            epoch_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                weight_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                epoch_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                        epoch: Option<u64>,
-        /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
-  __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
-=======
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     epoch_marker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -840,6 +635,7 @@ struct InitializeEpochSnapshotCpiBuilderInstruction<'a, 'b> {
     epoch_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_schedule: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -847,6 +643,4 @@ struct InitializeEpochSnapshotCpiBuilderInstruction<'a, 'b> {
         bool,
         bool,
     )>,
->>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
 }
-
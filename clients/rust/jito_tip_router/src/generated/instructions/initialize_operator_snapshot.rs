@@ -88,7 +88,7 @@ impl InitializeOperatorSnapshot {
             self.epoch_marker,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             self.epoch_state,
             false
@@ -169,7 +169,7 @@ pub struct InitializeOperatorSnapshotInstructionArgs {
 /// ### Accounts:
 ///
 <<<<<<< HEAD
-          ///   0. `[]` epoch_state
+          ///   0. `[writable]` epoch_state
           ///   1. `[]` config
           ///   2. `[]` ncn
           ///   3. `[]` operator
@@ -200,7 +200,7 @@ impl InitializeOperatorSnapshotBuilder {
             #[inline(always)]
 =======
 ///   0. `[]` epoch_marker
-///   1. `[]` epoch_state
+///   1. `[writable]` epoch_state
 ///   2. `[]` config
 ///   3. `[]` ncn
 ///   4. `[]` operator
@@ -584,7 +584,7 @@ impl<'a, 'b> InitializeOperatorSnapshotCpi<'a, 'b> {
             *self.epoch_marker.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             *self.epoch_state.key,
             false
@@ -692,7 +692,7 @@ impl<'a, 'b> InitializeOperatorSnapshotCpi<'a, 'b> {
 /// ### Accounts:
 ///
 <<<<<<< HEAD
-          ///   0. `[]` epoch_state
+          ///   0. `[writable]` epoch_state
           ///   1. `[]` config
           ///   2. `[]` ncn
           ///   3. `[]` operator
@@ -703,7 +703,7 @@ impl<'a, 'b> InitializeOperatorSnapshotCpi<'a, 'b> {
           ///   8. `[]` system_program
 =======
 ///   0. `[]` epoch_marker
-///   1. `[]` epoch_state
+///   1. `[writable]` epoch_state
 ///   2. `[]` config
 ///   3. `[]` ncn
 ///   4. `[]` operator
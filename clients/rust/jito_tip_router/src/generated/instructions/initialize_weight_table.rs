@@ -73,7 +73,7 @@ impl InitializeWeightTable {
             self.epoch_marker,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             self.epoch_state,
             false
@@ -142,7 +142,7 @@ pub struct InitializeWeightTableInstructionArgs {
 /// ### Accounts:
 ///
 <<<<<<< HEAD
-          ///   0. `[]` epoch_state
+          ///   0. `[writable]` epoch_state
           ///   1. `[]` vault_registry
           ///   2. `[]` ncn
                 ///   3. `[writable]` weight_table
@@ -167,7 +167,7 @@ impl InitializeWeightTableBuilder {
             #[inline(always)]
 =======
 ///   0. `[]` epoch_marker
-///   1. `[]` epoch_state
+///   1. `[writable]` epoch_state
 ///   2. `[]` vault_registry
 ///   3. `[]` ncn
 ///   4. `[writable]` weight_table
@@ -484,7 +484,7 @@ impl<'a, 'b> InitializeWeightTableCpi<'a, 'b> {
             *self.epoch_marker.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
 >>>>>>> cf534adfb33ea5afa9eccb11b35199f5b149fea2
             *self.epoch_state.key,
             false
@@ -574,7 +574,7 @@ impl<'a, 'b> InitializeWeightTableCpi<'a, 'b> {
 /// ### Accounts:
 ///
 <<<<<<< HEAD
-          ///   0. `[]` epoch_state
+          ///   0. `[writable]` epoch_state
           ///   1. `[]` vault_registry
           ///   2. `[]` ncn
                 ///   3. `[writable]` weight_table
@@ -582,7 +582,7 @@ impl<'a, 'b> InitializeWeightTableCpi<'a, 'b> {
           ///   5. `[]` system_program
 =======
 ///   0. `[]` epoch_marker
-///   1. `[]` epoch_state
+///   1. `[writable]` epoch_state
 ///   2. `[]` vault_registry
 ///   3. `[]` ncn
 ///   4. `[writable]` weight_table
@@ -5,75 +5,119 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+  pub(crate) mod r#admin_accept_new_admin;
+  pub(crate) mod r#admin_cancel_new_admin;
+  pub(crate) mod r#admin_initialize_config_extension;
+  pub(crate) mod r#admin_initialize_fee_schedule;
+  pub(crate) mod r#admin_initialize_tie_breaker_committee;
+  pub(crate) mod r#admin_propose_new_admin;
   pub(crate) mod r#admin_register_st_mint;
+  pub(crate) mod r#admin_retire_st_mint;
+  pub(crate) mod r#admin_retire_vault;
   pub(crate) mod r#admin_set_config_fees;
-  pub(crate) mod r#admin_set_new_admin;
   pub(crate) mod r#admin_set_parameters;
+  pub(crate) mod r#admin_set_pause;
   pub(crate) mod r#admin_set_st_mint;
+  pub(crate) mod r#admin_set_st_mint_oracle_feeds;
   pub(crate) mod r#admin_set_tie_breaker;
+  pub(crate) mod r#admin_set_tie_breaker_committee;
+  pub(crate) mod r#admin_set_vault_reward_asset;
+  pub(crate) mod r#admin_set_vault_reward_multiplier;
   pub(crate) mod r#admin_set_weight;
   pub(crate) mod r#cast_vote;
   pub(crate) mod r#claim_with_payer;
   pub(crate) mod r#close_epoch_account;
+  pub(crate) mod r#commit_vote;
   pub(crate) mod r#distribute_base_ncn_reward_route;
   pub(crate) mod r#distribute_base_rewards;
   pub(crate) mod r#distribute_ncn_operator_rewards;
   pub(crate) mod r#distribute_ncn_vault_rewards;
+  pub(crate) mod r#finalize_stalled_ballot;
   pub(crate) mod r#initialize_ballot_box;
   pub(crate) mod r#initialize_base_reward_router;
   pub(crate) mod r#initialize_config;
   pub(crate) mod r#initialize_epoch_snapshot;
   pub(crate) mod r#initialize_epoch_state;
   pub(crate) mod r#initialize_ncn_reward_router;
+  pub(crate) mod r#initialize_operator_record;
   pub(crate) mod r#initialize_operator_snapshot;
   pub(crate) mod r#initialize_vault_registry;
   pub(crate) mod r#initialize_weight_table;
+  pub(crate) mod r#median_set_weight;
   pub(crate) mod r#realloc_ballot_box;
+  pub(crate) mod r#realloc_ballot_box_page;
   pub(crate) mod r#realloc_base_reward_router;
+  pub(crate) mod r#realloc_base_reward_router_page;
   pub(crate) mod r#realloc_epoch_state;
   pub(crate) mod r#realloc_operator_snapshot;
   pub(crate) mod r#realloc_vault_registry;
   pub(crate) mod r#realloc_weight_table;
   pub(crate) mod r#register_vault;
+  pub(crate) mod r#reveal_vote;
   pub(crate) mod r#route_base_rewards;
   pub(crate) mod r#route_ncn_rewards;
   pub(crate) mod r#set_merkle_root;
+  pub(crate) mod r#set_merkle_root_batch;
   pub(crate) mod r#snapshot_vault_operator_delegation;
+  pub(crate) mod r#sweep_dust;
   pub(crate) mod r#switchboard_set_weight;
+  pub(crate) mod r#tie_breaker_approve;
 
+  pub use self::r#admin_accept_new_admin::*;
+  pub use self::r#admin_cancel_new_admin::*;
+  pub use self::r#admin_initialize_config_extension::*;
+  pub use self::r#admin_initialize_fee_schedule::*;
+  pub use self::r#admin_initialize_tie_breaker_committee::*;
+  pub use self::r#admin_propose_new_admin::*;
   pub use self::r#admin_register_st_mint::*;
+  pub use self::r#admin_retire_st_mint::*;
+  pub use self::r#admin_retire_vault::*;
   pub use self::r#admin_set_config_fees::*;
-  pub use self::r#admin_set_new_admin::*;
   pub use self::r#admin_set_parameters::*;
+  pub use self::r#admin_set_pause::*;
   pub use self::r#admin_set_st_mint::*;
+  pub use self::r#admin_set_st_mint_oracle_feeds::*;
   pub use self::r#admin_set_tie_breaker::*;
+  pub use self::r#admin_set_tie_breaker_committee::*;
+  pub use self::r#admin_set_vault_reward_asset::*;
+  pub use self::r#admin_set_vault_reward_multiplier::*;
   pub use self::r#admin_set_weight::*;
   pub use self::r#cast_vote::*;
   pub use self::r#claim_with_payer::*;
   pub use self::r#close_epoch_account::*;
+  pub use self::r#commit_vote::*;
   pub use self::r#distribute_base_ncn_reward_route::*;
   pub use self::r#distribute_base_rewards::*;
   pub use self::r#distribute_ncn_operator_rewards::*;
   pub use self::r#distribute_ncn_vault_rewards::*;
+  pub use self::r#finalize_stalled_ballot::*;
   pub use self::r#initialize_ballot_box::*;
   pub use self::r#initialize_base_reward_router::*;
   pub use self::r#initialize_config::*;
   pub use self::r#initialize_epoch_snapshot::*;
   pub use self::r#initialize_epoch_state::*;
   pub use self::r#initialize_ncn_reward_router::*;
+  pub use self::r#initialize_operator_record::*;
   pub use self::r#initialize_operator_snapshot::*;
   pub use self::r#initialize_vault_registry::*;
   pub use self::r#initialize_weight_table::*;
+  pub use self::r#median_set_weight::*;
   pub use self::r#realloc_ballot_box::*;
+  pub use self::r#realloc_ballot_box_page::*;
   pub use self::r#realloc_base_reward_router::*;
+  pub use self::r#realloc_base_reward_router_page::*;
   pub use self::r#realloc_epoch_state::*;
   pub use self::r#realloc_operator_snapshot::*;
   pub use self::r#realloc_vault_registry::*;
   pub use self::r#realloc_weight_table::*;
   pub use self::r#register_vault::*;
+  pub use self::r#reveal_vote::*;
   pub use self::r#route_base_rewards::*;
   pub use self::r#route_ncn_rewards::*;
   pub use self::r#set_merkle_root::*;
+  pub use self::r#set_merkle_root_batch::*;
   pub use self::r#snapshot_vault_operator_delegation::*;
+  pub use self::r#sweep_dust::*;
   pub use self::r#switchboard_set_weight::*;
+  pub use self::r#tie_breaker_approve::*;
 
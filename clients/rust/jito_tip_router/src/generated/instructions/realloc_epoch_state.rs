@@ -25,6 +25,7 @@ pub struct ReallocEpochState {
           
               
           pub system_program: solana_program::pubkey::Pubkey,
+          pub config_extension: solana_program::pubkey::Pubkey,
       }
 
 impl ReallocEpochState {
@@ -33,7 +34,7 @@ impl ReallocEpochState {
   }
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: ReallocEpochStateInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false
@@ -53,6 +54,10 @@ impl ReallocEpochState {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config_extension,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = ReallocEpochStateInstructionData::new().try_to_vec().unwrap();
@@ -102,6 +107,7 @@ pub struct ReallocEpochStateInstructionArgs {
           ///   2. `[]` ncn
                 ///   3. `[writable]` account_payer
                 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   5. `[]` config_extension
 #[derive(Clone, Debug, Default)]
 pub struct ReallocEpochStateBuilder {
             epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -109,6 +115,7 @@ pub struct ReallocEpochStateBuilder {
                 ncn: Option<solana_program::pubkey::Pubkey>,
                 account_payer: Option<solana_program::pubkey::Pubkey>,
                 system_program: Option<solana_program::pubkey::Pubkey>,
+                config_extension: Option<solana_program::pubkey::Pubkey>,
                         epoch: Option<u64>,
         __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -142,6 +149,11 @@ impl ReallocEpochStateBuilder {
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.system_program = Some(system_program);
                     self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.config_extension = Some(config_extension);
+                    self
     }
                     #[inline(always)]
       pub fn epoch(&mut self, epoch: u64) -> &mut Self {
@@ -168,6 +180,7 @@ impl ReallocEpochStateBuilder {
                                         ncn: self.ncn.expect("ncn is not set"),
                                         account_payer: self.account_payer.expect("account_payer is not set"),
                                         system_program: self.system_program.unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+                                        config_extension: self.config_extension.expect("config_extension is not set"),
                       };
           let args = ReallocEpochStateInstructionArgs {
                                                               epoch: self.epoch.clone().expect("epoch is not set"),
@@ -194,6 +207,7 @@ impl ReallocEpochStateBuilder {
                 
                     
               pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+              pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             }
 
 /// `realloc_epoch_state` CPI instruction.
@@ -215,6 +229,7 @@ pub struct ReallocEpochStateCpi<'a, 'b> {
           
               
           pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+          pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: ReallocEpochStateInstructionArgs,
   }
@@ -232,6 +247,7 @@ impl<'a, 'b> ReallocEpochStateCpi<'a, 'b> {
               ncn: accounts.ncn,
               account_payer: accounts.account_payer,
               system_program: accounts.system_program,
+              config_extension: accounts.config_extension,
                     __args: args,
           }
   }
@@ -254,7 +270,7 @@ impl<'a, 'b> ReallocEpochStateCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false
@@ -274,6 +290,10 @@ impl<'a, 'b> ReallocEpochStateCpi<'a, 'b> {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config_extension.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_program::instruction::AccountMeta {
@@ -291,13 +311,14 @@ impl<'a, 'b> ReallocEpochStateCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.epoch_state.clone());
                         account_infos.push(self.config.clone());
                         account_infos.push(self.ncn.clone());
                         account_infos.push(self.account_payer.clone());
                         account_infos.push(self.system_program.clone());
+                        account_infos.push(self.config_extension.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -317,6 +338,7 @@ impl<'a, 'b> ReallocEpochStateCpi<'a, 'b> {
           ///   2. `[]` ncn
                 ///   3. `[writable]` account_payer
           ///   4. `[]` system_program
+          ///   5. `[]` config_extension
 #[derive(Clone, Debug)]
 pub struct ReallocEpochStateCpiBuilder<'a, 'b> {
   instruction: Box<ReallocEpochStateCpiBuilderInstruction<'a, 'b>>,
@@ -331,6 +353,7 @@ impl<'a, 'b> ReallocEpochStateCpiBuilder<'a, 'b> {
               ncn: None,
               account_payer: None,
               system_program: None,
+              config_extension: None,
                                             epoch: None,
                     __remaining_accounts: Vec::new(),
     });
@@ -360,6 +383,11 @@ impl<'a, 'b> ReallocEpochStateCpiBuilder<'a, 'b> {
     pub fn system_program(&mut self, system_program: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.system_program = Some(system_program);
                     self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.config_extension = Some(config_extension);
+                    self
     }
                     #[inline(always)]
       pub fn epoch(&mut self, epoch: u64) -> &mut Self {
@@ -403,6 +431,7 @@ impl<'a, 'b> ReallocEpochStateCpiBuilder<'a, 'b> {
           account_payer: self.instruction.account_payer.expect("account_payer is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
+          config_extension: self.instruction.config_extension.expect("config_extension is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -417,6 +446,7 @@ struct ReallocEpochStateCpiBuilderInstruction<'a, 'b> {
                 ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                config_extension: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                         epoch: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
@@ -40,6 +40,7 @@ pub struct ReallocOperatorSnapshot {
           
               
           pub system_program: solana_program::pubkey::Pubkey,
+          pub config_extension: solana_program::pubkey::Pubkey,
       }
 
 impl ReallocOperatorSnapshot {
@@ -48,7 +49,7 @@ impl ReallocOperatorSnapshot {
   }
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: ReallocOperatorSnapshotInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false
@@ -88,6 +89,10 @@ impl ReallocOperatorSnapshot {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config_extension,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = ReallocOperatorSnapshotInstructionData::new().try_to_vec().unwrap();
@@ -142,6 +147,7 @@ pub struct ReallocOperatorSnapshotInstructionArgs {
                 ///   7. `[writable]` operator_snapshot
                 ///   8. `[writable]` account_payer
                 ///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   10. `[]` config_extension
 #[derive(Clone, Debug, Default)]
 pub struct ReallocOperatorSnapshotBuilder {
             epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -154,6 +160,7 @@ pub struct ReallocOperatorSnapshotBuilder {
                 operator_snapshot: Option<solana_program::pubkey::Pubkey>,
                 account_payer: Option<solana_program::pubkey::Pubkey>,
                 system_program: Option<solana_program::pubkey::Pubkey>,
+                config_extension: Option<solana_program::pubkey::Pubkey>,
                         epoch: Option<u64>,
         __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -212,6 +219,11 @@ impl ReallocOperatorSnapshotBuilder {
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.system_program = Some(system_program);
                     self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.config_extension = Some(config_extension);
+                    self
     }
                     #[inline(always)]
       pub fn epoch(&mut self, epoch: u64) -> &mut Self {
@@ -243,6 +255,7 @@ impl ReallocOperatorSnapshotBuilder {
                                         operator_snapshot: self.operator_snapshot.expect("operator_snapshot is not set"),
                                         account_payer: self.account_payer.expect("account_payer is not set"),
                                         system_program: self.system_program.unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+                                        config_extension: self.config_extension.expect("config_extension is not set"),
                       };
           let args = ReallocOperatorSnapshotInstructionArgs {
                                                               epoch: self.epoch.clone().expect("epoch is not set"),
@@ -284,6 +297,7 @@ impl ReallocOperatorSnapshotBuilder {
                 
                     
               pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+              pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             }
 
 /// `realloc_operator_snapshot` CPI instruction.
@@ -320,6 +334,7 @@ pub struct ReallocOperatorSnapshotCpi<'a, 'b> {
           
               
           pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+          pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: ReallocOperatorSnapshotInstructionArgs,
   }
@@ -342,6 +357,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
               operator_snapshot: accounts.operator_snapshot,
               account_payer: accounts.account_payer,
               system_program: accounts.system_program,
+              config_extension: accounts.config_extension,
                     __args: args,
           }
   }
@@ -364,7 +380,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false
@@ -404,6 +420,10 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
                                           accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config_extension.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_program::instruction::AccountMeta {
@@ -421,7 +441,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.epoch_state.clone());
                         account_infos.push(self.config.clone());
@@ -433,6 +453,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
                         account_infos.push(self.operator_snapshot.clone());
                         account_infos.push(self.account_payer.clone());
                         account_infos.push(self.system_program.clone());
+                        account_infos.push(self.config_extension.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -457,6 +478,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
                 ///   7. `[writable]` operator_snapshot
                 ///   8. `[writable]` account_payer
           ///   9. `[]` system_program
+          ///   10. `[]` config_extension
 #[derive(Clone, Debug)]
 pub struct ReallocOperatorSnapshotCpiBuilder<'a, 'b> {
   instruction: Box<ReallocOperatorSnapshotCpiBuilderInstruction<'a, 'b>>,
@@ -476,6 +498,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpiBuilder<'a, 'b> {
               operator_snapshot: None,
               account_payer: None,
               system_program: None,
+              config_extension: None,
                                             epoch: None,
                     __remaining_accounts: Vec::new(),
     });
@@ -530,6 +553,11 @@ impl<'a, 'b> ReallocOperatorSnapshotCpiBuilder<'a, 'b> {
     pub fn system_program(&mut self, system_program: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.system_program = Some(system_program);
                     self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.config_extension = Some(config_extension);
+                    self
     }
                     #[inline(always)]
       pub fn epoch(&mut self, epoch: u64) -> &mut Self {
@@ -583,6 +611,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpiBuilder<'a, 'b> {
           account_payer: self.instruction.account_payer.expect("account_payer is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
+          config_extension: self.instruction.config_extension.expect("config_extension is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -602,6 +631,7 @@ struct ReallocOperatorSnapshotCpiBuilderInstruction<'a, 'b> {
                 operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                config_extension: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                         epoch: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
@@ -0,0 +1,735 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct RevealVote {
+    pub epoch_state: solana_program::pubkey::Pubkey,
+
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub ballot_box: solana_program::pubkey::Pubkey,
+
+    pub ncn: solana_program::pubkey::Pubkey,
+
+    pub epoch_snapshot: solana_program::pubkey::Pubkey,
+
+    pub operator_snapshot: solana_program::pubkey::Pubkey,
+
+    pub operator: solana_program::pubkey::Pubkey,
+
+    pub operator_voter: solana_program::pubkey::Pubkey,
+    pub operator_record: solana_program::pubkey::Pubkey,
+}
+
+impl RevealVote {
+    pub fn instruction(
+        &self,
+        args: RevealVoteInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: RevealVoteInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.epoch_state,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.ballot_box,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.epoch_snapshot,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.operator_snapshot,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.operator,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.operator_voter,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.operator_record,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = RevealVoteInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct RevealVoteInstructionData {
+    discriminator: u8,
+}
+
+impl RevealVoteInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 36 }
+    }
+}
+
+impl Default for RevealVoteInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevealVoteInstructionArgs {
+    pub meta_merkle_root: [u8; 32],
+    pub salt: [u8; 32],
+    pub epoch: u64,
+    pub snapshot_slot: u64,
+    pub bank_hash: [u8; 32],
+    pub tip_distribution_account_count: u64,
+}
+
+/// Instruction builder for `RevealVote`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` epoch_state
+///   1. `[]` config
+///   2. `[writable]` ballot_box
+///   3. `[]` ncn
+///   4. `[]` epoch_snapshot
+///   5. `[writable]` operator_snapshot
+///   6. `[]` operator
+///   7. `[signer]` operator_voter
+///   8. `[writable]` operator_record
+#[derive(Clone, Debug, Default)]
+pub struct RevealVoteBuilder {
+    epoch_state: Option<solana_program::pubkey::Pubkey>,
+    config: Option<solana_program::pubkey::Pubkey>,
+    ballot_box: Option<solana_program::pubkey::Pubkey>,
+    ncn: Option<solana_program::pubkey::Pubkey>,
+    epoch_snapshot: Option<solana_program::pubkey::Pubkey>,
+    operator_snapshot: Option<solana_program::pubkey::Pubkey>,
+    operator: Option<solana_program::pubkey::Pubkey>,
+    operator_voter: Option<solana_program::pubkey::Pubkey>,
+    operator_record: Option<solana_program::pubkey::Pubkey>,
+    meta_merkle_root: Option<[u8; 32]>,
+    salt: Option<[u8; 32]>,
+    epoch: Option<u64>,
+    snapshot_slot: Option<u64>,
+    bank_hash: Option<[u8; 32]>,
+    tip_distribution_account_count: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RevealVoteBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn epoch_state(&mut self, epoch_state: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.epoch_state = Some(epoch_state);
+        self
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn ballot_box(&mut self, ballot_box: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ballot_box = Some(ballot_box);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch_snapshot(&mut self, epoch_snapshot: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.epoch_snapshot = Some(epoch_snapshot);
+        self
+    }
+    #[inline(always)]
+    pub fn operator_snapshot(
+        &mut self,
+        operator_snapshot: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.operator_snapshot = Some(operator_snapshot);
+        self
+    }
+    #[inline(always)]
+    pub fn operator(&mut self, operator: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.operator = Some(operator);
+        self
+    }
+    #[inline(always)]
+    pub fn operator_voter(&mut self, operator_voter: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.operator_voter = Some(operator_voter);
+        self
+    }
+    #[inline(always)]
+    pub fn operator_record(
+        &mut self,
+        operator_record: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.operator_record = Some(operator_record);
+        self
+    }
+    #[inline(always)]
+    pub fn meta_merkle_root(&mut self, meta_merkle_root: [u8; 32]) -> &mut Self {
+        self.meta_merkle_root = Some(meta_merkle_root);
+        self
+    }
+    #[inline(always)]
+    pub fn salt(&mut self, salt: [u8; 32]) -> &mut Self {
+        self.salt = Some(salt);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch(&mut self, epoch: u64) -> &mut Self {
+        self.epoch = Some(epoch);
+        self
+    }
+    #[inline(always)]
+    pub fn snapshot_slot(&mut self, snapshot_slot: u64) -> &mut Self {
+        self.snapshot_slot = Some(snapshot_slot);
+        self
+    }
+    #[inline(always)]
+    pub fn bank_hash(&mut self, bank_hash: [u8; 32]) -> &mut Self {
+        self.bank_hash = Some(bank_hash);
+        self
+    }
+                #[inline(always)]
+    pub fn tip_distribution_account_count(&mut self, tip_distribution_account_count: u64) -> &mut Self {
+      self.tip_distribution_account_count = Some(tip_distribution_account_count);
+      self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = RevealVote {
+            epoch_state: self.epoch_state.expect("epoch_state is not set"),
+            config: self.config.expect("config is not set"),
+            ballot_box: self.ballot_box.expect("ballot_box is not set"),
+            ncn: self.ncn.expect("ncn is not set"),
+            epoch_snapshot: self.epoch_snapshot.expect("epoch_snapshot is not set"),
+            operator_snapshot: self
+                .operator_snapshot
+                .expect("operator_snapshot is not set"),
+            operator: self.operator.expect("operator is not set"),
+            operator_voter: self.operator_voter.expect("operator_voter is not set"),
+            operator_record: self.operator_record.expect("operator_record is not set"),
+        };
+        let args = RevealVoteInstructionArgs {
+            meta_merkle_root: self
+                .meta_merkle_root
+                .clone()
+                .expect("meta_merkle_root is not set"),
+            salt: self.salt.clone().expect("salt is not set"),
+            epoch: self.epoch.clone().expect("epoch is not set"),
+            snapshot_slot: self
+                .snapshot_slot
+                .clone()
+                .expect("snapshot_slot is not set"),
+            bank_hash: self.bank_hash.clone().expect("bank_hash is not set"),
+            tip_distribution_account_count: self.tip_distribution_account_count.clone().expect("tip_distribution_account_count is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `reveal_vote` CPI accounts.
+pub struct RevealVoteCpiAccounts<'a, 'b> {
+    pub epoch_state: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ballot_box: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub epoch_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_voter: &'b solana_program::account_info::AccountInfo<'a>,
+    pub operator_record: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `reveal_vote` CPI instruction.
+pub struct RevealVoteCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub epoch_state: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ballot_box: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub epoch_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_voter: &'b solana_program::account_info::AccountInfo<'a>,
+    pub operator_record: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// The arguments for the instruction.
+    pub __args: RevealVoteInstructionArgs,
+}
+
+impl<'a, 'b> RevealVoteCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RevealVoteCpiAccounts<'a, 'b>,
+        args: RevealVoteInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            epoch_state: accounts.epoch_state,
+            config: accounts.config,
+            ballot_box: accounts.ballot_box,
+            ncn: accounts.ncn,
+            epoch_snapshot: accounts.epoch_snapshot,
+            operator_snapshot: accounts.operator_snapshot,
+            operator: accounts.operator,
+            operator_voter: accounts.operator_voter,
+            operator_record: accounts.operator_record,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.epoch_state.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.ballot_box.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.epoch_snapshot.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.operator_snapshot.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.operator.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.operator_voter.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.operator_record.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = RevealVoteInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.epoch_state.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.ballot_box.clone());
+        account_infos.push(self.ncn.clone());
+        account_infos.push(self.epoch_snapshot.clone());
+        account_infos.push(self.operator_snapshot.clone());
+        account_infos.push(self.operator.clone());
+        account_infos.push(self.operator_voter.clone());
+        account_infos.push(self.operator_record.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `RevealVote` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` epoch_state
+///   1. `[]` config
+///   2. `[writable]` ballot_box
+///   3. `[]` ncn
+///   4. `[]` epoch_snapshot
+///   5. `[writable]` operator_snapshot
+///   6. `[]` operator
+///   7. `[signer]` operator_voter
+///   8. `[writable]` operator_record
+#[derive(Clone, Debug)]
+pub struct RevealVoteCpiBuilder<'a, 'b> {
+    instruction: Box<RevealVoteCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RevealVoteCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RevealVoteCpiBuilderInstruction {
+            __program: program,
+            epoch_state: None,
+            config: None,
+            ballot_box: None,
+            ncn: None,
+            epoch_snapshot: None,
+            operator_snapshot: None,
+            operator: None,
+            operator_voter: None,
+            operator_record: None,
+            meta_merkle_root: None,
+            salt: None,
+            epoch: None,
+            snapshot_slot: None,
+            bank_hash: None,
+            tip_distribution_account_count: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn epoch_state(
+        &mut self,
+        epoch_state: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.epoch_state = Some(epoch_state);
+        self
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn ballot_box(
+        &mut self,
+        ballot_box: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ballot_box = Some(ballot_box);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch_snapshot(
+        &mut self,
+        epoch_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.epoch_snapshot = Some(epoch_snapshot);
+        self
+    }
+    #[inline(always)]
+    pub fn operator_snapshot(
+        &mut self,
+        operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.operator_snapshot = Some(operator_snapshot);
+        self
+    }
+    #[inline(always)]
+    pub fn operator(
+        &mut self,
+        operator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.operator = Some(operator);
+        self
+    }
+    #[inline(always)]
+    pub fn operator_voter(
+        &mut self,
+        operator_voter: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.operator_voter = Some(operator_voter);
+        self
+    }
+    #[inline(always)]
+    pub fn operator_record(
+        &mut self,
+        operator_record: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.operator_record = Some(operator_record);
+        self
+    }
+    #[inline(always)]
+    pub fn meta_merkle_root(&mut self, meta_merkle_root: [u8; 32]) -> &mut Self {
+        self.instruction.meta_merkle_root = Some(meta_merkle_root);
+        self
+    }
+    #[inline(always)]
+    pub fn salt(&mut self, salt: [u8; 32]) -> &mut Self {
+        self.instruction.salt = Some(salt);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch(&mut self, epoch: u64) -> &mut Self {
+        self.instruction.epoch = Some(epoch);
+        self
+    }
+    #[inline(always)]
+    pub fn snapshot_slot(&mut self, snapshot_slot: u64) -> &mut Self {
+        self.instruction.snapshot_slot = Some(snapshot_slot);
+        self
+    }
+    #[inline(always)]
+    pub fn bank_hash(&mut self, bank_hash: [u8; 32]) -> &mut Self {
+        self.instruction.bank_hash = Some(bank_hash);
+        self
+    }
+                #[inline(always)]
+    pub fn tip_distribution_account_count(&mut self, tip_distribution_account_count: u64) -> &mut Self {
+      self.instruction.tip_distribution_account_count = Some(tip_distribution_account_count);
+      self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = RevealVoteInstructionArgs {
+            meta_merkle_root: self
+                .instruction
+                .meta_merkle_root
+                .clone()
+                .expect("meta_merkle_root is not set"),
+            salt: self.instruction.salt.clone().expect("salt is not set"),
+            epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+            snapshot_slot: self
+                .instruction
+                .snapshot_slot
+                .clone()
+                .expect("snapshot_slot is not set"),
+            bank_hash: self
+                .instruction
+                .bank_hash
+                .clone()
+                .expect("bank_hash is not set"),
+        };
+        let instruction = RevealVoteCpi {
+            __program: self.instruction.__program,
+
+            epoch_state: self
+                .instruction
+                .epoch_state
+                .expect("epoch_state is not set"),
+
+            config: self.instruction.config.expect("config is not set"),
+
+            ballot_box: self.instruction.ballot_box.expect("ballot_box is not set"),
+
+            ncn: self.instruction.ncn.expect("ncn is not set"),
+
+            epoch_snapshot: self
+                .instruction
+                .epoch_snapshot
+                .expect("epoch_snapshot is not set"),
+
+            operator_snapshot: self
+                .instruction
+                .operator_snapshot
+                .expect("operator_snapshot is not set"),
+
+            operator: self.instruction.operator.expect("operator is not set"),
+
+            operator_voter: self
+                .instruction
+                .operator_voter
+                .expect("operator_voter is not set"),
+
+            operator_record: self
+                .instruction
+                .operator_record
+                .expect("operator_record is not set"),
+
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct RevealVoteCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    epoch_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ballot_box: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_voter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    meta_merkle_root: Option<[u8; 32]>,
+    salt: Option<[u8; 32]>,
+    epoch: Option<u64>,
+    snapshot_slot: Option<u64>,
+    bank_hash: Option<[u8; 32]>,
+    tip_distribution_account_count: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
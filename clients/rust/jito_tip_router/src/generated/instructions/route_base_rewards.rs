@@ -31,6 +31,9 @@ pub struct RouteBaseRewards {
           
               
           pub base_reward_receiver: solana_program::pubkey::Pubkey,
+          pub account_payer: solana_program::pubkey::Pubkey,
+          pub system_program: solana_program::pubkey::Pubkey,
+          pub config_extension: solana_program::pubkey::Pubkey,
       }
 
 impl RouteBaseRewards {
@@ -39,7 +42,7 @@ impl RouteBaseRewards {
   }
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: RouteBaseRewardsInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false
@@ -67,6 +70,18 @@ impl RouteBaseRewards {
                                           accounts.push(solana_program::instruction::AccountMeta::new(
             self.base_reward_receiver,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            self.account_payer,
+            false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config_extension,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = RouteBaseRewardsInstructionData::new().try_to_vec().unwrap();
@@ -119,6 +134,9 @@ pub struct RouteBaseRewardsInstructionArgs {
           ///   4. `[]` ballot_box
                 ///   5. `[writable]` base_reward_router
                 ///   6. `[writable]` base_reward_receiver
+                ///   7. `[writable]` account_payer
+                ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   9. `[]` config_extension
 #[derive(Clone, Debug, Default)]
 pub struct RouteBaseRewardsBuilder {
             epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -128,6 +146,9 @@ pub struct RouteBaseRewardsBuilder {
                 ballot_box: Option<solana_program::pubkey::Pubkey>,
                 base_reward_router: Option<solana_program::pubkey::Pubkey>,
                 base_reward_receiver: Option<solana_program::pubkey::Pubkey>,
+                account_payer: Option<solana_program::pubkey::Pubkey>,
+                system_program: Option<solana_program::pubkey::Pubkey>,
+                config_extension: Option<solana_program::pubkey::Pubkey>,
                         max_iterations: Option<u16>,
                 epoch: Option<u64>,
         __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -171,6 +192,21 @@ impl RouteBaseRewardsBuilder {
     pub fn base_reward_receiver(&mut self, base_reward_receiver: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.base_reward_receiver = Some(base_reward_receiver);
                     self
+    }
+            #[inline(always)]
+    pub fn account_payer(&mut self, account_payer: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.account_payer = Some(account_payer);
+                    self
+    }
+            #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.system_program = Some(system_program);
+                    self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.config_extension = Some(config_extension);
+                    self
     }
                     #[inline(always)]
       pub fn max_iterations(&mut self, max_iterations: u16) -> &mut Self {
@@ -204,6 +240,9 @@ impl RouteBaseRewardsBuilder {
                                         ballot_box: self.ballot_box.expect("ballot_box is not set"),
                                         base_reward_router: self.base_reward_router.expect("base_reward_router is not set"),
                                         base_reward_receiver: self.base_reward_receiver.expect("base_reward_receiver is not set"),
+                                        account_payer: self.account_payer.expect("account_payer is not set"),
+                                        system_program: self.system_program.unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+                                        config_extension: self.config_extension.expect("config_extension is not set"),
                       };
           let args = RouteBaseRewardsInstructionArgs {
                                                               max_iterations: self.max_iterations.clone().expect("max_iterations is not set"),
@@ -237,6 +276,9 @@ impl RouteBaseRewardsBuilder {
                 
                     
               pub base_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+              pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
+              pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+              pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             }
 
 /// `route_base_rewards` CPI instruction.
@@ -264,6 +306,9 @@ pub struct RouteBaseRewardsCpi<'a, 'b> {
           
               
           pub base_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+          pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
+          pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+          pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: RouteBaseRewardsInstructionArgs,
   }
@@ -283,6 +328,9 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
               ballot_box: accounts.ballot_box,
               base_reward_router: accounts.base_reward_router,
               base_reward_receiver: accounts.base_reward_receiver,
+              account_payer: accounts.account_payer,
+              system_program: accounts.system_program,
+              config_extension: accounts.config_extension,
                     __args: args,
           }
   }
@@ -305,7 +353,7 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false
@@ -333,6 +381,18 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
                                           accounts.push(solana_program::instruction::AccountMeta::new(
             *self.base_reward_receiver.key,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.account_payer.key,
+            false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config_extension.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_program::instruction::AccountMeta {
@@ -350,7 +410,7 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.epoch_state.clone());
                         account_infos.push(self.config.clone());
@@ -359,6 +419,9 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
                         account_infos.push(self.ballot_box.clone());
                         account_infos.push(self.base_reward_router.clone());
                         account_infos.push(self.base_reward_receiver.clone());
+                        account_infos.push(self.account_payer.clone());
+                        account_infos.push(self.system_program.clone());
+                        account_infos.push(self.config_extension.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -380,6 +443,9 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
           ///   4. `[]` ballot_box
                 ///   5. `[writable]` base_reward_router
                 ///   6. `[writable]` base_reward_receiver
+                ///   7. `[writable]` account_payer
+                ///   8. `[]` system_program
+                ///   9. `[]` config_extension
 #[derive(Clone, Debug)]
 pub struct RouteBaseRewardsCpiBuilder<'a, 'b> {
   instruction: Box<RouteBaseRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -396,6 +462,9 @@ impl<'a, 'b> RouteBaseRewardsCpiBuilder<'a, 'b> {
               ballot_box: None,
               base_reward_router: None,
               base_reward_receiver: None,
+              account_payer: None,
+              system_program: None,
+              config_extension: None,
                                             max_iterations: None,
                                 epoch: None,
                     __remaining_accounts: Vec::new(),
@@ -436,6 +505,21 @@ impl<'a, 'b> RouteBaseRewardsCpiBuilder<'a, 'b> {
     pub fn base_reward_receiver(&mut self, base_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.base_reward_receiver = Some(base_reward_receiver);
                     self
+    }
+            #[inline(always)]
+    pub fn account_payer(&mut self, account_payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.account_payer = Some(account_payer);
+                    self
+    }
+            #[inline(always)]
+    pub fn system_program(&mut self, system_program: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.system_program = Some(system_program);
+                    self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.config_extension = Some(config_extension);
+                    self
     }
                     #[inline(always)]
       pub fn max_iterations(&mut self, max_iterations: u16) -> &mut Self {
@@ -489,6 +573,9 @@ impl<'a, 'b> RouteBaseRewardsCpiBuilder<'a, 'b> {
           base_reward_router: self.instruction.base_reward_router.expect("base_reward_router is not set"),
                   
           base_reward_receiver: self.instruction.base_reward_receiver.expect("base_reward_receiver is not set"),
+          account_payer: self.instruction.account_payer.expect("account_payer is not set"),
+          system_program: self.instruction.system_program.expect("system_program is not set"),
+          config_extension: self.instruction.config_extension.expect("config_extension is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -505,6 +592,9 @@ struct RouteBaseRewardsCpiBuilderInstruction<'a, 'b> {
                 ballot_box: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 base_reward_router: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 base_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                config_extension: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                         max_iterations: Option<u16>,
                 epoch: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -46,6 +46,7 @@ pub struct SnapshotVaultOperatorDelegation {
           
               
           pub operator_snapshot: solana_program::pubkey::Pubkey,
+          pub config_extension: solana_program::pubkey::Pubkey,
       }
 
 impl SnapshotVaultOperatorDelegation {
@@ -54,7 +55,7 @@ impl SnapshotVaultOperatorDelegation {
   }
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: SnapshotVaultOperatorDelegationInstructionArgs, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false
@@ -102,6 +103,10 @@ impl SnapshotVaultOperatorDelegation {
                                           accounts.push(solana_program::instruction::AccountMeta::new(
             self.operator_snapshot,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config_extension,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = SnapshotVaultOperatorDelegationInstructionData::new().try_to_vec().unwrap();
@@ -158,6 +163,7 @@ pub struct SnapshotVaultOperatorDelegationInstructionArgs {
           ///   9. `[]` weight_table
                 ///   10. `[writable]` epoch_snapshot
                 ///   11. `[writable]` operator_snapshot
+                ///   12. `[]` config_extension
 #[derive(Clone, Debug, Default)]
 pub struct SnapshotVaultOperatorDelegationBuilder {
             epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -172,6 +178,7 @@ pub struct SnapshotVaultOperatorDelegationBuilder {
                 weight_table: Option<solana_program::pubkey::Pubkey>,
                 epoch_snapshot: Option<solana_program::pubkey::Pubkey>,
                 operator_snapshot: Option<solana_program::pubkey::Pubkey>,
+                config_extension: Option<solana_program::pubkey::Pubkey>,
                         epoch: Option<u64>,
         __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -239,6 +246,11 @@ impl SnapshotVaultOperatorDelegationBuilder {
     pub fn operator_snapshot(&mut self, operator_snapshot: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.operator_snapshot = Some(operator_snapshot);
                     self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.config_extension = Some(config_extension);
+                    self
     }
                     #[inline(always)]
       pub fn epoch(&mut self, epoch: u64) -> &mut Self {
@@ -272,6 +284,7 @@ impl SnapshotVaultOperatorDelegationBuilder {
                                         weight_table: self.weight_table.expect("weight_table is not set"),
                                         epoch_snapshot: self.epoch_snapshot.expect("epoch_snapshot is not set"),
                                         operator_snapshot: self.operator_snapshot.expect("operator_snapshot is not set"),
+                                        config_extension: self.config_extension.expect("config_extension is not set"),
                       };
           let args = SnapshotVaultOperatorDelegationInstructionArgs {
                                                               epoch: self.epoch.clone().expect("epoch is not set"),
@@ -319,6 +332,7 @@ impl SnapshotVaultOperatorDelegationBuilder {
                 
                     
               pub operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+              pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             }
 
 /// `snapshot_vault_operator_delegation` CPI instruction.
@@ -361,6 +375,7 @@ pub struct SnapshotVaultOperatorDelegationCpi<'a, 'b> {
           
               
           pub operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+          pub config_extension: &'b solana_program::account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: SnapshotVaultOperatorDelegationInstructionArgs,
   }
@@ -385,6 +400,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
               weight_table: accounts.weight_table,
               epoch_snapshot: accounts.epoch_snapshot,
               operator_snapshot: accounts.operator_snapshot,
+              config_extension: accounts.config_extension,
                     __args: args,
           }
   }
@@ -407,7 +423,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false
@@ -455,6 +471,10 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
                                           accounts.push(solana_program::instruction::AccountMeta::new(
             *self.operator_snapshot.key,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config_extension.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_program::instruction::AccountMeta {
@@ -472,7 +492,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.epoch_state.clone());
                         account_infos.push(self.config.clone());
@@ -486,6 +506,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
                         account_infos.push(self.weight_table.clone());
                         account_infos.push(self.epoch_snapshot.clone());
                         account_infos.push(self.operator_snapshot.clone());
+                        account_infos.push(self.config_extension.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -512,6 +533,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
           ///   9. `[]` weight_table
                 ///   10. `[writable]` epoch_snapshot
                 ///   11. `[writable]` operator_snapshot
+                ///   12. `[]` config_extension
 #[derive(Clone, Debug)]
 pub struct SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
   instruction: Box<SnapshotVaultOperatorDelegationCpiBuilderInstruction<'a, 'b>>,
@@ -533,6 +555,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
               weight_table: None,
               epoch_snapshot: None,
               operator_snapshot: None,
+              config_extension: None,
                                             epoch: None,
                     __remaining_accounts: Vec::new(),
     });
@@ -597,6 +620,11 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
     pub fn operator_snapshot(&mut self, operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.operator_snapshot = Some(operator_snapshot);
                     self
+    }
+            #[inline(always)]
+    pub fn config_extension(&mut self, config_extension: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.config_extension = Some(config_extension);
+                    self
     }
                     #[inline(always)]
       pub fn epoch(&mut self, epoch: u64) -> &mut Self {
@@ -654,6 +682,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
           epoch_snapshot: self.instruction.epoch_snapshot.expect("epoch_snapshot is not set"),
                   
           operator_snapshot: self.instruction.operator_snapshot.expect("operator_snapshot is not set"),
+          config_extension: self.instruction.config_extension.expect("config_extension is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -675,6 +704,7 @@ struct SnapshotVaultOperatorDelegationCpiBuilderInstruction<'a, 'b> {
                 weight_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 epoch_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                config_extension: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                         epoch: Option<u64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
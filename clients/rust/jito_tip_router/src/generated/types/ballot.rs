@@ -13,8 +13,11 @@ use borsh::BorshDeserialize;
 pub struct Ballot {
 pub meta_merkle_root: [u8; 32],
 pub is_valid: bool,
-#[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-pub reserved: [u8; 63],
+pub is_abstain: bool,
+pub snapshot_slot: u64,
+pub bank_hash: [u8; 32],
+pub tip_distribution_account_count: u64,
+pub reserved: [u8; 14],
 }
 
 
@@ -14,6 +14,7 @@ use borsh::BorshDeserialize;
 pub enum ConfigAdminRole {
 FeeAdmin,
 TieBreakerAdmin,
+PauseAdmin,
 }
 
 
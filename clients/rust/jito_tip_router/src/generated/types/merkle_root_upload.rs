@@ -0,0 +1,20 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshSerialize;
+use borsh::BorshDeserialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MerkleRootUpload {
+pub proof: Vec<[u8; 32]>,
+pub merkle_root: [u8; 32],
+pub max_total_claim: u64,
+pub max_num_nodes: u64,
+}
+
+
@@ -14,13 +14,17 @@
   pub(crate) mod r#fee;
   pub(crate) mod r#fee_config;
   pub(crate) mod r#fees;
+  pub(crate) mod r#merkle_root_upload;
   pub(crate) mod r#ncn_fee_group;
   pub(crate) mod r#ncn_fee_group_weight;
   pub(crate) mod r#ncn_reward_route;
   pub(crate) mod r#operator_vote;
+  pub(crate) mod r#oracle_feed;
   pub(crate) mod r#progress;
+  pub(crate) mod r#reward_asset;
   pub(crate) mod r#stake_weights;
   pub(crate) mod r#st_mint_entry;
+  pub(crate) mod r#tie_breaker_member;
   pub(crate) mod r#vault_entry;
   pub(crate) mod r#vault_operator_stake_weight;
   pub(crate) mod r#vault_reward_route;
@@ -35,13 +39,17 @@
   pub use self::r#fee::*;
   pub use self::r#fee_config::*;
   pub use self::r#fees::*;
+  pub use self::r#merkle_root_upload::*;
   pub use self::r#ncn_fee_group::*;
   pub use self::r#ncn_fee_group_weight::*;
   pub use self::r#ncn_reward_route::*;
   pub use self::r#operator_vote::*;
+  pub use self::r#oracle_feed::*;
   pub use self::r#progress::*;
+  pub use self::r#reward_asset::*;
   pub use self::r#stake_weights::*;
   pub use self::r#st_mint_entry::*;
+  pub use self::r#tie_breaker_member::*;
   pub use self::r#vault_entry::*;
   pub use self::r#vault_operator_stake_weight::*;
   pub use self::r#vault_reward_route::*;
@@ -18,8 +18,10 @@ pub operator: Pubkey,
 pub slot_voted: u64,
 pub stake_weights: StakeWeights,
 pub ballot_index: u16,
-#[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-pub reserved: [u8; 64],
+pub commitment: [u8; 32],
+pub slot_committed: u64,
+pub tip_distribution_account_count: u64,
+pub reserved: [u8; 16],
 }
 
 
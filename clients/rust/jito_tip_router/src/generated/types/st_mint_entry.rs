@@ -7,6 +7,7 @@
 
 use solana_program::pubkey::Pubkey;
 use crate::generated::types::NcnFeeGroup;
+use crate::generated::types::OracleFeed;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -20,8 +21,12 @@ pub reward_multiplier_bps: u64,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub switchboard_feed: Pubkey,
 pub no_feed_weight: u128,
-#[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-pub reserved: [u8; 128],
+pub oracle_feeds: [OracleFeed; 3],
+pub max_feed_divergence_bps: u16,
+pub max_weight_change_bps: u64,
+pub max_stale_slots: u64,
+pub retired_epoch: u64,
+pub reserved: [u8; 3],
 }
 
 
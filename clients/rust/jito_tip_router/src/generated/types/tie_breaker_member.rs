@@ -0,0 +1,21 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use solana_program::pubkey::Pubkey;
+use borsh::BorshSerialize;
+use borsh::BorshDeserialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TieBreakerMember {
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub member: Pubkey,
+pub approved_epoch: u64,
+pub approved_meta_merkle_root: [u8; 32],
+}
+
+
@@ -6,6 +6,7 @@
 //!
 
 use solana_program::pubkey::Pubkey;
+use crate::generated::types::RewardAsset;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -18,8 +19,11 @@ pub vault: Pubkey,
 pub st_mint: Pubkey,
 pub vault_index: u64,
 pub slot_registered: u64,
+pub retired_epoch: u64,
+pub reward_asset: RewardAsset,
+pub reward_multiplier_bps_override: u64,
 #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-pub reserved: [u8; 128],
+pub reserved: [u8; 79],
 }
 
 
@@ -27,3 +27,5 @@ pub mod types {
 pub mod programs {
     pub use super::generated::programs::*;
 }
+
+pub mod events;
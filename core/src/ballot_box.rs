@@ -14,12 +14,13 @@ use solana_program::{
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    constants::{precise_consensus, DEFAULT_CONSENSUS_REACHED_SLOT, MAX_OPERATORS},
+    constants::{precise_consensus, DEFAULT_CONSENSUS_REACHED_SLOT, MAX_FEE_BPS, MAX_OPERATORS},
     discriminators::Discriminators,
     error::TipRouterError,
     loaders::check_load,
     ncn_fee_group::NcnFeeGroup,
     stake_weight::StakeWeights,
+    utils::get_pages_required,
 };
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
@@ -29,10 +30,20 @@ pub struct Ballot {
     meta_merkle_root: [u8; 32],
     /// Whether the ballot is valid
     is_valid: PodBool,
+    /// Whether the operator abstained, having no data to vote with
+    is_abstain: PodBool,
+    /// The slot of the snapshot the meta merkle root was computed from
+    snapshot_slot: PodU64,
+    /// The bank hash at `snapshot_slot`
+    bank_hash: [u8; 32],
+    /// The number of tip distribution accounts in the meta merkle tree, 0 if not reported
+    tip_distribution_account_count: PodU64,
     /// Reserved space
-    reserved: [u8; 63],
+    reserved: [u8; 14],
 }
 
+/// Only the meta merkle root is compared. The snapshot metadata is informational and the tip
+/// distribution account count is taken from the winning votes once consensus is reached
 impl PartialEq for Ballot {
     fn eq(&self, other: &Self) -> bool {
         if !self.is_valid() || !other.is_valid() {
@@ -49,13 +60,20 @@ impl Default for Ballot {
         Self {
             meta_merkle_root: [0; 32],
             is_valid: PodBool::from(false),
-            reserved: [0; 63],
+            is_abstain: PodBool::from(false),
+            snapshot_slot: PodU64::from(0),
+            bank_hash: [0; 32],
+            tip_distribution_account_count: PodU64::from(0),
+            reserved: [0; 14],
         }
     }
 }
 
 impl std::fmt::Display for Ballot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_abstain() {
+            return write!(f, "Abstain");
+        }
         write!(f, "{:?}", self.meta_merkle_root)
     }
 }
@@ -65,7 +83,11 @@ impl Ballot {
         let mut ballot = Self {
             meta_merkle_root: *merkle_root,
             is_valid: PodBool::from(false),
-            reserved: [0; 63],
+            is_abstain: PodBool::from(false),
+            snapshot_slot: PodU64::from(0),
+            bank_hash: [0; 32],
+            tip_distribution_account_count: PodU64::from(0),
+            reserved: [0; 14],
         };
 
         for byte in ballot.meta_merkle_root.iter() {
@@ -78,6 +100,36 @@ impl Ballot {
         ballot
     }
 
+    /// A ballot carrying the snapshot slot and bank hash the meta merkle root was computed from
+    pub fn new_with_snapshot(
+        merkle_root: &[u8; 32],
+        snapshot_slot: u64,
+        bank_hash: &[u8; 32],
+    ) -> Self {
+        Self {
+            snapshot_slot: PodU64::from(snapshot_slot),
+            bank_hash: *bank_hash,
+            ..Self::new(merkle_root)
+        }
+    }
+
+    /// The ballot, expecting merkle roots for `tip_distribution_account_count` tip distribution
+    /// accounts once it wins
+    pub fn with_tip_distribution_account_count(&self, tip_distribution_account_count: u64) -> Self {
+        Self {
+            tip_distribution_account_count: PodU64::from(tip_distribution_account_count),
+            ..*self
+        }
+    }
+
+    /// An explicit "no data" ballot, it is recorded but never tallied
+    pub fn abstain() -> Self {
+        Self {
+            is_abstain: PodBool::from(true),
+            ..Self::default()
+        }
+    }
+
     pub const fn root(&self) -> [u8; 32] {
         self.meta_merkle_root
     }
@@ -85,6 +137,47 @@ impl Ballot {
     pub fn is_valid(&self) -> bool {
         self.is_valid.into()
     }
+
+    pub fn is_abstain(&self) -> bool {
+        self.is_abstain.into()
+    }
+
+    pub fn snapshot_slot(&self) -> u64 {
+        self.snapshot_slot.into()
+    }
+
+    pub const fn bank_hash(&self) -> &[u8; 32] {
+        &self.bank_hash
+    }
+
+    pub fn tip_distribution_account_count(&self) -> u64 {
+        self.tip_distribution_account_count.into()
+    }
+
+    /// The ballot without its snapshot metadata, as stored in the ballot tallies
+    pub fn without_snapshot(&self) -> Self {
+        Self {
+            snapshot_slot: PodU64::from(0),
+            bank_hash: [0; 32],
+            ..*self
+        }
+    }
+
+    /// The hash an operator commits to before revealing this ballot. It is bound to the operator
+    /// and epoch, so a revealed commitment can't be reused by another operator, and covers the
+    /// snapshot the root was computed from.
+    pub fn commitment(&self, operator: &Pubkey, epoch: u64, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[
+            operator.as_ref(),
+            &epoch.to_le_bytes(),
+            &self.meta_merkle_root,
+            &self.snapshot_slot().to_le_bytes(),
+            &self.bank_hash,
+            &self.tip_distribution_account_count().to_le_bytes(),
+            salt,
+        ])
+        .to_bytes()
+    }
 }
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
@@ -178,8 +271,14 @@ pub struct OperatorVote {
     stake_weights: StakeWeights,
     /// The index of the ballot in the ballot_tallies
     ballot_index: PodU16,
+    /// Hash of the ballot and salt committed to before revealing
+    commitment: [u8; 32],
+    /// The slot the operator committed
+    slot_committed: PodU64,
+    /// The tip distribution account count the operator's ballot reported
+    tip_distribution_account_count: PodU64,
     /// Reserved space
-    reserved: [u8; 64],
+    reserved: [u8; 16],
 }
 
 impl Default for OperatorVote {
@@ -189,15 +288,22 @@ impl Default for OperatorVote {
             slot_voted: PodU64::from(0),
             stake_weights: StakeWeights::default(),
             ballot_index: PodU16::from(u16::MAX),
-            reserved: [0; 64],
+            commitment: [0; 32],
+            slot_committed: PodU64::from(0),
+            tip_distribution_account_count: PodU64::from(0),
+            reserved: [0; 16],
         }
     }
 }
 
 impl OperatorVote {
+    /// Ballot index of an abstained vote, which has no ballot tally
+    pub const ABSTAIN_BALLOT_INDEX: u16 = u16::MAX - 1;
+
     pub fn new(
         ballot_index: usize,
         operator: &Pubkey,
+        ballot: &Ballot,
         current_slot: u64,
         stake_weights: &StakeWeights,
     ) -> Self {
@@ -206,7 +312,19 @@ impl OperatorVote {
             ballot_index: PodU16::from(ballot_index as u16),
             slot_voted: PodU64::from(current_slot),
             stake_weights: *stake_weights,
-            reserved: [0; 64],
+            commitment: [0; 32],
+            slot_committed: PodU64::from(0),
+            tip_distribution_account_count: ballot.tip_distribution_account_count,
+            reserved: [0; 16],
+        }
+    }
+
+    pub fn new_commitment(operator: &Pubkey, commitment: &[u8; 32], current_slot: u64) -> Self {
+        Self {
+            operator: *operator,
+            commitment: *commitment,
+            slot_committed: PodU64::from(current_slot),
+            ..Self::default()
         }
     }
 
@@ -226,8 +344,47 @@ impl OperatorVote {
         self.ballot_index.into()
     }
 
+    pub const fn commitment(&self) -> &[u8; 32] {
+        &self.commitment
+    }
+
+    pub fn slot_committed(&self) -> u64 {
+        self.slot_committed.into()
+    }
+
+    pub fn tip_distribution_account_count(&self) -> u64 {
+        self.tip_distribution_account_count.into()
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.ballot_index() == u16::MAX
+        self.operator.eq(&Pubkey::default())
+    }
+
+    /// Whether the operator has a ballot counted, as opposed to only a commitment
+    pub fn has_voted(&self) -> bool {
+        self.ballot_index() != u16::MAX
+    }
+
+    /// Whether the operator's counted vote is an abstain
+    pub fn is_abstain(&self) -> bool {
+        self.ballot_index() == Self::ABSTAIN_BALLOT_INDEX
+    }
+
+    pub fn has_commitment(&self) -> bool {
+        self.commitment.iter().any(|byte| *byte != 0)
+    }
+
+    fn set_ballot(
+        &mut self,
+        ballot_index: usize,
+        ballot: &Ballot,
+        current_slot: u64,
+        stake_weights: &StakeWeights,
+    ) {
+        self.ballot_index = PodU16::from(ballot_index as u16);
+        self.slot_voted = PodU64::from(current_slot);
+        self.stake_weights = *stake_weights;
+        self.tip_distribution_account_count = ballot.tip_distribution_account_count;
     }
 }
 
@@ -245,8 +402,10 @@ pub struct BallotBox {
     slot_created: PodU64,
     /// Slot when consensus was reached
     slot_consensus_reached: PodU64,
+    /// Number of `BallotBoxPage` accounts chained to the ballot box
+    page_count: u8,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 127],
     /// Number of operators that have voted
     operators_voted: PodU64,
     /// Number of unique ballots
@@ -278,7 +437,8 @@ impl BallotBox {
             winning_ballot: Ballot::default(),
             operator_votes: [OperatorVote::default(); MAX_OPERATORS],
             ballot_tallies: [BallotTally::default(); MAX_OPERATORS],
-            reserved: [0; 128],
+            page_count: 0,
+            reserved: [0; 127],
         }
     }
 
@@ -294,7 +454,8 @@ impl BallotBox {
         self.winning_ballot = Ballot::default();
         self.operator_votes = [OperatorVote::default(); MAX_OPERATORS];
         self.ballot_tallies = [BallotTally::default(); MAX_OPERATORS];
-        self.reserved = [0; 128];
+        self.page_count = 0;
+        self.reserved = [0; 127];
     }
 
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
@@ -337,6 +498,32 @@ impl BallotBox {
         )
     }
 
+    /// Loads the ballot box if it was created for the epoch, returning whether it exists
+    pub fn load_if_initialized(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+        expect_writable: bool,
+    ) -> Result<bool, ProgramError> {
+        if account.data_is_empty() {
+            let expected_pda = Self::find_program_address(program_id, ncn, epoch).0;
+            if expected_pda.ne(account.key) {
+                msg!(
+                    "Ballot box PDA does not match {} != {}",
+                    account.key,
+                    expected_pda
+                );
+                return Err(ProgramError::InvalidSeeds);
+            }
+
+            return Ok(false);
+        }
+
+        Self::load(program_id, account, ncn, epoch, expect_writable)?;
+        Ok(true)
+    }
+
     pub fn load_to_close(
         program_id: &Pubkey,
         account_to_close: &AccountInfo,
@@ -350,6 +537,10 @@ impl BallotBox {
         self.epoch.into()
     }
 
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
     pub fn slot_consensus_reached(&self) -> u64 {
         self.slot_consensus_reached.into()
     }
@@ -362,8 +553,90 @@ impl BallotBox {
         self.operators_voted.into()
     }
 
-    pub fn has_ballot(&self, ballot: &Ballot) -> bool {
-        self.ballot_tallies.iter().any(|t| t.ballot.eq(ballot))
+    pub const fn page_count(&self) -> u8 {
+        self.page_count
+    }
+
+    /// Number of `BallotBoxPage` accounts needed to hold a vote from every operator
+    pub fn pages_required(operator_count: u64) -> Result<u8, TipRouterError> {
+        get_pages_required(operator_count, MAX_OPERATORS)
+    }
+
+    pub fn increment_page_count(&mut self) -> Result<(), TipRouterError> {
+        self.page_count = self
+            .page_count
+            .checked_add(1)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    // Votes and tallies are indexed across the ballot box and its pages, with the ballot box
+    // holding the first `MAX_OPERATORS` of each
+    const fn capacity(page_count: usize) -> usize {
+        MAX_OPERATORS * (page_count + 1)
+    }
+
+    fn operator_vote_mut<'a>(
+        &'a mut self,
+        pages: &'a mut [&mut BallotBoxPage],
+        vote_index: usize,
+    ) -> Option<&'a mut OperatorVote> {
+        match vote_index / MAX_OPERATORS {
+            0 => self.operator_votes.get_mut(vote_index),
+            page => pages.get_mut(page - 1).map(|ballot_box_page| {
+                &mut ballot_box_page.operator_votes[vote_index % MAX_OPERATORS]
+            }),
+        }
+    }
+
+    fn ballot_tally_mut<'a>(
+        &'a mut self,
+        pages: &'a mut [&mut BallotBoxPage],
+        tally_index: usize,
+    ) -> Option<&'a mut BallotTally> {
+        match tally_index / MAX_OPERATORS {
+            0 => self.ballot_tallies.get_mut(tally_index),
+            page => pages.get_mut(page - 1).map(|ballot_box_page| {
+                &mut ballot_box_page.ballot_tallies[tally_index % MAX_OPERATORS]
+            }),
+        }
+    }
+
+    /// Index of the operator's vote, or of the first empty vote if the operator has not voted
+    fn operator_vote_index(
+        &mut self,
+        pages: &mut [&mut BallotBoxPage],
+        operator: &Pubkey,
+    ) -> Option<usize> {
+        (0..Self::capacity(pages.len())).find(|vote_index| {
+            self.operator_vote_mut(pages, *vote_index)
+                .is_some_and(|vote| vote.operator().eq(operator) || vote.is_empty())
+        })
+    }
+
+    /// Operator votes across the ballot box and its pages
+    pub fn all_operator_votes<'a>(
+        &'a self,
+        pages: &'a [&'a BallotBoxPage],
+    ) -> impl Iterator<Item = &'a OperatorVote> {
+        self.operator_votes
+            .iter()
+            .chain(pages.iter().flat_map(|page| page.operator_votes.iter()))
+    }
+
+    /// Ballot tallies across the ballot box and its pages
+    pub fn all_ballot_tallies<'a>(
+        &'a self,
+        pages: &'a [&'a BallotBoxPage],
+    ) -> impl Iterator<Item = &'a BallotTally> {
+        self.ballot_tallies
+            .iter()
+            .chain(pages.iter().flat_map(|page| page.ballot_tallies.iter()))
+    }
+
+    pub fn has_ballot(&self, pages: &[&BallotBoxPage], ballot: &Ballot) -> bool {
+        self.all_ballot_tallies(pages).any(|t| t.ballot.eq(ballot))
     }
 
     pub const fn ballot_tallies(&self) -> &[BallotTally; MAX_OPERATORS] {
@@ -388,13 +661,22 @@ impl BallotBox {
         }
     }
 
-    pub fn get_winning_ballot_tally(&self) -> Result<&BallotTally, TipRouterError> {
+    /// The number of tip distribution accounts the winning ballot expects merkle roots for, 0
+    /// before consensus or when the ballot did not report it
+    pub fn winning_tip_distribution_account_count(&self) -> u64 {
+        self.get_winning_ballot()
+            .map_or(0, |ballot| ballot.tip_distribution_account_count())
+    }
+
+    pub fn get_winning_ballot_tally<'a>(
+        &'a self,
+        pages: &'a [&'a BallotBoxPage],
+    ) -> Result<&'a BallotTally, TipRouterError> {
         if !self.winning_ballot.is_valid() {
             Err(TipRouterError::ConsensusNotReached)
         } else {
             let winning_ballot_tally = self
-                .ballot_tallies
-                .iter()
+                .all_ballot_tallies(pages)
                 .find(|t| t.ballot.eq(&self.winning_ballot))
                 .ok_or(TipRouterError::BallotTallyNotFoundFull)?;
 
@@ -406,6 +688,35 @@ impl BallotBox {
         self.winning_ballot.is_valid()
     }
 
+    /// The operator's counted vote, `None` if they did not vote or only committed
+    pub fn operator_vote<'a>(
+        &'a self,
+        pages: &'a [&'a BallotBoxPage],
+        operator: &Pubkey,
+    ) -> Option<&'a OperatorVote> {
+        self.all_operator_votes(pages)
+            .find(|vote| vote.operator().eq(operator) && vote.has_voted())
+    }
+
+    /// Number of operators whose counted vote is an abstain
+    pub fn operators_abstained(&self, pages: &[&BallotBoxPage]) -> u64 {
+        self.all_operator_votes(pages)
+            .filter(|vote| !vote.is_empty() && vote.is_abstain())
+            .count() as u64
+    }
+
+    pub fn voted_for_winning_ballot(&self, pages: &[&BallotBoxPage], operator: &Pubkey) -> bool {
+        if !self.has_winning_ballot() {
+            return false;
+        }
+
+        self.operator_vote(pages, operator).is_some_and(|vote| {
+            self.all_ballot_tallies(pages)
+                .nth(vote.ballot_index() as usize)
+                .is_some_and(|tally| tally.ballot.eq(&self.winning_ballot))
+        })
+    }
+
     pub const fn operator_votes(&self) -> &[OperatorVote; MAX_OPERATORS] {
         &self.operator_votes
     }
@@ -414,41 +725,90 @@ impl BallotBox {
         self.winning_ballot = *ballot;
     }
 
+    /// The tally's ballot carrying the tip distribution account count with the most stake weight
+    /// among the votes for it. Operators that agree on the merkle root can report different counts
+    fn ballot_with_majority_count(&self, pages: &[&BallotBoxPage], tally: &BallotTally) -> Ballot {
+        let mut count_stake_weights: Vec<(u64, u128)> = Vec::new();
+        for vote in self
+            .all_operator_votes(pages)
+            .filter(|vote| !vote.is_empty() && vote.ballot_index() == tally.index())
+        {
+            let count = vote.tip_distribution_account_count();
+            let stake_weight = vote.stake_weights().stake_weight();
+            match count_stake_weights.iter_mut().find(|(c, _)| *c == count) {
+                Some((_, total)) => *total = total.saturating_add(stake_weight),
+                None => count_stake_weights.push((count, stake_weight)),
+            }
+        }
+
+        // Ties go to the higher count, so no expected merkle root goes untracked
+        let majority_count = count_stake_weights
+            .into_iter()
+            .max_by_key(|(count, stake_weight)| (*stake_weight, *count))
+            .map_or(
+                tally.ballot().tip_distribution_account_count(),
+                |(count, _)| count,
+            );
+
+        tally
+            .ballot()
+            .with_tip_distribution_account_count(majority_count)
+    }
+
     fn increment_or_create_ballot_tally(
         &mut self,
+        pages: &mut [&mut BallotBoxPage],
         ballot: &Ballot,
         stake_weights: &StakeWeights,
     ) -> Result<usize, TipRouterError> {
-        let result = self
-            .ballot_tallies
-            .iter()
-            .enumerate()
-            .find(|(_, t)| t.is_valid() && t.ballot.eq(ballot));
-
-        if let Some((tally_index, _)) = result {
-            self.ballot_tallies[tally_index].increment_tally(stake_weights)?;
-            return Ok(tally_index);
-        }
-
-        for (tally_index, tally) in self.ballot_tallies.iter_mut().enumerate() {
-            if !tally.is_valid() {
-                *tally = BallotTally::new(tally_index as u16, ballot, stake_weights);
+        let mut empty_tally_index = None;
 
-                self.unique_ballots = PodU64::from(
-                    self.unique_ballots()
-                        .checked_add(1)
-                        .ok_or(TipRouterError::ArithmeticOverflow)?,
-                );
+        for tally_index in 0..Self::capacity(pages.len()) {
+            let Some(tally) = self.ballot_tally_mut(pages, tally_index) else {
+                break;
+            };
 
+            if tally.is_valid() && tally.ballot.eq(ballot) {
+                tally.increment_tally(stake_weights)?;
                 return Ok(tally_index);
             }
+
+            if !tally.is_valid() && empty_tally_index.is_none() {
+                empty_tally_index = Some(tally_index);
+            }
         }
 
-        Err(TipRouterError::BallotTallyFull)
+        let tally_index = empty_tally_index.ok_or(TipRouterError::BallotTallyFull)?;
+        if let Some(tally) = self.ballot_tally_mut(pages, tally_index) {
+            *tally = BallotTally::new(
+                tally_index as u16,
+                &ballot.without_snapshot(),
+                stake_weights,
+            );
+        }
+
+        self.unique_ballots = PodU64::from(
+            self.unique_ballots()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(tally_index)
+    }
+
+    fn increment_operators_voted(&mut self) -> Result<(), TipRouterError> {
+        self.operators_voted = PodU64::from(
+            self.operators_voted()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
     }
 
     pub fn cast_vote(
         &mut self,
+        pages: &mut [&mut BallotBoxPage],
         operator: &Pubkey,
         ballot: &Ballot,
         stake_weights: &StakeWeights,
@@ -459,73 +819,174 @@ impl BallotBox {
             return Err(TipRouterError::VotingNotValid);
         }
 
-        if !ballot.is_valid() {
+        if !ballot.is_valid() && !ballot.is_abstain() {
             return Err(TipRouterError::BadBallot);
         }
 
-        let ballot_index = self.increment_or_create_ballot_tally(ballot, stake_weights)?;
+        // Abstaining counts the operator as voted without adding stake to any ballot
+        let ballot_index = if ballot.is_abstain() {
+            OperatorVote::ABSTAIN_BALLOT_INDEX as usize
+        } else {
+            self.increment_or_create_ballot_tally(pages, ballot, stake_weights)?
+        };
 
         let unique_ballots = self.unique_ballots();
         let consensus_reached = self.is_consensus_reached();
 
-        for vote in self.operator_votes.iter_mut() {
-            if vote.operator().eq(operator) {
-                if consensus_reached {
-                    return Err(TipRouterError::ConsensusAlreadyReached);
-                }
+        let vote_index = self
+            .operator_vote_index(pages, operator)
+            .ok_or(TipRouterError::OperatorVotesFull)?;
+        let vote = self
+            .operator_vote_mut(pages, vote_index)
+            .ok_or(TipRouterError::OperatorVotesFull)?;
 
-                // If the operator has already voted, we need to decrement their vote from the previous ballot
-                let prev_ballot_index = vote.ballot_index();
-                if let Some(prev_tally) = self.ballot_tallies.get_mut(prev_ballot_index as usize) {
-                    prev_tally.decrement_tally(vote.stake_weights())?;
-
-                    // If no more operators voting for the previous ballot, wipe and decrement the unique ballots
-                    if prev_tally.tally() == 0 {
-                        *prev_tally = BallotTally::default();
-                        self.unique_ballots = PodU64::from(
-                            unique_ballots
-                                .checked_sub(1)
-                                .ok_or(TipRouterError::ArithmeticOverflow)?,
-                        );
-                    }
-                }
+        if vote.is_empty() {
+            *vote = OperatorVote::new(ballot_index, operator, ballot, current_slot, stake_weights);
+            return self.increment_operators_voted();
+        }
 
-                let operator_vote =
-                    OperatorVote::new(ballot_index, operator, current_slot, stake_weights);
-                *vote = operator_vote;
-                return Ok(());
-            }
+        // Only a commitment so far, this is the operator's first counted vote
+        if !vote.has_voted() {
+            vote.set_ballot(ballot_index, ballot, current_slot, stake_weights);
+            return self.increment_operators_voted();
+        }
+
+        if consensus_reached {
+            return Err(TipRouterError::ConsensusAlreadyReached);
+        }
 
-            if vote.is_empty() {
-                let operator_vote =
-                    OperatorVote::new(ballot_index, operator, current_slot, stake_weights);
-                *vote = operator_vote;
+        let previous_vote = *vote;
+        vote.set_ballot(ballot_index, ballot, current_slot, stake_weights);
 
-                self.operators_voted = PodU64::from(
-                    self.operators_voted()
-                        .checked_add(1)
+        // If the operator has already voted, we need to decrement their vote from the previous ballot
+        let prev_ballot_index = previous_vote.ballot_index() as usize;
+        if let Some(prev_tally) = self.ballot_tally_mut(pages, prev_ballot_index) {
+            prev_tally.decrement_tally(previous_vote.stake_weights())?;
+
+            // If no more operators voting for the previous ballot, wipe and decrement the unique ballots
+            if prev_tally.tally() == 0 {
+                *prev_tally = BallotTally::default();
+                self.unique_ballots = PodU64::from(
+                    unique_ballots
+                        .checked_sub(1)
                         .ok_or(TipRouterError::ArithmeticOverflow)?,
                 );
-                return Ok(());
             }
         }
 
-        Err(TipRouterError::OperatorVotesFull)
+        Ok(())
+    }
+
+    /// Whether votes are still being committed, reveals are only accepted once the window closes.
+    /// The window starts once voting opens, when the epoch snapshot was finalized, or when the
+    /// ballot box was created if that was later.
+    pub fn is_commit_window_open(
+        &self,
+        current_slot: u64,
+        voting_opened_slot: u64,
+        commit_window_slots: u64,
+    ) -> Result<bool, TipRouterError> {
+        let commit_window_end = self
+            .slot_created()
+            .max(voting_opened_slot)
+            .checked_add(commit_window_slots)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        Ok(current_slot < commit_window_end)
+    }
+
+    /// Records the hash of an operator's ballot and salt, the ballot itself is not counted until it is revealed
+    pub fn commit_vote(
+        &mut self,
+        pages: &mut [&mut BallotBoxPage],
+        operator: &Pubkey,
+        commitment: &[u8; 32],
+        current_slot: u64,
+        voting_opened_slot: u64,
+        commit_window_slots: u64,
+    ) -> Result<(), TipRouterError> {
+        if !self.is_commit_window_open(current_slot, voting_opened_slot, commit_window_slots)? {
+            return Err(TipRouterError::CommitWindowClosed);
+        }
+
+        if commitment.iter().all(|byte| *byte == 0) {
+            return Err(TipRouterError::InvalidVoteCommitment);
+        }
+
+        let vote_index = self
+            .operator_vote_index(pages, operator)
+            .ok_or(TipRouterError::OperatorVotesFull)?;
+        let vote = self
+            .operator_vote_mut(pages, vote_index)
+            .ok_or(TipRouterError::OperatorVotesFull)?;
+
+        // A revealed vote is already counted and cannot be committed over
+        if vote.has_voted() {
+            return Err(TipRouterError::CommitWindowClosed);
+        }
+
+        *vote = OperatorVote::new_commitment(operator, commitment, current_slot);
+        Ok(())
+    }
+
+    /// Checks the ballot against the operator's commitment and casts it
+    #[allow(clippy::too_many_arguments)]
+    pub fn reveal_vote(
+        &mut self,
+        pages: &mut [&mut BallotBoxPage],
+        operator: &Pubkey,
+        ballot: &Ballot,
+        salt: &[u8; 32],
+        stake_weights: &StakeWeights,
+        current_slot: u64,
+        valid_slots_after_consensus: u64,
+        voting_opened_slot: u64,
+        commit_window_slots: u64,
+    ) -> Result<(), TipRouterError> {
+        if self.is_commit_window_open(current_slot, voting_opened_slot, commit_window_slots)? {
+            return Err(TipRouterError::CommitWindowOpen);
+        }
+
+        let vote_index = self
+            .operator_vote_index(pages, operator)
+            .ok_or(TipRouterError::VoteCommitmentNotFound)?;
+        let commitment = self
+            .operator_vote_mut(pages, vote_index)
+            .filter(|vote| vote.operator().eq(operator) && vote.has_commitment())
+            .map(|vote| *vote.commitment())
+            .ok_or(TipRouterError::VoteCommitmentNotFound)?;
+
+        if ballot
+            .commitment(operator, self.epoch(), salt)
+            .ne(&commitment)
+        {
+            return Err(TipRouterError::InvalidVoteCommitment);
+        }
+
+        self.cast_vote(
+            pages,
+            operator,
+            ballot,
+            stake_weights,
+            current_slot,
+            valid_slots_after_consensus,
+        )
     }
 
     // Should be called anytime a new vote is cast
     pub fn tally_votes(
         &mut self,
+        pages: &[&BallotBoxPage],
         total_stake_weight: u128,
         current_slot: u64,
+        consensus_threshold_bps: u64,
     ) -> Result<(), TipRouterError> {
         if self.slot_consensus_reached() != DEFAULT_CONSENSUS_REACHED_SLOT {
             return Ok(());
         }
 
-        let max_tally = self
-            .ballot_tallies
-            .iter()
+        let max_tally = *self
+            .all_ballot_tallies(pages)
             .max_by_key(|t| t.stake_weights().stake_weight())
             .unwrap();
 
@@ -539,14 +1000,14 @@ impl BallotBox {
             .checked_div(&precise_total_stake_weight)
             .ok_or(TipRouterError::DenominatorIsZero)?;
 
-        let target_precise_percentage = precise_consensus()?;
+        let target_precise_percentage = precise_consensus(consensus_threshold_bps)?;
 
         let consensus_reached =
             ballot_percentage_of_total.greater_than_or_equal(&target_precise_percentage);
 
         if consensus_reached && !self.winning_ballot.is_valid() {
             self.slot_consensus_reached = PodU64::from(current_slot);
-            let winning_ballot = *max_tally.ballot();
+            let winning_ballot = self.ballot_with_majority_count(pages, &max_tally);
 
             self.set_winning_ballot(&winning_ballot);
         }
@@ -556,10 +1017,30 @@ impl BallotBox {
 
     pub fn set_tie_breaker_ballot(
         &mut self,
+        pages: &[&BallotBoxPage],
         meta_merkle_root: &[u8; 32],
         current_epoch: u64,
         epochs_before_stall: u64,
     ) -> Result<(), TipRouterError> {
+        let finalized_ballot = self.check_tie_breaker_ballot(
+            pages,
+            meta_merkle_root,
+            current_epoch,
+            epochs_before_stall,
+        )?;
+
+        self.set_winning_ballot(&finalized_ballot);
+        Ok(())
+    }
+
+    /// Checks that voting has stalled and the tie breaker ballot was voted on by an operator
+    pub fn check_tie_breaker_ballot(
+        &self,
+        pages: &[&BallotBoxPage],
+        meta_merkle_root: &[u8; 32],
+        current_epoch: u64,
+        epochs_before_stall: u64,
+    ) -> Result<Ballot, TipRouterError> {
         // Check that consensus has not been reached
         if self.is_consensus_reached() {
             msg!("Consensus already reached");
@@ -576,15 +1057,75 @@ impl BallotBox {
             return Err(TipRouterError::VotingNotFinalized);
         }
 
-        let finalized_ballot = Ballot::new(meta_merkle_root);
+        // Check that the merkle root is one of the existing options
+        let finalized_tally = self
+            .all_ballot_tallies(pages)
+            .find(|tally| tally.is_valid() && tally.ballot().root().eq(meta_merkle_root))
+            .ok_or(TipRouterError::TieBreakerNotInPriorVotes)?;
+
+        Ok(self.ballot_with_majority_count(pages, finalized_tally))
+    }
+
+    /// Sets the ballot with the most stake weight as the winner once voting has stalled past the
+    /// plurality fallback, returning its tally. A tie for the most stake weight cannot be finalized
+    pub fn finalize_stalled_ballot(
+        &mut self,
+        pages: &[&BallotBoxPage],
+        total_stake_weight: u128,
+        current_epoch: u64,
+        epochs_before_plurality_fallback: u64,
+        min_stake_weight_bps: u64,
+    ) -> Result<BallotTally, TipRouterError> {
+        if self.is_consensus_reached() {
+            msg!("Consensus already reached");
+            return Err(TipRouterError::ConsensusAlreadyReached);
+        }
 
-        // // Check that the merkle root is one of the existing options
-        if !self.has_ballot(&finalized_ballot) {
-            return Err(TipRouterError::TieBreakerNotInPriorVotes);
+        if current_epoch
+            < self
+                .epoch()
+                .checked_add(epochs_before_plurality_fallback)
+                .ok_or(TipRouterError::ArithmeticOverflow)?
+        {
+            return Err(TipRouterError::VotingNotFinalized);
         }
 
-        self.set_winning_ballot(&finalized_ballot);
-        Ok(())
+        let plurality_tally = *self
+            .all_ballot_tallies(pages)
+            .filter(|tally| tally.is_valid())
+            .max_by_key(|tally| tally.stake_weights().stake_weight())
+            .ok_or(TipRouterError::NoPluralityBallot)?;
+        let plurality_stake_weight = plurality_tally.stake_weights().stake_weight();
+
+        let is_tied = self.all_ballot_tallies(pages).any(|tally| {
+            tally.is_valid()
+                && tally.index() != plurality_tally.index()
+                && tally.stake_weights().stake_weight() == plurality_stake_weight
+        });
+        if is_tied {
+            msg!(
+                "Plurality is tied at {} stake weight",
+                plurality_stake_weight
+            );
+            return Err(TipRouterError::NoPluralityBallot);
+        }
+
+        let min_stake_weight = total_stake_weight
+            .checked_mul(min_stake_weight_bps as u128)
+            .and_then(|x| x.checked_div(MAX_FEE_BPS as u128))
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+        if plurality_stake_weight == 0 || plurality_stake_weight < min_stake_weight {
+            msg!(
+                "Plurality stake weight {} is below the minimum {}",
+                plurality_stake_weight,
+                min_stake_weight
+            );
+            return Err(TipRouterError::PluralityBelowStakeFloor);
+        }
+
+        let winning_ballot = self.ballot_with_majority_count(pages, &plurality_tally);
+        self.set_winning_ballot(&winning_ballot);
+        Ok(plurality_tally)
     }
 
     /// Determines if an operator can still cast their vote.
@@ -641,6 +1182,60 @@ impl BallotBox {
     }
 }
 
+#[rustfmt::skip]
+fn fmt_operator_votes(f: &mut fmt::Formatter<'_>, operator_votes: &[OperatorVote]) -> fmt::Result {
+    for vote in operator_votes.iter() {
+        if !vote.is_empty() {
+            writeln!(f, "  Operator:                     {}", vote.operator())?;
+            if vote.has_commitment() {
+                writeln!(f, "    Slot Committed:             {}", vote.slot_committed())?;
+            }
+            if !vote.has_voted() {
+                continue;
+            }
+            writeln!(f, "    Slot Voted:                 {}", vote.slot_voted())?;
+            if vote.is_abstain() {
+                writeln!(f, "    Abstained:                  true")?;
+            } else {
+                writeln!(f, "    Ballot Index:               {}", vote.ballot_index())?;
+            }
+            writeln!(f, "    Stake Weights:")?;
+            let weights = vote.stake_weights();
+            for group in NcnFeeGroup::all_groups() {
+                if let Ok(weight) = weights.ncn_fee_group_stake_weight(group) {
+                    if weight > 0 {
+                        writeln!(f, "      Group {}:                  {}", group.group, weight)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+fn fmt_ballot_tallies(f: &mut fmt::Formatter<'_>, ballot_tallies: &[BallotTally]) -> fmt::Result {
+    for tally in ballot_tallies.iter() {
+        if tally.is_valid() {
+            writeln!(f, "  Index {}:", tally.index())?;
+            writeln!(f, "    Ballot:                     {}", tally.ballot())?;
+            writeln!(f, "    Tally:                      {}", tally.tally())?;
+            writeln!(f, "    Stake Weights:")?;
+            let weights = tally.stake_weights();
+            for group in NcnFeeGroup::all_groups() {
+                if let Ok(weight) = weights.ncn_fee_group_stake_weight(group) {
+                    if weight > 0 {
+                        writeln!(f, "      Group {}:                  {}", group.group, weight)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[rustfmt::skip]
 impl fmt::Display for BallotBox {
    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -651,48 +1246,24 @@ impl fmt::Display for BallotBox {
        writeln!(f, "  Slot Consensus Reached:       {}", self.slot_consensus_reached())?;
        writeln!(f, "  Operators Voted:              {}", self.operators_voted())?;
        writeln!(f, "  Unique Ballots:               {}", self.unique_ballots())?;
+       writeln!(f, "  Page Count:                   {}", self.page_count())?;
        writeln!(f, "  IS Consensus Reached:         {}", self.is_consensus_reached())?;
        if self.is_consensus_reached() {
            writeln!(f, "  Tie Breaker Set:              {}", self.tie_breaker_set())?;
            if let Ok(winning_ballot) = self.get_winning_ballot() {
                writeln!(f, "  Winning Ballot:               {}", winning_ballot)?;
+               writeln!(f, "  Tip Distribution Accounts:    {}", winning_ballot.tip_distribution_account_count())?;
            }
        }
 
        writeln!(f, "\nOperator Votes:")?;
-       for vote in self.operator_votes().iter() {
-           if !vote.is_empty() {
-               writeln!(f, "  Operator:                     {}", vote.operator())?;
-               writeln!(f, "    Slot Voted:                 {}", vote.slot_voted())?;
-               writeln!(f, "    Ballot Index:               {}", vote.ballot_index())?;
-               writeln!(f, "    Stake Weights:")?;
-               let weights = vote.stake_weights();
-               for group in NcnFeeGroup::all_groups() {
-                   if let Ok(weight) = weights.ncn_fee_group_stake_weight(group) {
-                       if weight > 0 {
-                           writeln!(f, "      Group {}:                  {}", group.group, weight)?;
-                       }
-                   }
-               }
-           }
-       }
+       fmt_operator_votes(f, self.operator_votes())?;
 
        writeln!(f, "\nBallot Tallies:")?;
-       for tally in self.ballot_tallies().iter() {
-           if tally.is_valid() {
-               writeln!(f, "  Index {}:", tally.index())?;
-               writeln!(f, "    Ballot:                     {}", tally.ballot())?;
-               writeln!(f, "    Tally:                      {}", tally.tally())?;
-               writeln!(f, "    Stake Weights:")?;
-               let weights = tally.stake_weights();
-               for group in NcnFeeGroup::all_groups() {
-                   if let Ok(weight) = weights.ncn_fee_group_stake_weight(group) {
-                       if weight > 0 {
-                           writeln!(f, "      Group {}:                  {}", group.group, weight)?;
-                       }
-                   }
-               }
-           }
+       fmt_ballot_tallies(f, self.ballot_tallies())?;
+
+       if self.page_count() > 0 {
+           writeln!(f, "\nTruncated: votes and tallies in the {} ballot box page(s) are not shown", self.page_count())?;
        }
 
        writeln!(f, "\n")?;
@@ -700,9 +1271,163 @@ impl fmt::Display for BallotBox {
    }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::utils::assert_tip_router_error;
+// PDA'd ["ballot_box_page", BALLOT_BOX, PAGE]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct BallotBoxPage {
+    /// The NCN account this page is for
+    ncn: Pubkey,
+    /// The epoch this page is for
+    epoch: PodU64,
+    /// The page number, starting at 1 as the ballot box holds the first page
+    page: u8,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 128],
+    /// Operator votes
+    operator_votes: [OperatorVote; 256],
+    /// Mapping of ballots votes to stake weight
+    ballot_tallies: [BallotTally; 256],
+}
+
+impl Discriminator for BallotBoxPage {
+    const DISCRIMINATOR: u8 = Discriminators::BallotBoxPage as u8;
+}
+
+impl BallotBoxPage {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn initialize(&mut self, ncn: &Pubkey, epoch: u64, page: u8, bump: u8) {
+        // Avoids overflowing stack
+        self.ncn = *ncn;
+        self.epoch = PodU64::from(epoch);
+        self.page = page;
+        self.bump = bump;
+        self.reserved = [0; 128];
+        self.operator_votes = [OperatorVote::default(); MAX_OPERATORS];
+        self.ballot_tallies = [BallotTally::default(); MAX_OPERATORS];
+    }
+
+    pub fn seeds(ballot_box: &Pubkey, page: u8) -> Vec<Vec<u8>> {
+        Vec::from_iter(
+            [
+                b"ballot_box_page".to_vec(),
+                ballot_box.to_bytes().to_vec(),
+                vec![page],
+            ]
+            .iter()
+            .cloned(),
+        )
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ballot_box: &Pubkey,
+        page: u8,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ballot_box, page);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ballot_box: &Pubkey,
+        page: u8,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ballot_box, page).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads the first `page_count` accounts as the ballot box's pages, in order
+    pub fn load_pages(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        ballot_box: &Pubkey,
+        page_count: u8,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if accounts.len() < page_count as usize {
+            msg!("Missing ballot box pages");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        for (page, account) in (1..=page_count).zip(accounts.iter()) {
+            Self::load(program_id, account, ballot_box, page, expect_writable)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn load_to_close(
+        program_id: &Pubkey,
+        account_to_close: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        let page = {
+            let account_data = account_to_close.try_borrow_data()?;
+            let account_struct = Self::try_from_slice_unchecked(&account_data)?;
+            account_struct.page()
+        };
+
+        let ballot_box = BallotBox::find_program_address(program_id, ncn, epoch).0;
+        Self::load(program_id, account_to_close, &ballot_box, page, true)
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub const fn page(&self) -> u8 {
+        self.page
+    }
+
+    pub const fn operator_votes(&self) -> &[OperatorVote; MAX_OPERATORS] {
+        &self.operator_votes
+    }
+
+    pub const fn ballot_tallies(&self) -> &[BallotTally; MAX_OPERATORS] {
+        &self.ballot_tallies
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for BallotBoxPage {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+       writeln!(f, "\n\n----------- Ballot Box Page {} -------------", self.page())?;
+       writeln!(f, "  NCN:                          {}", self.ncn)?;
+       writeln!(f, "  Epoch:                        {}", self.epoch())?;
+       writeln!(f, "  Bump:                         {}", self.bump)?;
+
+       writeln!(f, "\nOperator Votes:")?;
+       fmt_operator_votes(f, self.operator_votes())?;
+
+       writeln!(f, "\nBallot Tallies:")?;
+       fmt_ballot_tallies(f, self.ballot_tallies())?;
+
+       writeln!(f, "\n")?;
+       Ok(())
+   }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{constants::DEFAULT_CONSENSUS_THRESHOLD_BPS, utils::assert_tip_router_error};
 
     use super::*;
 
@@ -715,7 +1440,8 @@ mod tests {
             + 1 // bump
             + size_of::<PodU64>() // slot_created
             + size_of::<PodU64>() // slot_consensus_reached
-            + 128 // reserved
+            + 1 // page_count
+            + 127 // reserved
             + size_of::<PodU64>() // operators_voted
             + size_of::<PodU64>() // unique_ballots
             + size_of::<Ballot>() // winning_ballot
@@ -724,6 +1450,12 @@ mod tests {
 
         assert_eq!(size_of::<BallotBox>(), expected_total);
 
+        // Ballot boxes created before new fields were carved from reserved space keep their layout
+        assert_eq!(size_of::<Ballot>(), 96);
+        assert_eq!(size_of::<OperatorVote>(), 250);
+        assert_eq!(size_of::<BallotTally>(), 250);
+        assert_eq!(size_of::<BallotBox>(), 128_297);
+
         let ballot_box = BallotBox::new(&Pubkey::default(), 0, 0, 0);
         assert_eq!(ballot_box.operator_votes.len(), MAX_OPERATORS);
         assert_eq!(ballot_box.ballot_tallies.len(), MAX_OPERATORS);
@@ -806,6 +1538,7 @@ mod tests {
         // Test initial cast vote
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot,
                 &stake_weights,
@@ -842,6 +1575,7 @@ mod tests {
         let new_slot = current_slot + 1;
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &new_ballot,
                 &stake_weights,
@@ -866,6 +1600,7 @@ mod tests {
         ballot_box.set_winning_ballot(&winning_ballot);
         ballot_box.slot_consensus_reached = PodU64::from(new_slot);
         let result = ballot_box.cast_vote(
+            &mut [],
             &operator,
             &ballot,
             &stake_weights,
@@ -879,6 +1614,7 @@ mod tests {
 
         // Test voting window expired after consensus
         let result = ballot_box.cast_vote(
+            &mut [],
             &operator,
             &ballot,
             &stake_weights,
@@ -929,6 +1665,7 @@ mod tests {
         for _ in 0..MAX_OPERATORS {
             let operator = Pubkey::new_unique();
             let result = ballot_box.cast_vote(
+                &mut [],
                 &operator,
                 &ballot,
                 &stake_weights,
@@ -941,6 +1678,7 @@ mod tests {
         // Try to add one more vote, which should fail
         let extra_operator = Pubkey::new_unique();
         let result = ballot_box.cast_vote(
+            &mut [],
             &extra_operator,
             &ballot,
             &stake_weights,
@@ -954,6 +1692,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_vote_into_page() {
+        use crate::constants::MAX_PAGES;
+
+        let current_slot = 100;
+        let epoch = 1;
+        let valid_slots_after_consensus = 10;
+        let ncn = Pubkey::new_unique();
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+        let mut page = BallotBoxPage::zeroed();
+        page.initialize(&ncn, epoch, 1, 0);
+
+        assert_eq!(BallotBox::pages_required(MAX_OPERATORS as u64), Ok(0));
+        assert_eq!(BallotBox::pages_required(MAX_OPERATORS as u64 + 1), Ok(1));
+        assert_eq!(
+            BallotBox::pages_required((MAX_OPERATORS * (MAX_PAGES + 1)) as u64 + 1),
+            Err(TipRouterError::TooManyPages)
+        );
+
+        // Fill every vote and tally in the ballot box with a distinct ballot
+        let stake_weights = StakeWeights::new(1);
+        for i in 0..MAX_OPERATORS {
+            let mut root = [0u8; 32];
+            root[..8].copy_from_slice(&(i as u64 + 1).to_le_bytes());
+            ballot_box
+                .cast_vote(
+                    &mut [],
+                    &Pubkey::new_unique(),
+                    &Ballot::new(&root),
+                    &stake_weights,
+                    current_slot,
+                    valid_slots_after_consensus,
+                )
+                .unwrap();
+        }
+
+        let operator = Pubkey::new_unique();
+        let ballot = Ballot::new(&[0xff; 32]);
+        let stake_weights = StakeWeights::new(10_000);
+
+        // Without a page there is no room left
+        let result = ballot_box.cast_vote(
+            &mut [],
+            &operator,
+            &ballot,
+            &stake_weights,
+            current_slot,
+            valid_slots_after_consensus,
+        );
+        assert_eq!(result, Err(TipRouterError::BallotTallyFull));
+
+        ballot_box
+            .cast_vote(
+                &mut [&mut page],
+                &operator,
+                &ballot,
+                &stake_weights,
+                current_slot,
+                valid_slots_after_consensus,
+            )
+            .unwrap();
+
+        assert_eq!(ballot_box.operators_voted(), MAX_OPERATORS as u64 + 1);
+        assert_eq!(ballot_box.unique_ballots(), MAX_OPERATORS as u64 + 1);
+        assert_eq!(page.operator_votes()[0].operator(), &operator);
+        assert_eq!(
+            page.operator_votes()[0].ballot_index() as usize,
+            MAX_OPERATORS
+        );
+        assert_eq!(page.ballot_tallies()[0].ballot(), &ballot);
+        assert_eq!(
+            ballot_box.all_operator_votes(&[&page]).count(),
+            MAX_OPERATORS + 1
+        );
+        assert!(ballot_box.has_ballot(&[&page], &ballot));
+        assert!(!ballot_box.has_ballot(&[], &ballot));
+
+        ballot_box
+            .tally_votes(
+                &[&page],
+                MAX_OPERATORS as u128 + 10_000,
+                current_slot,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
+            .unwrap();
+
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.get_winning_ballot().unwrap(), &ballot);
+        assert_eq!(
+            ballot_box
+                .get_winning_ballot_tally(&[&page])
+                .unwrap()
+                .index() as usize,
+            MAX_OPERATORS
+        );
+        assert_eq!(
+            ballot_box.get_winning_ballot_tally(&[]).err(),
+            Some(TipRouterError::BallotTallyNotFoundFull)
+        );
+    }
+
     #[test]
     fn test_increment_or_create_ballot_tally() {
         let mut ballot_box = BallotBox::new(&Pubkey::new_unique(), 1, 1, 1);
@@ -962,7 +1801,7 @@ mod tests {
 
         // Test creating new ballot tally
         let tally_index = ballot_box
-            .increment_or_create_ballot_tally(&ballot, &stake_weights)
+            .increment_or_create_ballot_tally(&mut [], &ballot, &stake_weights)
             .unwrap();
         assert_eq!(tally_index, 0);
         assert_eq!(ballot_box.unique_ballots(), 1);
@@ -974,132 +1813,424 @@ mod tests {
 
         // Test incrementing existing ballot tally
         let tally_index = ballot_box
-            .increment_or_create_ballot_tally(&ballot, &stake_weights)
+            .increment_or_create_ballot_tally(&mut [], &ballot, &stake_weights)
             .unwrap();
         assert_eq!(tally_index, 0);
         assert_eq!(ballot_box.unique_ballots(), 1);
         assert_eq!(
-            ballot_box.ballot_tallies[0].stake_weights().stake_weight(),
-            stake_weights.stake_weight() * 2
+            ballot_box.ballot_tallies[0].stake_weights().stake_weight(),
+            stake_weights.stake_weight() * 2
+        );
+        assert_eq!(*ballot_box.ballot_tallies[0].ballot(), ballot);
+
+        // Test creating second ballot tally
+        let ballot2 = Ballot::new(&[2u8; 32]);
+        let tally_index = ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot2, &stake_weights)
+            .unwrap();
+        assert_eq!(tally_index, 1);
+        assert_eq!(ballot_box.unique_ballots(), 2);
+        assert_eq!(
+            ballot_box.ballot_tallies[1].stake_weights().stake_weight(),
+            stake_weights.stake_weight()
+        );
+        assert_eq!(*ballot_box.ballot_tallies[1].ballot(), ballot2);
+
+        // Test error when ballot tallies are full
+        for _ in 3..=ballot_box.ballot_tallies.len() {
+            let ballot = Ballot::new(&Pubkey::new_unique().to_bytes());
+            ballot_box
+                .increment_or_create_ballot_tally(&mut [], &ballot, &stake_weights)
+                .unwrap();
+        }
+        let ballot_full = Ballot::new(&Pubkey::new_unique().to_bytes());
+        let result =
+            ballot_box.increment_or_create_ballot_tally(&mut [], &ballot_full, &stake_weights);
+        assert!(matches!(result, Err(TipRouterError::BallotTallyFull)));
+    }
+
+    #[test]
+    fn test_tally_votes() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let epoch = 1;
+        let quarter_stake_weights = StakeWeights::new(500);
+        let half_stake_weights = StakeWeights::new(500);
+        let full_stake_weights = StakeWeights::new(1000);
+        let total_stake_weight: u128 = 1000;
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+        let ballot = Ballot::new(&[1; 32]);
+
+        // Test no consensus when below threshold
+        ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot, &half_stake_weights)
+            .unwrap();
+        ballot_box
+            .tally_votes(
+                &[],
+                total_stake_weight,
+                current_slot,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
+            .unwrap();
+        assert!(!ballot_box.is_consensus_reached());
+        assert_eq!(
+            ballot_box.slot_consensus_reached(),
+            DEFAULT_CONSENSUS_REACHED_SLOT
+        );
+        assert!(matches!(
+            ballot_box.get_winning_ballot_tally(&[]),
+            Err(TipRouterError::ConsensusNotReached)
+        ));
+
+        // Test consensus reached when above threshold
+        ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot, &half_stake_weights)
+            .unwrap();
+        ballot_box
+            .tally_votes(
+                &[],
+                total_stake_weight,
+                current_slot,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.slot_consensus_reached(), current_slot);
+        assert_eq!(
+            *ballot_box.get_winning_ballot_tally(&[]).unwrap().ballot(),
+            ballot
+        );
+
+        // Consensus remains after additional votes
+        let ballot2 = Ballot::new(&[2; 32]);
+        ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot2, &full_stake_weights)
+            .unwrap();
+        ballot_box
+            .tally_votes(
+                &[],
+                total_stake_weight,
+                current_slot + 1,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.slot_consensus_reached(), current_slot);
+        assert_eq!(
+            *ballot_box.get_winning_ballot_tally(&[]).unwrap().ballot(),
+            ballot
+        );
+
+        // Test with multiple competing ballots
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+        let ballot1 = Ballot::new(&[1; 32]);
+        let ballot2 = Ballot::new(&[2; 32]);
+        let ballot3 = Ballot::new(&[3; 32]);
+
+        ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot1, &quarter_stake_weights)
+            .unwrap();
+        ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot2, &quarter_stake_weights)
+            .unwrap();
+        ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot3, &half_stake_weights)
+            .unwrap();
+
+        ballot_box
+            .tally_votes(
+                &[],
+                total_stake_weight,
+                current_slot,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
+            .unwrap();
+        assert!(!ballot_box.is_consensus_reached());
+
+        // Add more votes to reach consensus
+        ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot3, &half_stake_weights)
+            .unwrap();
+        ballot_box
+            .tally_votes(
+                &[],
+                total_stake_weight,
+                current_slot,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(
+            *ballot_box.get_winning_ballot_tally(&[]).unwrap().ballot(),
+            ballot3
+        );
+    }
+
+    #[test]
+    fn test_voted_for_winning_ballot() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let mut ballot_box = BallotBox::new(&ncn, 1, 0, current_slot);
+        let winner = Pubkey::new_unique();
+        let loser = Pubkey::new_unique();
+        let absent = Pubkey::new_unique();
+
+        ballot_box
+            .cast_vote(
+                &mut [],
+                &winner,
+                &Ballot::new(&[1; 32]),
+                &StakeWeights::new(700),
+                current_slot,
+                0,
+            )
+            .unwrap();
+        ballot_box
+            .cast_vote(
+                &mut [],
+                &loser,
+                &Ballot::new(&[2; 32]),
+                &StakeWeights::new(300),
+                current_slot,
+                0,
+            )
+            .unwrap();
+
+        // No winner yet
+        assert!(!ballot_box.voted_for_winning_ballot(&[], &winner));
+
+        ballot_box
+            .tally_votes(&[], 1000, current_slot, DEFAULT_CONSENSUS_THRESHOLD_BPS)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+
+        assert!(ballot_box.voted_for_winning_ballot(&[], &winner));
+        assert!(!ballot_box.voted_for_winning_ballot(&[], &loser));
+        assert!(ballot_box.operator_vote(&[], &loser).is_some());
+        assert!(!ballot_box.voted_for_winning_ballot(&[], &absent));
+        assert!(ballot_box.operator_vote(&[], &absent).is_none());
+    }
+
+    #[test]
+    fn test_cast_abstain_vote() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let mut ballot_box = BallotBox::new(&ncn, 1, 0, current_slot);
+        let abstainer = Pubkey::new_unique();
+        let voter = Pubkey::new_unique();
+        let ballot = Ballot::new(&[1; 32]);
+
+        let abstain = Ballot::abstain();
+        assert!(abstain.is_abstain());
+        assert!(!abstain.is_valid());
+
+        ballot_box
+            .cast_vote(
+                &mut [],
+                &abstainer,
+                &abstain,
+                &StakeWeights::new(600),
+                current_slot,
+                0,
+            )
+            .unwrap();
+
+        // Counted as voted, but no ballot is tallied
+        assert_eq!(ballot_box.operators_voted(), 1);
+        assert_eq!(ballot_box.operators_abstained(&[]), 1);
+        assert_eq!(ballot_box.unique_ballots(), 0);
+        assert!(!ballot_box.has_ballot(&[], &abstain));
+        let vote = ballot_box.operator_vote(&[], &abstainer).unwrap();
+        assert!(vote.has_voted());
+        assert!(vote.is_abstain());
+
+        ballot_box
+            .cast_vote(
+                &mut [],
+                &voter,
+                &ballot,
+                &StakeWeights::new(400),
+                current_slot,
+                0,
+            )
+            .unwrap();
+
+        // The abstained stake does not count toward consensus
+        ballot_box
+            .tally_votes(&[], 1000, current_slot, DEFAULT_CONSENSUS_THRESHOLD_BPS)
+            .unwrap();
+        assert!(!ballot_box.is_consensus_reached());
+        assert_eq!(
+            ballot_box
+                .all_ballot_tallies(&[])
+                .find(|tally| tally.ballot().eq(&ballot))
+                .unwrap()
+                .stake_weights()
+                .stake_weight(),
+            400
+        );
+
+        // The abstainer can still vote once they have data
+        ballot_box
+            .cast_vote(
+                &mut [],
+                &abstainer,
+                &ballot,
+                &StakeWeights::new(600),
+                current_slot,
+                0,
+            )
+            .unwrap();
+        assert_eq!(ballot_box.operators_voted(), 2);
+        assert_eq!(ballot_box.operators_abstained(&[]), 0);
+
+        ballot_box
+            .tally_votes(&[], 1000, current_slot, DEFAULT_CONSENSUS_THRESHOLD_BPS)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert!(ballot_box.voted_for_winning_ballot(&[], &abstainer));
+    }
+
+    #[test]
+    fn test_cast_vote_with_snapshot() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let mut ballot_box = BallotBox::new(&ncn, 1, 0, current_slot);
+        let operator_1 = Pubkey::new_unique();
+        let operator_2 = Pubkey::new_unique();
+
+        // Snapshot metadata does not affect ballot equality
+        let ballot_1 = Ballot::new_with_snapshot(&[1; 32], 50, &[2; 32]);
+        let ballot_2 = Ballot::new_with_snapshot(&[1; 32], 60, &[3; 32]);
+        assert_eq!(ballot_1, ballot_2);
+        assert_eq!(ballot_1, Ballot::new(&[1; 32]));
+
+        for (operator, ballot) in [(&operator_1, &ballot_1), (&operator_2, &ballot_2)] {
+            ballot_box
+                .cast_vote(
+                    &mut [],
+                    operator,
+                    ballot,
+                    &StakeWeights::new(500),
+                    current_slot,
+                    0,
+                )
+                .unwrap();
+        }
+
+        assert_eq!(ballot_box.unique_ballots(), 1);
+
+        // Stored in each operator's snapshot, not in the tally
+        let tally = ballot_box.all_ballot_tallies(&[]).next().unwrap();
+        assert_eq!(tally.ballot().snapshot_slot(), 0);
+        assert_eq!(tally.ballot().bank_hash(), &[0; 32]);
+    }
+
+    #[test]
+    fn test_ballot_tip_distribution_account_count() {
+        let ncn = Pubkey::new_unique();
+        let epoch = 0;
+        let current_slot = 100;
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+
+        // Operators agreeing on the root are tallied together whatever count they report
+        let ballot = Ballot::new(&[1; 32]).with_tip_distribution_account_count(10);
+        let miscounted_ballot = Ballot::new(&[1; 32]).with_tip_distribution_account_count(11);
+        assert_eq!(ballot, miscounted_ballot);
+        assert_eq!(
+            ballot,
+            Ballot::new_with_snapshot(&[1; 32], 50, &[2; 32])
+                .with_tip_distribution_account_count(10)
         );
-        assert_eq!(*ballot_box.ballot_tallies[0].ballot(), ballot);
 
-        // Test creating second ballot tally
-        let ballot2 = Ballot::new(&[2u8; 32]);
-        let tally_index = ballot_box
-            .increment_or_create_ballot_tally(&ballot2, &stake_weights)
-            .unwrap();
-        assert_eq!(tally_index, 1);
-        assert_eq!(ballot_box.unique_ballots(), 2);
-        assert_eq!(
-            ballot_box.ballot_tallies[1].stake_weights().stake_weight(),
-            stake_weights.stake_weight()
+        let operator = Pubkey::new_unique();
+        let salt = [7; 32];
+        assert_ne!(
+            ballot.commitment(&operator, epoch, &salt),
+            miscounted_ballot.commitment(&operator, epoch, &salt)
         );
-        assert_eq!(*ballot_box.ballot_tallies[1].ballot(), ballot2);
 
-        // Test error when ballot tallies are full
-        for _ in 3..=ballot_box.ballot_tallies.len() {
-            let ballot = Ballot::new(&Pubkey::new_unique().to_bytes());
+        let valid_slots_after_consensus = 10;
+        for (ballot, stake_weight) in [(&miscounted_ballot, 100), (&ballot, 200)] {
             ballot_box
-                .increment_or_create_ballot_tally(&ballot, &stake_weights)
+                .cast_vote(
+                    &mut [],
+                    &Pubkey::new_unique(),
+                    ballot,
+                    &StakeWeights::new(stake_weight),
+                    current_slot,
+                    valid_slots_after_consensus,
+                )
                 .unwrap();
         }
-        let ballot_full = Ballot::new(&Pubkey::new_unique().to_bytes());
-        let result = ballot_box.increment_or_create_ballot_tally(&ballot_full, &stake_weights);
-        assert!(matches!(result, Err(TipRouterError::BallotTallyFull)));
+        assert_eq!(ballot_box.unique_ballots(), 1);
+
+        // The count with the most stake behind it wins, even though the tally was opened with the
+        // other one
+        ballot_box
+            .tally_votes(&[], 300, current_slot, DEFAULT_CONSENSUS_THRESHOLD_BPS)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+
+        let winning_ballot = ballot_box.get_winning_ballot().unwrap();
+        assert_eq!(*winning_ballot, ballot);
+        assert_eq!(winning_ballot.tip_distribution_account_count(), 10);
     }
 
     #[test]
-    fn test_tally_votes() {
+    fn test_tally_votes_with_consensus_threshold() {
         let ncn = Pubkey::new_unique();
         let current_slot = 100;
         let epoch = 1;
-        let quarter_stake_weights = StakeWeights::new(500);
-        let half_stake_weights = StakeWeights::new(500);
-        let full_stake_weights = StakeWeights::new(1000);
         let total_stake_weight: u128 = 1000;
-        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
         let ballot = Ballot::new(&[1; 32]);
 
-        // Test no consensus when below threshold
+        // 55% of stake is not enough with the default threshold
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
         ballot_box
-            .increment_or_create_ballot_tally(&ballot, &half_stake_weights)
+            .increment_or_create_ballot_tally(&mut [], &ballot, &StakeWeights::new(550))
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(
+                &[],
+                total_stake_weight,
+                current_slot,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
             .unwrap();
         assert!(!ballot_box.is_consensus_reached());
-        assert_eq!(
-            ballot_box.slot_consensus_reached(),
-            DEFAULT_CONSENSUS_REACHED_SLOT
-        );
-        assert!(matches!(
-            ballot_box.get_winning_ballot_tally(),
-            Err(TipRouterError::ConsensusNotReached)
-        ));
 
-        // Test consensus reached when above threshold
-        ballot_box
-            .increment_or_create_ballot_tally(&ballot, &half_stake_weights)
-            .unwrap();
+        // But it is with a 51% threshold
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(&[], total_stake_weight, current_slot, 5_100)
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
-        assert_eq!(ballot_box.slot_consensus_reached(), current_slot);
-        assert_eq!(
-            *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
-            ballot
-        );
+        assert_eq!(ballot_box.get_winning_ballot().unwrap(), &ballot);
 
-        // Consensus remains after additional votes
-        let ballot2 = Ballot::new(&[2; 32]);
+        // 50% of stake is still not enough with a 51% threshold
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
         ballot_box
-            .increment_or_create_ballot_tally(&ballot2, &full_stake_weights)
+            .increment_or_create_ballot_tally(&mut [], &ballot, &StakeWeights::new(500))
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot + 1)
+            .tally_votes(&[], total_stake_weight, current_slot, 5_100)
             .unwrap();
-        assert!(ballot_box.is_consensus_reached());
-        assert_eq!(ballot_box.slot_consensus_reached(), current_slot);
-        assert_eq!(
-            *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
-            ballot
-        );
+        assert!(!ballot_box.is_consensus_reached());
 
-        // Test with multiple competing ballots
+        // Exactly 2/3 of stake falls short of 6_667 bps but meets the default threshold
         let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
-        let ballot1 = Ballot::new(&[1; 32]);
-        let ballot2 = Ballot::new(&[2; 32]);
-        let ballot3 = Ballot::new(&[3; 32]);
-
-        ballot_box
-            .increment_or_create_ballot_tally(&ballot1, &quarter_stake_weights)
-            .unwrap();
         ballot_box
-            .increment_or_create_ballot_tally(&ballot2, &quarter_stake_weights)
+            .increment_or_create_ballot_tally(&mut [], &ballot, &StakeWeights::new(2_000))
             .unwrap();
         ballot_box
-            .increment_or_create_ballot_tally(&ballot3, &half_stake_weights)
-            .unwrap();
-
-        ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(&[], 3_000, current_slot, 6_667)
             .unwrap();
         assert!(!ballot_box.is_consensus_reached());
-
-        // Add more votes to reach consensus
-        ballot_box
-            .increment_or_create_ballot_tally(&ballot3, &half_stake_weights)
-            .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(&[], 3_000, current_slot, DEFAULT_CONSENSUS_THRESHOLD_BPS)
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
-        assert_eq!(
-            *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
-            ballot3
-        );
     }
 
     #[test]
@@ -1118,6 +2249,7 @@ mod tests {
 
         // Operator 1 votes for ballot1 initially
         let result = ballot_box.cast_vote(
+            &mut [],
             &operator1,
             &ballot1,
             &stake_weights,
@@ -1149,6 +2281,7 @@ mod tests {
         // Operator 1 votes for ballot1 initially
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator1,
                 &ballot1,
                 &stake_weights,
@@ -1162,6 +2295,7 @@ mod tests {
         // Operator 1 changes vote to ballot2
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator1,
                 &ballot2,
                 &stake_weights,
@@ -1176,6 +2310,7 @@ mod tests {
         // Operator 2 votes for ballot2
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator2,
                 &ballot2,
                 &stake_weights,
@@ -1188,6 +2323,7 @@ mod tests {
         // Operator 3 votes for ballot2
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator3,
                 &ballot2,
                 &stake_weights,
@@ -1214,7 +2350,12 @@ mod tests {
 
         // Verify ballot2 wins consensus with all votes
         ballot_box
-            .tally_votes(total_stake_weight as u128, current_slot + 4)
+            .tally_votes(
+                &[],
+                total_stake_weight as u128,
+                current_slot + 4,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
             .unwrap();
         assert!(ballot_box.has_winning_ballot());
         assert_eq!(*ballot_box.get_winning_ballot().unwrap(), ballot2);
@@ -1234,10 +2375,10 @@ mod tests {
         let double_stake_weights = StakeWeights::new(200);
 
         ballot_box
-            .increment_or_create_ballot_tally(&ballot1, &stake_weights)
+            .increment_or_create_ballot_tally(&mut [], &ballot1, &stake_weights)
             .unwrap();
         ballot_box
-            .increment_or_create_ballot_tally(&ballot2, &stake_weights)
+            .increment_or_create_ballot_tally(&mut [], &ballot2, &stake_weights)
             .unwrap();
 
         // Test setting tie breaker before voting is stalled
@@ -1245,42 +2386,155 @@ mod tests {
         let epochs_before_stall = 3;
 
         assert_eq!(
-            ballot_box.set_tie_breaker_ballot(&ballot1.root(), current_epoch, epochs_before_stall),
+            ballot_box.set_tie_breaker_ballot(
+                &[],
+                &ballot1.root(),
+                current_epoch,
+                epochs_before_stall
+            ),
             Err(TipRouterError::VotingNotFinalized)
         );
 
         // Test setting tie breaker after voting is stalled (current_epoch >= epoch + epochs_before_stall)
         let current_epoch = epoch + epochs_before_stall;
         ballot_box
-            .set_tie_breaker_ballot(&ballot1.root(), current_epoch, epochs_before_stall)
+            .set_tie_breaker_ballot(&[], &ballot1.root(), current_epoch, epochs_before_stall)
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(
-            *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
+            *ballot_box.get_winning_ballot_tally(&[]).unwrap().ballot(),
             ballot1
         );
 
         // Test setting tie breaker with invalid merkle root
         let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
         ballot_box
-            .increment_or_create_ballot_tally(&ballot1, &stake_weights)
+            .increment_or_create_ballot_tally(&mut [], &ballot1, &stake_weights)
             .unwrap();
         assert_eq!(
-            ballot_box.set_tie_breaker_ballot(&[99; 32], current_epoch, epochs_before_stall),
+            ballot_box.set_tie_breaker_ballot(&[], &[99; 32], current_epoch, epochs_before_stall),
             Err(TipRouterError::TieBreakerNotInPriorVotes)
         );
 
         // Test setting tie breaker when consensus already reached
         let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
         ballot_box
-            .increment_or_create_ballot_tally(&ballot1, &double_stake_weights)
+            .increment_or_create_ballot_tally(&mut [], &ballot1, &double_stake_weights)
+            .unwrap();
+        ballot_box
+            .tally_votes(
+                &[],
+                double_stake_weights.stake_weight(),
+                current_slot,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(
+            ballot_box.set_tie_breaker_ballot(
+                &[],
+                &ballot1.root(),
+                current_epoch,
+                epochs_before_stall
+            ),
+            Err(TipRouterError::ConsensusAlreadyReached)
+        );
+    }
+
+    #[test]
+    fn test_finalize_stalled_ballot() {
+        let ncn = Pubkey::new_unique();
+        let epoch = 0;
+        let current_slot = 1000;
+        let total_stake_weight = 1000;
+        let epochs_before_fallback = 5;
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+
+        let ballot1 = Ballot::new(&[1; 32]);
+        let ballot2 = Ballot::new(&[2; 32]);
+
+        // No ballots to select from
+        assert_eq!(
+            ballot_box.finalize_stalled_ballot(
+                &[],
+                total_stake_weight,
+                epochs_before_fallback,
+                epochs_before_fallback,
+                0
+            ),
+            Err(TipRouterError::NoPluralityBallot)
+        );
+
+        ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot1, &StakeWeights::new(300))
+            .unwrap();
+        ballot_box
+            .increment_or_create_ballot_tally(&mut [], &ballot2, &StakeWeights::new(300))
             .unwrap();
+
+        // Before the fallback epoch
+        assert_eq!(
+            ballot_box.finalize_stalled_ballot(
+                &[],
+                total_stake_weight,
+                epochs_before_fallback - 1,
+                epochs_before_fallback,
+                0
+            ),
+            Err(TipRouterError::VotingNotFinalized)
+        );
+
+        // Tied for the most stake
+        assert_eq!(
+            ballot_box.finalize_stalled_ballot(
+                &[],
+                total_stake_weight,
+                epochs_before_fallback,
+                epochs_before_fallback,
+                0
+            ),
+            Err(TipRouterError::NoPluralityBallot)
+        );
+
         ballot_box
-            .tally_votes(double_stake_weights.stake_weight(), current_slot)
+            .increment_or_create_ballot_tally(&mut [], &ballot1, &StakeWeights::new(100))
+            .unwrap();
+
+        // 400 of 1000 stake is below a 50% floor
+        assert_eq!(
+            ballot_box.finalize_stalled_ballot(
+                &[],
+                total_stake_weight,
+                epochs_before_fallback,
+                epochs_before_fallback,
+                5_000
+            ),
+            Err(TipRouterError::PluralityBelowStakeFloor)
+        );
+
+        let tally = ballot_box
+            .finalize_stalled_ballot(
+                &[],
+                total_stake_weight,
+                epochs_before_fallback,
+                epochs_before_fallback,
+                4_000,
+            )
             .unwrap();
+        assert_eq!(*tally.ballot(), ballot1);
+        assert_eq!(tally.stake_weights().stake_weight(), 400);
         assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.get_winning_ballot().unwrap(), ballot1);
+
+        // Already finalized
         assert_eq!(
-            ballot_box.set_tie_breaker_ballot(&ballot1.root(), current_epoch, epochs_before_stall),
+            ballot_box.finalize_stalled_ballot(
+                &[],
+                total_stake_weight,
+                epochs_before_fallback,
+                epochs_before_fallback,
+                0
+            ),
             Err(TipRouterError::ConsensusAlreadyReached)
         );
     }
@@ -1299,6 +2553,7 @@ mod tests {
         let ballot1 = Ballot::new(&[1; 32]);
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot1,
                 &stake_weights,
@@ -1322,6 +2577,7 @@ mod tests {
         let ballot2 = Ballot::new(&[2; 32]);
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot2,
                 &stake_weights,
@@ -1362,6 +2618,7 @@ mod tests {
 #[cfg(test)]
 mod fuzz_tests {
     use super::*;
+    use crate::constants::DEFAULT_CONSENSUS_THRESHOLD_BPS;
     use std::collections::HashMap;
 
     // Generate pseudo-random ballot roots using Pubkey's random bytes
@@ -1429,6 +2686,7 @@ mod fuzz_tests {
 
             // Cast vote and verify BallotBox state
             match ballot_box.cast_vote(
+                &mut [],
                 &operator,
                 &ballot,
                 &stake_weights,
@@ -1471,10 +2729,12 @@ mod fuzz_tests {
 
                     // Periodically check consensus
                     if i % 10 == 0 {
-                        ballot_box.tally_votes(total_stake, slot).unwrap();
+                        ballot_box
+                            .tally_votes(&[], total_stake, slot, DEFAULT_CONSENSUS_THRESHOLD_BPS)
+                            .unwrap();
 
                         if ballot_box.is_consensus_reached() {
-                            let winning_tally = ballot_box.get_winning_ballot_tally().unwrap();
+                            let winning_tally = ballot_box.get_winning_ballot_tally(&[]).unwrap();
 
                             // Verify winning ballot has highest stake
                             for tally in ballot_box.ballot_tallies().iter().filter(|t| t.is_valid())
@@ -1563,6 +2823,7 @@ mod vote_change_tests {
         // Cast initial vote for ballot1
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot1,
                 &stake_weights,
@@ -1587,6 +2848,7 @@ mod vote_change_tests {
         // Change vote to ballot2
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot2,
                 &stake_weights,
@@ -1671,6 +2933,7 @@ mod vote_change_tests {
             for operator in &operators {
                 ballot_box
                     .cast_vote(
+                        &mut [],
                         operator,
                         ballot,
                         &stake_weights,
@@ -1721,6 +2984,7 @@ mod same_ballot_tests {
         let stake_weights1 = StakeWeights::new(1000);
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot,
                 &stake_weights1,
@@ -1742,6 +3006,7 @@ mod same_ballot_tests {
         let stake_weights2 = StakeWeights::new(2000);
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot,
                 &stake_weights2,
@@ -1803,6 +3068,7 @@ mod revote_same_tests {
         // Cast initial vote
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot,
                 &stake_weights,
@@ -1824,6 +3090,7 @@ mod revote_same_tests {
         // Vote again for the same ballot
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot,
                 &stake_weights,
@@ -1866,6 +3133,7 @@ mod revote_same_tests {
         // Try voting one more time with same ballot
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot,
                 &stake_weights,
@@ -1904,6 +3172,7 @@ mod revote_same_tests {
 #[cfg(test)]
 mod zero_stake_tests {
     use super::*;
+    use crate::constants::DEFAULT_CONSENSUS_THRESHOLD_BPS;
 
     #[test]
     fn test_zero_stake_operator_basic_voting() {
@@ -1923,6 +3192,7 @@ mod zero_stake_tests {
         // Zero stake operator can cast a vote
         ballot_box
             .cast_vote(
+                &mut [],
                 &zero_stake_operator,
                 &ballot1,
                 &zero_stake,
@@ -1953,6 +3223,7 @@ mod zero_stake_tests {
         // Zero stake operator can change their vote
         ballot_box
             .cast_vote(
+                &mut [],
                 &zero_stake_operator,
                 &ballot2,
                 &zero_stake,
@@ -2001,6 +3272,7 @@ mod zero_stake_tests {
         for (i, operator) in zero_stake_operators.iter().enumerate() {
             ballot_box
                 .cast_vote(
+                    &mut [],
                     operator,
                     &ballot,
                     &zero_stake,
@@ -2023,7 +3295,12 @@ mod zero_stake_tests {
         // Calculate consensus with only zero stake votes
         let total_stake = 1000u128;
         ballot_box
-            .tally_votes(total_stake, current_slot + num_zero_stake as u64)
+            .tally_votes(
+                &[],
+                total_stake,
+                current_slot + num_zero_stake as u64,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
             .unwrap();
         assert!(
             !ballot_box.is_consensus_reached(),
@@ -2036,6 +3313,7 @@ mod zero_stake_tests {
 
         ballot_box
             .cast_vote(
+                &mut [],
                 &normal_operator,
                 &ballot,
                 &normal_stake,
@@ -2059,7 +3337,12 @@ mod zero_stake_tests {
 
         // Check consensus again
         ballot_box
-            .tally_votes(total_stake, current_slot + num_zero_stake as u64 + 1)
+            .tally_votes(
+                &[],
+                total_stake,
+                current_slot + num_zero_stake as u64 + 1,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
             .unwrap();
         assert!(
             ballot_box.is_consensus_reached(),
@@ -2091,6 +3374,7 @@ mod zero_stake_tests {
         // Cast votes for ballot1
         ballot_box
             .cast_vote(
+                &mut [],
                 &zero_stake_operator,
                 &ballot1,
                 &zero_stake,
@@ -2100,6 +3384,7 @@ mod zero_stake_tests {
             .unwrap();
         ballot_box
             .cast_vote(
+                &mut [],
                 &normal_operator1,
                 &ballot1,
                 &normal_stake1,
@@ -2111,6 +3396,7 @@ mod zero_stake_tests {
         // Cast vote for ballot2
         ballot_box
             .cast_vote(
+                &mut [],
                 &normal_operator2,
                 &ballot2,
                 &normal_stake2,
@@ -2146,7 +3432,14 @@ mod zero_stake_tests {
 
         // Check consensus
         let total_stake = 1000u128;
-        ballot_box.tally_votes(total_stake, current_slot).unwrap();
+        ballot_box
+            .tally_votes(
+                &[],
+                total_stake,
+                current_slot,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
+            .unwrap();
 
         // Neither ballot should have consensus yet
         assert!(!ballot_box.is_consensus_reached());
@@ -2156,6 +3449,7 @@ mod zero_stake_tests {
         let normal_stake3 = StakeWeights::new(300);
         ballot_box
             .cast_vote(
+                &mut [],
                 &normal_operator3,
                 &ballot2,
                 &normal_stake3,
@@ -2164,9 +3458,322 @@ mod zero_stake_tests {
             )
             .unwrap();
 
-        ballot_box.tally_votes(total_stake, current_slot).unwrap();
+        ballot_box
+            .tally_votes(
+                &[],
+                total_stake,
+                current_slot,
+                DEFAULT_CONSENSUS_THRESHOLD_BPS,
+            )
+            .unwrap();
 
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(ballot_box.get_winning_ballot().unwrap(), &ballot2);
     }
+
+    #[test]
+    fn test_commit_and_reveal_vote() {
+        let ncn = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let slot_created = 100;
+        let commit_window_slots = 50;
+        let valid_slots_after_consensus = TEST_VALID_SLOTS_AFTER_CONSENSUS;
+        let stake_weights = StakeWeights::new(1000);
+        let mut ballot_box = BallotBox::new(&ncn, TEST_EPOCH, 0, slot_created);
+
+        let ballot = Ballot::new(&[1; 32]);
+        let salt = [7; 32];
+        let commitment = ballot.commitment(&operator, TEST_EPOCH, &salt);
+
+        // Cannot reveal without a commitment
+        let result = ballot_box.reveal_vote(
+            &mut [],
+            &operator,
+            &ballot,
+            &salt,
+            &stake_weights,
+            slot_created + commit_window_slots,
+            valid_slots_after_consensus,
+            slot_created,
+            commit_window_slots,
+        );
+        assert_eq!(result, Err(TipRouterError::VoteCommitmentNotFound));
+
+        ballot_box
+            .commit_vote(
+                &mut [],
+                &operator,
+                &commitment,
+                slot_created + 1,
+                slot_created,
+                commit_window_slots,
+            )
+            .unwrap();
+
+        // A commitment is recorded but not counted
+        let vote = ballot_box
+            .operator_votes()
+            .iter()
+            .find(|v| v.operator().eq(&operator))
+            .unwrap();
+        assert!(vote.has_commitment());
+        assert!(!vote.has_voted());
+        assert_eq!(vote.slot_committed(), slot_created + 1);
+        assert_eq!(ballot_box.operators_voted(), 0);
+        assert_eq!(ballot_box.unique_ballots(), 0);
+
+        // Cannot reveal while the commit window is open
+        let result = ballot_box.reveal_vote(
+            &mut [],
+            &operator,
+            &ballot,
+            &salt,
+            &stake_weights,
+            slot_created + commit_window_slots - 1,
+            valid_slots_after_consensus,
+            slot_created,
+            commit_window_slots,
+        );
+        assert_eq!(result, Err(TipRouterError::CommitWindowOpen));
+
+        // Cannot commit once the window is closed
+        let result = ballot_box.commit_vote(
+            &mut [],
+            &operator,
+            &commitment,
+            slot_created + commit_window_slots,
+            slot_created,
+            commit_window_slots,
+        );
+        assert_eq!(result, Err(TipRouterError::CommitWindowClosed));
+
+        // Revealing a different ballot or salt fails
+        let result = ballot_box.reveal_vote(
+            &mut [],
+            &operator,
+            &Ballot::new(&[2; 32]),
+            &salt,
+            &stake_weights,
+            slot_created + commit_window_slots,
+            valid_slots_after_consensus,
+            slot_created,
+            commit_window_slots,
+        );
+        assert_eq!(result, Err(TipRouterError::InvalidVoteCommitment));
+
+        let result = ballot_box.reveal_vote(
+            &mut [],
+            &operator,
+            &ballot,
+            &[8; 32],
+            &stake_weights,
+            slot_created + commit_window_slots,
+            valid_slots_after_consensus,
+            slot_created,
+            commit_window_slots,
+        );
+        assert_eq!(result, Err(TipRouterError::InvalidVoteCommitment));
+
+        // Revealing the committed ballot counts the vote
+        ballot_box
+            .reveal_vote(
+                &mut [],
+                &operator,
+                &ballot,
+                &salt,
+                &stake_weights,
+                slot_created + commit_window_slots,
+                valid_slots_after_consensus,
+                slot_created,
+                commit_window_slots,
+            )
+            .unwrap();
+
+        let vote = ballot_box
+            .operator_votes()
+            .iter()
+            .find(|v| v.operator().eq(&operator))
+            .unwrap();
+        assert!(vote.has_voted());
+        assert_eq!(vote.slot_voted(), slot_created + commit_window_slots);
+        assert_eq!(*vote.commitment(), commitment);
+        assert_eq!(ballot_box.operators_voted(), 1);
+        assert_eq!(ballot_box.unique_ballots(), 1);
+        assert!(ballot_box.has_ballot(&[], &ballot));
+    }
+
+    #[test]
+    fn test_commitment_cannot_be_copied() {
+        let ncn = Pubkey::new_unique();
+        let operator_a = Pubkey::new_unique();
+        let operator_b = Pubkey::new_unique();
+        let slot_created = 100;
+        let commit_window_slots = 50;
+        let valid_slots_after_consensus = TEST_VALID_SLOTS_AFTER_CONSENSUS;
+        let stake_weights = StakeWeights::new(1000);
+        let mut ballot_box = BallotBox::new(&ncn, TEST_EPOCH, 0, slot_created);
+
+        let ballot = Ballot::new(&[1; 32]);
+        let salt = [7; 32];
+        let commitment = ballot.commitment(&operator_a, TEST_EPOCH, &salt);
+
+        // The same ballot and salt commit to something different for another operator or epoch
+        assert_ne!(
+            commitment,
+            ballot.commitment(&operator_b, TEST_EPOCH, &salt)
+        );
+        assert_ne!(
+            commitment,
+            ballot.commitment(&operator_a, TEST_EPOCH + 1, &salt)
+        );
+
+        // Or for the same root computed from a different snapshot
+        assert_ne!(
+            commitment,
+            Ballot::new_with_snapshot(&[1; 32], 1, &[0; 32]).commitment(
+                &operator_a,
+                TEST_EPOCH,
+                &salt
+            )
+        );
+        assert_ne!(
+            commitment,
+            Ballot::new_with_snapshot(&[1; 32], 0, &[9; 32]).commitment(
+                &operator_a,
+                TEST_EPOCH,
+                &salt
+            )
+        );
+
+        // Operator B copies operator A's commitment
+        for operator in [&operator_a, &operator_b] {
+            ballot_box
+                .commit_vote(
+                    &mut [],
+                    operator,
+                    &commitment,
+                    slot_created + 1,
+                    slot_created,
+                    commit_window_slots,
+                )
+                .unwrap();
+        }
+
+        ballot_box
+            .reveal_vote(
+                &mut [],
+                &operator_a,
+                &ballot,
+                &salt,
+                &stake_weights,
+                slot_created + commit_window_slots,
+                valid_slots_after_consensus,
+                slot_created,
+                commit_window_slots,
+            )
+            .unwrap();
+
+        // Operator A's revealed ballot and salt don't open operator B's copy
+        let result = ballot_box.reveal_vote(
+            &mut [],
+            &operator_b,
+            &ballot,
+            &salt,
+            &stake_weights,
+            slot_created + commit_window_slots,
+            valid_slots_after_consensus,
+            slot_created,
+            commit_window_slots,
+        );
+        assert_eq!(result, Err(TipRouterError::InvalidVoteCommitment));
+        assert_eq!(ballot_box.operators_voted(), 1);
+    }
+
+    #[test]
+    fn test_commit_vote_does_not_take_other_operators_slot() {
+        let ncn = Pubkey::new_unique();
+        let commit_window_slots = 50;
+        let mut ballot_box = BallotBox::new(&ncn, TEST_EPOCH, 0, TEST_CURRENT_SLOT);
+
+        let operator1 = Pubkey::new_unique();
+        let operator2 = Pubkey::new_unique();
+
+        ballot_box
+            .commit_vote(
+                &mut [],
+                &operator1,
+                &[1; 32],
+                TEST_CURRENT_SLOT,
+                TEST_CURRENT_SLOT,
+                commit_window_slots,
+            )
+            .unwrap();
+        ballot_box
+            .commit_vote(
+                &mut [],
+                &operator2,
+                &[2; 32],
+                TEST_CURRENT_SLOT,
+                TEST_CURRENT_SLOT,
+                commit_window_slots,
+            )
+            .unwrap();
+
+        // Recommitting overwrites the operator's own entry
+        ballot_box
+            .commit_vote(
+                &mut [],
+                &operator1,
+                &[3; 32],
+                TEST_CURRENT_SLOT + 1,
+                TEST_CURRENT_SLOT,
+                commit_window_slots,
+            )
+            .unwrap();
+
+        let committed: Vec<&OperatorVote> = ballot_box
+            .operator_votes()
+            .iter()
+            .filter(|v| !v.is_empty())
+            .collect();
+        assert_eq!(committed.len(), 2);
+        assert_eq!(*committed[0].operator(), operator1);
+        assert_eq!(*committed[0].commitment(), [3; 32]);
+        assert_eq!(*committed[1].operator(), operator2);
+        assert_eq!(*committed[1].commitment(), [2; 32]);
+    }
+
+    #[test]
+    fn test_commit_window_starts_when_voting_opens() {
+        let ncn = Pubkey::new_unique();
+        let slot_created = 100;
+        let voting_opened_slot = 500;
+        let commit_window_slots = 50;
+        let ballot_box = BallotBox::new(&ncn, TEST_EPOCH, 0, slot_created);
+
+        // A ballot box created before the snapshot was finalized waits for voting to open
+        assert!(ballot_box
+            .is_commit_window_open(
+                voting_opened_slot + commit_window_slots - 1,
+                voting_opened_slot,
+                commit_window_slots,
+            )
+            .unwrap());
+        assert!(!ballot_box
+            .is_commit_window_open(
+                voting_opened_slot + commit_window_slots,
+                voting_opened_slot,
+                commit_window_slots,
+            )
+            .unwrap());
+
+        // A ballot box created after the snapshot was finalized starts at its creation
+        assert!(ballot_box
+            .is_commit_window_open(
+                slot_created + commit_window_slots - 1,
+                0,
+                commit_window_slots,
+            )
+            .unwrap());
+    }
 }
@@ -14,9 +14,16 @@ use solana_program::{
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    ballot_box::BallotBox, base_fee_group::BaseFeeGroup, constants::MAX_OPERATORS,
-    discriminators::Discriminators, error::TipRouterError, fees::Fees, loaders::check_load,
+    ballot_box::{BallotBox, BallotBoxPage},
+    base_fee_group::BaseFeeGroup,
+    constants::MAX_OPERATORS,
+    discriminators::Discriminators,
+    error::TipRouterError,
+    fees::Fees,
+    late_vote_decay::LateVoteDecay,
+    loaders::check_load,
     ncn_fee_group::NcnFeeGroup,
+    utils::get_pages_required,
 };
 
 // PDA'd ["epoch_reward_router", NCN, NCN_EPOCH_SLOT]
@@ -37,8 +44,12 @@ pub struct BaseRewardRouter {
     reward_pool: PodU64,
     /// Amount of rewards processed ( in lamports )
     rewards_processed: PodU64,
+    /// Number of `BaseRewardRouterPage` accounts chained to the router
+    page_count: u8,
+    /// Lamports diverted from the DAO base fee group to refill the account payer
+    account_payer_top_up: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 119],
 
     // route state tracking - to recover from unfinished routing
     /// Last NCN fee group index
@@ -77,7 +88,9 @@ impl BaseRewardRouter {
             total_rewards: PodU64::from(0),
             reward_pool: PodU64::from(0),
             rewards_processed: PodU64::from(0),
-            reserved: [0; 128],
+            page_count: 0,
+            account_payer_top_up: PodU64::from(0),
+            reserved: [0; 119],
             last_ncn_group_index: Self::NO_LAST_NCN_GROUP_INDEX,
             last_vote_index: PodU16::from(Self::NO_LAST_VOTE_INDEX),
             last_rewards_to_process: PodU64::from(Self::NO_LAST_REWARDS_TO_PROCESS),
@@ -98,7 +111,9 @@ impl BaseRewardRouter {
         self.total_rewards = PodU64::from(0);
         self.reward_pool = PodU64::from(0);
         self.rewards_processed = PodU64::from(0);
-        self.reserved = [0; 128];
+        self.page_count = 0;
+        self.account_payer_top_up = PodU64::from(0);
+        self.reserved = [0; 119];
         self.base_fee_group_rewards =
             [BaseRewardRouterRewards::default(); NcnFeeGroup::FEE_GROUP_COUNT];
         self.ncn_fee_group_rewards =
@@ -205,6 +220,14 @@ impl BaseRewardRouter {
             || self.last_rewards_to_process() != Self::NO_LAST_REWARDS_TO_PROCESS
     }
 
+    /// Whether rewards have been routed and every routed lamport has been distributed
+    pub fn distribution_complete(&self) -> bool {
+        self.total_rewards() > 0
+            && self.reward_pool() == 0
+            && self.rewards_processed() == 0
+            && !self.still_routing()
+    }
+
     // ----------------- ROUTE REWARDS ---------------------
     pub fn route_incoming_rewards(
         &mut self,
@@ -267,9 +290,12 @@ impl BaseRewardRouter {
     pub fn route_ncn_fee_group_rewards(
         &mut self,
         ballot_box: &BallotBox,
+        ballot_box_pages: &[&BallotBoxPage],
+        late_vote_decay: &LateVoteDecay,
+        pages: &mut [&mut BaseRewardRouterPage],
         max_iterations: u16,
     ) -> Result<(), TipRouterError> {
-        let winning_ballot = ballot_box.get_winning_ballot_tally()?;
+        let winning_ballot = ballot_box.get_winning_ballot_tally(ballot_box_pages)?;
         let winning_stake_weight = winning_ballot.stake_weights();
 
         let (starting_group_index, mut starting_vote_index, mut starting_rewards_to_process) =
@@ -294,9 +320,11 @@ impl BaseRewardRouter {
                 continue;
             }
 
-            for vote_index in starting_vote_index..ballot_box.operator_votes().len() {
-                let votes = ballot_box.operator_votes()[vote_index];
-
+            for (vote_index, votes) in ballot_box
+                .all_operator_votes(ballot_box_pages)
+                .enumerate()
+                .skip(starting_vote_index)
+            {
                 if votes.ballot_index() == winning_ballot.index() {
                     // Update iteration state
                     {
@@ -328,8 +356,19 @@ impl BaseRewardRouter {
                         rewards_to_process,
                     )?;
 
+                    // Forfeited rewards stay in the group and go to the DAO with the remainder
+                    let forfeited_rewards = late_vote_decay.forfeited_rewards(
+                        ncn_fee_group_route_reward,
+                        votes.slot_voted(),
+                        ballot_box.slot_consensus_reached(),
+                    )?;
+                    let ncn_fee_group_route_reward = ncn_fee_group_route_reward
+                        .checked_sub(forfeited_rewards)
+                        .ok_or(TipRouterError::ArithmeticUnderflowError)?;
+
                     self.route_from_ncn_fee_group_rewards(group, ncn_fee_group_route_reward)?;
                     self.route_to_ncn_fee_group_reward_route(
+                        pages,
                         group,
                         operator,
                         ncn_fee_group_route_reward,
@@ -441,6 +480,19 @@ impl BaseRewardRouter {
         Ok(total_rewards)
     }
 
+    /// Receiver balance above rent that is not tracked as rewards in transit
+    pub fn untracked_rewards(
+        &self,
+        rent_cost: u64,
+        account_balance: u64,
+    ) -> Result<u64, TipRouterError> {
+        let untracked_rewards = account_balance
+            .saturating_sub(self.total_rewards_in_transit()?)
+            .saturating_sub(rent_cost);
+
+        Ok(untracked_rewards)
+    }
+
     pub fn rent_cost(&self, rent: &Rent) -> Result<u64, TipRouterError> {
         let size = 8_u64
             .checked_add(size_of::<Self>() as u64)
@@ -580,6 +632,40 @@ impl BaseRewardRouter {
         Ok(rewards)
     }
 
+    // ------------------ ACCOUNT PAYER TOP UP ---------------------
+
+    pub fn account_payer_top_up(&self) -> u64 {
+        self.account_payer_top_up.into()
+    }
+
+    /// Diverts up to `lamports` from the DAO base fee group rewards to the account payer,
+    /// returning the amount diverted
+    pub fn route_to_account_payer_top_up(&mut self, lamports: u64) -> Result<u64, TipRouterError> {
+        let group = BaseFeeGroup::dao();
+        let group_index = group.group_index()?;
+
+        let top_up = lamports.min(self.base_fee_group_reward(group)?);
+        if top_up == 0 {
+            return Ok(0);
+        }
+
+        self.base_fee_group_rewards[group_index].rewards = PodU64::from(
+            self.base_fee_group_reward(group)?
+                .checked_sub(top_up)
+                .ok_or(TipRouterError::ArithmeticUnderflowError)?,
+        );
+
+        self.decrement_rewards_processed(top_up)?;
+
+        self.account_payer_top_up = PodU64::from(
+            self.account_payer_top_up()
+                .checked_add(top_up)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(top_up)
+    }
+
     // ------------------ NCN FEE GROUP REWARDS ---------------------
 
     pub fn ncn_fee_group_rewards(&self, group: NcnFeeGroup) -> Result<u64, TipRouterError> {
@@ -627,27 +713,73 @@ impl BaseRewardRouter {
 
     // ------------------ NCN REWARD ROUTES ---------------------
 
-    pub fn has_operator_reward_route(&self, operator: &Pubkey) -> bool {
-        for ncn_route_reward in self.ncn_fee_group_reward_routes.iter() {
-            if ncn_route_reward.operator.eq(operator) {
-                return true;
-            }
+    pub const fn page_count(&self) -> u8 {
+        self.page_count
+    }
+
+    /// Number of `BaseRewardRouterPage` accounts needed to hold a route for every operator
+    pub fn pages_required(operator_count: u64) -> Result<u8, TipRouterError> {
+        get_pages_required(operator_count, MAX_OPERATORS)
+    }
+
+    pub fn increment_page_count(&mut self) -> Result<(), TipRouterError> {
+        self.page_count = self
+            .page_count
+            .checked_add(1)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    // Routes are indexed across the router and its pages, with the router holding the first
+    // `MAX_OPERATORS`
+    const fn capacity(page_count: usize) -> usize {
+        MAX_OPERATORS * (page_count + 1)
+    }
+
+    fn ncn_fee_group_reward_route_mut<'a>(
+        &'a mut self,
+        pages: &'a mut [&mut BaseRewardRouterPage],
+        route_index: usize,
+    ) -> Option<&'a mut NcnRewardRoute> {
+        match route_index / MAX_OPERATORS {
+            0 => self.ncn_fee_group_reward_routes.get_mut(route_index),
+            page => pages.get_mut(page - 1).map(|base_reward_router_page| {
+                &mut base_reward_router_page.ncn_fee_group_reward_routes
+                    [route_index % MAX_OPERATORS]
+            }),
         }
+    }
 
-        false
+    /// NCN fee group reward routes across the router and its pages
+    pub fn all_ncn_fee_group_reward_routes<'a>(
+        &'a self,
+        pages: &'a [&'a BaseRewardRouterPage],
+    ) -> impl Iterator<Item = &'a NcnRewardRoute> {
+        self.ncn_fee_group_reward_routes.iter().chain(
+            pages
+                .iter()
+                .flat_map(|page| page.ncn_fee_group_reward_routes.iter()),
+        )
     }
 
-    pub fn ncn_fee_group_reward_route(
+    pub fn has_operator_reward_route(
         &self,
+        pages: &[&BaseRewardRouterPage],
         operator: &Pubkey,
-    ) -> Result<&NcnRewardRoute, TipRouterError> {
-        for ncn_route_reward in self.ncn_fee_group_reward_routes.iter() {
-            if ncn_route_reward.operator.eq(operator) {
-                return Ok(ncn_route_reward);
-            }
-        }
+    ) -> bool {
+        self.all_ncn_fee_group_reward_routes(pages)
+            .any(|ncn_route_reward| ncn_route_reward.operator.eq(operator))
+    }
 
-        Err(TipRouterError::NcnRewardRouteNotFound)
+    pub fn ncn_fee_group_reward_route<'a>(
+        &'a self,
+        pages: &'a [&'a BaseRewardRouterPage],
+        operator: &Pubkey,
+    ) -> Result<&'a NcnRewardRoute, TipRouterError> {
+        self.all_ncn_fee_group_reward_routes(pages)
+            .find(|ncn_route_reward| ncn_route_reward.operator.eq(operator))
+            .ok_or(TipRouterError::NcnRewardRouteNotFound)
     }
 
     pub const fn ncn_fee_group_reward_routes(&self) -> &[NcnRewardRoute; MAX_OPERATORS] {
@@ -656,6 +788,7 @@ impl BaseRewardRouter {
 
     pub fn route_to_ncn_fee_group_reward_route(
         &mut self,
+        pages: &mut [&mut BaseRewardRouterPage],
         ncn_fee_group: NcnFeeGroup,
         operator: &Pubkey,
         rewards: u64,
@@ -664,39 +797,199 @@ impl BaseRewardRouter {
             return Ok(());
         }
 
-        for ncn_route_reward in self.ncn_fee_group_reward_routes.iter_mut() {
-            if ncn_route_reward.operator.eq(operator) {
-                ncn_route_reward.increment_rewards(ncn_fee_group, rewards)?;
-                return Ok(());
-            }
+        let capacity = Self::capacity(pages.len());
+        let route_index = (0..capacity)
+            .find(|route_index| {
+                self.ncn_fee_group_reward_route_mut(pages, *route_index)
+                    .is_some_and(|ncn_route_reward| ncn_route_reward.operator.eq(operator))
+            })
+            .or_else(|| {
+                (0..capacity).find(|route_index| {
+                    self.ncn_fee_group_reward_route_mut(pages, *route_index)
+                        .is_some_and(|ncn_route_reward| ncn_route_reward.is_empty())
+                })
+            })
+            .ok_or(TipRouterError::OperatorRewardListFull)?;
+
+        let ncn_route_reward = self
+            .ncn_fee_group_reward_route_mut(pages, route_index)
+            .ok_or(TipRouterError::OperatorRewardListFull)?;
+
+        if ncn_route_reward.is_empty() {
+            *ncn_route_reward = NcnRewardRoute::new(operator, ncn_fee_group, rewards)?;
+        } else {
+            ncn_route_reward.increment_rewards(ncn_fee_group, rewards)?;
         }
 
-        for ncn_route_reward in self.ncn_fee_group_reward_routes.iter_mut() {
-            if ncn_route_reward.operator.eq(&Pubkey::default()) {
-                *ncn_route_reward = NcnRewardRoute::new(operator, ncn_fee_group, rewards)?;
-                return Ok(());
-            }
-        }
-
-        Err(TipRouterError::OperatorRewardListFull)
+        Ok(())
     }
 
     pub fn distribute_ncn_fee_group_reward_route(
         &mut self,
+        pages: &mut [&mut BaseRewardRouterPage],
         ncn_fee_group: NcnFeeGroup,
         operator: &Pubkey,
     ) -> Result<u64, TipRouterError> {
-        for route in self.ncn_fee_group_reward_routes.iter_mut() {
-            if route.operator.eq(operator) {
-                let rewards = route.rewards(ncn_fee_group)?;
-                route.decrement_rewards(ncn_fee_group, rewards)?;
-                self.decrement_rewards_processed(rewards)?;
+        let route_index = (0..Self::capacity(pages.len()))
+            .find(|route_index| {
+                self.ncn_fee_group_reward_route_mut(pages, *route_index)
+                    .is_some_and(|route| route.operator.eq(operator))
+            })
+            .ok_or(TipRouterError::OperatorRewardNotFound)?;
+
+        let rewards = {
+            let route = self
+                .ncn_fee_group_reward_route_mut(pages, route_index)
+                .ok_or(TipRouterError::OperatorRewardNotFound)?;
+
+            let rewards = route.rewards(ncn_fee_group)?;
+            route.decrement_rewards(ncn_fee_group, rewards)?;
+            rewards
+        };
 
-                return Ok(rewards);
-            }
+        self.decrement_rewards_processed(rewards)?;
+
+        Ok(rewards)
+    }
+}
+
+// PDA'd ["base_reward_router_page", BASE_REWARD_ROUTER, PAGE]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct BaseRewardRouterPage {
+    /// NCN the account is associated with
+    ncn: Pubkey,
+    /// The epoch the account is associated with
+    epoch: PodU64,
+    /// The page number, starting at 1 as the router holds the first page
+    page: u8,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 128],
+    /// NCN Fee Group Reward Routes
+    ncn_fee_group_reward_routes: [NcnRewardRoute; 256],
+}
+
+impl Discriminator for BaseRewardRouterPage {
+    const DISCRIMINATOR: u8 = Discriminators::BaseRewardRouterPage as u8;
+}
+
+impl BaseRewardRouterPage {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn initialize(&mut self, ncn: &Pubkey, ncn_epoch: u64, page: u8, bump: u8) {
+        // Initializes field by field to avoid overflowing stack
+        self.ncn = *ncn;
+        self.epoch = PodU64::from(ncn_epoch);
+        self.page = page;
+        self.bump = bump;
+        self.reserved = [0; 128];
+        self.ncn_fee_group_reward_routes = [NcnRewardRoute::default(); MAX_OPERATORS];
+    }
+
+    pub fn seeds(base_reward_router: &Pubkey, page: u8) -> Vec<Vec<u8>> {
+        Vec::from_iter(
+            [
+                b"base_reward_router_page".to_vec(),
+                base_reward_router.to_bytes().to_vec(),
+                vec![page],
+            ]
+            .iter()
+            .cloned(),
+        )
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        base_reward_router: &Pubkey,
+        page: u8,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds: Vec<Vec<u8>> = Self::seeds(base_reward_router, page);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        base_reward_router: &Pubkey,
+        page: u8,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, base_reward_router, page).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads the first `page_count` accounts as the router's pages, in order
+    pub fn load_pages(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        base_reward_router: &Pubkey,
+        page_count: u8,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if accounts.len() < page_count as usize {
+            msg!("Missing base reward router pages");
+            return Err(ProgramError::NotEnoughAccountKeys);
         }
 
-        Err(TipRouterError::OperatorRewardNotFound)
+        for (page, account) in (1..=page_count).zip(accounts.iter()) {
+            Self::load(
+                program_id,
+                account,
+                base_reward_router,
+                page,
+                expect_writable,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub fn load_to_close(
+        program_id: &Pubkey,
+        account_to_close: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        let page = {
+            let account_data = account_to_close.try_borrow_data()?;
+            let account_struct = Self::try_from_slice_unchecked(&account_data)?;
+            account_struct.page()
+        };
+
+        let base_reward_router = BaseRewardRouter::find_program_address(program_id, ncn, epoch).0;
+        Self::load(
+            program_id,
+            account_to_close,
+            &base_reward_router,
+            page,
+            true,
+        )
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub const fn page(&self) -> u8 {
+        self.page
+    }
+
+    pub const fn ncn_fee_group_reward_routes(&self) -> &[NcnRewardRoute; MAX_OPERATORS] {
+        &self.ncn_fee_group_reward_routes
     }
 }
 
@@ -712,6 +1005,7 @@ impl fmt::Display for BaseRewardRouter {
         writeln!(f, "  Total Rewards:                {}", self.total_rewards())?;
         writeln!(f, "  Reward Pool:                  {}", self.reward_pool())?;
         writeln!(f, "  Rewards Processed:            {}", self.rewards_processed())?;
+        writeln!(f, "  Account Payer Top Up:         {}", self.account_payer_top_up())?;
 
         if self.still_routing() {
             writeln!(f, "\nRouting State:")?;
@@ -994,7 +1288,10 @@ mod tests {
     use solana_program::pubkey::Pubkey;
 
     use super::*;
-    use crate::{ballot_box::Ballot, stake_weight::StakeWeights, utils::assert_tip_router_error};
+    use crate::{
+        ballot_box::Ballot, constants::DEFAULT_CONSENSUS_THRESHOLD_BPS, stake_weight::StakeWeights,
+        utils::assert_tip_router_error,
+    };
 
     const TEST_EPOCH: u64 = 1;
     const TEST_CURRENT_SLOT: u64 = 100;
@@ -1023,6 +1320,7 @@ mod tests {
 
         ballot_box
             .cast_vote(
+                &mut [],
                 &operator,
                 &ballot,
                 &stake_weights,
@@ -1061,7 +1359,9 @@ mod tests {
             + size_of::<PodU64>() // total_rewards
             + size_of::<PodU64>() // reward_pool
             + size_of::<PodU64>() // rewards_processed
-            + 128 // reserved
+            + 1 // page_count
+            + size_of::<PodU64>() // account_payer_top_up
+            + 119 // reserved
             + 1 // last_ncn_group_index
             + size_of::<PodU16>() // last_vote_index
             + size_of::<PodU64>() // last_rewards_to_process
@@ -1120,13 +1420,61 @@ mod tests {
 
         // Try to distribute rewards for a non-existent operator
         let non_existent_operator = Pubkey::new_unique();
-        let result = router
-            .distribute_ncn_fee_group_reward_route(NcnFeeGroup::default(), &non_existent_operator);
+        let result = router.distribute_ncn_fee_group_reward_route(
+            &mut [],
+            NcnFeeGroup::default(),
+            &non_existent_operator,
+        );
 
         // Verify we get the expected error
         assert_eq!(result.unwrap_err(), TipRouterError::OperatorRewardNotFound);
     }
 
+    #[test]
+    fn test_route_to_ncn_fee_group_reward_route_into_page() {
+        let ncn = Pubkey::new_unique();
+        let mut router = BaseRewardRouter::new(&ncn, TEST_EPOCH, 1, TEST_CURRENT_SLOT);
+        let mut page = BaseRewardRouterPage::zeroed();
+        page.initialize(&ncn, TEST_EPOCH, 1, 0);
+        let group = NcnFeeGroup::default();
+
+        assert_eq!(
+            BaseRewardRouter::pages_required(MAX_OPERATORS as u64 + 1),
+            Ok(1)
+        );
+
+        for _ in 0..MAX_OPERATORS {
+            router
+                .route_to_ncn_fee_group_reward_route(&mut [], group, &Pubkey::new_unique(), 1)
+                .unwrap();
+        }
+
+        // The router is full, the next operator needs the page
+        let operator = Pubkey::new_unique();
+        let result = router.route_to_ncn_fee_group_reward_route(&mut [], group, &operator, 1);
+        assert_eq!(result.unwrap_err(), TipRouterError::OperatorRewardListFull);
+
+        router
+            .route_to_ncn_fee_group_reward_route(&mut [&mut page], group, &operator, 1)
+            .unwrap();
+        router
+            .route_to_ncn_fee_group_reward_route(&mut [&mut page], group, &operator, 2)
+            .unwrap();
+
+        assert_eq!(page.ncn_fee_group_reward_routes()[0].operator(), &operator);
+        assert!(page.ncn_fee_group_reward_routes()[1].is_empty());
+        assert!(router.has_operator_reward_route(&[&page], &operator));
+        assert!(!router.has_operator_reward_route(&[], &operator));
+        assert_eq!(
+            router
+                .ncn_fee_group_reward_route(&[&page], &operator)
+                .unwrap()
+                .rewards(group)
+                .unwrap(),
+            3
+        );
+    }
+
     #[test]
     fn test_route_to_reward_pool_zero() {
         // Create a new router
@@ -1228,6 +1576,50 @@ mod tests {
         assert_eq!(router.rewards_processed(), 0);
     }
 
+    #[test]
+    fn test_distribution_complete() {
+        let mut router = BaseRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+
+        // Nothing has been routed yet
+        assert!(!router.distribution_complete());
+
+        router.route_incoming_rewards(0, 1000).unwrap();
+        assert!(!router.distribution_complete());
+
+        router.route_from_reward_pool(1000).unwrap();
+        assert!(!router.distribution_complete());
+
+        router.decrement_rewards_processed(1000).unwrap();
+        assert!(router.distribution_complete());
+    }
+
+    #[test]
+    fn test_untracked_rewards() {
+        const RENT_COST: u64 = 100;
+
+        let mut router = BaseRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+
+        // Everything above rent is untracked before routing
+        assert_eq!(router.untracked_rewards(RENT_COST, 1100), Ok(1000));
+
+        router.route_incoming_rewards(RENT_COST, 1100).unwrap();
+        assert_eq!(router.untracked_rewards(RENT_COST, 1100), Ok(0));
+        assert_eq!(router.untracked_rewards(RENT_COST, 1105), Ok(5));
+
+        // A balance below what is tracked has nothing untracked
+        assert_eq!(router.untracked_rewards(RENT_COST, 50), Ok(0));
+    }
+
     #[test]
     fn test_route_reward_pool() {
         const INCOMING_REWARDS: u64 = 1000;
@@ -1260,6 +1652,52 @@ mod tests {
         assert_eq!(router.ncn_fee_group_rewards(ncn_group).unwrap(), 100);
     }
 
+    #[test]
+    fn test_route_to_account_payer_top_up() {
+        const INCOMING_REWARDS: u64 = 1000;
+
+        let mut router = BaseRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+
+        let fees = Fees::new(900, 100, 1).unwrap();
+
+        router.route_incoming_rewards(0, INCOMING_REWARDS).unwrap();
+        router.route_reward_pool(&fees).unwrap();
+
+        // Nothing to top up
+        assert_eq!(router.route_to_account_payer_top_up(0).unwrap(), 0);
+
+        let top_up = router.route_to_account_payer_top_up(400).unwrap();
+        assert_eq!(top_up, 400);
+        assert_eq!(router.account_payer_top_up(), 400);
+        assert_eq!(
+            router.base_fee_group_reward(BaseFeeGroup::dao()).unwrap(),
+            500
+        );
+        assert_eq!(router.rewards_processed(), INCOMING_REWARDS - 400);
+        assert_eq!(router.total_rewards(), INCOMING_REWARDS);
+
+        // Capped by the DAO rewards left, the NCN fee groups are untouched
+        let top_up = router.route_to_account_payer_top_up(10_000).unwrap();
+        assert_eq!(top_up, 500);
+        assert_eq!(router.account_payer_top_up(), 900);
+        assert_eq!(
+            router.base_fee_group_reward(BaseFeeGroup::dao()).unwrap(),
+            0
+        );
+        assert_eq!(
+            router
+                .ncn_fee_group_rewards(NcnFeeGroup::default())
+                .unwrap(),
+            100
+        );
+        assert_eq!(router.route_to_account_payer_top_up(1).unwrap(), 0);
+    }
+
     #[test]
     fn test_route_reward_pool_multiple_groups() {
         const INCOMING_REWARDS: u64 = 1600;
@@ -1392,7 +1830,13 @@ mod tests {
             (ballot_box, get_test_operators(&ballot_box))
         };
 
-        let result = router.route_ncn_fee_group_rewards(&ballot_box, 100);
+        let result = router.route_ncn_fee_group_rewards(
+            &ballot_box,
+            &[],
+            &LateVoteDecay::default(),
+            &mut [],
+            100,
+        );
 
         assert_tip_router_error(result, TipRouterError::ConsensusNotReached);
     }
@@ -1448,18 +1892,23 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    &[],
+                    total_stake_weights.stake_weight(),
+                    TEST_CURRENT_SLOT,
+                    DEFAULT_CONSENSUS_THRESHOLD_BPS,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
         };
 
         router
-            .route_ncn_fee_group_rewards(&ballot_box, 100)
+            .route_ncn_fee_group_rewards(&ballot_box, &[], &LateVoteDecay::default(), &mut [], 100)
             .unwrap();
 
         for operator in operators.iter() {
-            let route = router.ncn_fee_group_reward_route(operator).unwrap();
+            let route = router.ncn_fee_group_reward_route(&[], operator).unwrap();
 
             let mut rewards = 0;
             for group in NcnFeeGroup::all_groups().iter() {
@@ -1525,14 +1974,19 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    &[],
+                    total_stake_weights.stake_weight(),
+                    TEST_CURRENT_SLOT,
+                    DEFAULT_CONSENSUS_THRESHOLD_BPS,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
         };
 
         router
-            .route_ncn_fee_group_rewards(&ballot_box, 100)
+            .route_ncn_fee_group_rewards(&ballot_box, &[], &LateVoteDecay::default(), &mut [], 100)
             .unwrap();
 
         // Operator 1, did not vote with consensus, so it should not have a route
@@ -1545,6 +1999,91 @@ mod tests {
         assert_eq!(route_count, 7);
     }
 
+    #[test]
+    fn test_route_to_operators_with_late_vote_decay() {
+        const INCOMING_REWARDS: u64 = 1600;
+
+        let mut router = BaseRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+
+        // Fees
+        let mut fees = Fees::new(100, 100, 1).unwrap();
+
+        for group in BaseFeeGroup::all_groups().iter() {
+            fees.set_base_fee_bps(*group, 100).unwrap();
+        }
+
+        for group in NcnFeeGroup::all_groups().iter() {
+            fees.set_ncn_fee_bps(*group, 100).unwrap();
+        }
+
+        router.route_incoming_rewards(0, INCOMING_REWARDS).unwrap();
+        router.route_reward_pool(&fees).unwrap();
+
+        let group = NcnFeeGroup::default();
+        let late_operator = Pubkey::new_unique();
+        let late_slot = TEST_CURRENT_SLOT + 600;
+
+        let ballot_box = {
+            let mut ballot_box = get_test_ballot_box();
+
+            // Two timely votes reach consensus with 400 of 500 stake
+            cast_test_vote(&mut ballot_box, group, 200, 1, 1);
+            cast_test_vote(&mut ballot_box, group, 200, 1, 1);
+
+            ballot_box
+                .tally_votes(&[], 500, TEST_CURRENT_SLOT, DEFAULT_CONSENSUS_THRESHOLD_BPS)
+                .unwrap();
+
+            // The last operator votes 600 slots after consensus
+            ballot_box
+                .cast_vote(
+                    &mut [],
+                    &late_operator,
+                    &Ballot::new(&[1; 32]),
+                    &StakeWeights::snapshot(group, 100, 1).unwrap(),
+                    late_slot,
+                    TEST_VALID_SLOTS_AFTER_CONSENSUS,
+                )
+                .unwrap();
+
+            ballot_box
+        };
+        let timely_operators: Vec<Pubkey> = get_test_operators(&ballot_box)
+            .into_iter()
+            .filter(|operator| operator.ne(&late_operator))
+            .collect();
+
+        // Full rewards for 100 slots, then 25% decayed halfway to the 50% cap
+        let late_vote_decay = LateVoteDecay::new(100, 1_100, 5_000);
+
+        router
+            .route_ncn_fee_group_rewards(&ballot_box, &[], &late_vote_decay, &mut [], 100)
+            .unwrap();
+
+        for operator in timely_operators.iter() {
+            let route = router.ncn_fee_group_reward_route(&[], operator).unwrap();
+            assert_eq!(route.rewards(group).unwrap(), 40);
+        }
+
+        // 20 lamports by stake weight, 5 forfeited
+        let route = router
+            .ncn_fee_group_reward_route(&[], &late_operator)
+            .unwrap();
+        assert_eq!(route.rewards(group).unwrap(), 15);
+
+        // DAO fee, the unvoted NCN fee groups and the forfeited rewards
+        assert_eq!(
+            router.base_fee_group_reward(BaseFeeGroup::dao()).unwrap(),
+            100 + 700 + 5
+        );
+        assert_eq!(router.ncn_fee_group_rewards(group).unwrap(), 0);
+    }
+
     #[test]
     fn test_route_to_max_operators() {
         const INCOMING_REWARDS: u64 = 256_000;
@@ -1591,20 +2130,25 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    &[],
+                    total_stake_weights.stake_weight(),
+                    TEST_CURRENT_SLOT,
+                    DEFAULT_CONSENSUS_THRESHOLD_BPS,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
         };
 
         router
-            .route_ncn_fee_group_rewards(&ballot_box, 1000)
+            .route_ncn_fee_group_rewards(&ballot_box, &[], &LateVoteDecay::default(), &mut [], 1000)
             .unwrap();
 
         assert!(!router.still_routing());
 
         for operator in operators.iter() {
-            let route = router.ncn_fee_group_reward_route(operator).unwrap();
+            let route = router.ncn_fee_group_reward_route(&[], operator).unwrap();
 
             let mut rewards = 0;
             for group in NcnFeeGroup::all_groups().iter() {
@@ -1671,7 +2215,12 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    &[],
+                    total_stake_weights.stake_weight(),
+                    TEST_CURRENT_SLOT,
+                    DEFAULT_CONSENSUS_THRESHOLD_BPS,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
@@ -1679,18 +2228,26 @@ mod tests {
 
         assert_eq!(operators.len(), 256);
 
-        router.route_ncn_fee_group_rewards(&ballot_box, 5).unwrap();
+        router
+            .route_ncn_fee_group_rewards(&ballot_box, &[], &LateVoteDecay::default(), &mut [], 5)
+            .unwrap();
 
         assert!(router.still_routing());
 
         router
-            .route_ncn_fee_group_rewards(&ballot_box, 256 * 8)
+            .route_ncn_fee_group_rewards(
+                &ballot_box,
+                &[],
+                &LateVoteDecay::default(),
+                &mut [],
+                256 * 8,
+            )
             .unwrap();
 
         assert!(!router.still_routing());
 
         for operator in operators.iter() {
-            let route = router.ncn_fee_group_reward_route(operator).unwrap();
+            let route = router.ncn_fee_group_reward_route(&[], operator).unwrap();
 
             let mut rewards = 0;
             for group in NcnFeeGroup::all_groups().iter() {
@@ -1757,7 +2314,12 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    &[],
+                    total_stake_weights.stake_weight(),
+                    TEST_CURRENT_SLOT,
+                    DEFAULT_CONSENSUS_THRESHOLD_BPS,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
@@ -1765,18 +2327,28 @@ mod tests {
 
         assert_eq!(operators.len(), 256);
 
-        router.route_ncn_fee_group_rewards(&ballot_box, 0).unwrap();
+        router
+            .route_ncn_fee_group_rewards(&ballot_box, &[], &LateVoteDecay::default(), &mut [], 0)
+            .unwrap();
 
         assert!(router.still_routing());
 
         for _ in 0..256 * 8 {
-            router.route_ncn_fee_group_rewards(&ballot_box, 0).unwrap();
+            router
+                .route_ncn_fee_group_rewards(
+                    &ballot_box,
+                    &[],
+                    &LateVoteDecay::default(),
+                    &mut [],
+                    0,
+                )
+                .unwrap();
         }
 
         assert!(!router.still_routing());
 
         for operator in operators.iter() {
-            let route = router.ncn_fee_group_reward_route(operator).unwrap();
+            let route = router.ncn_fee_group_reward_route(&[], operator).unwrap();
 
             let mut rewards = 0;
             for group in NcnFeeGroup::all_groups().iter() {
@@ -3,19 +3,32 @@ use std::mem::size_of;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
-    base_fee_group::BaseFeeGroup, discriminators::Discriminators, fees::FeeConfig,
-    loaders::check_load, ncn_fee_group::NcnFeeGroup,
+    base_fee_group::BaseFeeGroup,
+    constants::{
+        DEFAULT_CONSENSUS_THRESHOLD_BPS, PAUSE_ALL, PAUSE_ROUTING, PAUSE_SET_MERKLE_ROOT,
+        PAUSE_VOTING, PENDING_ADMIN_EXPIRY_EPOCHS,
+    },
+    discriminators::Discriminators,
+    error::TipRouterError,
+    fees::FeeConfig,
+    late_vote_decay::LateVoteDecay,
+    loaders::check_load,
+    ncn_fee_group::NcnFeeGroup,
 };
 
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub enum ConfigAdminRole {
     FeeAdmin,
     TieBreakerAdmin,
+    PauseAdmin,
 }
 
 #[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
@@ -39,8 +52,32 @@ pub struct Config {
     pub epochs_after_consensus_before_close: PodU64,
     /// Only epochs after this epoch are valid for voting
     pub starting_valid_epoch: PodU64,
+    /// Share of the total stake weight, in bps, a ballot needs to reach consensus
+    pub consensus_threshold_bps: PodU16,
+    /// Number of slots after voting opens where votes are committed, 0 disables commit-reveal
+    pub commit_window_slots: PodU64,
+    /// The admin proposed for `pending_admin_role`, must sign to accept
+    pub pending_admin: Pubkey,
+    /// The `ConfigAdminRole` the pending admin is proposed for
+    pub pending_admin_role: u8,
+    /// Last epoch the pending admin can accept the proposal
+    pub pending_admin_expiry_epoch: PodU64,
+    /// The admin who can pause and unpause the program
+    pub pause_admin: Pubkey,
+    /// Bitmask of paused instruction groups, see `PAUSE_*` constants
+    pub paused: u8,
+    /// Number of slots after consensus where late votes keep their full NCN fee group rewards
+    pub late_vote_grace_slots: PodU64,
+    /// Share of NCN fee group rewards, in bps, forfeited to the DAO by a vote at the end of the
+    /// valid slots after consensus, 0 disables late vote decay
+    pub late_vote_max_decay_bps: PodU16,
+    /// Number of epochs after `epochs_before_stall` before a stalled ballot box can be finalized
+    /// with its plurality ballot, 0 disables the plurality fallback
+    pub plurality_fallback_epochs: PodU64,
+    /// Share of the total stake weight, in bps, the plurality ballot needs to be finalized
+    pub plurality_min_stake_weight_bps: PodU16,
     /// Reserved space
-    reserved: [u8; 111],
+    reserved: [u8; 7],
 }
 
 impl Discriminator for Config {
@@ -72,7 +109,18 @@ impl Config {
             epochs_after_consensus_before_close: PodU64::from(epochs_after_consensus_before_close),
             fee_config: *fee_config,
             bump,
-            reserved: [0; 111],
+            consensus_threshold_bps: PodU16::from(DEFAULT_CONSENSUS_THRESHOLD_BPS as u16),
+            commit_window_slots: PodU64::from(0),
+            pending_admin: Pubkey::default(),
+            pending_admin_role: 0,
+            pending_admin_expiry_epoch: PodU64::from(0),
+            pause_admin: *fee_admin,
+            paused: 0,
+            late_vote_grace_slots: PodU64::from(0),
+            late_vote_max_decay_bps: PodU16::from(0),
+            plurality_fallback_epochs: PodU64::from(0),
+            plurality_min_stake_weight_bps: PodU16::from(0),
+            reserved: [0; 7],
         }
     }
 
@@ -130,6 +178,174 @@ impl Config {
     pub fn epochs_after_consensus_before_close(&self) -> u64 {
         self.epochs_after_consensus_before_close.into()
     }
+
+    /// Configs created before the threshold was configurable read as
+    /// `DEFAULT_CONSENSUS_THRESHOLD_BPS`, which keeps the exact 2/3 threshold
+    pub fn consensus_threshold_bps(&self) -> u64 {
+        u16::from(self.consensus_threshold_bps) as u64
+    }
+
+    pub fn commit_window_slots(&self) -> u64 {
+        self.commit_window_slots.into()
+    }
+
+    pub fn is_commit_reveal_enabled(&self) -> bool {
+        self.commit_window_slots() > 0
+    }
+
+    pub fn late_vote_grace_slots(&self) -> u64 {
+        self.late_vote_grace_slots.into()
+    }
+
+    pub fn late_vote_max_decay_bps(&self) -> u64 {
+        u16::from(self.late_vote_max_decay_bps) as u64
+    }
+
+    /// The decay curve applied to votes cast after consensus, spanning the valid slots after consensus
+    pub fn late_vote_decay(&self) -> LateVoteDecay {
+        LateVoteDecay::new(
+            self.late_vote_grace_slots(),
+            self.valid_slots_after_consensus(),
+            self.late_vote_max_decay_bps(),
+        )
+    }
+
+    pub fn plurality_fallback_epochs(&self) -> u64 {
+        self.plurality_fallback_epochs.into()
+    }
+
+    pub fn plurality_min_stake_weight_bps(&self) -> u64 {
+        u16::from(self.plurality_min_stake_weight_bps) as u64
+    }
+
+    pub fn is_plurality_fallback_enabled(&self) -> bool {
+        self.plurality_fallback_epochs() > 0
+    }
+
+    /// Number of epochs after the ballot box's epoch before its plurality ballot can be finalized
+    pub fn epochs_before_plurality_fallback(&self) -> Result<u64, TipRouterError> {
+        self.epochs_before_stall()
+            .checked_add(self.plurality_fallback_epochs())
+            .ok_or(TipRouterError::ArithmeticOverflow)
+    }
+
+    pub fn admin(&self, role: ConfigAdminRole) -> Pubkey {
+        match role {
+            ConfigAdminRole::FeeAdmin => self.fee_admin,
+            ConfigAdminRole::TieBreakerAdmin => self.tie_breaker_admin,
+            ConfigAdminRole::PauseAdmin => self.pause_admin(),
+        }
+    }
+
+    fn set_admin(&mut self, role: ConfigAdminRole, new_admin: &Pubkey) {
+        match role {
+            ConfigAdminRole::FeeAdmin => self.fee_admin = *new_admin,
+            ConfigAdminRole::TieBreakerAdmin => self.tie_breaker_admin = *new_admin,
+            ConfigAdminRole::PauseAdmin => self.pause_admin = *new_admin,
+        }
+    }
+
+    /// The pause admin, or the fee admin for configs created before the pause admin was added
+    pub fn pause_admin(&self) -> Pubkey {
+        if self.pause_admin == Pubkey::default() {
+            self.fee_admin
+        } else {
+            self.pause_admin
+        }
+    }
+
+    pub fn set_paused(&mut self, paused: u8) -> Result<(), TipRouterError> {
+        if paused & !PAUSE_ALL != 0 {
+            return Err(TipRouterError::InvalidPauseFlags);
+        }
+
+        self.paused = paused;
+        Ok(())
+    }
+
+    pub const fn is_paused(&self, flag: u8) -> bool {
+        self.paused & flag != 0
+    }
+
+    /// Errors with the flag's dedicated error if the instruction group is paused
+    pub const fn check_not_paused(&self, flag: u8) -> Result<(), TipRouterError> {
+        if !self.is_paused(flag) {
+            return Ok(());
+        }
+
+        Err(match flag {
+            PAUSE_VOTING => TipRouterError::VotingPaused,
+            PAUSE_SET_MERKLE_ROOT => TipRouterError::SetMerkleRootPaused,
+            PAUSE_ROUTING => TipRouterError::RoutingPaused,
+            _ => TipRouterError::DistributionPaused,
+        })
+    }
+
+    pub fn pending_admin_expiry_epoch(&self) -> u64 {
+        self.pending_admin_expiry_epoch.into()
+    }
+
+    pub fn has_pending_admin(&self) -> bool {
+        self.pending_admin != Pubkey::default()
+    }
+
+    /// Proposes a new admin for a role. Roles share the pending slot, so an existing
+    /// proposal has to be accepted or cancelled first
+    pub fn propose_new_admin(
+        &mut self,
+        role: ConfigAdminRole,
+        new_admin: &Pubkey,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        if self.has_pending_admin() {
+            return Err(TipRouterError::PendingAdminExists);
+        }
+
+        let expiry_epoch = current_epoch
+            .checked_add(PENDING_ADMIN_EXPIRY_EPOCHS)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        self.pending_admin = *new_admin;
+        self.pending_admin_role = role as u8;
+        self.pending_admin_expiry_epoch = PodU64::from(expiry_epoch);
+
+        Ok(())
+    }
+
+    pub fn cancel_new_admin(&mut self) -> Result<(), TipRouterError> {
+        if !self.has_pending_admin() {
+            return Err(TipRouterError::NoPendingAdmin);
+        }
+
+        self.pending_admin = Pubkey::default();
+        self.pending_admin_role = 0;
+        self.pending_admin_expiry_epoch = PodU64::from(0);
+
+        Ok(())
+    }
+
+    /// Hands the role over to the pending admin, who must match the proposal
+    pub fn accept_new_admin(
+        &mut self,
+        role: ConfigAdminRole,
+        new_admin: &Pubkey,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        if !self.has_pending_admin() {
+            return Err(TipRouterError::NoPendingAdmin);
+        }
+
+        if self.pending_admin != *new_admin || self.pending_admin_role != role as u8 {
+            return Err(TipRouterError::PendingAdminMismatch);
+        }
+
+        if current_epoch > self.pending_admin_expiry_epoch() {
+            return Err(TipRouterError::PendingAdminExpired);
+        }
+
+        self.set_admin(role, new_admin);
+        self.cancel_new_admin()
+    }
 }
 
 #[rustfmt::skip]
@@ -143,22 +359,35 @@ impl fmt::Display for Config {
         writeln!(f, "  Epochs Before Stall:          {}", self.epochs_before_stall())?;
         writeln!(f, "  Starting Valid Epochs:        {}", self.starting_valid_epoch())?;
         writeln!(f, "  Close Epoch:                  {}", self.epochs_after_consensus_before_close())?;
+        writeln!(f, "  Consensus Threshold (bps):    {}", self.consensus_threshold_bps())?;
+        writeln!(f, "  Commit Window Slots:          {}", self.commit_window_slots())?;
+        writeln!(f, "  Late Vote Grace Slots:        {}", self.late_vote_grace_slots())?;
+        writeln!(f, "  Late Vote Max Decay (bps):    {}", self.late_vote_max_decay_bps())?;
+        writeln!(f, "  Plurality Fallback Epochs:    {}", self.plurality_fallback_epochs())?;
+        writeln!(f, "  Plurality Min Stake (bps):    {}", self.plurality_min_stake_weight_bps())?;
+        if self.has_pending_admin() {
+            writeln!(f, "  Pending Admin:                {}", self.pending_admin)?;
+            writeln!(f, "  Pending Admin Role:           {}", self.pending_admin_role)?;
+            writeln!(f, "  Pending Admin Expiry Epoch:   {}", self.pending_admin_expiry_epoch())?;
+        }
+        writeln!(f, "  Pause Admin:                  {}", self.pause_admin())?;
+        writeln!(f, "  Paused:                       {:#06b}", self.paused)?;
         writeln!(f, "  Fees:")?;
         writeln!(f, "    Block Engine Fee:           {}", self.fee_config.block_engine_fee_bps())?;
         for group in BaseFeeGroup::all_groups() {
             writeln!(f, "    Base Fee Wallet [{:?}]:        {:?}", group.group, self.fee_config.base_fee_wallet(group).unwrap())?;
         }
         for group in BaseFeeGroup::all_groups() {
-            writeln!(f, "    Late Base Fee [{:?}]:          {}", group.group, self.fee_config.base_fee_bps(group, u64::MAX).unwrap())?;
+            writeln!(f, "    Late Base Fee [{:?}]:          {}", group.group, self.fee_config.base_fee_bps(group, None, u64::MAX).unwrap())?;
         }
         for group in NcnFeeGroup::all_groups() {
-            writeln!(f, "    Late NCN Fee [{:?}]:           {}", group.group, self.fee_config.ncn_fee_bps(group, u64::MAX).unwrap())?;
+            writeln!(f, "    Late NCN Fee [{:?}]:           {}", group.group, self.fee_config.ncn_fee_bps(group, None, u64::MAX).unwrap())?;
         }
         for group in BaseFeeGroup::all_groups() {
-            writeln!(f, "    Current Base Fee [{:?}]:       {}", group.group, self.fee_config.base_fee_bps(group, 0).unwrap())?;
+            writeln!(f, "    Current Base Fee [{:?}]:       {}", group.group, self.fee_config.base_fee_bps(group, None, 0).unwrap())?;
         }
         for group in NcnFeeGroup::all_groups() {
-            writeln!(f, "    Current NCN Fee [{:?}]:        {}", group.group, self.fee_config.ncn_fee_bps(group, 0).unwrap())?;
+            writeln!(f, "    Current NCN Fee [{:?}]:        {}", group.group, self.fee_config.ncn_fee_bps(group, None, 0).unwrap())?;
         }
 
         Ok(())
@@ -168,6 +397,7 @@ impl fmt::Display for Config {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::PAUSE_DISTRIBUTION;
 
     #[test]
     fn test_len() {
@@ -182,9 +412,199 @@ mod tests {
             + 1 // bump
             + size_of::<PodU64>() //TODO move up before deploy epochs_after_consensus_before_close
             + size_of::<PodU64>() //TODO starting_valid_epoch
-            + 111; // reserved
+            + size_of::<PodU16>() // consensus_threshold_bps
+            + size_of::<PodU64>() // commit_window_slots
+            + size_of::<Pubkey>() // pending_admin
+            + 1 // pending_admin_role
+            + size_of::<PodU64>() // pending_admin_expiry_epoch
+            + size_of::<Pubkey>() // pause_admin
+            + 1 // paused
+            + size_of::<PodU64>() // late_vote_grace_slots
+            + size_of::<PodU16>() // late_vote_max_decay_bps
+            + size_of::<PodU64>() // plurality_fallback_epochs
+            + size_of::<PodU16>() // plurality_min_stake_weight_bps
+            + 7; // reserved
 
         assert_eq!(size_of::<Config>(), expected_total);
         assert_eq!(size_of::<Config>() + 8, Config::SIZE);
+
+        // Deployed configs are never reallocated, so the size must not change
+        assert_eq!(Config::SIZE, 970);
+    }
+
+    #[test]
+    fn test_consensus_threshold_bps() {
+        let mut config = Config::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &FeeConfig::zeroed(),
+            0,
+            1000,
+            1,
+            10,
+            255,
+        );
+        assert_eq!(
+            config.consensus_threshold_bps(),
+            DEFAULT_CONSENSUS_THRESHOLD_BPS
+        );
+
+        config.consensus_threshold_bps = PodU16::from(5_100);
+        assert_eq!(config.consensus_threshold_bps(), 5_100);
+
+        // Accounts created before the field existed read as the default
+        config.consensus_threshold_bps = PodU16::from(0);
+        assert_eq!(
+            config.consensus_threshold_bps(),
+            DEFAULT_CONSENSUS_THRESHOLD_BPS
+        );
+    }
+
+    #[test]
+    fn test_propose_and_accept_new_admin() {
+        let mut config = Config::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &FeeConfig::zeroed(),
+            0,
+            1000,
+            1,
+            10,
+            255,
+        );
+        let new_admin = Pubkey::new_unique();
+
+        assert_eq!(
+            config.accept_new_admin(ConfigAdminRole::FeeAdmin, &new_admin, 0),
+            Err(TipRouterError::NoPendingAdmin)
+        );
+
+        config
+            .propose_new_admin(ConfigAdminRole::FeeAdmin, &new_admin, 5)
+            .unwrap();
+        assert!(config.has_pending_admin());
+        assert_eq!(
+            config.pending_admin_expiry_epoch(),
+            5 + PENDING_ADMIN_EXPIRY_EPOCHS
+        );
+
+        // Wrong role or wrong signer
+        assert_eq!(
+            config.accept_new_admin(ConfigAdminRole::TieBreakerAdmin, &new_admin, 5),
+            Err(TipRouterError::PendingAdminMismatch)
+        );
+        assert_eq!(
+            config.accept_new_admin(ConfigAdminRole::FeeAdmin, &Pubkey::new_unique(), 5),
+            Err(TipRouterError::PendingAdminMismatch)
+        );
+
+        // Expired
+        assert_eq!(
+            config.accept_new_admin(
+                ConfigAdminRole::FeeAdmin,
+                &new_admin,
+                6 + PENDING_ADMIN_EXPIRY_EPOCHS
+            ),
+            Err(TipRouterError::PendingAdminExpired)
+        );
+
+        config
+            .accept_new_admin(
+                ConfigAdminRole::FeeAdmin,
+                &new_admin,
+                5 + PENDING_ADMIN_EXPIRY_EPOCHS,
+            )
+            .unwrap();
+        assert_eq!(config.fee_admin, new_admin);
+        assert!(!config.has_pending_admin());
+    }
+
+    #[test]
+    fn test_cancel_new_admin() {
+        let mut config = Config::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &FeeConfig::zeroed(),
+            0,
+            1000,
+            1,
+            10,
+            255,
+        );
+        let tie_breaker_admin = config.tie_breaker_admin;
+        let new_admin = Pubkey::new_unique();
+
+        assert_eq!(
+            config.cancel_new_admin(),
+            Err(TipRouterError::NoPendingAdmin)
+        );
+
+        config
+            .propose_new_admin(ConfigAdminRole::TieBreakerAdmin, &new_admin, 0)
+            .unwrap();
+
+        // A proposal for another role can't replace the pending one
+        assert_eq!(
+            config.propose_new_admin(ConfigAdminRole::FeeAdmin, &Pubkey::new_unique(), 0),
+            Err(TipRouterError::PendingAdminExists)
+        );
+
+        config.cancel_new_admin().unwrap();
+
+        assert_eq!(
+            config.accept_new_admin(ConfigAdminRole::TieBreakerAdmin, &new_admin, 0),
+            Err(TipRouterError::NoPendingAdmin)
+        );
+        assert_eq!(config.tie_breaker_admin, tie_breaker_admin);
+    }
+
+    #[test]
+    fn test_pause_flags() {
+        let mut config = Config::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &FeeConfig::zeroed(),
+            0,
+            1000,
+            1,
+            10,
+            255,
+        );
+        assert_eq!(config.pause_admin, config.fee_admin);
+
+        // Configs created before the pause admin was added fall back to the fee admin
+        config.pause_admin = Pubkey::default();
+        assert_eq!(config.pause_admin(), config.fee_admin);
+        assert_eq!(config.admin(ConfigAdminRole::PauseAdmin), config.fee_admin);
+        assert_eq!(config.check_not_paused(PAUSE_ALL), Ok(()));
+
+        config.set_paused(PAUSE_VOTING | PAUSE_ROUTING).unwrap();
+        assert_eq!(
+            config.check_not_paused(PAUSE_VOTING),
+            Err(TipRouterError::VotingPaused)
+        );
+        assert_eq!(
+            config.check_not_paused(PAUSE_ROUTING),
+            Err(TipRouterError::RoutingPaused)
+        );
+        assert_eq!(config.check_not_paused(PAUSE_SET_MERKLE_ROOT), Ok(()));
+        assert_eq!(config.check_not_paused(PAUSE_DISTRIBUTION), Ok(()));
+
+        config.set_paused(PAUSE_DISTRIBUTION).unwrap();
+        assert_eq!(config.check_not_paused(PAUSE_VOTING), Ok(()));
+        assert_eq!(
+            config.check_not_paused(PAUSE_DISTRIBUTION),
+            Err(TipRouterError::DistributionPaused)
+        );
+
+        assert_eq!(
+            config.set_paused(1 << 7),
+            Err(TipRouterError::InvalidPauseFlags)
+        );
+        assert_eq!(config.paused, PAUSE_DISTRIBUTION);
     }
 }
@@ -0,0 +1,218 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{
+    types::{PodU128, PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{discriminators::Discriminators, loaders::check_load};
+
+/// NCN settings added after `Config` was deployed. They live in their own account so existing
+/// configs keep loading, and every setting reads as disabled until the account is initialized.
+// PDA'd ["config_extension", NCN]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct ConfigExtension {
+    /// The NCN the extension is associated with
+    ncn: Pubkey,
+    /// The bump seed for the PDA
+    bump: u8,
+    /// Lamports paid from the account payer to the signer of each useful crank step, 0 disables
+    /// crank bounties
+    pub crank_bounty_lamports: PodU64,
+    /// Number of crank bounties that can be paid in an epoch
+    pub max_crank_bounties_per_epoch: PodU64,
+    /// Balance routing refills the account payer to from the DAO base fee group, 0 disables
+    /// top-ups
+    pub account_payer_target_balance: PodU64,
+    /// Stake weight an operator needs to be counted in the epoch snapshot, 0 disables the minimum
+    pub min_operator_stake_weight: PodU128,
+    /// Highest fee an operator can charge and still be active in the epoch snapshot, 0 disables
+    /// the maximum
+    pub max_operator_fee_bps: PodU16,
+    /// Reserved space
+    reserved: [u8; 101],
+}
+
+impl Discriminator for ConfigExtension {
+    const DISCRIMINATOR: u8 = Discriminators::ConfigExtension as u8;
+}
+
+impl ConfigExtension {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            bump,
+            crank_bounty_lamports: PodU64::from(0),
+            max_crank_bounties_per_epoch: PodU64::from(0),
+            account_payer_target_balance: PodU64::from(0),
+            min_operator_stake_weight: PodU128::from(0),
+            max_operator_fee_bps: PodU16::from(0),
+            reserved: [0; 101],
+        }
+    }
+
+    pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter(
+            [b"config_extension".to_vec(), ncn.to_bytes().to_vec()]
+                .iter()
+                .cloned(),
+        )
+    }
+
+    pub fn find_program_address(program_id: &Pubkey, ncn: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (address, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (address, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads the config extension if the NCN has initialized one, returning whether it exists
+    pub fn load_if_initialized(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<bool, ProgramError> {
+        if account.data_is_empty() {
+            let expected_pda = Self::find_program_address(program_id, ncn).0;
+            if expected_pda.ne(account.key) {
+                msg!(
+                    "Config extension PDA does not match {} != {}",
+                    account.key,
+                    expected_pda
+                );
+                return Err(ProgramError::InvalidSeeds);
+            }
+
+            return Ok(false);
+        }
+
+        Self::load(program_id, account, ncn, expect_writable)?;
+        Ok(true)
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn crank_bounty_lamports(&self) -> u64 {
+        self.crank_bounty_lamports.into()
+    }
+
+    pub fn max_crank_bounties_per_epoch(&self) -> u64 {
+        self.max_crank_bounties_per_epoch.into()
+    }
+
+    pub fn is_crank_bounty_enabled(&self) -> bool {
+        self.crank_bounty_lamports() > 0 && self.max_crank_bounties_per_epoch() > 0
+    }
+
+    pub fn account_payer_target_balance(&self) -> u64 {
+        self.account_payer_target_balance.into()
+    }
+
+    pub fn min_operator_stake_weight(&self) -> u128 {
+        self.min_operator_stake_weight.into()
+    }
+
+    pub fn max_operator_fee_bps(&self) -> u16 {
+        self.max_operator_fee_bps.into()
+    }
+
+    /// Whether an operator's fee is above the configured maximum
+    pub fn operator_fee_above_maximum(&self, operator_fee_bps: u16) -> bool {
+        self.max_operator_fee_bps() > 0 && operator_fee_bps > self.max_operator_fee_bps()
+    }
+
+    /// Whether an operator's stake weight is below the configured minimum
+    pub fn operator_stake_below_minimum(&self, stake_weight: u128) -> bool {
+        stake_weight < self.min_operator_stake_weight()
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for ConfigExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Config Extension -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Crank Bounty Lamports:        {}", self.crank_bounty_lamports())?;
+        writeln!(f, "  Max Crank Bounties / Epoch:   {}", self.max_crank_bounties_per_epoch())?;
+        writeln!(f, "  Account Payer Target Balance: {}", self.account_payer_target_balance())?;
+        writeln!(f, "  Min Operator Stake Weight:    {}", self.min_operator_stake_weight())?;
+        writeln!(f, "  Max Operator Fee (bps):       {}", self.max_operator_fee_bps())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + 1 // bump
+            + size_of::<PodU64>() // crank_bounty_lamports
+            + size_of::<PodU64>() // max_crank_bounties_per_epoch
+            + size_of::<PodU64>() // account_payer_target_balance
+            + size_of::<PodU128>() // min_operator_stake_weight
+            + size_of::<PodU16>() // max_operator_fee_bps
+            + 101; // reserved
+
+        assert_eq!(size_of::<ConfigExtension>(), expected_total);
+        assert_eq!(size_of::<ConfigExtension>() + 8, ConfigExtension::SIZE);
+    }
+
+    #[test]
+    fn test_crank_bounty_enabled() {
+        let mut config_extension = ConfigExtension::new(&Pubkey::new_unique(), 255);
+        assert!(!config_extension.is_crank_bounty_enabled());
+
+        config_extension.crank_bounty_lamports = PodU64::from(5_000);
+        assert!(!config_extension.is_crank_bounty_enabled());
+
+        config_extension.max_crank_bounties_per_epoch = PodU64::from(20);
+        assert!(config_extension.is_crank_bounty_enabled());
+    }
+
+    #[test]
+    fn test_operator_bounds() {
+        let mut config_extension = ConfigExtension::new(&Pubkey::new_unique(), 255);
+
+        // Both bounds are disabled by default
+        assert!(!config_extension.operator_fee_above_maximum(u16::MAX));
+        assert!(!config_extension.operator_stake_below_minimum(0));
+
+        config_extension.max_operator_fee_bps = PodU16::from(1_000);
+        assert!(!config_extension.operator_fee_above_maximum(1_000));
+        assert!(config_extension.operator_fee_above_maximum(1_001));
+
+        config_extension.min_operator_stake_weight = PodU128::from(500);
+        assert!(!config_extension.operator_stake_below_minimum(500));
+        assert!(config_extension.operator_stake_below_minimum(499));
+    }
+}
@@ -9,21 +9,41 @@ pub const MAX_FEE_BPS: u64 = 10_000;
 pub const MAX_ST_MINTS: usize = 64;
 pub const MAX_VAULTS: usize = 64;
 pub const MAX_OPERATORS: usize = 256;
+/// Overflow pages that can be chained to a ballot box or base reward router
+pub const MAX_PAGES: usize = 8;
+pub const MAX_TIE_BREAKER_COMMITTEE_MEMBERS: usize = 8;
+/// Fee versions that can be scheduled ahead of time, including the active one
+pub const MAX_FEE_SCHEDULE_ENTRIES: usize = 8;
 pub const MIN_EPOCHS_BEFORE_STALL: u64 = 1;
 pub const MAX_EPOCHS_BEFORE_STALL: u64 = 50;
 pub const MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE: u64 = 10;
 pub const MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE: u64 = 100;
 pub const MIN_VALID_SLOTS_AFTER_CONSENSUS: u64 = 1000;
 pub const MAX_VALID_SLOTS_AFTER_CONSENSUS: u64 = 50 * DEFAULT_SLOTS_PER_EPOCH;
+pub const MIN_CONSENSUS_THRESHOLD_BPS: u64 = 5_100;
+pub const MAX_CONSENSUS_THRESHOLD_BPS: u64 = MAX_FEE_BPS;
+/// Threshold of configs that never set one, requires exactly 2/3 of the stake weight
+pub const DEFAULT_CONSENSUS_THRESHOLD_BPS: u64 = 0;
+pub const MAX_COMMIT_WINDOW_SLOTS: u64 = DEFAULT_SLOTS_PER_EPOCH;
+pub const MAX_LATE_VOTE_DECAY_BPS: u64 = MAX_FEE_BPS;
+pub const MAX_PLURALITY_FALLBACK_EPOCHS: u64 = 50;
+pub const MAX_PLURALITY_MIN_STAKE_WEIGHT_BPS: u64 = MAX_FEE_BPS;
+pub const MAX_CRANK_BOUNTY_LAMPORTS: u64 = 10_000_000;
+/// Untracked reward receiver balance that is swept to the DAO wallet rather than routed
+pub const MAX_DUST_LAMPORTS: u64 = 10_000;
 const PRECISE_CONSENSUS_NUMERATOR: u128 = 2;
 const PRECISE_CONSENSUS_DENOMINATOR: u128 = 3;
-pub fn precise_consensus() -> Result<PreciseNumber, TipRouterError> {
-    PreciseNumber::new(PRECISE_CONSENSUS_NUMERATOR)
+pub fn precise_consensus(consensus_threshold_bps: u64) -> Result<PreciseNumber, TipRouterError> {
+    let (numerator, denominator) = match consensus_threshold_bps {
+        DEFAULT_CONSENSUS_THRESHOLD_BPS => {
+            (PRECISE_CONSENSUS_NUMERATOR, PRECISE_CONSENSUS_DENOMINATOR)
+        }
+        bps => (bps as u128, MAX_FEE_BPS as u128),
+    };
+
+    PreciseNumber::new(numerator)
         .ok_or(TipRouterError::NewPreciseNumberError)?
-        .checked_div(
-            &PreciseNumber::new(PRECISE_CONSENSUS_DENOMINATOR)
-                .ok_or(TipRouterError::NewPreciseNumberError)?,
-        )
+        .checked_div(&PreciseNumber::new(denominator).ok_or(TipRouterError::NewPreciseNumberError)?)
         .ok_or(TipRouterError::DenominatorIsZero)
 }
 
@@ -32,6 +52,11 @@ pub const MAX_REALLOC_BYTES: u64 = MAX_PERMITTED_DATA_INCREASE as u64;
 
 pub const WEIGHT_PRECISION: u128 = 1_000_000_000;
 pub const SWITCHBOARD_MAX_STALE_SLOTS: u64 = 100;
+/// Largest staleness window an st mint can set, longer windows would accept a price from the
+/// previous epoch
+pub const MAX_STALE_SLOTS: u64 = DEFAULT_SLOTS_PER_EPOCH;
+pub const PYTH_MAX_STALE_SLOTS: u64 = 100;
+pub const MAX_ORACLE_FEEDS: usize = 3;
 pub const JTO_SOL_FEED: Pubkey = pubkey!("5S7ErPSkFmyXuq2aE3rZ6ofwVyZpwzUt6w7m6kqekvMe");
 pub const JITOSOL_SOL_FEED: Pubkey = pubkey!("4Z1SLH9g4ikNBV8uP2ZctEouqjYmVqB2Tz5SZxKYBN7z");
 
@@ -43,3 +68,13 @@ pub const JITOSOL_RESERVE_STAKE: Pubkey = pubkey!("BgKUXdS29YcHCFrPm5M8oLHiTzZaM
 
 // There is only one of these
 pub const SWITCHBOARD_QUEUE: Pubkey = pubkey!("A43DyUGA7s8eXPxqEjJY6EBu1KKbNgfxF8h17VAHn13w");
+
+/// Number of epochs a proposed admin has to accept before the proposal expires
+pub const PENDING_ADMIN_EXPIRY_EPOCHS: u64 = 10;
+
+/// Pause flags stored in `Config::paused`, each blocks a group of instructions
+pub const PAUSE_VOTING: u8 = 1 << 0;
+pub const PAUSE_SET_MERKLE_ROOT: u8 = 1 << 1;
+pub const PAUSE_ROUTING: u8 = 1 << 2;
+pub const PAUSE_DISTRIBUTION: u8 = 1 << 3;
+pub const PAUSE_ALL: u8 = PAUSE_VOTING | PAUSE_SET_MERKLE_ROOT | PAUSE_ROUTING | PAUSE_DISTRIBUTION;
@@ -0,0 +1,117 @@
+use jito_bytemuck::AccountDeserialize;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    account_payer::AccountPayer,
+    epoch_state::EpochState,
+    event::{CrankBountyPaid, TipRouterEvent},
+};
+
+/// Bounties paid from the `AccountPayer` to whoever signs a useful crank step
+///
+/// A crank step claims the bounty by appending `[account_payer, system_program, cranker]` right
+/// after its own accounts, with the cranker signing. Bounties are capped per epoch in the
+/// `EpochState`.
+pub struct CrankBounty {}
+
+impl CrankBounty {
+    pub const ACCOUNT_COUNT: usize = 3;
+
+    /// Splits the bounty accounts off after the step's own `account_count` accounts
+    ///
+    /// Anything other than no trailing accounts, or exactly the bounty accounts, is rejected
+    pub fn split_accounts<'a, 'info>(
+        accounts: &'a [AccountInfo<'info>],
+        account_count: usize,
+    ) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>]), ProgramError> {
+        if accounts.len() < account_count {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let (accounts, bounty_accounts) = accounts.split_at(account_count);
+        if !bounty_accounts.is_empty() && bounty_accounts.len() != Self::ACCOUNT_COUNT {
+            msg!(
+                "Expected {} crank bounty accounts, got {}",
+                Self::ACCOUNT_COUNT,
+                bounty_accounts.len()
+            );
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok((accounts, bounty_accounts))
+    }
+
+    /// Pays the epoch's crank bounty to the cranker, if one was claimed and is still available
+    ///
+    /// Skips the bounty, without failing the crank step, when the epoch's bounties are used up or
+    /// the account payer cannot fund it and stay rent exempt
+    pub fn pay<'a, 'info>(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        epoch_state: &'a AccountInfo<'info>,
+        bounty_accounts: &'a [AccountInfo<'info>],
+    ) -> ProgramResult {
+        let [account_payer, system_program, cranker] = bounty_accounts else {
+            return Ok(());
+        };
+
+        AccountPayer::load(program_id, account_payer, ncn, true)?;
+        if system_program.key.ne(&system_program::id()) {
+            msg!("Incorrect system program");
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if !cranker.is_signer {
+            msg!("Cranker must sign to claim the bounty");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if !cranker.is_writable {
+            msg!("Cranker must be writable to receive the bounty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // The epoch state is closed, or not yet initialized
+        if epoch_state.data_len() < EpochState::SIZE {
+            msg!("No crank bounty, epoch state is not initialized");
+            return Ok(());
+        }
+
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+
+        let bounty = epoch_state_account.next_crank_bounty();
+        if bounty == 0 {
+            msg!("No crank bounty available for epoch {}", epoch);
+            return Ok(());
+        }
+
+        let minimum_balance = Rent::get()?.minimum_balance(0);
+        if account_payer.lamports().saturating_sub(bounty) < minimum_balance {
+            msg!("Account payer cannot fund the crank bounty");
+            return Ok(());
+        }
+
+        AccountPayer::transfer(program_id, ncn, account_payer, cranker, bounty)?;
+        epoch_state_account.update_pay_crank_bounty(bounty)?;
+
+        msg!(
+            "Paid crank bounty of {} lamports to {}",
+            bounty,
+            cranker.key
+        );
+
+        TipRouterEvent::CrankBountyPaid(CrankBountyPaid {
+            ncn: *ncn,
+            epoch,
+            cranker: *cranker.key,
+            lamports: bounty,
+            slot: Clock::get()?.slot,
+        })
+        .emit();
+
+        Ok(())
+    }
+}
@@ -3,6 +3,8 @@ pub enum Discriminators {
     // Configs
     Config = 0x01,
     VaultRegistry = 0x02,
+    FeeSchedule = 0x03,
+    ConfigExtension = 0x04,
 
     // Snapshots
     WeightTable = 0x10,
@@ -11,13 +13,16 @@ pub enum Discriminators {
 
     // Voting
     BallotBox = 0x20,
+    BallotBoxPage = 0x21,
+    OperatorRecord = 0x22,
 
     // Validation and Consensus
-    // - Reserved for future use
+    TieBreakerCommittee = 0x30,
 
     // Distribution
     BaseRewardRouter = 0x40,
     NcnRewardRouter = 0x41,
+    BaseRewardRouterPage = 0x42,
 
     // State Tracking
     EpochState = 0x50,
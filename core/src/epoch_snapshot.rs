@@ -12,9 +12,9 @@ use solana_program::{account_info::AccountInfo, program_error::ProgramError, pub
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    base_fee_group::BaseFeeGroup, constants::MAX_VAULTS, discriminators::Discriminators,
-    error::TipRouterError, fees::Fees, loaders::check_load, ncn_fee_group::NcnFeeGroup,
-    stake_weight::StakeWeights, weight_table::WeightTable,
+    ballot_box::Ballot, base_fee_group::BaseFeeGroup, constants::MAX_VAULTS,
+    discriminators::Discriminators, error::TipRouterError, fees::Fees, loaders::check_load,
+    ncn_fee_group::NcnFeeGroup, stake_weight::StakeWeights, weight_table::WeightTable,
 };
 
 // PDA'd ["epoch_snapshot", NCN, NCN_EPOCH_SLOT]
@@ -196,6 +196,34 @@ impl EpochSnapshot {
     }
 }
 
+/// Why an operator snapshot was recorded as inactive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum OperatorInactiveReason {
+    /// The operator is active
+    None = 0x0,
+    /// The NCN or the operator has not opted in to the other
+    NotOptedIn = 0x1,
+    /// The operator's total stake weight is below the config's minimum
+    StakeBelowMinimum = 0x2,
+    /// The operator's fee is above the config's maximum
+    FeeAboveMaximum = 0x3,
+}
+
+impl TryFrom<u8> for OperatorInactiveReason {
+    type Error = TipRouterError;
+
+    fn try_from(reason: u8) -> Result<Self, Self::Error> {
+        match reason {
+            0x0 => Ok(Self::None),
+            0x1 => Ok(Self::NotOptedIn),
+            0x2 => Ok(Self::StakeBelowMinimum),
+            0x3 => Ok(Self::FeeAboveMaximum),
+            _ => Err(TipRouterError::InvalidOperatorInactiveReason),
+        }
+    }
+}
+
 // PDA'd ["operator_snapshot", OPERATOR, NCN, NCN_EPOCH_SLOT]
 #[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
 #[repr(C)]
@@ -219,7 +247,13 @@ pub struct OperatorSnapshot {
     valid_operator_vault_delegations: PodU64,
 
     stake_weights: StakeWeights,
-    reserved: [u8; 256],
+    /// Why the operator is inactive, see `OperatorInactiveReason`
+    inactive_reason: u8,
+    /// The slot of the snapshot the operator's latest vote was computed from, 0 if not reported
+    vote_snapshot_slot: PodU64,
+    /// The bank hash at `vote_snapshot_slot`
+    vote_bank_hash: [u8; 32],
+    reserved: [u8; 215],
 
     vault_operator_stake_weight: [VaultOperatorStakeWeight; 64],
 }
@@ -263,7 +297,10 @@ impl OperatorSnapshot {
             vault_operator_delegations_registered: PodU64::from(0),
             valid_operator_vault_delegations: PodU64::from(0),
             stake_weights: StakeWeights::default(),
-            reserved: [0; 256],
+            inactive_reason: Self::initial_inactive_reason(is_active) as u8,
+            vote_snapshot_slot: PodU64::from(0),
+            vote_bank_hash: [0; 32],
+            reserved: [0; 215],
             vault_operator_stake_weight: [VaultOperatorStakeWeight::default(); MAX_VAULTS],
         })
     }
@@ -308,12 +345,31 @@ impl OperatorSnapshot {
         self.vault_operator_delegations_registered = PodU64::from(0);
         self.valid_operator_vault_delegations = PodU64::from(0);
         self.stake_weights = StakeWeights::default();
-        self.reserved = [0; 256];
+        self.inactive_reason = Self::initial_inactive_reason(is_active) as u8;
+        self.vote_snapshot_slot = PodU64::from(0);
+        self.vote_bank_hash = [0; 32];
+        self.reserved = [0; 215];
         self.vault_operator_stake_weight = [VaultOperatorStakeWeight::default(); MAX_VAULTS];
 
         Ok(())
     }
 
+    const fn initial_inactive_reason(is_active: bool) -> OperatorInactiveReason {
+        if is_active {
+            OperatorInactiveReason::None
+        } else {
+            OperatorInactiveReason::NotOptedIn
+        }
+    }
+
+    /// Records the operator as inactive, dropping any stake weight it has registered
+    pub fn deactivate(&mut self, reason: OperatorInactiveReason) {
+        self.is_active = PodBool::from(false);
+        self.inactive_reason = reason as u8;
+        self.valid_operator_vault_delegations = PodU64::from(0);
+        self.stake_weights = StakeWeights::default();
+    }
+
     pub fn seeds(operator: &Pubkey, ncn: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
         Vec::from_iter(
             [
@@ -390,6 +446,10 @@ impl OperatorSnapshot {
         self.is_active.into()
     }
 
+    pub fn inactive_reason(&self) -> Result<OperatorInactiveReason, TipRouterError> {
+        OperatorInactiveReason::try_from(self.inactive_reason)
+    }
+
     pub const fn operator(&self) -> &Pubkey {
         &self.operator
     }
@@ -418,6 +478,26 @@ impl OperatorSnapshot {
         self.vault_operator_delegations_registered() == self.vault_operator_delegation_count()
     }
 
+    pub fn vote_snapshot_slot(&self) -> u64 {
+        self.vote_snapshot_slot.into()
+    }
+
+    pub const fn vote_bank_hash(&self) -> &[u8; 32] {
+        &self.vote_bank_hash
+    }
+
+    /// Whether the operator reported the snapshot its latest vote was computed from
+    pub fn has_vote_snapshot(&self) -> bool {
+        self.vote_snapshot_slot() != 0
+    }
+
+    /// Records the snapshot the operator's ballot was computed from, so diverging ballots can be
+    /// told apart by slot and bank hash
+    pub fn set_vote_snapshot(&mut self, ballot: &Ballot) {
+        self.vote_snapshot_slot = PodU64::from(ballot.snapshot_slot());
+        self.vote_bank_hash = *ballot.bank_hash();
+    }
+
     pub fn contains_vault_index(&self, vault_index: u64) -> bool {
         self.vault_operator_stake_weight
             .iter()
@@ -635,12 +715,21 @@ impl fmt::Display for OperatorSnapshot {
        writeln!(f, "  Bump:                         {}", self.bump)?;
        writeln!(f, "  Slot Finalized:               {}", self.slot_finalized())?;
        writeln!(f, "  Is Active:                    {}", self.is_active())?;
+       if let Ok(reason) = self.inactive_reason() {
+           if reason != OperatorInactiveReason::None {
+               writeln!(f, "  Inactive Reason:              {:?}", reason)?;
+           }
+       }
        writeln!(f, "  NCN Operator Index:           {}", self.ncn_operator_index())?;
        writeln!(f, "  Operator Fee BPS:             {}", self.operator_fee_bps())?;
        writeln!(f, "  Delegation Count:             {}", self.vault_operator_delegation_count())?;
        writeln!(f, "  Delegations Registered:       {}", self.vault_operator_delegations_registered())?;
        writeln!(f, "  Valid Delegations:            {}", self.valid_operator_vault_delegations())?;
        writeln!(f, "  Finalized:                    {}", self.finalized())?;
+       if self.has_vote_snapshot() {
+           writeln!(f, "  Vote Snapshot Slot:           {}", self.vote_snapshot_slot())?;
+           writeln!(f, "  Vote Bank Hash:               {:?}", self.vote_bank_hash())?;
+       }
 
        let stake_weights = self.stake_weights();
        writeln!(f, "\nTotal Stake Weight: {}", stake_weights.stake_weight())?;
@@ -697,12 +786,43 @@ mod tests {
             + size_of::<PodU64>() // vault_operator_delegations_registered
             + size_of::<PodU64>() // valid_operator_vault_delegations
             + size_of::<StakeWeights>() // stake_weight
-            + 256 // reserved
+            + 1 // inactive_reason
+            + size_of::<PodU64>() // vote_snapshot_slot
+            + 32 // vote_bank_hash
+            + 215 // reserved
             + size_of::<VaultOperatorStakeWeight>() * MAX_VAULTS; // vault_operator_stake_weight
 
         assert_eq!(size_of::<OperatorSnapshot>(), expected_total);
     }
 
+    #[test]
+    fn test_set_vote_snapshot() {
+        let mut snapshot = OperatorSnapshot::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            1,
+            1,
+            100,
+            true,
+            0,
+            0,
+            100,
+            1,
+        )
+        .unwrap();
+        assert!(!snapshot.has_vote_snapshot());
+
+        snapshot.set_vote_snapshot(&Ballot::new_with_snapshot(&[1; 32], 50, &[2; 32]));
+        assert!(snapshot.has_vote_snapshot());
+        assert_eq!(snapshot.vote_snapshot_slot(), 50);
+        assert_eq!(snapshot.vote_bank_hash(), &[2; 32]);
+
+        // A revote replaces the recorded snapshot
+        snapshot.set_vote_snapshot(&Ballot::new_with_snapshot(&[1; 32], 60, &[3; 32]));
+        assert_eq!(snapshot.vote_snapshot_slot(), 60);
+        assert_eq!(snapshot.vote_bank_hash(), &[3; 32]);
+    }
+
     #[test]
     fn test_vault_operator_stake_weight_ncn_fee_group() {
         // Test with default
@@ -1021,5 +1141,62 @@ mod tests {
         assert_eq!(inactive_snapshot.operator_fee_bps(), 0); // fee should be zeroed
         assert_eq!(inactive_snapshot.vault_operator_delegation_count(), 0);
         // count should be zeroed
+
+        assert_eq!(
+            active_snapshot.inactive_reason(),
+            Ok(OperatorInactiveReason::None)
+        );
+        assert_eq!(
+            inactive_snapshot.inactive_reason(),
+            Ok(OperatorInactiveReason::NotOptedIn)
+        );
+    }
+
+    #[test]
+    fn test_operator_snapshot_deactivate() {
+        let mut snapshot = OperatorSnapshot::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            1,
+            1,
+            100,
+            true,
+            0,
+            0,
+            100,
+            1,
+        )
+        .unwrap();
+
+        let stake_weights = StakeWeights::snapshot(NcnFeeGroup::default(), 1_000, 10_000).unwrap();
+        snapshot
+            .increment_vault_operator_delegation_registration(
+                200,
+                &Pubkey::new_unique(),
+                0,
+                NcnFeeGroup::default(),
+                &stake_weights,
+            )
+            .unwrap();
+        assert!(snapshot.finalized());
+        assert_eq!(snapshot.valid_operator_vault_delegations(), 1);
+        assert!(snapshot.stake_weights().stake_weight() > 0);
+
+        snapshot.deactivate(OperatorInactiveReason::StakeBelowMinimum);
+
+        assert!(!snapshot.is_active());
+        assert_eq!(
+            snapshot.inactive_reason(),
+            Ok(OperatorInactiveReason::StakeBelowMinimum)
+        );
+        assert_eq!(snapshot.valid_operator_vault_delegations(), 0);
+        assert_eq!(snapshot.stake_weights().stake_weight(), 0);
+        // The registered delegations still count towards finalizing the snapshot
+        assert!(snapshot.finalized());
+
+        assert_eq!(
+            OperatorInactiveReason::try_from(0x4),
+            Err(TipRouterError::InvalidOperatorInactiveReason)
+        );
     }
 }
@@ -80,8 +80,12 @@ impl EpochAccountStatus {
         Self::get_account_status(self.epoch_snapshot)
     }
 
-    pub const fn operator_snapshot(&self, index: usize) -> Result<AccountStatus, TipRouterError> {
-        Self::get_account_status(self.operator_snapshot[index])
+    pub fn operator_snapshot(&self, index: usize) -> Result<AccountStatus, TipRouterError> {
+        let status = self
+            .operator_snapshot
+            .get(index)
+            .ok_or(TipRouterError::OperatorIndexOutOfBounds)?;
+        Self::get_account_status(*status)
     }
 
     pub const fn ballot_box(&self) -> Result<AccountStatus, TipRouterError> {
@@ -114,8 +118,17 @@ impl EpochAccountStatus {
         self.epoch_snapshot = status as u8;
     }
 
-    pub fn set_operator_snapshot(&mut self, index: usize, status: AccountStatus) {
-        self.operator_snapshot[index] = status as u8;
+    pub fn set_operator_snapshot(
+        &mut self,
+        index: usize,
+        status: AccountStatus,
+    ) -> Result<(), TipRouterError> {
+        let operator_snapshot = self
+            .operator_snapshot
+            .get_mut(index)
+            .ok_or(TipRouterError::OperatorIndexOutOfBounds)?;
+        *operator_snapshot = status as u8;
+        Ok(())
     }
 
     pub fn set_ballot_box(&mut self, status: AccountStatus) {
@@ -137,6 +150,13 @@ impl EpochAccountStatus {
         Ok(())
     }
 
+    pub fn are_operator_snapshots_closed(&self) -> bool {
+        self.operator_snapshot.iter().all(|operator_snapshot| {
+            *operator_snapshot == AccountStatus::DNE as u8
+                || *operator_snapshot == AccountStatus::Closed as u8
+        })
+    }
+
     pub fn are_all_closed(&self) -> bool {
         // We don't need to check epoch state since it's the account we are closing
 
@@ -148,14 +168,8 @@ impl EpochAccountStatus {
             return false;
         }
 
-        for operator_snapshot_ref in self.operator_snapshot.iter() {
-            let operator_snapshot = *operator_snapshot_ref;
-            let is_dne = operator_snapshot == AccountStatus::DNE as u8;
-            let is_closed = operator_snapshot == AccountStatus::Closed as u8;
-
-            if !is_dne && !is_closed {
-                return false;
-            }
+        if !self.are_operator_snapshots_closed() {
+            return false;
         }
 
         if self.ballot_box != AccountStatus::Closed as u8 {
@@ -299,7 +313,7 @@ pub struct EpochState {
     /// Progress on validation
     validation_progress: Progress,
 
-    /// Upload progress
+    /// Upload progress, the total is the tip distribution account count reported by operators
     upload_progress: Progress,
 
     /// Distribution progress
@@ -314,8 +328,35 @@ pub struct EpochState {
     /// Is closing
     is_closing: PodBool,
 
+    /// Progress on reallocating ballot box pages
+    ballot_box_page_progress: Progress,
+
+    /// Progress on reallocating base reward router pages
+    base_reward_router_page_progress: Progress,
+
+    /// Number of page accounts created and not yet closed
+    open_page_count: PodU64,
+
+    /// Was the ballot decided by the plurality fallback
+    was_plurality_set: PodBool,
+
+    /// Lamports paid to the signer of each useful crank step, copied from the config
+    crank_bounty_lamports: PodU64,
+
+    /// Number of crank bounties that can be paid this epoch, copied from the config
+    max_crank_bounties: PodU64,
+
+    /// Number of crank bounties paid this epoch
+    crank_bounties_paid: PodU64,
+
+    /// Lamports paid in crank bounties this epoch
+    crank_bounty_lamports_paid: PodU64,
+
+    /// Untracked lamports swept from the reward receivers to the DAO wallet
+    dust_swept: PodU64,
+
     /// Reserved space
-    reserved: [u8; 1023],
+    reserved: [u8; 926],
 }
 
 impl Discriminator for EpochState {
@@ -347,7 +388,16 @@ impl EpochState {
             ncn_distribution_progress: [Progress::default();
                 MAX_OPERATORS * NcnFeeGroup::FEE_GROUP_COUNT],
             is_closing: PodBool::from(false),
-            reserved: [0; 1023],
+            ballot_box_page_progress: Progress::default(),
+            base_reward_router_page_progress: Progress::default(),
+            open_page_count: PodU64::from(0),
+            was_plurality_set: PodBool::from(false),
+            crank_bounty_lamports: PodU64::from(0),
+            max_crank_bounties: PodU64::from(0),
+            crank_bounties_paid: PodU64::from(0),
+            crank_bounty_lamports_paid: PodU64::from(0),
+            dust_swept: PodU64::from(0),
+            reserved: [0; 926],
         }
     }
 
@@ -358,7 +408,7 @@ impl EpochState {
         self.epoch = PodU64::from(epoch);
         self.slot_created = PodU64::from(slot_created);
         self.slot_consensus_reached = PodU64::from(DEFAULT_CONSENSUS_REACHED_SLOT);
-        self.reserved = [0; 1023];
+        self.reserved = [0; 926];
     }
 
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
@@ -422,6 +472,11 @@ impl EpochState {
             return Err(TipRouterError::CannotCloseEpochStateAccount.into());
         }
 
+        if account_to_close.open_page_count() > 0 {
+            msg!("Cannot close Epoch State until all pages are closed");
+            return Err(TipRouterError::CannotCloseEpochStateAccount.into());
+        }
+
         Ok(())
     }
 
@@ -448,6 +503,10 @@ impl EpochState {
         ncn_operator_index: usize,
         group: NcnFeeGroup,
     ) -> Result<usize, TipRouterError> {
+        if ncn_operator_index >= MAX_OPERATORS {
+            return Err(TipRouterError::OperatorIndexOutOfBounds);
+        }
+
         let mut index = ncn_operator_index
             .checked_mul(NcnFeeGroup::FEE_GROUP_COUNT)
             .ok_or(TipRouterError::ArithmeticOverflow)?;
@@ -479,6 +538,39 @@ impl EpochState {
         self.was_tie_breaker_set.into()
     }
 
+    pub fn was_plurality_set(&self) -> bool {
+        self.was_plurality_set.into()
+    }
+
+    pub fn crank_bounty_lamports(&self) -> u64 {
+        self.crank_bounty_lamports.into()
+    }
+
+    pub fn max_crank_bounties(&self) -> u64 {
+        self.max_crank_bounties.into()
+    }
+
+    pub fn crank_bounties_paid(&self) -> u64 {
+        self.crank_bounties_paid.into()
+    }
+
+    pub fn crank_bounty_lamports_paid(&self) -> u64 {
+        self.crank_bounty_lamports_paid.into()
+    }
+
+    pub fn dust_swept(&self) -> u64 {
+        self.dust_swept.into()
+    }
+
+    /// The bounty owed to the next useful crank step, 0 once the epoch's bounties are used up
+    pub fn next_crank_bounty(&self) -> u64 {
+        if self.crank_bounties_paid() >= self.max_crank_bounties() {
+            return 0;
+        }
+
+        self.crank_bounty_lamports()
+    }
+
     pub fn is_consensus_reached(&self) -> bool {
         self.slot_consensus_reached() != DEFAULT_CONSENSUS_REACHED_SLOT
     }
@@ -529,8 +621,14 @@ impl EpochState {
         self.epoch_snapshot_progress
     }
 
-    pub const fn operator_snapshot_progress(&self, ncn_operator_index: usize) -> Progress {
-        self.operator_snapshot_progress[ncn_operator_index]
+    pub fn operator_snapshot_progress(
+        &self,
+        ncn_operator_index: usize,
+    ) -> Result<Progress, TipRouterError> {
+        self.operator_snapshot_progress
+            .get(ncn_operator_index)
+            .copied()
+            .ok_or(TipRouterError::OperatorIndexOutOfBounds)
     }
 
     pub const fn voting_progress(&self) -> Progress {
@@ -553,6 +651,18 @@ impl EpochState {
         self.base_distribution_progress
     }
 
+    pub const fn ballot_box_page_progress(&self) -> Progress {
+        self.ballot_box_page_progress
+    }
+
+    pub const fn base_reward_router_page_progress(&self) -> Progress {
+        self.base_reward_router_page_progress
+    }
+
+    pub fn open_page_count(&self) -> u64 {
+        self.open_page_count.into()
+    }
+
     pub fn ncn_distribution_progress(
         &self,
         ncn_ncn_operator_index: usize,
@@ -567,6 +677,26 @@ impl EpochState {
         self.account_status.set_epoch_state(AccountStatus::Created);
     }
 
+    pub fn set_crank_bounty(&mut self, crank_bounty_lamports: u64, max_crank_bounties: u64) {
+        self.crank_bounty_lamports = PodU64::from(crank_bounty_lamports);
+        self.max_crank_bounties = PodU64::from(max_crank_bounties);
+    }
+
+    pub fn update_pay_crank_bounty(&mut self, lamports: u64) -> Result<(), TipRouterError> {
+        self.crank_bounties_paid = PodU64::from(
+            self.crank_bounties_paid()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        self.crank_bounty_lamports_paid = PodU64::from(
+            self.crank_bounty_lamports_paid()
+                .checked_add(lamports)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
     pub fn update_realloc_weight_table(&mut self, vault_count: u64, st_mint_count: u64) {
         self.account_status.set_weight_table(AccountStatus::Created);
 
@@ -593,14 +723,19 @@ impl EpochState {
         is_active: bool,
     ) -> Result<(), TipRouterError> {
         self.account_status
-            .set_operator_snapshot(ncn_operator_index, AccountStatus::Created);
+            .set_operator_snapshot(ncn_operator_index, AccountStatus::Created)?;
+
+        let vault_count = self.vault_count.into();
+        let operator_snapshot_progress = self
+            .operator_snapshot_progress
+            .get_mut(ncn_operator_index)
+            .ok_or(TipRouterError::OperatorIndexOutOfBounds)?;
 
         if is_active {
-            self.operator_snapshot_progress[ncn_operator_index] =
-                Progress::new(self.vault_count.into());
+            *operator_snapshot_progress = Progress::new(vault_count);
         } else {
-            self.operator_snapshot_progress[ncn_operator_index] = Progress::new(1);
-            self.operator_snapshot_progress[ncn_operator_index].increment_one()?;
+            *operator_snapshot_progress = Progress::new(1);
+            operator_snapshot_progress.increment_one()?;
             self.epoch_snapshot_progress.increment_one()?;
         }
 
@@ -612,7 +747,10 @@ impl EpochState {
         ncn_operator_index: usize,
         finalized: bool,
     ) -> Result<(), TipRouterError> {
-        self.operator_snapshot_progress[ncn_operator_index].increment_one()?;
+        self.operator_snapshot_progress
+            .get_mut(ncn_operator_index)
+            .ok_or(TipRouterError::OperatorIndexOutOfBounds)?
+            .increment_one()?;
         if finalized {
             self.epoch_snapshot_progress.increment_one()?;
         }
@@ -620,21 +758,44 @@ impl EpochState {
         Ok(())
     }
 
-    pub fn update_realloc_ballot_box(&mut self) {
+    pub fn update_realloc_ballot_box(&mut self, pages_required: u8) {
         self.account_status.set_ballot_box(AccountStatus::Created);
         self.voting_progress = Progress::new(self.operator_count());
         self.validation_progress = Progress::new(1);
         self.upload_progress = Progress::new(1);
+        self.ballot_box_page_progress = Progress::new(pages_required as u64);
+    }
+
+    pub fn update_realloc_ballot_box_page(&mut self) -> Result<(), TipRouterError> {
+        self.ballot_box_page_progress.increment_one()?;
+        self.increment_open_page_count()
+    }
+
+    pub fn update_realloc_base_reward_router_page(&mut self) -> Result<(), TipRouterError> {
+        self.base_reward_router_page_progress.increment_one()?;
+        self.increment_open_page_count()
+    }
+
+    fn increment_open_page_count(&mut self) -> Result<(), TipRouterError> {
+        self.open_page_count = PodU64::from(
+            self.open_page_count()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
     }
 
     pub fn update_cast_vote(
         &mut self,
         operators_voted: u64,
         is_consensus_reached: bool,
+        tip_distribution_account_count: u64,
         current_slot: u64,
     ) -> Result<(), TipRouterError> {
         if is_consensus_reached && !self.is_consensus_reached() {
             self.slot_consensus_reached = PodU64::from(current_slot);
+            self.set_expected_merkle_roots(tip_distribution_account_count);
         }
 
         self.voting_progress.set_tally(operators_voted);
@@ -645,27 +806,71 @@ impl EpochState {
     pub fn update_set_tie_breaker(
         &mut self,
         is_consensus_reached: bool,
+        tip_distribution_account_count: u64,
         current_slot: u64,
     ) -> Result<(), TipRouterError> {
         if is_consensus_reached && !self.is_consensus_reached() {
             self.slot_consensus_reached = PodU64::from(current_slot);
             self.was_tie_breaker_set = PodBool::from(true);
+            self.set_expected_merkle_roots(tip_distribution_account_count);
         }
 
         Ok(())
     }
 
-    // Just tracks the amount of times set_merkle_root is called
-    pub fn update_set_merkle_root(&mut self) -> Result<(), TipRouterError> {
-        self.upload_progress.increment_one()?;
-        self.upload_progress.set_total(self.upload_progress.tally());
+    pub fn update_finalize_stalled_ballot(
+        &mut self,
+        is_consensus_reached: bool,
+        tip_distribution_account_count: u64,
+        current_slot: u64,
+    ) -> Result<(), TipRouterError> {
+        if is_consensus_reached && !self.is_consensus_reached() {
+            self.slot_consensus_reached = PodU64::from(current_slot);
+            self.was_plurality_set = PodBool::from(true);
+            self.set_expected_merkle_roots(tip_distribution_account_count);
+        }
+
         Ok(())
     }
 
-    pub fn update_realloc_base_reward_router(&mut self) {
+    /// Expects a merkle root for each tip distribution account in the winning ballot, a ballot
+    /// without a reported count leaves the total to follow the merkle roots set. The count is
+    /// reported by the voting operators and is not checked against the tip distribution program.
+    fn set_expected_merkle_roots(&mut self, tip_distribution_account_count: u64) {
+        if tip_distribution_account_count > 0 {
+            self.upload_progress
+                .set_total(tip_distribution_account_count);
+        }
+    }
+
+    /// Counts the tip distribution accounts that received their first merkle root, re-uploads to
+    /// an account already counted must not be passed in. `expected_merkle_roots` is the winning
+    /// ballot's tip distribution account count, merkle roots set past a reported count are logged
+    /// and raise the total so progress can still complete.
+    pub fn update_set_merkle_root(
+        &mut self,
+        merkle_roots_set: u64,
+        expected_merkle_roots: u64,
+    ) -> Result<(), TipRouterError> {
+        self.upload_progress.increment(merkle_roots_set)?;
+        if self.upload_progress.tally() > self.upload_progress.total() {
+            if expected_merkle_roots > 0 {
+                msg!(
+                    "Merkle roots set {} exceeds the {} tip distribution accounts reported in the winning ballot",
+                    self.upload_progress.tally(),
+                    expected_merkle_roots
+                );
+            }
+            self.upload_progress.set_total(self.upload_progress.tally());
+        }
+        Ok(())
+    }
+
+    pub fn update_realloc_base_reward_router(&mut self, pages_required: u8) {
         self.account_status
             .set_base_reward_router(AccountStatus::CreatedWithReceiver);
         self.base_distribution_progress = Progress::new(0);
+        self.base_reward_router_page_progress = Progress::new(pages_required as u64);
     }
 
     pub fn update_realloc_ncn_reward_router(
@@ -684,9 +889,38 @@ impl EpochState {
         Ok(())
     }
 
-    pub fn update_route_base_rewards(&mut self, total_rewards: u64) {
-        self.total_distribution_progress.set_total(total_rewards);
+    pub fn update_route_base_rewards(&mut self, total_rewards: u64) -> Result<(), TipRouterError> {
+        // Swept dust was never routed, but is distributed
+        let total_distribution = total_rewards
+            .checked_add(self.dust_swept())
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        self.total_distribution_progress
+            .set_total(total_distribution);
         self.base_distribution_progress.set_total(total_rewards);
+
+        Ok(())
+    }
+
+    /// Counts swept dust towards both the total and the tally of the distribution progress
+    pub fn update_sweep_dust(&mut self, lamports: u64) -> Result<(), TipRouterError> {
+        self.dust_swept = PodU64::from(
+            self.dust_swept()
+                .checked_add(lamports)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        let total_distribution = self
+            .total_distribution_progress
+            .total()
+            .checked_add(lamports)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        self.total_distribution_progress
+            .set_total(total_distribution);
+        self.total_distribution_progress.increment(lamports)?;
+
+        Ok(())
     }
 
     pub fn update_route_ncn_rewards(
@@ -747,9 +981,12 @@ impl EpochState {
             .set_epoch_snapshot(AccountStatus::Closed);
     }
 
-    pub fn close_operator_snapshot(&mut self, ncn_operator_index: usize) {
+    pub fn close_operator_snapshot(
+        &mut self,
+        ncn_operator_index: usize,
+    ) -> Result<(), TipRouterError> {
         self.account_status
-            .set_operator_snapshot(ncn_operator_index, AccountStatus::Closed);
+            .set_operator_snapshot(ncn_operator_index, AccountStatus::Closed)
     }
 
     pub fn close_ballot_box(&mut self) {
@@ -770,6 +1007,16 @@ impl EpochState {
             .set_ncn_reward_router(ncn_operator_index, group, AccountStatus::Closed)
     }
 
+    pub fn close_page(&mut self) -> Result<(), TipRouterError> {
+        self.open_page_count = PodU64::from(
+            self.open_page_count()
+                .checked_sub(1)
+                .ok_or(TipRouterError::ArithmeticUnderflowError)?,
+        );
+
+        Ok(())
+    }
+
     // ------------ STATE ------------
     pub fn can_start_routing(
         &self,
@@ -898,6 +1145,10 @@ impl fmt::Display for EpochState {
        writeln!(f, "  Bump:                         {}", self.bump)?;
        writeln!(f, "  Slot Created:                 {}", self.slot_created())?;
        writeln!(f, "  Was Tie Breaker Set:          {}", self.was_tie_breaker_set())?;
+       writeln!(f, "  Was Plurality Set:            {}", self.was_plurality_set())?;
+       writeln!(f, "  Crank Bounties Paid:          {}/{}", self.crank_bounties_paid(), self.max_crank_bounties())?;
+       writeln!(f, "  Crank Bounty Lamports Paid:   {}", self.crank_bounty_lamports_paid())?;
+       writeln!(f, "  Dust Swept:                   {}", self.dust_swept())?;
        writeln!(f, "  Slot Consensus Reached:       {}", self.slot_consensus_reached())?;
        writeln!(f, "  Operator Count:               {}", self.operator_count())?;
        writeln!(f, "  Vault Count:                  {}", self.vault_count())?;
@@ -932,11 +1183,16 @@ impl fmt::Display for EpochState {
        writeln!(f, "\nProgress:")?;
        writeln!(f, "  Set Weight Progress:          {}/{}", self.set_weight_progress.tally(), self.set_weight_progress.total())?;
        writeln!(f, "  Epoch Snapshot Progress:      {}/{}", self.epoch_snapshot_progress.tally(), self.epoch_snapshot_progress.total())?;
+       writeln!(f, "  Ballot Box Pages:             {}/{}", self.ballot_box_page_progress.tally(), self.ballot_box_page_progress.total())?;
+       writeln!(f, "  Base Reward Router Pages:     {}/{}", self.base_reward_router_page_progress.tally(), self.base_reward_router_page_progress.total())?;
+       writeln!(f, "  Open Pages:                   {}", self.open_page_count())?;
        
        writeln!(f, "\nOperator Snapshot Progress:")?;
        for i in 0..MAX_OPERATORS {
-            if self.operator_snapshot_progress(i).total() > 0 {
-                writeln!(f, "  Operator {}:                   {}/{}", i, self.operator_snapshot_progress(i).tally(), self.operator_snapshot_progress(i).total())?;                
+            if let Ok(progress) = self.operator_snapshot_progress(i) {
+                if progress.total() > 0 {
+                    writeln!(f, "  Operator {}:                   {}/{}", i, progress.tally(), progress.total())?;
+                }
             }
        }
 
@@ -187,6 +187,122 @@ pub enum TipRouterError {
     EpochIsClosingDown,
     #[error("Marker exists")]
     MarkerExists,
+    #[error("Invalid consensus threshold")]
+    InvalidConsensusThreshold,
+    #[error("Invalid commit window")]
+    InvalidCommitWindow,
+    #[error("Votes must be committed and revealed")]
+    CommitRevealRequired,
+    #[error("Commit-reveal voting is not enabled")]
+    CommitRevealNotEnabled,
+    #[error("Commit window is closed")]
+    CommitWindowClosed,
+    #[error("Commit window is still open")]
+    CommitWindowOpen,
+    #[error("Vote commitment not found")]
+    VoteCommitmentNotFound,
+    #[error("Revealed vote does not match commitment")]
+    InvalidVoteCommitment,
+    #[error("No pending admin")]
+    NoPendingAdmin,
+    #[error("Pending admin does not match")]
+    PendingAdminMismatch,
+    #[error("Pending admin proposal has expired")]
+    PendingAdminExpired,
+    #[error("Voting is paused")]
+    VotingPaused,
+    #[error("Setting merkle roots is paused")]
+    SetMerkleRootPaused,
+    #[error("Routing rewards is paused")]
+    RoutingPaused,
+    #[error("Distributing rewards is paused")]
+    DistributionPaused,
+    #[error("Invalid pause flags")]
+    InvalidPauseFlags,
+    #[error("Incorrect pause admin")]
+    IncorrectPauseAdmin,
+    #[error("Invalid oracle feed type")]
+    InvalidOracleFeedType,
+    #[error("No oracle feeds registered")]
+    NoOracleFeeds,
+    #[error("Oracle feed not registered")]
+    OracleFeedNotRegistered,
+    #[error("Bad pyth feed")]
+    BadPythFeed,
+    #[error("Stale pyth feed")]
+    StalePythFeed,
+    #[error("Oracle feeds diverge beyond tolerance")]
+    OracleFeedsDiverged,
+    #[error("Invalid feed divergence tolerance")]
+    InvalidFeedDivergence,
+    #[error("Weight change from the previous epoch exceeds the limit")]
+    WeightChangeExceedsLimit,
+    #[error("Invalid previous weight table")]
+    InvalidPreviousWeightTable,
+    #[error("Retired epoch must be in the future")]
+    InvalidRetiredEpoch,
+    #[error("Vault is retired")]
+    VaultRetired,
+    #[error("ST mint is retired")]
+    StMintRetired,
+    #[error("ST mint has vaults that are not retired")]
+    StMintHasActiveVaults,
+    #[error("Too many pages required")]
+    TooManyPages,
+    #[error("Page is out of order or not required")]
+    InvalidPage,
+    #[error("Invalid tie breaker committee members or threshold")]
+    InvalidTieBreakerCommittee,
+    #[error("Signer is not a tie breaker committee member")]
+    TieBreakerCommitteeMemberInvalid,
+    #[error("Cannot close account - No operator record or ballot box provided")]
+    CannotCloseAccountNoOperatorRecordProvided,
+    #[error("Cannot close ballot box - Operator snapshots need to be closed first")]
+    CannotCloseBallotBoxOperatorSnapshotsOpen,
+    #[error("Invalid late vote decay")]
+    InvalidLateVoteDecay,
+    #[error("Invalid plurality fallback")]
+    InvalidPluralityFallback,
+    #[error("Plurality fallback is disabled")]
+    PluralityFallbackDisabled,
+    #[error("No single ballot has the most stake weight")]
+    NoPluralityBallot,
+    #[error("Plurality ballot is below the minimum stake weight")]
+    PluralityBelowStakeFloor,
+    #[error("Invalid crank bounty")]
+    InvalidCrankBounty,
+    #[error("Merkle root batch does not match its accounts")]
+    InvalidMerkleRootBatch,
+    #[error("Invalid reward asset")]
+    InvalidRewardAsset,
+    #[error("Invalid max operator fee")]
+    InvalidMaxOperatorFee,
+    #[error("Invalid operator inactive reason")]
+    InvalidOperatorInactiveReason,
+    #[error("Untracked rewards are above the dust threshold")]
+    UntrackedRewardsAboveDust,
+    #[error("Invalid reward router")]
+    InvalidRewardRouter,
+    #[error("Invalid fee schedule action")]
+    InvalidFeeScheduleAction,
+    #[error("Invalid fee schedule activation epoch")]
+    InvalidFeeScheduleEpoch,
+    #[error("Fee schedule is full")]
+    FeeScheduleFull,
+    #[error("Fee schedule entry not found")]
+    FeeScheduleEntryNotFound,
+    #[error("Rewards are not fully routed and distributed")]
+    RewardsNotDistributed,
+    #[error("Tie breaker committee is initialized, the committee sets the tie breaker")]
+    TieBreakerCommitteeActive,
+    #[error("Another admin proposal is pending, cancel it first")]
+    PendingAdminExists,
+    #[error("Operator index is out of bounds")]
+    OperatorIndexOutOfBounds,
+    #[error("Duplicate oracle feed")]
+    DuplicateOracleFeed,
+    #[error("Invalid max stale slots")]
+    InvalidMaxStaleSlots,
 }
 
 impl<T> DecodeError<T> for TipRouterError {
@@ -0,0 +1,175 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+/// Marks log data written by `TipRouterEvent::emit`, so it can be told apart from other
+/// `Program data:` lines in the same transaction
+pub const EVENT_PREFIX: [u8; 8] = *b"tiprtevt";
+
+/// An operator's vote was counted in the ballot box
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct VoteCast {
+    pub ncn: Pubkey,
+    pub epoch: u64,
+    pub operator: Pubkey,
+    pub meta_merkle_root: [u8; 32],
+    /// The operator had no data and abstained, `meta_merkle_root` is unset
+    pub abstain: bool,
+    pub stake_weight: u128,
+    pub slot: u64,
+}
+
+/// The ballot box reached consensus through voting
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ConsensusReached {
+    pub ncn: Pubkey,
+    pub epoch: u64,
+    pub meta_merkle_root: [u8; 32],
+    pub stake_weight: u128,
+    pub total_stake_weight: u128,
+    pub slot: u64,
+}
+
+/// The tie breaker admin decided the ballot box
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TieBreakerSet {
+    pub ncn: Pubkey,
+    pub epoch: u64,
+    pub meta_merkle_root: [u8; 32],
+    pub tie_breaker_admin: Pubkey,
+    pub slot: u64,
+}
+
+/// The plurality fallback decided a stalled ballot box
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct StalledBallotFinalized {
+    pub ncn: Pubkey,
+    pub epoch: u64,
+    pub meta_merkle_root: [u8; 32],
+    pub stake_weight: u128,
+    pub total_stake_weight: u128,
+    pub slot: u64,
+}
+
+/// A merkle root was uploaded to a tip distribution account
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MerkleRootSet {
+    pub ncn: Pubkey,
+    pub epoch: u64,
+    pub vote_account: Pubkey,
+    pub tip_distribution_account: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub max_total_claim: u64,
+    pub max_num_nodes: u64,
+}
+
+/// A base or NCN reward router processed its incoming rewards
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RewardsRouted {
+    pub ncn: Pubkey,
+    pub epoch: u64,
+    pub router: Pubkey,
+    pub total_rewards: u64,
+    pub still_routing: bool,
+}
+
+/// Rewards left a router's receiver for their destination
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RewardsDistributed {
+    pub ncn: Pubkey,
+    pub epoch: u64,
+    pub router: Pubkey,
+    pub destination: Pubkey,
+    pub rewards: u64,
+}
+
+/// An epoch account was closed and its rent returned to the account payer
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AccountClosed {
+    pub ncn: Pubkey,
+    pub epoch: u64,
+    pub account: Pubkey,
+    pub discriminator: u8,
+    pub lamports: u64,
+}
+
+/// A crank bounty was paid from the account payer to the signer of a crank step
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CrankBountyPaid {
+    pub ncn: Pubkey,
+    pub epoch: u64,
+    pub cranker: Pubkey,
+    pub lamports: u64,
+    pub slot: u64,
+}
+
+/// Lifecycle events, borsh encoded after `EVENT_PREFIX` in the program's log data
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum TipRouterEvent {
+    VoteCast(VoteCast),
+    ConsensusReached(ConsensusReached),
+    TieBreakerSet(TieBreakerSet),
+    MerkleRootSet(MerkleRootSet),
+    RewardsRouted(RewardsRouted),
+    RewardsDistributed(RewardsDistributed),
+    AccountClosed(AccountClosed),
+    StalledBallotFinalized(StalledBallotFinalized),
+    CrankBountyPaid(CrankBountyPaid),
+}
+
+impl TipRouterEvent {
+    /// Writes the event as `Program data: <prefix> <event>`
+    pub fn emit(&self) {
+        // Serializing into a Vec cannot fail
+        let data = self.try_to_vec().unwrap_or_default();
+        sol_log_data(&[&EVENT_PREFIX, &data]);
+    }
+
+    /// Decodes the fields of a `Program data:` line, returning `None` if they are not an event
+    pub fn from_log_data(fields: &[&[u8]]) -> Option<Self> {
+        let [prefix, data] = fields else {
+            return None;
+        };
+
+        if prefix.ne(&EVENT_PREFIX) {
+            return None;
+        }
+
+        Self::try_from_slice(data).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_log_data() {
+        let event = TipRouterEvent::VoteCast(VoteCast {
+            ncn: Pubkey::new_unique(),
+            epoch: 1,
+            operator: Pubkey::new_unique(),
+            meta_merkle_root: [1; 32],
+            abstain: false,
+            stake_weight: 1_000,
+            slot: 100,
+        });
+        let data = event.try_to_vec().unwrap();
+
+        assert_eq!(
+            TipRouterEvent::from_log_data(&[&EVENT_PREFIX, &data]),
+            Some(event)
+        );
+
+        // Wrong prefix
+        assert_eq!(TipRouterEvent::from_log_data(&[&[0; 8], &data]), None);
+
+        // Missing prefix
+        assert_eq!(TipRouterEvent::from_log_data(&[&data]), None);
+
+        // Truncated event
+        assert_eq!(
+            TipRouterEvent::from_log_data(&[&EVENT_PREFIX, &data[..data.len() - 1]]),
+            None
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::{MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MAX_FEE_SCHEDULE_ENTRIES},
+    discriminators::Discriminators,
+    error::TipRouterError,
+    fees::Fees,
+    loaders::check_load,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FeeScheduleAction {
+    /// Adds an entry after the last scheduled one
+    Append = 0x0,
+    /// Changes the fees of a future entry
+    Replace = 0x1,
+    /// Removes a future entry
+    Cancel = 0x2,
+}
+
+impl TryFrom<u8> for FeeScheduleAction {
+    type Error = TipRouterError;
+
+    fn try_from(action: u8) -> Result<Self, Self::Error> {
+        match action {
+            0x0 => Ok(Self::Append),
+            0x1 => Ok(Self::Replace),
+            0x2 => Ok(Self::Cancel),
+            _ => Err(TipRouterError::InvalidFeeScheduleAction),
+        }
+    }
+}
+
+/// Fee versions announced ahead of time, each taking over from the `FeeConfig` fees once its
+/// activation epoch is reached
+// PDA'd ["fee_schedule", NCN]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct FeeSchedule {
+    /// The NCN the fee schedule is associated with
+    ncn: Pubkey,
+    /// The bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 127],
+    /// Entries ordered by activation epoch, followed by empty entries with an activation epoch of 0
+    entries: [Fees; 8],
+}
+
+impl Discriminator for FeeSchedule {
+    const DISCRIMINATOR: u8 = Discriminators::FeeSchedule as u8;
+}
+
+impl FeeSchedule {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            bump,
+            reserved: [0; 127],
+            entries: [Fees::zeroed(); MAX_FEE_SCHEDULE_ENTRIES],
+        }
+    }
+
+    pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter(
+            [b"fee_schedule".to_vec(), ncn.to_bytes().to_vec()]
+                .iter()
+                .cloned(),
+        )
+    }
+
+    pub fn find_program_address(program_id: &Pubkey, ncn: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (address, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (address, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads the fee schedule if the NCN has initialized one, returning whether it exists
+    pub fn load_if_initialized(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<bool, ProgramError> {
+        if account.data_is_empty() {
+            let expected_pda = Self::find_program_address(program_id, ncn).0;
+            if expected_pda.ne(account.key) {
+                msg!(
+                    "Fee schedule PDA does not match {} != {}",
+                    account.key,
+                    expected_pda
+                );
+                return Err(ProgramError::InvalidSeeds);
+            }
+
+            return Ok(false);
+        }
+
+        Self::load(program_id, account, ncn, expect_writable)?;
+        Ok(true)
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    /// The scheduled entries, in activation order
+    pub fn entries(&self) -> impl Iterator<Item = &Fees> {
+        self.entries.iter().filter(|entry| !Self::is_empty(entry))
+    }
+
+    pub fn entry_count(&self) -> usize {
+        self.entries().count()
+    }
+
+    /// The latest entry that has activated by `current_epoch`
+    pub fn active_fees(&self, current_epoch: u64) -> Option<&Fees> {
+        self.entries()
+            .filter(|entry| entry.activation_epoch() <= current_epoch)
+            .last()
+    }
+
+    /// The entry activating at `activation_epoch`
+    pub fn scheduled_fees(&self, activation_epoch: u64) -> Option<&Fees> {
+        self.entries()
+            .find(|entry| entry.activation_epoch() == activation_epoch)
+    }
+
+    /// Adds `fees` after the last entry, it must activate after it and in a future epoch
+    pub fn append(&mut self, fees: Fees, current_epoch: u64) -> Result<(), TipRouterError> {
+        Self::check_future_epoch(fees.activation_epoch(), current_epoch)?;
+
+        if let Some(last_entry) = self.entries().last() {
+            if fees.activation_epoch() <= last_entry.activation_epoch() {
+                msg!(
+                    "Fees must activate after the last scheduled entry at epoch {}",
+                    last_entry.activation_epoch()
+                );
+                return Err(TipRouterError::InvalidFeeScheduleEpoch);
+            }
+        }
+
+        if self.entry_count() >= MAX_FEE_SCHEDULE_ENTRIES {
+            self.prune(current_epoch);
+        }
+
+        let index = self.entry_count();
+        if index >= MAX_FEE_SCHEDULE_ENTRIES {
+            return Err(TipRouterError::FeeScheduleFull);
+        }
+
+        self.entries[index] = fees;
+
+        Ok(())
+    }
+
+    /// Replaces the future entry activating in the same epoch as `fees`
+    pub fn replace(&mut self, fees: Fees, current_epoch: u64) -> Result<(), TipRouterError> {
+        Self::check_future_epoch(fees.activation_epoch(), current_epoch)?;
+
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|entry| {
+                !Self::is_empty(entry) && entry.activation_epoch() == fees.activation_epoch()
+            })
+            .ok_or(TipRouterError::FeeScheduleEntryNotFound)?;
+
+        *entry = fees;
+
+        Ok(())
+    }
+
+    /// Removes the future entry activating at `activation_epoch`
+    pub fn cancel(
+        &mut self,
+        activation_epoch: u64,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        Self::check_future_epoch(activation_epoch, current_epoch)?;
+
+        let index = self
+            .entries()
+            .position(|entry| entry.activation_epoch() == activation_epoch)
+            .ok_or(TipRouterError::FeeScheduleEntryNotFound)?;
+
+        self.entries[index..].rotate_left(1);
+        self.entries[MAX_FEE_SCHEDULE_ENTRIES - 1] = Fees::zeroed();
+
+        Ok(())
+    }
+
+    /// Makes room by dropping entries superseded by a later active entry, the active one is kept.
+    /// An entry is only dropped once the longest close window has passed since it was superseded,
+    /// so snapshots of past epochs that can still be taken resolve their fees.
+    fn prune(&mut self, current_epoch: u64) {
+        let superseded = self
+            .entries()
+            .skip(1)
+            .take_while(|entry| {
+                entry
+                    .activation_epoch()
+                    .saturating_add(MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE)
+                    <= current_epoch
+            })
+            .count();
+
+        self.entries.rotate_left(superseded);
+        for entry in self.entries[MAX_FEE_SCHEDULE_ENTRIES - superseded..].iter_mut() {
+            *entry = Fees::zeroed();
+        }
+    }
+
+    fn check_future_epoch(activation_epoch: u64, current_epoch: u64) -> Result<(), TipRouterError> {
+        if activation_epoch <= current_epoch {
+            msg!(
+                "Scheduled fees must activate after the current epoch {}",
+                current_epoch
+            );
+            return Err(TipRouterError::InvalidFeeScheduleEpoch);
+        }
+
+        Ok(())
+    }
+
+    fn is_empty(entry: &Fees) -> bool {
+        entry.activation_epoch() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base_fee_group::BaseFeeGroup;
+
+    fn fees(dao_fee_bps: u16, activation_epoch: u64) -> Fees {
+        Fees::new(dao_fee_bps, 0, activation_epoch).unwrap()
+    }
+
+    fn dao_fees(fee_schedule: &FeeSchedule) -> Vec<(u64, u16)> {
+        fee_schedule
+            .entries()
+            .map(|entry| {
+                (
+                    entry.activation_epoch(),
+                    entry.base_fee_bps(BaseFeeGroup::dao()).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + 1 // bump
+            + 127 // reserved
+            + size_of::<Fees>() * MAX_FEE_SCHEDULE_ENTRIES; // entries
+
+        assert_eq!(size_of::<FeeSchedule>(), expected_total);
+    }
+
+    #[test]
+    fn test_fee_schedule_action() {
+        assert_eq!(
+            FeeScheduleAction::try_from(0x0),
+            Ok(FeeScheduleAction::Append)
+        );
+        assert_eq!(
+            FeeScheduleAction::try_from(0x1),
+            Ok(FeeScheduleAction::Replace)
+        );
+        assert_eq!(
+            FeeScheduleAction::try_from(0x2),
+            Ok(FeeScheduleAction::Cancel)
+        );
+        assert_eq!(
+            FeeScheduleAction::try_from(0x3),
+            Err(TipRouterError::InvalidFeeScheduleAction)
+        );
+    }
+
+    #[test]
+    fn test_append() {
+        let mut fee_schedule = FeeSchedule::new(&Pubkey::new_unique(), 0);
+
+        fee_schedule.append(fees(100, 12), 10).unwrap();
+        fee_schedule.append(fees(200, 14), 10).unwrap();
+        assert_eq!(dao_fees(&fee_schedule), vec![(12, 100), (14, 200)]);
+
+        // Entries activate in a future epoch, after the last entry
+        assert_eq!(
+            fee_schedule.append(fees(300, 10), 10),
+            Err(TipRouterError::InvalidFeeScheduleEpoch)
+        );
+        assert_eq!(
+            fee_schedule.append(fees(300, 13), 10),
+            Err(TipRouterError::InvalidFeeScheduleEpoch)
+        );
+
+        assert!(fee_schedule.active_fees(11).is_none());
+        assert_eq!(fee_schedule.active_fees(12).unwrap().activation_epoch(), 12);
+        assert_eq!(fee_schedule.active_fees(13).unwrap().activation_epoch(), 12);
+        assert_eq!(fee_schedule.active_fees(20).unwrap().activation_epoch(), 14);
+
+        for epoch in 15..15 + MAX_FEE_SCHEDULE_ENTRIES as u64 - 2 {
+            fee_schedule.append(fees(100, epoch), 10).unwrap();
+        }
+        assert_eq!(
+            fee_schedule.append(fees(100, 30), 10),
+            Err(TipRouterError::FeeScheduleFull)
+        );
+    }
+
+    #[test]
+    fn test_replace_and_cancel() {
+        let mut fee_schedule = FeeSchedule::new(&Pubkey::new_unique(), 0);
+
+        fee_schedule.append(fees(100, 12), 10).unwrap();
+        fee_schedule.append(fees(200, 14), 10).unwrap();
+        fee_schedule.append(fees(300, 16), 10).unwrap();
+
+        fee_schedule.replace(fees(250, 14), 10).unwrap();
+        assert_eq!(
+            dao_fees(&fee_schedule),
+            vec![(12, 100), (14, 250), (16, 300)]
+        );
+        assert_eq!(
+            fee_schedule.replace(fees(250, 15), 10),
+            Err(TipRouterError::FeeScheduleEntryNotFound)
+        );
+
+        fee_schedule.cancel(14, 10).unwrap();
+        assert_eq!(dao_fees(&fee_schedule), vec![(12, 100), (16, 300)]);
+        assert_eq!(
+            fee_schedule.cancel(14, 10),
+            Err(TipRouterError::FeeScheduleEntryNotFound)
+        );
+
+        // The active entry can't be changed
+        assert_eq!(
+            fee_schedule.replace(fees(150, 12), 12),
+            Err(TipRouterError::InvalidFeeScheduleEpoch)
+        );
+        assert_eq!(
+            fee_schedule.cancel(12, 13),
+            Err(TipRouterError::InvalidFeeScheduleEpoch)
+        );
+        assert_eq!(dao_fees(&fee_schedule), vec![(12, 100), (16, 300)]);
+    }
+
+    #[test]
+    fn test_prune() {
+        let mut fee_schedule = FeeSchedule::new(&Pubkey::new_unique(), 0);
+
+        for epoch in 12..12 + MAX_FEE_SCHEDULE_ENTRIES as u64 {
+            fee_schedule.append(fees(100, epoch), 10).unwrap();
+        }
+
+        // Superseded entries are kept while snapshots of the epochs they cover can be taken
+        assert_eq!(
+            fee_schedule.append(fees(200, 20), 15),
+            Err(TipRouterError::FeeScheduleFull)
+        );
+
+        // A full schedule only drops the entries superseded before the longest close window
+        let current_epoch = 15 + MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE;
+        fee_schedule
+            .append(fees(200, current_epoch + 1), current_epoch)
+            .unwrap();
+        assert_eq!(
+            fee_schedule
+                .entries()
+                .map(|entry| entry.activation_epoch())
+                .collect::<Vec<_>>(),
+            vec![15, 16, 17, 18, 19, current_epoch + 1]
+        );
+        assert_eq!(fee_schedule.active_fees(15).unwrap().activation_epoch(), 15);
+    }
+}
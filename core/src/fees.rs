@@ -5,7 +5,10 @@ use solana_program::pubkey::Pubkey;
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    base_fee_group::BaseFeeGroup, constants::MAX_FEE_BPS, error::TipRouterError,
+    base_fee_group::BaseFeeGroup,
+    constants::MAX_FEE_BPS,
+    error::TipRouterError,
+    fee_schedule::{FeeSchedule, FeeScheduleAction},
     ncn_fee_group::NcnFeeGroup,
 };
 
@@ -62,7 +65,24 @@ impl FeeConfig {
     }
 
     // ------------- Getters -------------
-    pub fn current_fees(&self, current_epoch: u64) -> &Fees {
+    /// The fees active in `current_epoch`. A fee schedule entry takes over from the config's own
+    /// fees once it activates, including when both activate in the same epoch.
+    pub fn current_fees<'a>(
+        &'a self,
+        fee_schedule: Option<&'a FeeSchedule>,
+        current_epoch: u64,
+    ) -> &'a Fees {
+        let config_fees = self.config_fees(current_epoch);
+
+        fee_schedule
+            .and_then(|fee_schedule| fee_schedule.active_fees(current_epoch))
+            .filter(|scheduled_fees| {
+                scheduled_fees.activation_epoch() >= config_fees.activation_epoch()
+            })
+            .unwrap_or(config_fees)
+    }
+
+    fn config_fees(&self, current_epoch: u64) -> &Fees {
         // If either fee is not yet active, return the other one
         if self.fee_1.activation_epoch() > current_epoch {
             return &self.fee_2;
@@ -108,21 +128,30 @@ impl FeeConfig {
     }
 
     // ------------------- TOTALS -------------------
-    pub fn total_fees_bps(&self, current_epoch: u64) -> Result<u64, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+    pub fn total_fees_bps(
+        &self,
+        fee_schedule: Option<&FeeSchedule>,
+        current_epoch: u64,
+    ) -> Result<u64, TipRouterError> {
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         current_fees.total_fees_bps()
     }
 
     pub fn precise_total_fee_bps(
         &self,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<PreciseNumber, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         current_fees.precise_total_fee_bps()
     }
 
-    pub fn adjusted_total_fees_bps(&self, current_epoch: u64) -> Result<u64, TipRouterError> {
-        let total_fees_bps = self.total_fees_bps(current_epoch)?;
+    pub fn adjusted_total_fees_bps(
+        &self,
+        fee_schedule: Option<&FeeSchedule>,
+        current_epoch: u64,
+    ) -> Result<u64, TipRouterError> {
+        let total_fees_bps = self.total_fees_bps(fee_schedule, current_epoch)?;
         self.adjusted_fee_bps(
             total_fees_bps
                 .try_into()
@@ -154,27 +183,30 @@ impl FeeConfig {
     pub fn base_fee_bps(
         &self,
         base_fee_group: BaseFeeGroup,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<u16, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         current_fees.base_fee_bps(base_fee_group)
     }
 
     pub fn precise_base_fee_bps(
         &self,
         base_fee_group: BaseFeeGroup,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<PreciseNumber, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         current_fees.precise_base_fee_bps(base_fee_group)
     }
 
     pub fn adjusted_base_fee_bps(
         &self,
         base_fee_group: BaseFeeGroup,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<u64, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         let fee = current_fees.base_fee_bps(base_fee_group)?;
         self.adjusted_fee_bps(fee)
     }
@@ -182,9 +214,10 @@ impl FeeConfig {
     pub fn adjusted_precise_base_fee_bps(
         &self,
         base_fee_group: BaseFeeGroup,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<PreciseNumber, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         let fee = current_fees.base_fee_bps(base_fee_group)?;
         self.adjusted_precise_fee_bps(fee)
     }
@@ -204,27 +237,30 @@ impl FeeConfig {
     pub fn ncn_fee_bps(
         &self,
         ncn_fee_group: NcnFeeGroup,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<u16, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         current_fees.ncn_fee_bps(ncn_fee_group)
     }
 
     pub fn precise_ncn_fee_bps(
         &self,
         ncn_fee_group: NcnFeeGroup,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<PreciseNumber, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         current_fees.precise_ncn_fee_bps(ncn_fee_group)
     }
 
     pub fn adjusted_ncn_fee_bps(
         &self,
         ncn_fee_group: NcnFeeGroup,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<u64, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         let fee = current_fees.ncn_fee_bps(ncn_fee_group)?;
         self.adjusted_fee_bps(fee)
     }
@@ -232,9 +268,10 @@ impl FeeConfig {
     pub fn adjusted_precise_ncn_fee_bps(
         &self,
         ncn_fee_group: NcnFeeGroup,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<PreciseNumber, TipRouterError> {
-        let current_fees = self.current_fees(current_epoch);
+        let current_fees = self.current_fees(fee_schedule, current_epoch);
         let fee = current_fees.ncn_fee_bps(ncn_fee_group)?;
         self.adjusted_precise_fee_bps(fee)
     }
@@ -268,14 +305,23 @@ impl FeeConfig {
 
     // ------------- Setters -------------
 
-    fn set_fees_to_current(&mut self, current_epoch: u64) -> Result<(), TipRouterError> {
+    fn set_fees_to_current(
+        &mut self,
+        fee_schedule: Option<&FeeSchedule>,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
         if self.fee_1.activation_epoch() > current_epoch
             || self.fee_2.activation_epoch() > current_epoch
         {
             return Err(TipRouterError::FeeNotActive);
         }
 
-        let cloned_current_fees = *self.current_fees(current_epoch);
+        // Carries over an active fee schedule entry, so the update does not revert it. The
+        // activation epoch is kept so the same fees stay updatable until the update sets it.
+        let updatable_activation_epoch = self.updatable_fees(current_epoch).activation_epoch();
+        let mut cloned_current_fees = *self.current_fees(fee_schedule, current_epoch);
+        cloned_current_fees.set_activation_epoch(updatable_activation_epoch);
+
         let updatable_fees = self.updatable_fees(current_epoch);
         *updatable_fees = cloned_current_fees;
 
@@ -292,13 +338,14 @@ impl FeeConfig {
         new_base_fee_bps: Option<u16>,
         ncn_fee_group: Option<NcnFeeGroup>,
         new_ncn_fee_bps: Option<u16>,
+        fee_schedule: Option<&FeeSchedule>,
         current_epoch: u64,
     ) -> Result<(), TipRouterError> {
         // IF NEW CHANGES, COPY OVER CURRENT FEES
         {
             let updatable_fees = self.updatable_fees(current_epoch);
             if updatable_fees.activation_epoch() <= current_epoch {
-                self.set_fees_to_current(current_epoch)?;
+                self.set_fees_to_current(fee_schedule, current_epoch)?;
             }
         }
 
@@ -339,22 +386,79 @@ impl FeeConfig {
         Ok(())
     }
 
+    /// Appends, replaces or cancels a future fee schedule entry, the active fees are untouched.
+    ///
+    /// An appended entry starts from the fees that would otherwise be active at
+    /// `activation_epoch`, a replaced one from its current fees, before the new fees are applied.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_fee_schedule(
+        &self,
+        fee_schedule: &mut FeeSchedule,
+        action: FeeScheduleAction,
+        activation_epoch: u64,
+        base_fee_group: Option<BaseFeeGroup>,
+        new_base_fee_bps: Option<u16>,
+        ncn_fee_group: Option<NcnFeeGroup>,
+        new_ncn_fee_bps: Option<u16>,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        let mut fees = match action {
+            FeeScheduleAction::Append => *self.current_fees(Some(&*fee_schedule), activation_epoch),
+            FeeScheduleAction::Replace => *fee_schedule
+                .scheduled_fees(activation_epoch)
+                .ok_or(TipRouterError::FeeScheduleEntryNotFound)?,
+            FeeScheduleAction::Cancel => {
+                return fee_schedule.cancel(activation_epoch, current_epoch);
+            }
+        };
+
+        fees.set_activation_epoch(activation_epoch);
+
+        if let Some(new_base_fee_bps) = new_base_fee_bps {
+            fees.set_base_fee_bps(base_fee_group.unwrap_or_default(), new_base_fee_bps)?;
+        }
+
+        if let Some(new_ncn_fee_bps) = new_ncn_fee_bps {
+            fees.set_ncn_fee_bps(ncn_fee_group.unwrap_or_default(), new_ncn_fee_bps)?;
+        }
+
+        self.check_fees(&fees)?;
+
+        match action {
+            FeeScheduleAction::Append => fee_schedule.append(fees, current_epoch),
+            _ => fee_schedule.replace(fees, current_epoch),
+        }
+    }
+
     // ------ Helpers -----------------
 
     pub fn check_fees_okay(&self, current_epoch: u64) -> Result<(), TipRouterError> {
+        self.check_fees(self.config_fees(current_epoch))
+    }
+
+    /// Checks every fee schedule entry, as the block engine fee they are adjusted by can change
+    pub fn check_fee_schedule(&self, fee_schedule: &FeeSchedule) -> Result<(), TipRouterError> {
+        for fees in fee_schedule.entries() {
+            self.check_fees(fees)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn check_fees(&self, fees: &Fees) -> Result<(), TipRouterError> {
         if self.block_engine_fee_bps() as u64 > MAX_FEE_BPS {
             return Err(TipRouterError::FeeCapExceeded);
         }
 
         for group in BaseFeeGroup::all_groups().iter() {
-            let _ = self.adjusted_precise_base_fee_bps(*group, current_epoch)?;
+            let _ = self.adjusted_precise_fee_bps(fees.base_fee_bps(*group)?)?;
         }
 
         for group in NcnFeeGroup::all_groups().iter() {
-            let _ = self.adjusted_precise_ncn_fee_bps(*group, current_epoch)?;
+            let _ = self.adjusted_precise_fee_bps(fees.ncn_fee_bps(*group)?)?;
         }
 
-        let total_fees_bps = self.total_fees_bps(current_epoch)?;
+        let total_fees_bps = fees.total_fees_bps()?;
         if total_fees_bps > MAX_FEE_BPS {
             return Err(TipRouterError::FeeCapExceeded);
         }
@@ -671,6 +775,7 @@ mod tests {
                 Some(NEW_DAO_FEE),
                 None,
                 Some(NEW_DEFAULT_NCN_FEE),
+                None,
                 STARTING_EPOCH,
             )
             .unwrap();
@@ -684,8 +789,8 @@ mod tests {
             new_dao_fee_wallet
         );
 
-        let current_fees = fee_config.current_fees(STARTING_EPOCH);
-        let next_epoch_fees = fee_config.current_fees(STARTING_EPOCH + 1);
+        let current_fees = fee_config.current_fees(None, STARTING_EPOCH);
+        let next_epoch_fees = fee_config.current_fees(None, STARTING_EPOCH + 1);
 
         assert_eq!(current_fees.base_fee_bps(dao_fee_group).unwrap(), DAO_FEE);
         assert_eq!(
@@ -713,6 +818,7 @@ mod tests {
                 Some(NEW_NEW_DAO_FEE),
                 None,
                 Some(NEW_NEW_DEFAULT_NCN_FEE),
+                None,
                 STARTING_EPOCH + 1,
             )
             .unwrap();
@@ -726,8 +832,8 @@ mod tests {
             new_dao_fee_wallet
         );
 
-        let current_fees = fee_config.current_fees(STARTING_EPOCH + 1);
-        let next_epoch_fees = fee_config.current_fees(STARTING_EPOCH + 2);
+        let current_fees = fee_config.current_fees(None, STARTING_EPOCH + 1);
+        let next_epoch_fees = fee_config.current_fees(None, STARTING_EPOCH + 2);
 
         assert_eq!(
             current_fees.base_fee_bps(dao_fee_group).unwrap(),
@@ -769,7 +875,7 @@ mod tests {
         .unwrap();
 
         fee_config
-            .update_fee_config(None, None, None, None, None, None, STARTING_EPOCH)
+            .update_fee_config(None, None, None, None, None, None, None, STARTING_EPOCH)
             .unwrap();
 
         assert_eq!(fee_config.block_engine_fee_bps(), BLOCK_ENGINE_FEE);
@@ -781,8 +887,8 @@ mod tests {
             dao_fee_wallet
         );
 
-        let current_fees = fee_config.current_fees(STARTING_EPOCH);
-        let next_epoch_fees = fee_config.current_fees(STARTING_EPOCH + 1);
+        let current_fees = fee_config.current_fees(None, STARTING_EPOCH);
+        let next_epoch_fees = fee_config.current_fees(None, STARTING_EPOCH + 1);
 
         assert_eq!(current_fees.base_fee_bps(dao_fee_group).unwrap(), DAO_FEE);
         assert_eq!(
@@ -832,6 +938,7 @@ mod tests {
                     Some(NEW_BASE_FEE),
                     None,
                     None,
+                    None,
                     STARTING_EPOCH,
                 )
                 .unwrap();
@@ -841,8 +948,8 @@ mod tests {
                 new_base_fee
             );
 
-            let current_fees = fee_config.current_fees(STARTING_EPOCH);
-            let next_epoch_fees = fee_config.current_fees(STARTING_EPOCH + 1);
+            let current_fees = fee_config.current_fees(None, STARTING_EPOCH);
+            let next_epoch_fees = fee_config.current_fees(None, STARTING_EPOCH + 1);
 
             if base_fee_group.group == BaseFeeGroup::default().group {
                 assert_eq!(current_fees.base_fee_bps(*base_fee_group).unwrap(), DAO_FEE);
@@ -865,12 +972,13 @@ mod tests {
                     None,
                     Some(*ncn_fee_group),
                     Some(NEW_NCN_FEE),
+                    None,
                     STARTING_EPOCH,
                 )
                 .unwrap();
 
-            let current_fees = fee_config.current_fees(STARTING_EPOCH);
-            let next_epoch_fees = fee_config.current_fees(STARTING_EPOCH + 1);
+            let current_fees = fee_config.current_fees(None, STARTING_EPOCH);
+            let next_epoch_fees = fee_config.current_fees(None, STARTING_EPOCH + 1);
 
             if ncn_fee_group.group == NcnFeeGroup::default().group {
                 assert_eq!(
@@ -938,6 +1046,7 @@ mod tests {
             None,
             None,
             None,
+            None,
             STARTING_EPOCH,
         );
 
@@ -950,6 +1059,7 @@ mod tests {
             Some(MAX_FEE_BPS + 1),
             None,
             None,
+            None,
             STARTING_EPOCH,
         );
 
@@ -962,6 +1072,7 @@ mod tests {
             None,
             None,
             Some(MAX_FEE_BPS + 1),
+            None,
             STARTING_EPOCH,
         );
 
@@ -972,17 +1083,267 @@ mod tests {
     fn test_current_fee() {
         let mut fee_config = FeeConfig::new(&Pubkey::new_unique(), 100, 200, 300, 5).unwrap();
 
-        assert_eq!(fee_config.current_fees(5).activation_epoch(), 5);
+        assert_eq!(fee_config.current_fees(None, 5).activation_epoch(), 5);
 
         fee_config.fee_1.set_activation_epoch(10);
 
-        assert_eq!(fee_config.current_fees(5).activation_epoch(), 5);
-        assert_eq!(fee_config.current_fees(10).activation_epoch(), 10);
+        assert_eq!(fee_config.current_fees(None, 5).activation_epoch(), 5);
+        assert_eq!(fee_config.current_fees(None, 10).activation_epoch(), 10);
 
         fee_config.fee_2.set_activation_epoch(15);
 
-        assert_eq!(fee_config.current_fees(12).activation_epoch(), 10);
-        assert_eq!(fee_config.current_fees(15).activation_epoch(), 15);
+        assert_eq!(fee_config.current_fees(None, 12).activation_epoch(), 10);
+        assert_eq!(fee_config.current_fees(None, 15).activation_epoch(), 15);
+    }
+
+    #[test]
+    fn test_current_fees_with_schedule() {
+        const DAO_FEE: u16 = 200;
+        const SCHEDULED_DAO_FEE: u16 = 400;
+
+        let dao_fee_group = BaseFeeGroup::default();
+        let mut fee_config = FeeConfig::new(&Pubkey::new_unique(), 100, DAO_FEE, 300, 5).unwrap();
+        let mut fee_schedule = FeeSchedule::new(&Pubkey::new_unique(), 0);
+
+        fee_config
+            .update_fee_schedule(
+                &mut fee_schedule,
+                FeeScheduleAction::Append,
+                10,
+                None,
+                Some(SCHEDULED_DAO_FEE),
+                None,
+                None,
+                5,
+            )
+            .unwrap();
+
+        // Without the schedule, or before the entry activates, the config's fees are used
+        assert_eq!(fee_config.current_fees(None, 10).activation_epoch(), 5);
+        assert_eq!(
+            fee_config
+                .current_fees(Some(&fee_schedule), 9)
+                .activation_epoch(),
+            5
+        );
+
+        let fees = fee_config.current_fees(Some(&fee_schedule), 10);
+        assert_eq!(fees.activation_epoch(), 10);
+        assert_eq!(fees.base_fee_bps(dao_fee_group).unwrap(), SCHEDULED_DAO_FEE);
+
+        // A later config update takes over from the scheduled entry
+        fee_config.fee_1.set_activation_epoch(11);
+        assert_eq!(
+            fee_config
+                .current_fees(Some(&fee_schedule), 11)
+                .activation_epoch(),
+            11
+        );
+    }
+
+    #[test]
+    fn test_fee_getters_with_schedule() {
+        const DAO_FEE: u16 = 200;
+        const DEFAULT_NCN_FEE: u16 = 300;
+        const SCHEDULED_DAO_FEE: u16 = 400;
+
+        let dao_fee_group = BaseFeeGroup::default();
+        let ncn_fee_group = NcnFeeGroup::default();
+        let mut fee_config =
+            FeeConfig::new(&Pubkey::new_unique(), 100, DAO_FEE, DEFAULT_NCN_FEE, 5).unwrap();
+        let mut fee_schedule = FeeSchedule::new(&Pubkey::new_unique(), 0);
+
+        fee_config
+            .update_fee_schedule(
+                &mut fee_schedule,
+                FeeScheduleAction::Append,
+                10,
+                None,
+                Some(SCHEDULED_DAO_FEE),
+                None,
+                None,
+                5,
+            )
+            .unwrap();
+
+        // The getters resolve the same fees the epoch snapshot does
+        let scheduled_fees = fee_config.current_fees(Some(&fee_schedule), 10);
+        assert_eq!(
+            fee_config.total_fees_bps(Some(&fee_schedule), 10).unwrap(),
+            scheduled_fees.total_fees_bps().unwrap()
+        );
+        assert_eq!(
+            fee_config
+                .base_fee_bps(dao_fee_group, Some(&fee_schedule), 10)
+                .unwrap(),
+            SCHEDULED_DAO_FEE
+        );
+        assert_eq!(
+            fee_config
+                .ncn_fee_bps(ncn_fee_group, Some(&fee_schedule), 10)
+                .unwrap(),
+            DEFAULT_NCN_FEE
+        );
+
+        // Without the schedule the config's own fees are used
+        assert_eq!(
+            fee_config.total_fees_bps(None, 10).unwrap(),
+            (DAO_FEE + DEFAULT_NCN_FEE) as u64
+        );
+        assert_ne!(
+            fee_config
+                .adjusted_total_fees_bps(Some(&fee_schedule), 10)
+                .unwrap(),
+            fee_config.adjusted_total_fees_bps(None, 10).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_update_fee_schedule() {
+        const DAO_FEE: u16 = 200;
+        const DEFAULT_NCN_FEE: u16 = 300;
+        const CURRENT_EPOCH: u64 = 5;
+
+        let dao_fee_group = BaseFeeGroup::default();
+        let ncn_fee_group = NcnFeeGroup::default();
+        let fee_config =
+            FeeConfig::new(&Pubkey::new_unique(), 100, DAO_FEE, DEFAULT_NCN_FEE, 5).unwrap();
+        let mut fee_schedule = FeeSchedule::new(&Pubkey::new_unique(), 0);
+
+        // A ramp of DAO fee changes, each entry carries over the fees before it
+        for (activation_epoch, dao_fee) in [(10, 300), (20, 400)] {
+            fee_config
+                .update_fee_schedule(
+                    &mut fee_schedule,
+                    FeeScheduleAction::Append,
+                    activation_epoch,
+                    None,
+                    Some(dao_fee),
+                    None,
+                    None,
+                    CURRENT_EPOCH,
+                )
+                .unwrap();
+        }
+        fee_config
+            .update_fee_schedule(
+                &mut fee_schedule,
+                FeeScheduleAction::Append,
+                30,
+                None,
+                None,
+                None,
+                Some(500),
+                CURRENT_EPOCH,
+            )
+            .unwrap();
+
+        let fees = fee_config.current_fees(Some(&fee_schedule), 30);
+        assert_eq!(fees.base_fee_bps(dao_fee_group).unwrap(), 400);
+        assert_eq!(fees.ncn_fee_bps(ncn_fee_group).unwrap(), 500);
+
+        // Replacing an entry only changes the given fee
+        fee_config
+            .update_fee_schedule(
+                &mut fee_schedule,
+                FeeScheduleAction::Replace,
+                20,
+                None,
+                None,
+                None,
+                Some(600),
+                CURRENT_EPOCH,
+            )
+            .unwrap();
+
+        let fees = fee_config.current_fees(Some(&fee_schedule), 20);
+        assert_eq!(fees.base_fee_bps(dao_fee_group).unwrap(), 400);
+        assert_eq!(fees.ncn_fee_bps(ncn_fee_group).unwrap(), 600);
+
+        fee_config
+            .update_fee_schedule(
+                &mut fee_schedule,
+                FeeScheduleAction::Cancel,
+                20,
+                None,
+                None,
+                None,
+                None,
+                CURRENT_EPOCH,
+            )
+            .unwrap();
+
+        let fees = fee_config.current_fees(Some(&fee_schedule), 25);
+        assert_eq!(fees.activation_epoch(), 10);
+        assert_eq!(fees.base_fee_bps(dao_fee_group).unwrap(), 300);
+
+        // Scheduled fees are held to the same caps as the config's fees
+        let result = fee_config.update_fee_schedule(
+            &mut fee_schedule,
+            FeeScheduleAction::Append,
+            40,
+            None,
+            Some(MAX_FEE_BPS as u16),
+            None,
+            None,
+            CURRENT_EPOCH,
+        );
+        assert_eq!(result, Err(TipRouterError::FeeCapExceeded));
+
+        let result = fee_config.update_fee_schedule(
+            &mut fee_schedule,
+            FeeScheduleAction::Append,
+            40,
+            None,
+            Some(0),
+            None,
+            Some(0),
+            CURRENT_EPOCH,
+        );
+        assert_eq!(result, Err(TipRouterError::TotalFeesCannotBeZero));
+    }
+
+    #[test]
+    fn test_update_fees_keeps_scheduled_fees() {
+        const SCHEDULED_DAO_FEE: u16 = 400;
+        const NEW_NCN_FEE: u16 = 500;
+
+        let dao_fee_group = BaseFeeGroup::default();
+        let ncn_fee_group = NcnFeeGroup::default();
+        let mut fee_config = FeeConfig::new(&Pubkey::new_unique(), 100, 200, 300, 5).unwrap();
+        let mut fee_schedule = FeeSchedule::new(&Pubkey::new_unique(), 0);
+
+        fee_config
+            .update_fee_schedule(
+                &mut fee_schedule,
+                FeeScheduleAction::Append,
+                10,
+                None,
+                Some(SCHEDULED_DAO_FEE),
+                None,
+                None,
+                5,
+            )
+            .unwrap();
+
+        // Updating the config after the entry activates starts from the scheduled fees
+        fee_config
+            .update_fee_config(
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(NEW_NCN_FEE),
+                Some(&fee_schedule),
+                12,
+            )
+            .unwrap();
+
+        let fees = fee_config.current_fees(Some(&fee_schedule), 13);
+        assert_eq!(fees.activation_epoch(), 13);
+        assert_eq!(fees.base_fee_bps(dao_fee_group).unwrap(), SCHEDULED_DAO_FEE);
+        assert_eq!(fees.ncn_fee_bps(ncn_fee_group).unwrap(), NEW_NCN_FEE);
     }
 
     #[test]
@@ -1031,7 +1392,7 @@ mod tests {
         .unwrap();
 
         // Test the function
-        let total = fee_config.precise_total_fee_bps(EPOCH).unwrap();
+        let total = fee_config.precise_total_fee_bps(None, EPOCH).unwrap();
         let expected = PreciseNumber::new((DAO_FEE + DEFAULT_NCN_FEE) as u128).unwrap();
 
         assert!(total.eq(&expected));
@@ -1073,7 +1434,9 @@ mod tests {
         let fee_config = FeeConfig::new(&dao_fee_wallet, 0, BASE_FEE, 0, EPOCH).unwrap();
 
         let base_fee_group = BaseFeeGroup::default();
-        let fee = fee_config.base_fee_bps(base_fee_group, EPOCH).unwrap();
+        let fee = fee_config
+            .base_fee_bps(base_fee_group, None, EPOCH)
+            .unwrap();
         assert_eq!(fee, BASE_FEE);
     }
 
@@ -1087,7 +1450,7 @@ mod tests {
 
         let base_fee_group = BaseFeeGroup::default();
         let precise_fee = fee_config
-            .precise_base_fee_bps(base_fee_group, EPOCH)
+            .precise_base_fee_bps(base_fee_group, None, EPOCH)
             .unwrap();
         let expected = PreciseNumber::new(BASE_FEE.into()).unwrap();
 
@@ -1106,7 +1469,7 @@ mod tests {
 
         let base_fee_group = BaseFeeGroup::default();
         let adjusted_fee = fee_config
-            .adjusted_base_fee_bps(base_fee_group, EPOCH)
+            .adjusted_base_fee_bps(base_fee_group, None, EPOCH)
             .unwrap();
 
         // Expected calculation: BASE_FEE * MAX_FEE_BPS / (MAX_FEE_BPS - BLOCK_ENGINE_FEE)
@@ -1125,7 +1488,7 @@ mod tests {
         let fee_config = FeeConfig::new(&dao_fee_wallet, 0, 0, NCN_FEE, EPOCH).unwrap();
 
         let ncn_fee_group = NcnFeeGroup::default();
-        let fee = fee_config.ncn_fee_bps(ncn_fee_group, EPOCH).unwrap();
+        let fee = fee_config.ncn_fee_bps(ncn_fee_group, None, EPOCH).unwrap();
         assert_eq!(fee, NCN_FEE);
     }
 
@@ -1139,7 +1502,7 @@ mod tests {
 
         let ncn_fee_group = NcnFeeGroup::default();
         let precise_fee = fee_config
-            .precise_ncn_fee_bps(ncn_fee_group, EPOCH)
+            .precise_ncn_fee_bps(ncn_fee_group, None, EPOCH)
             .unwrap();
         let expected = PreciseNumber::new(NCN_FEE.into()).unwrap();
 
@@ -1158,7 +1521,7 @@ mod tests {
 
         let ncn_fee_group = NcnFeeGroup::default();
         let adjusted_fee = fee_config
-            .adjusted_ncn_fee_bps(ncn_fee_group, EPOCH)
+            .adjusted_ncn_fee_bps(ncn_fee_group, None, EPOCH)
             .unwrap();
 
         // Expected calculation: NCN_FEE * MAX_FEE_BPS / (MAX_FEE_BPS - BLOCK_ENGINE_FEE)
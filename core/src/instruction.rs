@@ -1,9 +1,18 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use shank::ShankInstruction;
+use shank::{ShankInstruction, ShankType};
 use solana_program::pubkey::Pubkey;
 
 use crate::config::ConfigAdminRole;
 
+/// A tip distribution account's merkle root, with its proof against the winning meta merkle root
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct MerkleRootUpload {
+    pub proof: Vec<[u8; 32]>,
+    pub merkle_root: [u8; 32],
+    pub max_total_claim: u64,
+    pub max_num_nodes: u64,
+}
+
 #[rustfmt::skip]
 #[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
 pub enum TipRouterInstruction {
@@ -72,13 +81,14 @@ pub enum TipRouterInstruction {
     #[account(2, name = "ncn")]
     #[account(3, writable, name = "account_payer")]
     #[account(4, name = "system_program")]
+    #[account(5, name = "config_extension")]
     ReallocEpochState {
         epoch: u64,
     },
 
     /// Initializes the weight table for a given epoch
     #[account(0, name = "epoch_marker")]
-    #[account(1, name = "epoch_state")]
+    #[account(1, writable, name = "epoch_state")]
     #[account(2, name = "vault_registry")]
     #[account(3, name = "ncn")]
     #[account(4, writable, name = "weight_table")]
@@ -105,6 +115,7 @@ pub enum TipRouterInstruction {
     #[account(1, name = "ncn")]
     #[account(2, writable, name = "weight_table")]
     #[account(3, name = "switchboard_feed")]
+    #[account(4, name = "previous_weight_table")]
     SwitchboardSetWeight{
         st_mint: Pubkey,
         epoch: u64,
@@ -120,13 +131,14 @@ pub enum TipRouterInstruction {
     #[account(5, writable, name = "epoch_snapshot")]
     #[account(6, writable, name = "account_payer")]
     #[account(7, name = "system_program")]
+    #[account(8, name = "fee_schedule")]
     InitializeEpochSnapshot{
         epoch: u64,
     },
 
     /// Initializes the Operator Snapshot
     #[account(0, name = "epoch_marker")]
-    #[account(1, name = "epoch_state")]
+    #[account(1, writable, name = "epoch_state")]
     #[account(2, name = "config")]
     #[account(3, name = "ncn")]
     #[account(4, name = "operator")]
@@ -150,6 +162,7 @@ pub enum TipRouterInstruction {
     #[account(7, writable, name = "operator_snapshot")]
     #[account(8, writable, name = "account_payer")]
     #[account(9, name = "system_program")]
+    #[account(10, name = "config_extension")]
     ReallocOperatorSnapshot {
         epoch: u64,
     },
@@ -167,6 +180,7 @@ pub enum TipRouterInstruction {
     #[account(9, name = "weight_table")]
     #[account(10, writable, name = "epoch_snapshot")]
     #[account(11, writable, name = "operator_snapshot")]
+    #[account(12, name = "config_extension")]
     SnapshotVaultOperatorDelegation{
         epoch: u64,
     },
@@ -176,7 +190,7 @@ pub enum TipRouterInstruction {
     // ---------------------------------------------------- //
     /// Initializes the ballot box for an NCN
     #[account(0, name = "epoch_marker")]
-    #[account(1, name = "epoch_state")]
+    #[account(1, writable, name = "epoch_state")]
     #[account(2, name = "config")]
     #[account(3, writable, name = "ballot_box")]
     #[account(4, name = "ncn")]
@@ -197,18 +211,23 @@ pub enum TipRouterInstruction {
         epoch: u64,
     },
 
-    /// Cast a vote for a merkle root
+    /// Cast a vote for a merkle root, or abstain when the operator has no data to vote with
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, writable, name = "ballot_box")]
     #[account(3, name = "ncn")]
     #[account(4, name = "epoch_snapshot")]
-    #[account(5, name = "operator_snapshot")]
+    #[account(5, writable, name = "operator_snapshot")]
     #[account(6, name = "operator")]
     #[account(7, signer, name = "operator_voter")]
+    #[account(8, writable, name = "operator_record")]
     CastVote {
         meta_merkle_root: [u8; 32],
         epoch: u64,
+        abstain: bool,
+        snapshot_slot: u64,
+        bank_hash: [u8; 32],
+        tip_distribution_account_count: u64,
     },
 
     /// Set the merkle root after consensus is reached
@@ -233,7 +252,7 @@ pub enum TipRouterInstruction {
     // ---------------------------------------------------- //
     /// Initializes the base reward router
     #[account(0, name = "epoch_marker")]
-    #[account(1, name = "epoch_state")]
+    #[account(1, writable, name = "epoch_state")]
     #[account(2, name = "ncn")]
     #[account(3, writable, name = "base_reward_router")]
     #[account(4, writable, name = "base_reward_receiver")]
@@ -277,6 +296,9 @@ pub enum TipRouterInstruction {
     #[account(4, name = "ballot_box")]
     #[account(5, writable, name = "base_reward_router")]
     #[account(6, writable, name = "base_reward_receiver")]
+    #[account(7, writable, name = "account_payer")]
+    #[account(8, name = "system_program")]
+    #[account(9, name = "config_extension")]
     RouteBaseRewards{
         max_iterations: u16,
         epoch: u64,
@@ -289,6 +311,7 @@ pub enum TipRouterInstruction {
     #[account(3, name = "operator_snapshot")]
     #[account(4, writable, name = "ncn_reward_router")]
     #[account(5, writable, name = "ncn_reward_receiver")]
+    #[account(6, name = "config")]
     RouteNcnRewards{
         ncn_fee_group: u8,
         max_iterations: u16,
@@ -357,7 +380,8 @@ pub enum TipRouterInstruction {
         epoch: u64,
     },
 
-    /// Distributes ncn vault rewards
+    /// Distributes ncn vault rewards in the vault's reward asset, the stake pool accounts are
+    /// only used when it is deposited into a stake pool
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -377,6 +401,7 @@ pub enum TipRouterInstruction {
     #[account(15, writable, name = "pool_mint")]
     #[account(16, name = "token_program")]
     #[account(17, name = "system_program")]
+    #[account(18, name = "vault_registry")]
     DistributeNcnVaultRewards{
         ncn_fee_group: u8,
         epoch: u64,
@@ -398,7 +423,8 @@ pub enum TipRouterInstruction {
         bump: u8,
     },
 
-    /// Close an epoch account
+    /// Close an epoch account, operator snapshots take the operator record, ballot box and
+    /// ballot box pages in place of a receiver
     #[account(0, writable, name = "epoch_marker")]
     #[account(1, writable, name = "epoch_state")]
     #[account(2, name = "config")]
@@ -419,17 +445,31 @@ pub enum TipRouterInstruction {
     #[account(0, writable, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, signer, name = "ncn_admin")]
+    #[account(3, writable, name = "config_extension")]
     AdminSetParameters {
         starting_valid_epoch: Option<u64>,
         epochs_before_stall: Option<u64>,
         epochs_after_consensus_before_close: Option<u64>,
         valid_slots_after_consensus: Option<u64>,
-    },
-
-    /// Updates the fee configuration
+        consensus_threshold_bps: Option<u16>,
+        commit_window_slots: Option<u64>,
+        late_vote_grace_slots: Option<u64>,
+        late_vote_max_decay_bps: Option<u16>,
+        plurality_fallback_epochs: Option<u64>,
+        plurality_min_stake_weight_bps: Option<u16>,
+        crank_bounty_lamports: Option<u64>,
+        max_crank_bounties_per_epoch: Option<u64>,
+        account_payer_target_balance: Option<u64>,
+        min_operator_stake_weight: Option<u128>,
+        max_operator_fee_bps: Option<u16>,
+    },
+
+    /// Updates the fee configuration, or with a fee schedule action the fee schedule entry
+    /// activating at `activation_epoch`
     #[account(0, writable, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, signer, name = "ncn_admin")]
+    #[account(3, writable, name = "fee_schedule")]
     AdminSetConfigFees {
         new_block_engine_fee_bps: Option<u16>,
         base_fee_group: Option<u8>,
@@ -437,14 +477,16 @@ pub enum TipRouterInstruction {
         new_base_fee_bps: Option<u16>,
         ncn_fee_group: Option<u8>,
         new_ncn_fee_bps: Option<u16>,
+        fee_schedule_action: Option<u8>,
+        activation_epoch: Option<u64>,
     },
 
-    /// Sets a new secondary admin for the NCN
+    /// Proposes a new secondary admin for the NCN, who must accept before it takes effect
     #[account(0, writable, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, signer, name = "ncn_admin")]
     #[account(3, name = "new_admin")]
-    AdminSetNewAdmin {
+    AdminProposeNewAdmin {
         role: ConfigAdminRole,
     },
 
@@ -454,12 +496,13 @@ pub enum TipRouterInstruction {
     #[account(2, writable, name = "ballot_box")]
     #[account(3, name = "ncn")]
     #[account(4, signer, name = "tie_breaker_admin")]
+    #[account(5, name = "tie_breaker_committee")]
     AdminSetTieBreaker {
         meta_merkle_root: [u8; 32],
         epoch: u64,
     },
 
-    /// Sets a weight
+    /// Sets a weight, bypassing the weight change limit
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "ncn")]
     #[account(2, writable, name = "weight_table")]
@@ -481,6 +524,8 @@ pub enum TipRouterInstruction {
         reward_multiplier_bps: u64,
         switchboard_feed: Option<Pubkey>,
         no_feed_weight: Option<u128>,
+        max_weight_change_bps: Option<u64>,
+        max_stale_slots: Option<u64>,
     },
 
     /// Updates an ST mint in the Vault Registry
@@ -494,5 +539,252 @@ pub enum TipRouterInstruction {
         reward_multiplier_bps: Option<u64>,
         switchboard_feed: Option<Pubkey>,
         no_feed_weight: Option<u128>,
+        max_weight_change_bps: Option<u64>,
+        max_stale_slots: Option<u64>,
+    },
+
+    /// Commit to a merkle root without revealing it, when commit-reveal is enabled
+    #[account(0, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "ballot_box")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "epoch_snapshot")]
+    #[account(5, name = "operator_snapshot")]
+    #[account(6, name = "operator")]
+    #[account(7, signer, name = "operator_voter")]
+    CommitVote {
+        commitment: [u8; 32],
+        epoch: u64,
+    },
+
+    /// Reveal a committed merkle root, casting it as a vote
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "ballot_box")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "epoch_snapshot")]
+    #[account(5, writable, name = "operator_snapshot")]
+    #[account(6, name = "operator")]
+    #[account(7, signer, name = "operator_voter")]
+    #[account(8, writable, name = "operator_record")]
+    RevealVote {
+        meta_merkle_root: [u8; 32],
+        salt: [u8; 32],
+        epoch: u64,
+        snapshot_slot: u64,
+        bank_hash: [u8; 32],
+        tip_distribution_account_count: u64,
+    },
+
+    /// Cancels the pending secondary admin proposal
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "ncn_admin")]
+    AdminCancelNewAdmin,
+
+    /// Accepts a pending secondary admin proposal
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "new_admin")]
+    AdminAcceptNewAdmin {
+        role: ConfigAdminRole,
+    },
+
+    /// Pauses or unpauses groups of instructions, see `PAUSE_*` constants
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "pause_admin")]
+    AdminSetPause {
+        paused: u8,
+    },
+
+    /// Sets the weight table for a given epoch using the median of the registered oracle feeds
+    /// The oracle feeds are passed as remaining accounts, in registration order
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "weight_table")]
+    #[account(3, name = "previous_weight_table")]
+    MedianSetWeight{
+        st_mint: Pubkey,
+        epoch: u64,
+    },
+
+    /// Sets the oracle feeds of an ST mint in the Vault Registry
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, signer, writable, name = "admin")]
+    AdminSetStMintOracleFeeds{
+        st_mint: Pubkey,
+        oracle_feeds: [Pubkey; 3],
+        oracle_feed_types: [u8; 3],
+        max_feed_divergence_bps: u16,
+    },
+
+    /// Retires a vault from the Vault Registry from a future epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, name = "vault")]
+    #[account(4, signer, writable, name = "admin")]
+    AdminRetireVault{
+        retired_epoch: u64,
+    },
+
+    /// Retires an ST mint from the Vault Registry from a future epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, name = "st_mint")]
+    #[account(4, signer, writable, name = "admin")]
+    AdminRetireStMint{
+        retired_epoch: u64,
+    },
+
+    /// Creates or resizes a page chained to the ballot box
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "ballot_box")]
+    #[account(3, writable, name = "ballot_box_page")]
+    #[account(4, name = "ncn")]
+    #[account(5, writable, name = "account_payer")]
+    #[account(6, name = "system_program")]
+    ReallocBallotBoxPage {
+        epoch: u64,
+        page: u8,
+    },
+
+    /// Creates or resizes a page chained to the base reward router
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "base_reward_router")]
+    #[account(3, writable, name = "base_reward_router_page")]
+    #[account(4, name = "ncn")]
+    #[account(5, writable, name = "account_payer")]
+    #[account(6, name = "system_program")]
+    ReallocBaseRewardRouterPage {
+        epoch: u64,
+        page: u8,
+    },
+
+    /// Creates the tie breaker committee, unused member seats are the default pubkey
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "tie_breaker_committee")]
+    #[account(2, name = "ncn")]
+    #[account(3, signer, name = "tie_breaker_admin")]
+    #[account(4, writable, name = "account_payer")]
+    #[account(5, name = "system_program")]
+    AdminInitializeTieBreakerCommittee {
+        members: [Pubkey; 8],
+        threshold: u8,
+    },
+
+    /// Replaces the tie breaker committee members and threshold, clearing pending approvals
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "tie_breaker_committee")]
+    #[account(2, name = "ncn")]
+    #[account(3, signer, name = "tie_breaker_admin")]
+    AdminSetTieBreakerCommittee {
+        members: [Pubkey; 8],
+        threshold: u8,
+    },
+
+    /// Approves a tie breaker ballot as a committee member, setting it once the threshold is met
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "tie_breaker_committee")]
+    #[account(3, writable, name = "ballot_box")]
+    #[account(4, name = "ncn")]
+    #[account(5, signer, name = "member")]
+    TieBreakerApprove {
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    },
+
+    /// Initializes the record of an operator's voting participation across epochs
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "operator_record")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "ncn_operator_state")]
+    #[account(5, writable, name = "account_payer")]
+    #[account(6, name = "system_program")]
+    InitializeOperatorRecord,
+
+    /// Finalizes a stalled ballot box with its plurality ballot, once the plurality fallback is eligible
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "ballot_box")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "epoch_snapshot")]
+    FinalizeStalledBallot {
+        epoch: u64,
     },
+
+    /// Set the merkle roots of several tip distribution accounts after consensus is reached
+    ///
+    /// Each upload is matched, in order, by a `vote_account` and writable
+    /// `tip_distribution_account` pair in the remaining accounts
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, writable, name = "config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "ballot_box")]
+    #[account(4, name = "tip_distribution_config")]
+    #[account(5, name = "tip_distribution_program")]
+    SetMerkleRootBatch {
+        uploads: Vec<MerkleRootUpload>,
+        epoch: u64,
+    },
+
+    /// Sets the asset a vault's rewards are paid out in, see `RewardAssetType`
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, name = "vault")]
+    #[account(4, signer, writable, name = "admin")]
+    AdminSetVaultRewardAsset {
+        reward_asset_type: u8,
+        stake_pool: Pubkey,
+    },
+
+    /// Overrides the ST mint's reward multiplier for a vault, 0 removes the override
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, name = "vault")]
+    #[account(4, signer, writable, name = "admin")]
+    AdminSetVaultRewardMultiplier {
+        reward_multiplier_bps: u64,
+    },
+
+    /// Sweeps untracked dust from a base or NCN reward receiver to the DAO wallet, once the
+    /// receiver's rewards are routed and distributed
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "reward_router")]
+    #[account(4, writable, name = "reward_receiver")]
+    #[account(5, writable, name = "dao_wallet")]
+    #[account(6, name = "system_program")]
+    SweepDust {
+        epoch: u64,
+    },
+
+    /// Creates the fee schedule, letting fee changes be announced several versions ahead
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "fee_schedule")]
+    #[account(2, name = "ncn")]
+    #[account(3, signer, name = "fee_admin")]
+    #[account(4, writable, name = "account_payer")]
+    #[account(5, name = "system_program")]
+    AdminInitializeFeeSchedule,
+
+    /// Creates the config extension, which holds the NCN settings added after `Config`
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "config_extension")]
+    #[account(2, name = "ncn")]
+    #[account(3, signer, name = "ncn_admin")]
+    #[account(4, writable, name = "account_payer")]
+    #[account(5, name = "system_program")]
+    AdminInitializeConfigExtension,
 }
@@ -0,0 +1,153 @@
+use crate::{constants::MAX_FEE_BPS, error::TipRouterError};
+
+/// Linear decay of an operator's NCN fee group rewards for votes cast after consensus
+///
+/// Votes cast up to `grace_slots` after consensus keep their full reward. After that the
+/// forfeited share grows linearly, reaching `max_decay_bps` at the end of the
+/// `valid_slots_after_consensus` window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LateVoteDecay {
+    grace_slots: u64,
+    window_slots: u64,
+    max_decay_bps: u64,
+}
+
+impl LateVoteDecay {
+    pub const fn new(grace_slots: u64, window_slots: u64, max_decay_bps: u64) -> Self {
+        Self {
+            grace_slots,
+            window_slots,
+            max_decay_bps,
+        }
+    }
+
+    pub const fn grace_slots(&self) -> u64 {
+        self.grace_slots
+    }
+
+    pub const fn window_slots(&self) -> u64 {
+        self.window_slots
+    }
+
+    pub const fn max_decay_bps(&self) -> u64 {
+        self.max_decay_bps
+    }
+
+    pub const fn is_enabled(&self) -> bool {
+        self.max_decay_bps > 0
+    }
+
+    /// Share of the reward, in bps, forfeited by a vote cast at `slot_voted`
+    pub fn decay_bps(
+        &self,
+        slot_voted: u64,
+        slot_consensus_reached: u64,
+    ) -> Result<u64, TipRouterError> {
+        if !self.is_enabled() {
+            return Ok(0);
+        }
+
+        // Votes before consensus, or ballots decided by the tie breaker, are never late
+        let slots_late = slot_voted.saturating_sub(slot_consensus_reached);
+        if slots_late <= self.grace_slots {
+            return Ok(0);
+        }
+
+        let decay_slots = self.window_slots.saturating_sub(self.grace_slots);
+        let slots_decayed = slots_late - self.grace_slots;
+        if slots_decayed >= decay_slots {
+            return Ok(self.max_decay_bps.min(MAX_FEE_BPS));
+        }
+
+        let decay_bps = (self.max_decay_bps.min(MAX_FEE_BPS) as u128)
+            .checked_mul(slots_decayed as u128)
+            .and_then(|x| x.checked_div(decay_slots as u128))
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        u64::try_from(decay_bps).map_err(|_| TipRouterError::CastToU64Error)
+    }
+
+    /// Portion of `rewards` forfeited by a vote cast at `slot_voted`
+    pub fn forfeited_rewards(
+        &self,
+        rewards: u64,
+        slot_voted: u64,
+        slot_consensus_reached: u64,
+    ) -> Result<u64, TipRouterError> {
+        let decay_bps = self.decay_bps(slot_voted, slot_consensus_reached)?;
+        if decay_bps == 0 || rewards == 0 {
+            return Ok(0);
+        }
+
+        let forfeited_rewards = (rewards as u128)
+            .checked_mul(decay_bps as u128)
+            .and_then(|x| x.checked_div(MAX_FEE_BPS as u128))
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        u64::try_from(forfeited_rewards).map_err(|_| TipRouterError::CastToU64Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::DEFAULT_CONSENSUS_REACHED_SLOT;
+
+    #[test]
+    fn test_decay_disabled() {
+        let decay = LateVoteDecay::default();
+        assert!(!decay.is_enabled());
+        assert_eq!(decay.decay_bps(10_000, 100), Ok(0));
+        assert_eq!(decay.forfeited_rewards(1_000, 10_000, 100), Ok(0));
+    }
+
+    #[test]
+    fn test_decay_bps() {
+        let decay = LateVoteDecay::new(100, 1_100, 5_000);
+        let consensus_slot = 1_000;
+
+        // Before consensus and within the grace period
+        assert_eq!(decay.decay_bps(900, consensus_slot), Ok(0));
+        assert_eq!(decay.decay_bps(consensus_slot, consensus_slot), Ok(0));
+        assert_eq!(decay.decay_bps(consensus_slot + 100, consensus_slot), Ok(0));
+
+        // Linear between the grace period and the end of the window
+        assert_eq!(
+            decay.decay_bps(consensus_slot + 600, consensus_slot),
+            Ok(2_500)
+        );
+        assert_eq!(
+            decay.decay_bps(consensus_slot + 1_100, consensus_slot),
+            Ok(5_000)
+        );
+
+        // Capped after the window
+        assert_eq!(
+            decay.decay_bps(consensus_slot + 5_000, consensus_slot),
+            Ok(5_000)
+        );
+
+        // Tie breaker ballots have no consensus slot
+        assert_eq!(
+            decay.decay_bps(consensus_slot, DEFAULT_CONSENSUS_REACHED_SLOT),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_decay_grace_covers_window() {
+        let decay = LateVoteDecay::new(1_000, 1_000, 10_000);
+        assert_eq!(decay.decay_bps(1_000, 0), Ok(0));
+        assert_eq!(decay.decay_bps(1_001, 0), Ok(10_000));
+    }
+
+    #[test]
+    fn test_forfeited_rewards() {
+        let decay = LateVoteDecay::new(0, 1_000, 10_000);
+
+        assert_eq!(decay.forfeited_rewards(1_000, 250, 0), Ok(250));
+        assert_eq!(decay.forfeited_rewards(1_000, 1_000, 0), Ok(1_000));
+        assert_eq!(decay.forfeited_rewards(3, 500, 0), Ok(1));
+        assert_eq!(decay.forfeited_rewards(0, 500, 0), Ok(0));
+    }
+}
@@ -3,18 +3,27 @@ pub mod ballot_box;
 pub mod base_fee_group;
 pub mod base_reward_router;
 pub mod config;
+pub mod config_extension;
 pub mod constants;
+pub mod crank_bounty;
 pub mod discriminators;
 pub mod epoch_marker;
 pub mod epoch_snapshot;
 pub mod epoch_state;
 pub mod error;
+pub mod event;
+pub mod fee_schedule;
 pub mod fees;
 pub mod instruction;
+pub mod late_vote_decay;
 pub mod loaders;
 pub mod ncn_fee_group;
 pub mod ncn_reward_router;
+pub mod operator_record;
+pub mod oracle_feed;
+pub mod reward_asset;
 pub mod stake_weight;
+pub mod tie_breaker_committee;
 pub mod utils;
 pub mod vault_registry;
 pub mod weight_entry;
@@ -241,6 +241,14 @@ impl NcnRewardRouter {
                 != Self::NO_LAST_VAULT_OPERATION_DELEGATION_INDEX
     }
 
+    /// Whether rewards have been routed and every routed lamport has been distributed
+    pub fn distribution_complete(&self) -> bool {
+        self.total_rewards() > 0
+            && self.reward_pool() == 0
+            && self.rewards_processed() == 0
+            && !self.still_routing()
+    }
+
     // ------------------------ ROUTING ------------------------
     pub fn route_incoming_rewards(
         &mut self,
@@ -262,6 +270,8 @@ impl NcnRewardRouter {
         Ok(())
     }
 
+    /// Routes the operator's fee out of the reward pool. The fee is the one recorded in the
+    /// snapshot, operators charging more than the config's maximum were left inactive then
     pub fn route_operator_rewards(
         &mut self,
         operator_snapshot: &OperatorSnapshot,
@@ -446,6 +456,19 @@ impl NcnRewardRouter {
         Ok(total_rewards)
     }
 
+    /// Receiver balance above rent that is not tracked as rewards in transit
+    pub fn untracked_rewards(
+        &self,
+        rent_cost: u64,
+        account_balance: u64,
+    ) -> Result<u64, TipRouterError> {
+        let untracked_rewards = account_balance
+            .saturating_sub(self.total_rewards_in_transit()?)
+            .saturating_sub(rent_cost);
+
+        Ok(untracked_rewards)
+    }
+
     pub fn rent_cost(&self, rent: &Rent) -> Result<u64, TipRouterError> {
         let size = 8_u64
             .checked_add(size_of::<Self>() as u64)
@@ -0,0 +1,351 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{discriminators::Discriminators, error::TipRouterError, loaders::check_load};
+
+/// Voting participation of an operator across epochs, kept after the epoch's ballot box is closed
+// PDA'd ["operator_record", NCN, OPERATOR]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct OperatorRecord {
+    /// The NCN the record is associated with
+    ncn: Pubkey,
+    /// The operator the record is associated with
+    operator: Pubkey,
+    /// The bump seed for the PDA
+    bump: u8,
+    /// Epochs the operator had a vote counted in
+    epochs_voted: PodU64,
+    /// Epochs the operator was active in but did not vote
+    epochs_missed: PodU64,
+    /// Epochs the operator voted for a ballot other than the winning ballot, abstains excluded
+    epochs_non_winning: PodU64,
+    /// Sum of the slots between ballot box creation and the operator's first vote, across voted epochs
+    total_slots_to_vote: PodU64,
+    /// The latest epoch the operator voted in
+    last_voted_epoch: PodU64,
+    /// The slot of the operator's latest first vote
+    last_voted_slot: PodU64,
+    /// The latest epoch whose outcome was recorded when its accounts were closed
+    last_closed_epoch: PodU64,
+    /// Epochs the operator's counted vote was an abstain
+    epochs_abstained: PodU64,
+    /// Reserved space
+    reserved: [u8; 120],
+}
+
+impl Discriminator for OperatorRecord {
+    const DISCRIMINATOR: u8 = Discriminators::OperatorRecord as u8;
+}
+
+impl OperatorRecord {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, operator: &Pubkey, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            operator: *operator,
+            bump,
+            epochs_voted: PodU64::from(0),
+            epochs_missed: PodU64::from(0),
+            epochs_non_winning: PodU64::from(0),
+            total_slots_to_vote: PodU64::from(0),
+            last_voted_epoch: PodU64::from(0),
+            last_voted_slot: PodU64::from(0),
+            last_closed_epoch: PodU64::from(0),
+            epochs_abstained: PodU64::from(0),
+            reserved: [0; 120],
+        }
+    }
+
+    pub fn seeds(ncn: &Pubkey, operator: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter(
+            [
+                b"operator_record".to_vec(),
+                ncn.to_bytes().to_vec(),
+                operator.to_bytes().to_vec(),
+            ]
+            .iter()
+            .cloned(),
+        )
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, operator);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (address, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (address, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn, operator).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads the record if the operator has initialized one, returning whether it exists. Votes
+    /// and epoch outcomes are only tracked for operators with a record
+    pub fn load_if_initialized(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<bool, ProgramError> {
+        if account.data_is_empty() {
+            let expected_pda = Self::find_program_address(program_id, ncn, operator).0;
+            if expected_pda.ne(account.key) {
+                msg!(
+                    "Operator record PDA does not match {} != {}",
+                    account.key,
+                    expected_pda
+                );
+                return Err(ProgramError::InvalidSeeds);
+            }
+
+            return Ok(false);
+        }
+
+        Self::load(program_id, account, ncn, operator, expect_writable)?;
+        Ok(true)
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub const fn operator(&self) -> &Pubkey {
+        &self.operator
+    }
+
+    pub fn epochs_voted(&self) -> u64 {
+        self.epochs_voted.into()
+    }
+
+    pub fn epochs_missed(&self) -> u64 {
+        self.epochs_missed.into()
+    }
+
+    pub fn epochs_non_winning(&self) -> u64 {
+        self.epochs_non_winning.into()
+    }
+
+    pub fn total_slots_to_vote(&self) -> u64 {
+        self.total_slots_to_vote.into()
+    }
+
+    pub fn last_voted_epoch(&self) -> u64 {
+        self.last_voted_epoch.into()
+    }
+
+    pub fn last_voted_slot(&self) -> u64 {
+        self.last_voted_slot.into()
+    }
+
+    pub fn last_closed_epoch(&self) -> u64 {
+        self.last_closed_epoch.into()
+    }
+
+    pub fn epochs_abstained(&self) -> u64 {
+        self.epochs_abstained.into()
+    }
+
+    pub fn average_slots_to_vote(&self) -> u64 {
+        self.total_slots_to_vote()
+            .checked_div(self.epochs_voted())
+            .unwrap_or(0)
+    }
+
+    /// Should be called when the operator's first vote of an epoch is counted
+    pub fn record_vote(
+        &mut self,
+        epoch: u64,
+        current_slot: u64,
+        slots_to_vote: u64,
+    ) -> Result<(), TipRouterError> {
+        self.epochs_voted = PodU64::from(
+            self.epochs_voted()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        self.total_slots_to_vote = PodU64::from(
+            self.total_slots_to_vote()
+                .checked_add(slots_to_vote)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        self.last_voted_epoch = PodU64::from(self.last_voted_epoch().max(epoch));
+        self.last_voted_slot = PodU64::from(current_slot);
+
+        Ok(())
+    }
+
+    /// Should be called once per epoch, when the operator's snapshot is closed. An abstain is a
+    /// vote, but not one for a losing ballot
+    pub fn record_epoch_outcome(
+        &mut self,
+        epoch: u64,
+        is_active: bool,
+        voted: bool,
+        abstained: bool,
+        voted_for_winning_ballot: bool,
+    ) -> Result<(), TipRouterError> {
+        if abstained {
+            self.epochs_abstained = PodU64::from(
+                self.epochs_abstained()
+                    .checked_add(1)
+                    .ok_or(TipRouterError::ArithmeticOverflow)?,
+            );
+        } else if voted && !voted_for_winning_ballot {
+            self.epochs_non_winning = PodU64::from(
+                self.epochs_non_winning()
+                    .checked_add(1)
+                    .ok_or(TipRouterError::ArithmeticOverflow)?,
+            );
+        }
+
+        if is_active && !voted {
+            self.epochs_missed = PodU64::from(
+                self.epochs_missed()
+                    .checked_add(1)
+                    .ok_or(TipRouterError::ArithmeticOverflow)?,
+            );
+        }
+
+        self.last_closed_epoch = PodU64::from(self.last_closed_epoch().max(epoch));
+
+        Ok(())
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for OperatorRecord {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+       writeln!(f, "\n\n----------- Operator Record -------------")?;
+       writeln!(f, "  Operator:                     {}", self.operator)?;
+       writeln!(f, "  NCN:                          {}", self.ncn)?;
+       writeln!(f, "  Bump:                         {}", self.bump)?;
+       writeln!(f, "  Epochs Voted:                 {}", self.epochs_voted())?;
+       writeln!(f, "  Epochs Missed:                {}", self.epochs_missed())?;
+       writeln!(f, "  Epochs Non-Winning:           {}", self.epochs_non_winning())?;
+       writeln!(f, "  Epochs Abstained:             {}", self.epochs_abstained())?;
+       writeln!(f, "  Average Slots To Vote:        {}", self.average_slots_to_vote())?;
+       writeln!(f, "  Last Voted Epoch:             {}", self.last_voted_epoch())?;
+       writeln!(f, "  Last Voted Slot:              {}", self.last_voted_slot())?;
+       writeln!(f, "  Last Closed Epoch:            {}", self.last_closed_epoch())?;
+
+       writeln!(f, "\n")?;
+       Ok(())
+   }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        use std::mem::size_of;
+
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<Pubkey>() // operator
+            + 1 // bump
+            + size_of::<PodU64>() // epochs_voted
+            + size_of::<PodU64>() // epochs_missed
+            + size_of::<PodU64>() // epochs_non_winning
+            + size_of::<PodU64>() // total_slots_to_vote
+            + size_of::<PodU64>() // last_voted_epoch
+            + size_of::<PodU64>() // last_voted_slot
+            + size_of::<PodU64>() // last_closed_epoch
+            + size_of::<PodU64>() // epochs_abstained
+            + 120; // reserved
+
+        assert_eq!(size_of::<OperatorRecord>(), expected_total);
+    }
+
+    #[test]
+    fn test_record_vote() {
+        let mut record = OperatorRecord::new(&Pubkey::new_unique(), &Pubkey::new_unique(), 0);
+        assert_eq!(record.average_slots_to_vote(), 0);
+
+        record.record_vote(10, 1_100, 100).unwrap();
+        record.record_vote(12, 3_300, 300).unwrap();
+        assert_eq!(record.epochs_voted(), 2);
+        assert_eq!(record.total_slots_to_vote(), 400);
+        assert_eq!(record.average_slots_to_vote(), 200);
+        assert_eq!(record.last_voted_epoch(), 12);
+        assert_eq!(record.last_voted_slot(), 3_300);
+
+        // A late vote for an older epoch does not move the last voted epoch back
+        record.record_vote(11, 3_400, 1_400).unwrap();
+        assert_eq!(record.last_voted_epoch(), 12);
+        assert_eq!(record.average_slots_to_vote(), 600);
+    }
+
+    #[test]
+    fn test_record_epoch_outcome() {
+        let mut record = OperatorRecord::new(&Pubkey::new_unique(), &Pubkey::new_unique(), 0);
+
+        record
+            .record_epoch_outcome(10, true, true, false, true)
+            .unwrap();
+        assert_eq!(record.epochs_missed(), 0);
+        assert_eq!(record.epochs_non_winning(), 0);
+
+        record
+            .record_epoch_outcome(11, true, true, false, false)
+            .unwrap();
+        assert_eq!(record.epochs_non_winning(), 1);
+
+        record
+            .record_epoch_outcome(12, true, false, false, false)
+            .unwrap();
+        assert_eq!(record.epochs_missed(), 1);
+        assert_eq!(record.epochs_non_winning(), 1);
+
+        // Inactive operators are not expected to vote
+        record
+            .record_epoch_outcome(13, false, false, false, false)
+            .unwrap();
+        assert_eq!(record.epochs_missed(), 1);
+        assert_eq!(record.last_closed_epoch(), 13);
+    }
+
+    #[test]
+    fn test_record_epoch_outcome_abstain() {
+        let mut record = OperatorRecord::new(&Pubkey::new_unique(), &Pubkey::new_unique(), 0);
+
+        // Abstaining is neither a missed epoch nor a vote for a losing ballot
+        record
+            .record_epoch_outcome(10, true, true, true, false)
+            .unwrap();
+        assert_eq!(record.epochs_abstained(), 1);
+        assert_eq!(record.epochs_missed(), 0);
+        assert_eq!(record.epochs_non_winning(), 0);
+
+        record
+            .record_epoch_outcome(11, true, true, false, false)
+            .unwrap();
+        assert_eq!(record.epochs_abstained(), 1);
+        assert_eq!(record.epochs_non_winning(), 1);
+    }
+}
@@ -0,0 +1,267 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankType;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::{MAX_FEE_BPS, WEIGHT_PRECISION},
+    error::TipRouterError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum OracleFeedType {
+    Switchboard = 0x0,
+    Pyth = 0x1,
+}
+
+impl TryFrom<u8> for OracleFeedType {
+    type Error = TipRouterError;
+
+    fn try_from(feed_type: u8) -> Result<Self, Self::Error> {
+        match feed_type {
+            0x0 => Ok(Self::Switchboard),
+            0x1 => Ok(Self::Pyth),
+            _ => Err(TipRouterError::InvalidOracleFeedType),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod, PartialEq, Eq)]
+#[repr(C)]
+pub struct OracleFeed {
+    /// The price feed account
+    feed: Pubkey,
+    /// The format of the feed account, see `OracleFeedType`
+    feed_type: u8,
+}
+
+impl Default for OracleFeed {
+    fn default() -> Self {
+        Self::new(&Pubkey::default(), OracleFeedType::Switchboard)
+    }
+}
+
+impl OracleFeed {
+    pub const fn new(feed: &Pubkey, feed_type: OracleFeedType) -> Self {
+        Self {
+            feed: *feed,
+            feed_type: feed_type as u8,
+        }
+    }
+
+    pub const fn feed(&self) -> &Pubkey {
+        &self.feed
+    }
+
+    pub fn feed_type(&self) -> Result<OracleFeedType, TipRouterError> {
+        OracleFeedType::try_from(self.feed_type)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.feed.eq(&Pubkey::default())
+    }
+}
+
+/// The aggregate price of a Pyth-style ( v2 ) price account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PythPrice {
+    pub price: i64,
+    pub expo: i32,
+    pub publish_slot: u64,
+}
+
+impl PythPrice {
+    const MAGIC: u32 = 0xa1b2_c3d4;
+    const ACCOUNT_TYPE_PRICE: u32 = 3;
+    const STATUS_TRADING: u32 = 1;
+
+    const MAGIC_OFFSET: usize = 0;
+    const ACCOUNT_TYPE_OFFSET: usize = 8;
+    const EXPO_OFFSET: usize = 20;
+    const AGG_PRICE_OFFSET: usize = 208;
+    const AGG_STATUS_OFFSET: usize = 224;
+    const AGG_PUBLISH_SLOT_OFFSET: usize = 232;
+    const MIN_LEN: usize = 240;
+
+    fn read<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(&data[offset..offset + N]);
+        bytes
+    }
+
+    /// Parses the aggregate price, only accepting price accounts that are trading
+    pub fn parse(data: &[u8]) -> Result<Self, TipRouterError> {
+        if data.len() < Self::MIN_LEN {
+            return Err(TipRouterError::BadPythFeed);
+        }
+
+        let magic = u32::from_le_bytes(Self::read(data, Self::MAGIC_OFFSET));
+        let account_type = u32::from_le_bytes(Self::read(data, Self::ACCOUNT_TYPE_OFFSET));
+        let status = u32::from_le_bytes(Self::read(data, Self::AGG_STATUS_OFFSET));
+
+        if magic != Self::MAGIC
+            || account_type != Self::ACCOUNT_TYPE_PRICE
+            || status != Self::STATUS_TRADING
+        {
+            return Err(TipRouterError::BadPythFeed);
+        }
+
+        Ok(Self {
+            price: i64::from_le_bytes(Self::read(data, Self::AGG_PRICE_OFFSET)),
+            expo: i32::from_le_bytes(Self::read(data, Self::EXPO_OFFSET)),
+            publish_slot: u64::from_le_bytes(Self::read(data, Self::AGG_PUBLISH_SLOT_OFFSET)),
+        })
+    }
+
+    /// The price scaled by `WEIGHT_PRECISION`
+    pub fn weight(&self) -> Result<u128, TipRouterError> {
+        if self.price <= 0 {
+            return Err(TipRouterError::BadPythFeed);
+        }
+
+        let price = self.price as u128;
+        let scale = 10u128
+            .checked_pow(self.expo.unsigned_abs())
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        if self.expo >= 0 {
+            price
+                .checked_mul(scale)
+                .and_then(|p| p.checked_mul(WEIGHT_PRECISION))
+                .ok_or(TipRouterError::ArithmeticOverflow)
+        } else {
+            price
+                .checked_mul(WEIGHT_PRECISION)
+                .ok_or(TipRouterError::ArithmeticOverflow)?
+                .checked_div(scale)
+                .ok_or(TipRouterError::DenominatorIsZero)
+        }
+    }
+}
+
+/// Takes the median of the feed weights, rejecting them if the spread between the highest and
+/// lowest weight exceeds `max_divergence_bps` of the median
+pub fn median_weight(weights: &mut [u128], max_divergence_bps: u16) -> Result<u128, ProgramError> {
+    if weights.is_empty() {
+        return Err(TipRouterError::NoOracleFeeds.into());
+    }
+
+    weights.sort_unstable();
+
+    let mid = weights.len() / 2;
+    let median = if weights.len() % 2 == 0 {
+        weights[mid - 1]
+            .checked_add(weights[mid])
+            .ok_or(TipRouterError::ArithmeticOverflow)?
+            .checked_div(2)
+            .ok_or(TipRouterError::DenominatorIsZero)?
+    } else {
+        weights[mid]
+    };
+
+    if median == 0 {
+        return Err(TipRouterError::OracleFeedsDiverged.into());
+    }
+
+    let spread = weights[weights.len() - 1]
+        .checked_sub(weights[0])
+        .ok_or(TipRouterError::ArithmeticUnderflowError)?;
+
+    let divergence_bps = spread
+        .checked_mul(MAX_FEE_BPS as u128)
+        .ok_or(TipRouterError::ArithmeticOverflow)?
+        .checked_div(median)
+        .ok_or(TipRouterError::DenominatorIsZero)?;
+
+    if divergence_bps > max_divergence_bps as u128 {
+        return Err(TipRouterError::OracleFeedsDiverged.into());
+    }
+
+    Ok(median)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pyth_data(price: i64, expo: i32, status: u32, publish_slot: u64) -> Vec<u8> {
+        let mut data = vec![0u8; 3312];
+        data[0..4].copy_from_slice(&PythPrice::MAGIC.to_le_bytes());
+        data[8..12].copy_from_slice(&PythPrice::ACCOUNT_TYPE_PRICE.to_le_bytes());
+        data[20..24].copy_from_slice(&expo.to_le_bytes());
+        data[208..216].copy_from_slice(&price.to_le_bytes());
+        data[224..228].copy_from_slice(&status.to_le_bytes());
+        data[232..240].copy_from_slice(&publish_slot.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_oracle_feed_type() {
+        assert_eq!(
+            OracleFeedType::try_from(0).unwrap(),
+            OracleFeedType::Switchboard
+        );
+        assert_eq!(OracleFeedType::try_from(1).unwrap(), OracleFeedType::Pyth);
+        assert_eq!(
+            OracleFeedType::try_from(2),
+            Err(TipRouterError::InvalidOracleFeedType)
+        );
+        assert!(OracleFeed::default().is_empty());
+    }
+
+    #[test]
+    fn test_parse_pyth_price() {
+        let data = pyth_data(1_150_000_000, -9, 1, 100);
+        let price = PythPrice::parse(&data).unwrap();
+        assert_eq!(price.price, 1_150_000_000);
+        assert_eq!(price.expo, -9);
+        assert_eq!(price.publish_slot, 100);
+        assert_eq!(price.weight().unwrap(), 1_150_000_000);
+
+        let price = PythPrice::parse(&pyth_data(115, -2, 1, 100)).unwrap();
+        assert_eq!(price.weight().unwrap(), 1_150_000_000);
+
+        let price = PythPrice::parse(&pyth_data(2, 1, 1, 100)).unwrap();
+        assert_eq!(price.weight().unwrap(), 20 * WEIGHT_PRECISION);
+
+        // Not trading
+        assert_eq!(
+            PythPrice::parse(&pyth_data(115, -2, 0, 100)),
+            Err(TipRouterError::BadPythFeed)
+        );
+
+        // Bad magic
+        let mut data = pyth_data(115, -2, 1, 100);
+        data[0] = 0;
+        assert_eq!(PythPrice::parse(&data), Err(TipRouterError::BadPythFeed));
+
+        // Too short
+        assert_eq!(
+            PythPrice::parse(&data[..100]),
+            Err(TipRouterError::BadPythFeed)
+        );
+
+        // Negative price
+        let price = PythPrice::parse(&pyth_data(-1, -2, 1, 100)).unwrap();
+        assert_eq!(price.weight(), Err(TipRouterError::BadPythFeed));
+    }
+
+    #[test]
+    fn test_median_weight() {
+        assert_eq!(median_weight(&mut [100], 0).unwrap(), 100);
+        assert_eq!(median_weight(&mut [100, 102], 500).unwrap(), 101);
+        assert_eq!(median_weight(&mut [103, 100, 101], 500).unwrap(), 101);
+
+        // 3 / 101 = 297 bps of spread
+        assert_eq!(
+            median_weight(&mut [103, 100, 101], 296),
+            Err(TipRouterError::OracleFeedsDiverged.into())
+        );
+        assert_eq!(median_weight(&mut [103, 100, 101], 297).unwrap(), 101);
+
+        assert_eq!(
+            median_weight(&mut [], 500),
+            Err(TipRouterError::NoOracleFeeds.into())
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankType;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::TipRouterError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RewardAssetType {
+    /// Deposited into the JitoSOL stake pool
+    JitoSol = 0x0,
+    /// Deposited into the SPL stake pool set on the `RewardAsset`
+    StakePool = 0x1,
+    /// Transferred as lamports into the vault's wrapped SOL token account
+    Sol = 0x2,
+}
+
+impl TryFrom<u8> for RewardAssetType {
+    type Error = TipRouterError;
+
+    fn try_from(asset_type: u8) -> Result<Self, Self::Error> {
+        match asset_type {
+            0x0 => Ok(Self::JitoSol),
+            0x1 => Ok(Self::StakePool),
+            0x2 => Ok(Self::Sol),
+            _ => Err(TipRouterError::InvalidRewardAsset),
+        }
+    }
+}
+
+/// The asset a vault's rewards are paid out in, defaults to JitoSOL
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod, PartialEq, Eq)]
+#[repr(C)]
+pub struct RewardAsset {
+    /// The SPL stake pool rewards are deposited into, only set for `StakePool`
+    stake_pool: Pubkey,
+    /// How the rewards are paid out, see `RewardAssetType`
+    asset_type: u8,
+}
+
+impl Default for RewardAsset {
+    fn default() -> Self {
+        Self::jito_sol()
+    }
+}
+
+impl RewardAsset {
+    pub const fn jito_sol() -> Self {
+        Self {
+            stake_pool: Pubkey::new_from_array([0; 32]),
+            asset_type: RewardAssetType::JitoSol as u8,
+        }
+    }
+
+    /// Checks that only a `StakePool` reward asset names a stake pool
+    pub fn new(asset_type: RewardAssetType, stake_pool: &Pubkey) -> Result<Self, TipRouterError> {
+        let has_stake_pool = stake_pool.ne(&Pubkey::default());
+        if has_stake_pool != (asset_type == RewardAssetType::StakePool) {
+            return Err(TipRouterError::InvalidRewardAsset);
+        }
+
+        Ok(Self {
+            stake_pool: *stake_pool,
+            asset_type: asset_type as u8,
+        })
+    }
+
+    pub fn asset_type(&self) -> Result<RewardAssetType, TipRouterError> {
+        RewardAssetType::try_from(self.asset_type)
+    }
+
+    pub const fn stake_pool(&self) -> &Pubkey {
+        &self.stake_pool
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reward_asset() {
+        let stake_pool = Pubkey::new_unique();
+
+        assert_eq!(
+            RewardAsset::default().asset_type(),
+            Ok(RewardAssetType::JitoSol)
+        );
+        assert_eq!(
+            RewardAsset::new(RewardAssetType::JitoSol, &Pubkey::default()),
+            Ok(RewardAsset::jito_sol())
+        );
+
+        let reward_asset = RewardAsset::new(RewardAssetType::StakePool, &stake_pool).unwrap();
+        assert_eq!(reward_asset.asset_type(), Ok(RewardAssetType::StakePool));
+        assert_eq!(reward_asset.stake_pool(), &stake_pool);
+
+        // A stake pool has to be named, and only for a stake pool
+        assert_eq!(
+            RewardAsset::new(RewardAssetType::StakePool, &Pubkey::default()),
+            Err(TipRouterError::InvalidRewardAsset)
+        );
+        assert_eq!(
+            RewardAsset::new(RewardAssetType::Sol, &stake_pool),
+            Err(TipRouterError::InvalidRewardAsset)
+        );
+
+        assert_eq!(
+            RewardAssetType::try_from(0x3),
+            Err(TipRouterError::InvalidRewardAsset)
+        );
+    }
+}
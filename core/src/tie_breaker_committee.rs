@@ -0,0 +1,340 @@
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::{ShankAccount, ShankType};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::MAX_TIE_BREAKER_COMMITTEE_MEMBERS, discriminators::Discriminators,
+    error::TipRouterError, loaders::check_load,
+};
+
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
+#[repr(C)]
+pub struct TieBreakerMember {
+    /// The member's signing key, default if the seat is empty
+    member: Pubkey,
+    /// The epoch of the member's latest approval
+    approved_epoch: PodU64,
+    /// The meta merkle root of the member's latest approval
+    approved_meta_merkle_root: [u8; 32],
+}
+
+impl Default for TieBreakerMember {
+    fn default() -> Self {
+        Self {
+            member: Pubkey::default(),
+            approved_epoch: PodU64::from(0),
+            approved_meta_merkle_root: [0; 32],
+        }
+    }
+}
+
+impl TieBreakerMember {
+    pub fn new(member: &Pubkey) -> Self {
+        Self {
+            member: *member,
+            ..Self::default()
+        }
+    }
+
+    pub const fn member(&self) -> &Pubkey {
+        &self.member
+    }
+
+    pub fn approved_epoch(&self) -> u64 {
+        self.approved_epoch.into()
+    }
+
+    pub const fn approved_meta_merkle_root(&self) -> &[u8; 32] {
+        &self.approved_meta_merkle_root
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.member.eq(&Pubkey::default())
+    }
+
+    pub fn has_approved(&self, epoch: u64, meta_merkle_root: &[u8; 32]) -> bool {
+        !self.is_empty()
+            && self.approved_epoch() == epoch
+            && self.approved_meta_merkle_root.eq(meta_merkle_root)
+    }
+}
+
+/// M-of-N committee that can decide the ballot of a stalled epoch in place of the tie breaker admin
+// PDA'd ["tie_breaker_committee", NCN]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct TieBreakerCommittee {
+    /// The NCN the committee is associated with
+    ncn: Pubkey,
+    /// The bump seed for the PDA
+    bump: u8,
+    /// The number of member approvals needed to set the tie breaker ballot
+    threshold: u8,
+    /// Reserved space
+    reserved: [u8; 126],
+    /// The committee members and their latest approvals
+    members: [TieBreakerMember; 8],
+}
+
+impl Discriminator for TieBreakerCommittee {
+    const DISCRIMINATOR: u8 = Discriminators::TieBreakerCommittee as u8;
+}
+
+impl TieBreakerCommittee {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            bump,
+            threshold: 0,
+            reserved: [0; 126],
+            members: [TieBreakerMember::default(); MAX_TIE_BREAKER_COMMITTEE_MEMBERS],
+        }
+    }
+
+    pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter(
+            [b"tie_breaker_committee".to_vec(), ncn.to_bytes().to_vec()]
+                .iter()
+                .cloned(),
+        )
+    }
+
+    pub fn find_program_address(program_id: &Pubkey, ncn: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (address, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (address, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads the committee if the NCN has initialized one, returning whether it exists
+    pub fn load_if_initialized(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<bool, ProgramError> {
+        if account.data_is_empty() {
+            let expected_pda = Self::find_program_address(program_id, ncn).0;
+            if expected_pda.ne(account.key) {
+                msg!(
+                    "Tie breaker committee PDA does not match {} != {}",
+                    account.key,
+                    expected_pda
+                );
+                return Err(ProgramError::InvalidSeeds);
+            }
+
+            return Ok(false);
+        }
+
+        Self::load(program_id, account, ncn, expect_writable)?;
+        Ok(true)
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub const fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    pub const fn members(&self) -> &[TieBreakerMember; MAX_TIE_BREAKER_COMMITTEE_MEMBERS] {
+        &self.members
+    }
+
+    pub fn member_count(&self) -> usize {
+        self.members.iter().filter(|m| !m.is_empty()).count()
+    }
+
+    pub fn is_member(&self, member: &Pubkey) -> bool {
+        self.members
+            .iter()
+            .any(|m| !m.is_empty() && m.member.eq(member))
+    }
+
+    /// Replaces the committee, clearing any outstanding approvals
+    pub fn set_committee(
+        &mut self,
+        members: &[Pubkey; MAX_TIE_BREAKER_COMMITTEE_MEMBERS],
+        threshold: u8,
+    ) -> Result<(), TipRouterError> {
+        let active_members: Vec<&Pubkey> = members
+            .iter()
+            .filter(|m| m.ne(&&Pubkey::default()))
+            .collect();
+
+        for (index, member) in active_members.iter().enumerate() {
+            if active_members[..index].contains(member) {
+                msg!("Duplicate committee member {}", member);
+                return Err(TipRouterError::InvalidTieBreakerCommittee);
+            }
+        }
+
+        if threshold == 0 || threshold as usize > active_members.len() {
+            msg!(
+                "Threshold {} must be between 1 and {} members",
+                threshold,
+                active_members.len()
+            );
+            return Err(TipRouterError::InvalidTieBreakerCommittee);
+        }
+
+        for (entry, member) in self.members.iter_mut().zip(members.iter()) {
+            *entry = TieBreakerMember::new(member);
+        }
+        self.threshold = threshold;
+
+        Ok(())
+    }
+
+    /// Number of members whose latest approval is `meta_merkle_root` for `epoch`
+    pub fn approvals(&self, epoch: u64, meta_merkle_root: &[u8; 32]) -> u8 {
+        self.members
+            .iter()
+            .filter(|m| m.has_approved(epoch, meta_merkle_root))
+            .count() as u8
+    }
+
+    /// Records the member's approval, replacing their previous one, and returns the approvals
+    /// for `meta_merkle_root` in `epoch`
+    pub fn approve(
+        &mut self,
+        member: &Pubkey,
+        epoch: u64,
+        meta_merkle_root: &[u8; 32],
+    ) -> Result<u8, TipRouterError> {
+        let entry = self
+            .members
+            .iter_mut()
+            .find(|m| !m.is_empty() && m.member.eq(member))
+            .ok_or(TipRouterError::TieBreakerCommitteeMemberInvalid)?;
+
+        entry.approved_epoch = PodU64::from(epoch);
+        entry.approved_meta_merkle_root = *meta_merkle_root;
+
+        Ok(self.approvals(epoch, meta_merkle_root))
+    }
+
+    pub fn is_threshold_met(&self, epoch: u64, meta_merkle_root: &[u8; 32]) -> bool {
+        self.threshold > 0 && self.approvals(epoch, meta_merkle_root) >= self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        use std::mem::size_of;
+
+        let expected_total = size_of::<Pubkey>() // ncn
+            + 1 // bump
+            + 1 // threshold
+            + 126 // reserved
+            + size_of::<TieBreakerMember>() * MAX_TIE_BREAKER_COMMITTEE_MEMBERS; // members
+
+        assert_eq!(size_of::<TieBreakerCommittee>(), expected_total);
+    }
+
+    fn committee(members: &[Pubkey], threshold: u8) -> TieBreakerCommittee {
+        let mut committee = TieBreakerCommittee::new(&Pubkey::new_unique(), 0);
+        let mut seats = [Pubkey::default(); MAX_TIE_BREAKER_COMMITTEE_MEMBERS];
+        seats[..members.len()].copy_from_slice(members);
+        committee.set_committee(&seats, threshold).unwrap();
+        committee
+    }
+
+    #[test]
+    fn test_set_committee() {
+        let mut committee = TieBreakerCommittee::new(&Pubkey::new_unique(), 0);
+        let member = Pubkey::new_unique();
+
+        let mut seats = [Pubkey::default(); MAX_TIE_BREAKER_COMMITTEE_MEMBERS];
+        seats[0] = member;
+
+        // Threshold out of range
+        assert_eq!(
+            committee.set_committee(&seats, 0),
+            Err(TipRouterError::InvalidTieBreakerCommittee)
+        );
+        assert_eq!(
+            committee.set_committee(&seats, 2),
+            Err(TipRouterError::InvalidTieBreakerCommittee)
+        );
+
+        // Duplicate member
+        seats[3] = member;
+        assert_eq!(
+            committee.set_committee(&seats, 1),
+            Err(TipRouterError::InvalidTieBreakerCommittee)
+        );
+
+        seats[3] = Pubkey::new_unique();
+        committee.set_committee(&seats, 2).unwrap();
+        assert_eq!(committee.member_count(), 2);
+        assert_eq!(committee.threshold(), 2);
+        assert!(committee.is_member(&member));
+        assert!(!committee.is_member(&Pubkey::default()));
+    }
+
+    #[test]
+    fn test_approve() {
+        let members = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut committee = committee(&members, 2);
+
+        let epoch = 10;
+        let root = [1; 32];
+        let other_root = [2; 32];
+
+        assert_eq!(committee.approve(&members[0], epoch, &root), Ok(1));
+        assert!(!committee.is_threshold_met(epoch, &root));
+
+        // Approvals for other roots and epochs do not count
+        assert_eq!(committee.approve(&members[1], epoch, &other_root), Ok(1));
+        assert_eq!(committee.approve(&members[2], epoch + 1, &root), Ok(1));
+        assert!(!committee.is_threshold_met(epoch, &root));
+
+        // Changing an approval replaces the previous one
+        assert_eq!(committee.approve(&members[1], epoch, &root), Ok(2));
+        assert_eq!(committee.approvals(epoch, &other_root), 0);
+        assert!(committee.is_threshold_met(epoch, &root));
+
+        assert_eq!(
+            committee.approve(&Pubkey::new_unique(), epoch, &root),
+            Err(TipRouterError::TieBreakerCommitteeMemberInvalid)
+        );
+
+        // Replacing the committee clears approvals
+        let mut seats = [Pubkey::default(); MAX_TIE_BREAKER_COMMITTEE_MEMBERS];
+        seats[..members.len()].copy_from_slice(&members);
+        committee.set_committee(&seats, 2).unwrap();
+        assert_eq!(committee.approvals(epoch, &root), 0);
+    }
+}
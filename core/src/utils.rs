@@ -1,6 +1,9 @@
 use solana_program::program_error::ProgramError;
 
-use crate::{constants::MAX_REALLOC_BYTES, error::TipRouterError};
+use crate::{
+    constants::{MAX_PAGES, MAX_REALLOC_BYTES},
+    error::TipRouterError,
+};
 
 /// Calculate new size for reallocation, capped at target size
 /// Returns the minimum of (current_size + MAX_REALLOC_BYTES) and target_size
@@ -11,6 +14,18 @@ pub fn get_new_size(current_size: usize, target_size: usize) -> Result<usize, Pr
         .min(target_size))
 }
 
+/// Number of overflow pages needed to hold `count` entries, past the first
+/// `entries_per_page` held by the account itself
+pub fn get_pages_required(count: u64, entries_per_page: usize) -> Result<u8, TipRouterError> {
+    let pages_required = count.saturating_sub(1) / entries_per_page as u64;
+
+    if pages_required > MAX_PAGES as u64 {
+        return Err(TipRouterError::TooManyPages);
+    }
+
+    Ok(pages_required as u8)
+}
+
 #[inline(always)]
 #[track_caller]
 pub fn assert_tip_router_error<T>(
@@ -3,18 +3,23 @@ use std::mem::size_of;
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
-    types::{PodU128, PodU64},
+    types::{PodU128, PodU16, PodU64},
     AccountDeserialize, Discriminator,
 };
 use shank::{ShankAccount, ShankType};
 use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
-    constants::{MAX_ST_MINTS, MAX_VAULTS},
+    constants::{
+        MAX_FEE_BPS, MAX_ORACLE_FEEDS, MAX_STALE_SLOTS, MAX_ST_MINTS, MAX_VAULTS,
+        SWITCHBOARD_MAX_STALE_SLOTS,
+    },
     discriminators::Discriminators,
     error::TipRouterError,
     loaders::check_load,
     ncn_fee_group::NcnFeeGroup,
+    oracle_feed::OracleFeed,
+    reward_asset::{RewardAsset, RewardAssetType},
 };
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
@@ -31,8 +36,19 @@ pub struct StMintEntry {
     switchboard_feed: Pubkey,
     /// The weight when no feed is available
     no_feed_weight: PodU128,
+    /// Additional feeds, the weight is the median of all registered feeds
+    oracle_feeds: [OracleFeed; 3],
+    /// The max spread between the oracle feeds in basis points of the median
+    max_feed_divergence_bps: PodU16,
+    /// The max change in weight from the previous epoch in basis points, 0 is no limit
+    max_weight_change_bps: PodU64,
+    /// The max slots since the last switchboard update before the feed is stale, 0 uses
+    /// `SWITCHBOARD_MAX_STALE_SLOTS`, at most `MAX_STALE_SLOTS`
+    max_stale_slots: PodU64,
+    /// The epoch the mint is retired from, 0 is never retired
+    retired_epoch: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 3],
 }
 
 impl StMintEntry {
@@ -42,6 +58,8 @@ impl StMintEntry {
         reward_multiplier_bps: u64,
         switchboard_feed: &Pubkey,
         no_feed_weight: u128,
+        max_weight_change_bps: u64,
+        max_stale_slots: u64,
     ) -> Self {
         Self {
             st_mint: *st_mint,
@@ -49,7 +67,12 @@ impl StMintEntry {
             reward_multiplier_bps: PodU64::from(reward_multiplier_bps),
             switchboard_feed: *switchboard_feed,
             no_feed_weight: PodU128::from(no_feed_weight),
-            reserved: [0; 128],
+            oracle_feeds: [OracleFeed::default(); MAX_ORACLE_FEEDS],
+            max_feed_divergence_bps: PodU16::from(0),
+            max_weight_change_bps: PodU64::from(max_weight_change_bps),
+            max_stale_slots: PodU64::from(max_stale_slots),
+            retired_epoch: PodU64::from(0),
+            reserved: [0; 3],
         }
     }
 
@@ -73,6 +96,59 @@ impl StMintEntry {
         &self.switchboard_feed
     }
 
+    pub const fn oracle_feeds(&self) -> &[OracleFeed; MAX_ORACLE_FEEDS] {
+        &self.oracle_feeds
+    }
+
+    pub fn registered_oracle_feeds(&self) -> Vec<OracleFeed> {
+        self.oracle_feeds
+            .iter()
+            .filter(|f| !f.is_empty())
+            .copied()
+            .collect()
+    }
+
+    pub fn has_oracle_feeds(&self) -> bool {
+        self.oracle_feeds.iter().any(|f| !f.is_empty())
+    }
+
+    pub fn max_feed_divergence_bps(&self) -> u16 {
+        self.max_feed_divergence_bps.into()
+    }
+
+    pub fn max_weight_change_bps(&self) -> u64 {
+        self.max_weight_change_bps.into()
+    }
+
+    /// The staleness window for the switchboard feed, falling back to `SWITCHBOARD_MAX_STALE_SLOTS`
+    pub fn max_stale_slots(&self) -> u64 {
+        match self.max_stale_slots.into() {
+            0 => SWITCHBOARD_MAX_STALE_SLOTS,
+            max_stale_slots => max_stale_slots,
+        }
+    }
+
+    pub fn retired_epoch(&self) -> u64 {
+        self.retired_epoch.into()
+    }
+
+    pub fn is_retired(&self, epoch: u64) -> bool {
+        let retired_epoch = self.retired_epoch();
+        retired_epoch != 0 && epoch >= retired_epoch
+    }
+
+    /// A retired mint's entry can only be handed to another mint once every epoch that could
+    /// still reference it is past its close window
+    pub fn can_be_freed(&self, epoch: u64, epochs_after_consensus_before_close: u64) -> bool {
+        let retired_epoch = self.retired_epoch();
+        retired_epoch != 0
+            && epoch > retired_epoch.saturating_add(epochs_after_consensus_before_close)
+    }
+
+    pub fn is_active(&self, epoch: u64) -> bool {
+        !self.is_empty() && !self.is_retired(epoch)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.st_mint().eq(&Pubkey::default())
     }
@@ -86,6 +162,8 @@ impl Default for StMintEntry {
             0,
             &Pubkey::default(),
             0,
+            0,
+            0,
         )
     }
 }
@@ -101,8 +179,14 @@ pub struct VaultEntry {
     vault_index: PodU64,
     /// The slot the vault was registered
     slot_registered: PodU64,
+    /// The epoch the vault is retired from, 0 is never retired
+    retired_epoch: PodU64,
+    /// The asset the vault's rewards are paid out in
+    reward_asset: RewardAsset,
+    /// Replaces the ST mint's reward multiplier for this vault, 0 is no override
+    reward_multiplier_bps_override: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 79],
 }
 
 impl VaultEntry {
@@ -115,7 +199,10 @@ impl VaultEntry {
             st_mint: *st_mint,
             vault_index: PodU64::from(vault_index),
             slot_registered: PodU64::from(slot_registered),
-            reserved: [0; 128],
+            retired_epoch: PodU64::from(0),
+            reward_asset: RewardAsset::default(),
+            reward_multiplier_bps_override: PodU64::from(0),
+            reserved: [0; 79],
         }
     }
 
@@ -135,6 +222,44 @@ impl VaultEntry {
         self.slot_registered.into()
     }
 
+    pub fn retired_epoch(&self) -> u64 {
+        self.retired_epoch.into()
+    }
+
+    pub fn is_retired(&self, epoch: u64) -> bool {
+        let retired_epoch = self.retired_epoch();
+        retired_epoch != 0 && epoch >= retired_epoch
+    }
+
+    /// A retired entry can only be handed to another vault once every epoch that
+    /// could still reference it is past its close window
+    pub fn can_be_freed(&self, epoch: u64, epochs_after_consensus_before_close: u64) -> bool {
+        let retired_epoch = self.retired_epoch();
+        retired_epoch != 0
+            && epoch > retired_epoch.saturating_add(epochs_after_consensus_before_close)
+    }
+
+    pub const fn reward_asset(&self) -> &RewardAsset {
+        &self.reward_asset
+    }
+
+    pub fn reward_multiplier_bps_override(&self) -> Option<u64> {
+        match self.reward_multiplier_bps_override.into() {
+            0 => None,
+            reward_multiplier_bps => Some(reward_multiplier_bps),
+        }
+    }
+
+    /// The vault's override if set, otherwise the reward multiplier of its ST mint
+    pub fn reward_multiplier_bps(&self, st_mint_reward_multiplier_bps: u64) -> u64 {
+        self.reward_multiplier_bps_override()
+            .unwrap_or(st_mint_reward_multiplier_bps)
+    }
+
+    pub fn is_active(&self, epoch: u64) -> bool {
+        !self.is_empty() && !self.is_retired(epoch)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.slot_registered() == u64::MAX
     }
@@ -158,8 +283,10 @@ pub struct VaultRegistry {
     pub ncn: Pubkey,
     /// The bump seed for the PDA
     pub bump: u8,
+    /// The number of retired vaults whose entries have been freed for new vaults
+    pub removed_vault_count: PodU64,
     /// Reserved space
-    pub reserved: [u8; 127],
+    pub reserved: [u8; 119],
     /// The list of supported token ( ST ) mints
     pub st_mint_list: [StMintEntry; 64],
     /// The list of vaults
@@ -177,7 +304,8 @@ impl VaultRegistry {
         Self {
             ncn: *ncn,
             bump,
-            reserved: [0; 127],
+            removed_vault_count: PodU64::from(0),
+            reserved: [0; 119],
             st_mint_list: [StMintEntry::default(); MAX_ST_MINTS],
             vault_list: [VaultEntry::default(); MAX_VAULTS],
         }
@@ -187,7 +315,8 @@ impl VaultRegistry {
         // Initializes field by field to avoid overflowing stack
         self.ncn = *ncn;
         self.bump = bump;
-        self.reserved = [0; 127];
+        self.removed_vault_count = PodU64::from(0);
+        self.reserved = [0; 119];
         self.st_mint_list = [StMintEntry::default(); MAX_ST_MINTS];
         self.vault_list = [VaultEntry::default(); MAX_VAULTS];
     }
@@ -228,10 +357,18 @@ impl VaultRegistry {
     }
 
     pub fn check_st_mint_entry(entry: &StMintEntry) -> Result<(), ProgramError> {
-        if entry.no_feed_weight() == 0 && entry.switchboard_feed().eq(&Pubkey::default()) {
+        if entry.no_feed_weight() == 0
+            && entry.switchboard_feed().eq(&Pubkey::default())
+            && !entry.has_oracle_feeds()
+        {
             return Err(TipRouterError::NoFeedWeightOrSwitchboardFeed.into());
         }
 
+        // 0 uses `SWITCHBOARD_MAX_STALE_SLOTS`
+        if u64::from(entry.max_stale_slots) > MAX_STALE_SLOTS {
+            return Err(TipRouterError::InvalidMaxStaleSlots.into());
+        }
+
         Ok(())
     }
 
@@ -242,18 +379,34 @@ impl VaultRegistry {
         reward_multiplier_bps: u64,
         switchboard_feed: &Pubkey,
         no_feed_weight: u128,
+        max_weight_change_bps: u64,
+        max_stale_slots: u64,
+        current_epoch: u64,
+        epochs_after_consensus_before_close: u64,
     ) -> Result<(), ProgramError> {
         // Check if mint is already in the list
-        if self.st_mint_list.iter().any(|m| m.st_mint.eq(st_mint)) {
+        if self
+            .st_mint_list
+            .iter()
+            .any(|m| m.st_mint.eq(st_mint) && !m.is_retired(current_epoch))
+        {
             return Err(TipRouterError::MintInTable.into());
         }
 
-        // Insert at the first empty slot
-        let mint_entry = self
+        // Re-use the entry of the same mint if it was retired, then the first empty slot and
+        // lastly the first retired entry whose epochs can all be closed
+        let index = self
             .st_mint_list
-            .iter_mut()
-            .find(|m| m.st_mint == StMintEntry::default().st_mint)
+            .iter()
+            .position(|m| m.st_mint.eq(st_mint))
+            .or_else(|| self.st_mint_list.iter().position(|m| m.is_empty()))
+            .or_else(|| {
+                self.st_mint_list.iter().position(|m| {
+                    m.can_be_freed(current_epoch, epochs_after_consensus_before_close)
+                })
+            })
             .ok_or(TipRouterError::VaultRegistryListFull)?;
+        let mint_entry = &mut self.st_mint_list[index];
 
         let new_mint_entry = StMintEntry::new(
             st_mint,
@@ -261,6 +414,8 @@ impl VaultRegistry {
             reward_multiplier_bps,
             switchboard_feed,
             no_feed_weight,
+            max_weight_change_bps,
+            max_stale_slots,
         );
 
         Self::check_st_mint_entry(&new_mint_entry)?;
@@ -277,6 +432,8 @@ impl VaultRegistry {
         reward_multiplier_bps: Option<u64>,
         switchboard_feed: Option<Pubkey>,
         no_feed_weight: Option<u128>,
+        max_weight_change_bps: Option<u64>,
+        max_stale_slots: Option<u64>,
     ) -> Result<(), ProgramError> {
         let mint_entry = self
             .st_mint_list
@@ -302,6 +459,54 @@ impl VaultRegistry {
             updated_mint_entry.no_feed_weight = PodU128::from(no_feed_weight);
         }
 
+        if let Some(max_weight_change_bps) = max_weight_change_bps {
+            updated_mint_entry.max_weight_change_bps = PodU64::from(max_weight_change_bps);
+        }
+
+        if let Some(max_stale_slots) = max_stale_slots {
+            updated_mint_entry.max_stale_slots = PodU64::from(max_stale_slots);
+        }
+
+        Self::check_st_mint_entry(&updated_mint_entry)?;
+
+        *mint_entry = updated_mint_entry;
+
+        Ok(())
+    }
+
+    pub fn set_st_mint_oracle_feeds(
+        &mut self,
+        st_mint: &Pubkey,
+        oracle_feeds: [OracleFeed; MAX_ORACLE_FEEDS],
+        max_feed_divergence_bps: u16,
+    ) -> Result<(), ProgramError> {
+        if max_feed_divergence_bps as u64 > MAX_FEE_BPS {
+            return Err(TipRouterError::InvalidFeedDivergence.into());
+        }
+
+        // A feed registered twice would count twice towards the median
+        for (index, oracle_feed) in oracle_feeds.iter().enumerate() {
+            if !oracle_feed.is_empty()
+                && oracle_feeds
+                    .iter()
+                    .skip(index + 1)
+                    .any(|other_feed| other_feed.feed().eq(oracle_feed.feed()))
+            {
+                return Err(TipRouterError::DuplicateOracleFeed.into());
+            }
+        }
+
+        let mint_entry = self
+            .st_mint_list
+            .iter_mut()
+            .find(|m| m.st_mint.eq(st_mint))
+            .ok_or(TipRouterError::MintEntryNotFound)?;
+
+        let mut updated_mint_entry = *mint_entry;
+
+        updated_mint_entry.oracle_feeds = oracle_feeds;
+        updated_mint_entry.max_feed_divergence_bps = PodU16::from(max_feed_divergence_bps);
+
         Self::check_st_mint_entry(&updated_mint_entry)?;
 
         *mint_entry = updated_mint_entry;
@@ -315,23 +520,149 @@ impl VaultRegistry {
         st_mint: &Pubkey,
         vault_index: u64,
         current_slot: u64,
+        current_epoch: u64,
+        epochs_after_consensus_before_close: u64,
     ) -> Result<(), ProgramError> {
         // Check if (mint, vault_index) is already in the list
         if self.vault_list.iter().any(|m| m.vault.eq(vault)) {
             return Ok(());
         }
 
-        // Insert at the first empty slot
-        let mint_entry = self
+        if self
+            .st_mint_list
+            .iter()
+            .any(|m| m.st_mint.eq(st_mint) && m.is_retired(current_epoch))
+        {
+            return Err(TipRouterError::StMintRetired.into());
+        }
+
+        // Insert at the first empty slot, otherwise free the first retired entry whose
+        // epochs can all be closed
+        let index = match self.vault_list.iter().position(|m| m.is_empty()) {
+            Some(index) => index,
+            None => {
+                let index = self
+                    .vault_list
+                    .iter()
+                    .position(|m| {
+                        m.can_be_freed(current_epoch, epochs_after_consensus_before_close)
+                    })
+                    .ok_or(TipRouterError::VaultRegistryListFull)?;
+
+                self.removed_vault_count = PodU64::from(
+                    self.removed_vault_count()
+                        .checked_add(1)
+                        .ok_or(TipRouterError::ArithmeticOverflow)?,
+                );
+
+                index
+            }
+        };
+
+        self.vault_list[index] = VaultEntry::new(vault, st_mint, vault_index, current_slot);
+        Ok(())
+    }
+
+    /// Retires a vault from `retired_epoch` onwards, which has to be a future epoch
+    pub fn retire_vault(
+        &mut self,
+        vault: &Pubkey,
+        retired_epoch: u64,
+        current_epoch: u64,
+    ) -> Result<(), ProgramError> {
+        if retired_epoch <= current_epoch {
+            return Err(TipRouterError::InvalidRetiredEpoch.into());
+        }
+
+        let vault_entry = self
             .vault_list
             .iter_mut()
-            .find(|m| m.st_mint == VaultEntry::default().st_mint)
-            .ok_or(TipRouterError::VaultRegistryListFull)?;
+            .find(|m| m.vault.eq(vault) && !m.is_empty())
+            .ok_or(TipRouterError::VaultNotInRegistry)?;
+
+        if vault_entry.is_retired(current_epoch) {
+            return Err(TipRouterError::VaultRetired.into());
+        }
+
+        vault_entry.retired_epoch = PodU64::from(retired_epoch);
+
+        Ok(())
+    }
+
+    /// Sets the asset the vault's rewards are paid out in from the next distribution
+    pub fn set_vault_reward_asset(
+        &mut self,
+        vault: &Pubkey,
+        reward_asset: RewardAsset,
+    ) -> Result<(), ProgramError> {
+        let vault_entry = self
+            .vault_list
+            .iter_mut()
+            .find(|m| m.vault.eq(vault) && !m.is_empty())
+            .ok_or(TipRouterError::VaultNotInRegistry)?;
+
+        vault_entry.reward_asset = reward_asset;
+
+        Ok(())
+    }
+
+    /// Overrides the ST mint's reward multiplier for the vault, 0 removes the override. Takes
+    /// effect from the next weight table, which snapshots the registry.
+    pub fn set_vault_reward_multiplier_override(
+        &mut self,
+        vault: &Pubkey,
+        reward_multiplier_bps: u64,
+    ) -> Result<(), ProgramError> {
+        let vault_entry = self
+            .vault_list
+            .iter_mut()
+            .find(|m| m.vault.eq(vault) && !m.is_empty())
+            .ok_or(TipRouterError::VaultNotInRegistry)?;
+
+        vault_entry.reward_multiplier_bps_override = PodU64::from(reward_multiplier_bps);
+
+        Ok(())
+    }
+
+    /// Retires an ST mint from `retired_epoch` onwards, which has to be a future epoch. All of
+    /// the mint's vaults have to be retired by then.
+    pub fn retire_st_mint(
+        &mut self,
+        st_mint: &Pubkey,
+        retired_epoch: u64,
+        current_epoch: u64,
+    ) -> Result<(), ProgramError> {
+        if retired_epoch <= current_epoch {
+            return Err(TipRouterError::InvalidRetiredEpoch.into());
+        }
+
+        if self
+            .vault_list
+            .iter()
+            .any(|v| v.st_mint.eq(st_mint) && v.is_active(retired_epoch))
+        {
+            return Err(TipRouterError::StMintHasActiveVaults.into());
+        }
+
+        let mint_entry = self
+            .st_mint_list
+            .iter_mut()
+            .find(|m| m.st_mint.eq(st_mint))
+            .ok_or(TipRouterError::MintEntryNotFound)?;
+
+        if mint_entry.is_retired(current_epoch) {
+            return Err(TipRouterError::StMintRetired.into());
+        }
+
+        mint_entry.retired_epoch = PodU64::from(retired_epoch);
 
-        *mint_entry = VaultEntry::new(vault, st_mint, vault_index, current_slot);
         Ok(())
     }
 
+    pub fn removed_vault_count(&self) -> u64 {
+        self.removed_vault_count.into()
+    }
+
     pub const fn get_vault_entries(&self) -> &[VaultEntry; MAX_VAULTS] {
         &self.vault_list
     }
@@ -348,6 +679,21 @@ impl VaultRegistry {
             .collect()
     }
 
+    pub fn active_vault_count(&self, epoch: u64) -> u64 {
+        self.vault_list
+            .iter()
+            .filter(|m| m.is_active(epoch))
+            .count() as u64
+    }
+
+    pub fn get_active_vault_entries(&self, epoch: u64) -> Vec<VaultEntry> {
+        self.vault_list
+            .iter()
+            .filter(|m| m.is_active(epoch))
+            .copied()
+            .collect()
+    }
+
     pub fn get_valid_mint_entries(&self) -> Vec<StMintEntry> {
         self.st_mint_list
             .iter()
@@ -364,6 +710,23 @@ impl VaultRegistry {
         self.st_mint_list.iter().filter(|m| !m.is_empty()).count()
     }
 
+    pub fn active_st_mint_count(&self, epoch: u64) -> usize {
+        self.st_mint_list
+            .iter()
+            .filter(|m| m.is_active(epoch))
+            .count()
+    }
+
+    pub fn get_vault_entry(&self, vault: &Pubkey) -> Result<VaultEntry, ProgramError> {
+        let vault_entry = self
+            .vault_list
+            .iter()
+            .find(|m| m.vault().eq(vault) && !m.is_empty())
+            .ok_or(TipRouterError::VaultNotInRegistry)?;
+
+        Ok(*vault_entry)
+    }
+
     pub fn get_mint_entry(&self, st_mint: &Pubkey) -> Result<StMintEntry, ProgramError> {
         let mint_entry = self
             .st_mint_list
@@ -380,12 +743,20 @@ impl fmt::Display for VaultRegistry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "\n\n----------- Vault Registry -------------")?;
         writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Removed Vault Count:          {}", self.removed_vault_count())?;
         writeln!(f, "  ST Mints:                     ")?;
         for mint in self.get_valid_mint_entries() {
             writeln!(f, "    Mint:                       {}", mint.st_mint())?;
             writeln!(f, "      Fee Group:                {:?}", mint.ncn_fee_group())?;
             writeln!(f, "      Reward Multiplier:        {}", mint.reward_multiplier_bps())?;
             writeln!(f, "      Switchboard Feed:         {}", mint.switchboard_feed())?;
+            for oracle_feed in mint.registered_oracle_feeds() {
+                writeln!(f, "      Oracle Feed:              {} ({:?})", oracle_feed.feed(), oracle_feed.feed_type())?;
+            }
+            writeln!(f, "      Max Feed Divergence BPS:  {}", mint.max_feed_divergence_bps())?;
+            writeln!(f, "      Max Weight Change BPS:    {}", mint.max_weight_change_bps())?;
+            writeln!(f, "      Max Stale Slots:          {}", mint.max_stale_slots())?;
+            writeln!(f, "      Retired Epoch:            {}", mint.retired_epoch())?;
             writeln!(f, "      No Feed Weight:           {}\n", mint.no_feed_weight())?;
         }
         writeln!(f, "  Vaults:                     ")?;
@@ -393,6 +764,14 @@ impl fmt::Display for VaultRegistry {
             writeln!(f, "    Vault:                      {}", vault.vault())?;
             writeln!(f, "      Mint:                     {}", vault.st_mint())?;
             writeln!(f, "      Index:                    {}", vault.vault_index())?;
+            writeln!(f, "      Retired Epoch:            {}", vault.retired_epoch())?;
+            writeln!(f, "      Reward Asset:             {:?}", vault.reward_asset().asset_type())?;
+            if let Ok(RewardAssetType::StakePool) = vault.reward_asset().asset_type() {
+                writeln!(f, "      Reward Stake Pool:        {}", vault.reward_asset().stake_pool())?;
+            }
+            if let Some(reward_multiplier_bps) = vault.reward_multiplier_bps_override() {
+                writeln!(f, "      Reward Multiplier:        {} (override)", reward_multiplier_bps)?;
+            }
             writeln!(f, "      Slot Registered:          {}\n", vault.slot_registered())?;
         }
 
@@ -404,6 +783,7 @@ impl fmt::Display for VaultRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracle_feed::OracleFeedType;
 
     #[test]
     fn test_len() {
@@ -411,7 +791,8 @@ mod tests {
 
         let expected_total = size_of::<Pubkey>() // ncn
             + 1 // bump
-            + 127 // reserved
+            + size_of::<PodU64>() // removed_vault_count
+            + 119 // reserved
             + size_of::<StMintEntry>() * MAX_ST_MINTS // st_mint_list
             + size_of::<VaultEntry>() * MAX_VAULTS; // vault_list
 
@@ -430,20 +811,49 @@ mod tests {
         // Test 1: Initial registration should succeed
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 0);
         vault_registry
-            .register_st_mint(&mint, NcnFeeGroup::jto(), 1000, &switchboard_feed, 0)
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::jto(),
+                1000,
+                &switchboard_feed,
+                0,
+                0,
+                0,
+                0,
+                10,
+            )
             .unwrap();
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 1);
 
         // Test 2: Trying to add the same mint should fail
-        let result =
-            vault_registry.register_st_mint(&mint, NcnFeeGroup::jto(), 1000, &switchboard_feed, 0);
+        let result = vault_registry.register_st_mint(
+            &mint,
+            NcnFeeGroup::jto(),
+            1000,
+            &switchboard_feed,
+            0,
+            0,
+            0,
+            0,
+            10,
+        );
         assert!(result.is_err());
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 1);
 
         // Test 3: Adding a different mint should succeed
         let mint2 = Pubkey::new_unique();
         vault_registry
-            .register_st_mint(&mint2, NcnFeeGroup::jto(), 1000, &switchboard_feed, 0)
+            .register_st_mint(
+                &mint2,
+                NcnFeeGroup::jto(),
+                1000,
+                &switchboard_feed,
+                0,
+                0,
+                0,
+                0,
+                10,
+            )
             .unwrap();
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 2);
 
@@ -463,6 +873,10 @@ mod tests {
             1000,
             &Pubkey::default(),
             0,
+            0,
+            0,
+            0,
+            10,
         );
         assert!(result.is_err());
         assert_eq!(vault_registry.get_valid_mint_entries().len(), 2);
@@ -471,7 +885,17 @@ mod tests {
         for _ in 2..MAX_ST_MINTS {
             let new_mint = Pubkey::new_unique();
             vault_registry
-                .register_st_mint(&new_mint, NcnFeeGroup::jto(), 1000, &switchboard_feed, 0)
+                .register_st_mint(
+                    &new_mint,
+                    NcnFeeGroup::jto(),
+                    1000,
+                    &switchboard_feed,
+                    0,
+                    0,
+                    0,
+                    0,
+                    10,
+                )
                 .unwrap();
         }
 
@@ -483,6 +907,10 @@ mod tests {
             1000,
             &switchboard_feed,
             0,
+            0,
+            0,
+            0,
+            10,
         );
         assert!(result.is_err());
         assert_eq!(vault_registry.get_valid_mint_entries().len(), MAX_ST_MINTS);
@@ -502,6 +930,10 @@ mod tests {
                 1000,
                 &Pubkey::default(),
                 100,
+                0,
+                0,
+                0,
+                10,
             )
             .unwrap();
 
@@ -518,7 +950,17 @@ mod tests {
 
         // First register a mint to update
         vault_registry
-            .register_st_mint(&mint, NcnFeeGroup::lst(), 1000, &switchboard_feed, 0)
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::lst(),
+                1000,
+                &switchboard_feed,
+                0,
+                0,
+                0,
+                0,
+                10,
+            )
             .unwrap();
 
         // Test 1: Verify initial state
@@ -531,7 +973,15 @@ mod tests {
 
         // Test 2: Update ncn_fee_group only
         vault_registry
-            .set_st_mint(&mint, Some(NcnFeeGroup::lst().group), None, None, None)
+            .set_st_mint(
+                &mint,
+                Some(NcnFeeGroup::lst().group),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
             .unwrap();
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
         assert_eq!(entry.ncn_fee_group(), NcnFeeGroup::lst());
@@ -541,7 +991,7 @@ mod tests {
 
         // Test 3: Update reward_multiplier_bps only
         vault_registry
-            .set_st_mint(&mint, None, Some(2000), None, None)
+            .set_st_mint(&mint, None, Some(2000), None, None, None, None)
             .unwrap();
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
         assert_eq!(entry.ncn_fee_group(), NcnFeeGroup::lst()); // unchanged
@@ -552,7 +1002,15 @@ mod tests {
         // Test 4: Update switchboard_feed only
         let new_switchboard_feed = Pubkey::new_unique();
         vault_registry
-            .set_st_mint(&mint, None, None, Some(new_switchboard_feed), None)
+            .set_st_mint(
+                &mint,
+                None,
+                None,
+                Some(new_switchboard_feed),
+                None,
+                None,
+                None,
+            )
             .unwrap();
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
         assert_eq!(entry.ncn_fee_group(), NcnFeeGroup::lst()); // unchanged
@@ -562,7 +1020,7 @@ mod tests {
 
         // Test 5: Update no_feed_weight only
         vault_registry
-            .set_st_mint(&mint, None, None, None, Some(100))
+            .set_st_mint(&mint, None, None, None, Some(100), None, None)
             .unwrap();
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
         assert_eq!(entry.ncn_fee_group(), NcnFeeGroup::lst()); // unchanged
@@ -578,6 +1036,8 @@ mod tests {
                 Some(3000),
                 Some(switchboard_feed),
                 Some(200),
+                None,
+                None,
             )
             .unwrap();
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
@@ -594,6 +1054,8 @@ mod tests {
             None,
             None,
             None,
+            None,
+            None,
         );
         assert_eq!(
             result.unwrap_err(),
@@ -601,8 +1063,15 @@ mod tests {
         );
 
         // Test 8: Setting both switchboard_feed and no_feed_weight to invalid values should fail
-        let result =
-            vault_registry.set_st_mint(&mint, None, None, Some(Pubkey::default()), Some(0));
+        let result = vault_registry.set_st_mint(
+            &mint,
+            None,
+            None,
+            Some(Pubkey::default()),
+            Some(0),
+            None,
+            None,
+        );
         assert!(result.is_err());
 
         // Test 9: Verify original values remain after failed update
@@ -619,10 +1088,403 @@ mod tests {
             None,
             None,
             None,
+            None,
+            None,
         );
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_retire_vault() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+
+        vault_registry
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::lst(),
+                0,
+                &Pubkey::default(),
+                1,
+                0,
+                0,
+                0,
+                10,
+            )
+            .unwrap();
+        vault_registry
+            .register_vault(&vault, &mint, 0, 0, 0, 10)
+            .unwrap();
+
+        // Has to be a future epoch
+        assert_eq!(
+            vault_registry.retire_vault(&vault, 10, 10),
+            Err(TipRouterError::InvalidRetiredEpoch.into())
+        );
+        assert_eq!(
+            vault_registry.retire_vault(&Pubkey::new_unique(), 11, 10),
+            Err(TipRouterError::VaultNotInRegistry.into())
+        );
+
+        vault_registry.retire_vault(&vault, 11, 10).unwrap();
+        assert_eq!(vault_registry.active_vault_count(10), 1);
+        assert_eq!(vault_registry.active_vault_count(11), 0);
+        assert_eq!(vault_registry.vault_count(), 1);
+
+        // The retirement can be moved until it takes effect
+        vault_registry.retire_vault(&vault, 12, 10).unwrap();
+        assert_eq!(vault_registry.active_vault_count(11), 1);
+        assert_eq!(
+            vault_registry.retire_vault(&vault, 13, 12),
+            Err(TipRouterError::VaultRetired.into())
+        );
+
+        // Registering again is a no-op
+        vault_registry
+            .register_vault(&vault, &mint, 0, 0, 12, 10)
+            .unwrap();
+        assert_eq!(vault_registry.active_vault_count(12), 0);
+    }
+
+    #[test]
+    fn test_set_vault_reward_asset() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+
+        vault_registry
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::lst(),
+                0,
+                &Pubkey::default(),
+                1,
+                0,
+                0,
+                0,
+                10,
+            )
+            .unwrap();
+        vault_registry
+            .register_vault(&vault, &mint, 0, 0, 0, 10)
+            .unwrap();
+
+        // Vaults are paid in JitoSOL until set otherwise
+        let vault_entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert_eq!(*vault_entry.reward_asset(), RewardAsset::jito_sol());
+
+        let reward_asset =
+            RewardAsset::new(RewardAssetType::StakePool, &Pubkey::new_unique()).unwrap();
+        vault_registry
+            .set_vault_reward_asset(&vault, reward_asset)
+            .unwrap();
+
+        let vault_entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert_eq!(*vault_entry.reward_asset(), reward_asset);
+
+        assert_eq!(
+            vault_registry.set_vault_reward_asset(&Pubkey::new_unique(), reward_asset),
+            Err(TipRouterError::VaultNotInRegistry.into())
+        );
+    }
+
+    #[test]
+    fn test_set_vault_reward_multiplier_override() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+
+        vault_registry
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::lst(),
+                1000,
+                &Pubkey::default(),
+                1,
+                0,
+                0,
+                0,
+                10,
+            )
+            .unwrap();
+        vault_registry
+            .register_vault(&vault, &mint, 0, 0, 0, 10)
+            .unwrap();
+
+        // Without an override the ST mint's multiplier applies
+        let vault_entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert_eq!(vault_entry.reward_multiplier_bps_override(), None);
+        assert_eq!(vault_entry.reward_multiplier_bps(1000), 1000);
+
+        vault_registry
+            .set_vault_reward_multiplier_override(&vault, 15000)
+            .unwrap();
+
+        let vault_entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert_eq!(vault_entry.reward_multiplier_bps_override(), Some(15000));
+        assert_eq!(vault_entry.reward_multiplier_bps(1000), 15000);
+
+        // 0 removes the override
+        vault_registry
+            .set_vault_reward_multiplier_override(&vault, 0)
+            .unwrap();
+
+        let vault_entry = vault_registry.get_vault_entry(&vault).unwrap();
+        assert_eq!(vault_entry.reward_multiplier_bps_override(), None);
+        assert_eq!(vault_entry.reward_multiplier_bps(1000), 1000);
+
+        assert_eq!(
+            vault_registry.set_vault_reward_multiplier_override(&Pubkey::new_unique(), 15000),
+            Err(TipRouterError::VaultNotInRegistry.into())
+        );
+    }
+
+    #[test]
+    fn test_retired_vault_entry_is_freed() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+
+        vault_registry
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::lst(),
+                0,
+                &Pubkey::default(),
+                1,
+                0,
+                0,
+                0,
+                10,
+            )
+            .unwrap();
+
+        let vaults: Vec<Pubkey> = (0..MAX_VAULTS).map(|_| Pubkey::new_unique()).collect();
+        for (i, vault) in vaults.iter().enumerate() {
+            vault_registry
+                .register_vault(vault, &mint, i as u64, 0, 0, 10)
+                .unwrap();
+        }
+
+        let new_vault = Pubkey::new_unique();
+        assert_eq!(
+            vault_registry.register_vault(&new_vault, &mint, MAX_VAULTS as u64, 0, 0, 10),
+            Err(TipRouterError::VaultRegistryListFull.into())
+        );
+
+        vault_registry.retire_vault(&vaults[3], 1, 0).unwrap();
+
+        // Not freed until the retirement takes effect
+        assert_eq!(
+            vault_registry.register_vault(&new_vault, &mint, MAX_VAULTS as u64, 0, 0, 10),
+            Err(TipRouterError::VaultRegistryListFull.into())
+        );
+
+        // Nor while epochs that could still reference the retired vault can't be closed
+        for epoch in [1, 11] {
+            assert_eq!(
+                vault_registry.register_vault(&new_vault, &mint, MAX_VAULTS as u64, 0, epoch, 10),
+                Err(TipRouterError::VaultRegistryListFull.into())
+            );
+        }
+
+        vault_registry
+            .register_vault(&new_vault, &mint, MAX_VAULTS as u64, 0, 12, 10)
+            .unwrap();
+        assert_eq!(vault_registry.vault_list[3].vault(), &new_vault);
+        assert_eq!(vault_registry.vault_count(), MAX_VAULTS as u64);
+        assert_eq!(vault_registry.removed_vault_count(), 1);
+    }
+
+    #[test]
+    fn test_retire_st_mint() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+
+        vault_registry
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::lst(),
+                0,
+                &Pubkey::default(),
+                1,
+                0,
+                0,
+                0,
+                10,
+            )
+            .unwrap();
+        vault_registry
+            .register_vault(&vault, &mint, 0, 0, 0, 10)
+            .unwrap();
+
+        // Vaults of the mint have to be retired first
+        assert_eq!(
+            vault_registry.retire_st_mint(&mint, 5, 0),
+            Err(TipRouterError::StMintHasActiveVaults.into())
+        );
+
+        vault_registry.retire_vault(&vault, 5, 0).unwrap();
+        assert_eq!(
+            vault_registry.retire_st_mint(&mint, 4, 0),
+            Err(TipRouterError::StMintHasActiveVaults.into())
+        );
+        vault_registry.retire_st_mint(&mint, 5, 0).unwrap();
+
+        assert_eq!(vault_registry.active_st_mint_count(4), 1);
+        assert_eq!(vault_registry.active_st_mint_count(5), 0);
+
+        // No new vaults for a retired mint
+        assert_eq!(
+            vault_registry.register_vault(&Pubkey::new_unique(), &mint, 1, 0, 5, 10),
+            Err(TipRouterError::StMintRetired.into())
+        );
+
+        // The mint can be registered again once retired
+        assert_eq!(
+            vault_registry.register_st_mint(
+                &mint,
+                NcnFeeGroup::lst(),
+                0,
+                &Pubkey::default(),
+                1,
+                0,
+                0,
+                4,
+                10
+            ),
+            Err(TipRouterError::MintInTable.into())
+        );
+        vault_registry
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::jto(),
+                0,
+                &Pubkey::default(),
+                1,
+                0,
+                0,
+                5,
+                10,
+            )
+            .unwrap();
+        assert_eq!(vault_registry.st_mint_count(), 1);
+        assert_eq!(vault_registry.active_st_mint_count(5), 1);
+        assert_eq!(
+            vault_registry
+                .get_mint_entry(&mint)
+                .unwrap()
+                .ncn_fee_group(),
+            NcnFeeGroup::jto()
+        );
+    }
+
+    #[test]
+    fn test_retired_st_mint_entry_reuse() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+
+        let mints: Vec<Pubkey> = (0..MAX_ST_MINTS).map(|_| Pubkey::new_unique()).collect();
+        for mint in mints.iter() {
+            vault_registry
+                .register_st_mint(
+                    mint,
+                    NcnFeeGroup::lst(),
+                    0,
+                    &Pubkey::default(),
+                    1,
+                    0,
+                    0,
+                    0,
+                    10,
+                )
+                .unwrap();
+        }
+
+        vault_registry.retire_st_mint(&mints[3], 5, 0).unwrap();
+
+        // The retired entry is kept until every epoch that could still use it can be closed
+        let new_mint = Pubkey::new_unique();
+        for epoch in [5, 15] {
+            assert_eq!(
+                vault_registry.register_st_mint(
+                    &new_mint,
+                    NcnFeeGroup::lst(),
+                    0,
+                    &Pubkey::default(),
+                    1,
+                    0,
+                    0,
+                    epoch,
+                    10
+                ),
+                Err(TipRouterError::VaultRegistryListFull.into())
+            );
+        }
+
+        vault_registry
+            .register_st_mint(
+                &new_mint,
+                NcnFeeGroup::lst(),
+                0,
+                &Pubkey::default(),
+                1,
+                0,
+                0,
+                16,
+                10,
+            )
+            .unwrap();
+        assert_eq!(vault_registry.st_mint_list[3].st_mint(), &new_mint);
+    }
+
+    #[test]
+    fn test_max_stale_slots() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+
+        vault_registry
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::lst(),
+                1000,
+                &Pubkey::new_unique(),
+                0,
+                0,
+                0,
+                0,
+                10,
+            )
+            .unwrap();
+
+        // 0 falls back to the global default
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.max_stale_slots(), SWITCHBOARD_MAX_STALE_SLOTS);
+
+        vault_registry
+            .set_st_mint(&mint, None, None, None, None, None, Some(1000))
+            .unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.max_stale_slots(), 1000);
+
+        // Other updates leave the window untouched
+        vault_registry
+            .set_st_mint(&mint, None, Some(2000), None, None, None, None)
+            .unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.max_stale_slots(), 1000);
+
+        // The window is bounded
+        vault_registry
+            .set_st_mint(&mint, None, None, None, None, None, Some(MAX_STALE_SLOTS))
+            .unwrap();
+        let result =
+            vault_registry.set_st_mint(&mint, None, None, None, None, None, Some(u64::MAX));
+        assert_eq!(result, Err(TipRouterError::InvalidMaxStaleSlots.into()));
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.max_stale_slots(), MAX_STALE_SLOTS);
+    }
+
     #[test]
     fn test_mint_count() {
         let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
@@ -630,7 +1492,7 @@ mod tests {
 
         for i in 0..3 {
             vault_registry
-                .register_vault(&Pubkey::new_unique(), &Pubkey::new_unique(), i, 0)
+                .register_vault(&Pubkey::new_unique(), &Pubkey::new_unique(), i, 0, 0, 10)
                 .unwrap();
         }
         assert_eq!(vault_registry.vault_count(), 3);
@@ -643,10 +1505,30 @@ mod tests {
         let mint1 = Pubkey::new_unique();
         let mint2 = Pubkey::new_unique();
         vault_registry
-            .register_st_mint(&mint1, NcnFeeGroup::jto(), 0, &Pubkey::new_unique(), 0)
+            .register_st_mint(
+                &mint1,
+                NcnFeeGroup::jto(),
+                0,
+                &Pubkey::new_unique(),
+                0,
+                0,
+                0,
+                0,
+                10,
+            )
             .unwrap();
         vault_registry
-            .register_st_mint(&mint2, NcnFeeGroup::jto(), 0, &Pubkey::new_unique(), 0)
+            .register_st_mint(
+                &mint2,
+                NcnFeeGroup::jto(),
+                0,
+                &Pubkey::new_unique(),
+                0,
+                0,
+                0,
+                0,
+                10,
+            )
             .unwrap();
 
         let result = vault_registry.register_st_mint(
@@ -655,8 +1537,92 @@ mod tests {
             0,
             &Pubkey::new_unique(),
             0,
+            0,
+            0,
+            0,
+            10,
         );
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_set_st_mint_oracle_feeds() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+
+        vault_registry
+            .register_st_mint(
+                &mint,
+                NcnFeeGroup::lst(),
+                1000,
+                &Pubkey::default(),
+                100,
+                0,
+                0,
+                0,
+                10,
+            )
+            .unwrap();
+
+        let feed_a = Pubkey::new_unique();
+        let feed_b = Pubkey::new_unique();
+        let oracle_feeds = [
+            OracleFeed::new(&feed_a, OracleFeedType::Switchboard),
+            OracleFeed::new(&feed_b, OracleFeedType::Pyth),
+            OracleFeed::default(),
+        ];
+
+        vault_registry
+            .set_st_mint_oracle_feeds(&mint, oracle_feeds, 200)
+            .unwrap();
+
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert!(entry.has_oracle_feeds());
+        assert_eq!(entry.registered_oracle_feeds().len(), 2);
+        assert_eq!(entry.oracle_feeds()[1].feed(), &feed_b);
+        assert_eq!(
+            entry.oracle_feeds()[1].feed_type().unwrap(),
+            OracleFeedType::Pyth
+        );
+        assert_eq!(entry.max_feed_divergence_bps(), 200);
+
+        // Oracle feeds alone satisfy the feed requirement
+        vault_registry
+            .set_st_mint(&mint, None, None, None, Some(0), None, None)
+            .unwrap();
+
+        // Divergence over 100% is invalid
+        let result =
+            vault_registry.set_st_mint_oracle_feeds(&mint, oracle_feeds, MAX_FEE_BPS as u16 + 1);
+        assert_eq!(result, Err(TipRouterError::InvalidFeedDivergence.into()));
+
+        // Clearing the oracle feeds without a fallback is invalid
+        let result = vault_registry.set_st_mint_oracle_feeds(
+            &mint,
+            [OracleFeed::default(); MAX_ORACLE_FEEDS],
+            0,
+        );
+        assert_eq!(
+            result,
+            Err(TipRouterError::NoFeedWeightOrSwitchboardFeed.into())
+        );
+
+        // The same feed can't be registered twice, even with a different feed type
+        let result = vault_registry.set_st_mint_oracle_feeds(
+            &mint,
+            [
+                OracleFeed::new(&feed_a, OracleFeedType::Switchboard),
+                OracleFeed::new(&feed_a, OracleFeedType::Pyth),
+                OracleFeed::default(),
+            ],
+            200,
+        );
+        assert_eq!(result, Err(TipRouterError::DuplicateOracleFeed.into()));
+
+        // Unknown mint
+        let result =
+            vault_registry.set_st_mint_oracle_feeds(&Pubkey::new_unique(), oracle_feeds, 200);
+        assert_eq!(result, Err(TipRouterError::MintEntryNotFound.into()));
+    }
 }
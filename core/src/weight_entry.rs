@@ -4,7 +4,7 @@ use shank::ShankType;
 use solana_program::pubkey::Pubkey;
 use spl_math::precise_number::PreciseNumber;
 
-use crate::{error::TipRouterError, vault_registry::StMintEntry};
+use crate::{constants::MAX_FEE_BPS, error::TipRouterError, vault_registry::StMintEntry};
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
 #[repr(C)]
@@ -87,6 +87,33 @@ impl WeightEntry {
 
         self.slot_updated = PodU64::from(current_slot);
     }
+
+    /// Errors if `weight` moved further from `previous_weight` than the mint's
+    /// `max_weight_change_bps` allows
+    pub fn check_weight_change(
+        &self,
+        previous_weight: u128,
+        weight: u128,
+    ) -> Result<(), TipRouterError> {
+        let max_weight_change_bps = self.st_mint_entry.max_weight_change_bps();
+
+        if max_weight_change_bps == 0 || previous_weight == 0 {
+            return Ok(());
+        }
+
+        let weight_change_bps = weight
+            .abs_diff(previous_weight)
+            .checked_mul(MAX_FEE_BPS as u128)
+            .ok_or(TipRouterError::ArithmeticOverflow)?
+            .checked_div(previous_weight)
+            .ok_or(TipRouterError::DenominatorIsZero)?;
+
+        if weight_change_bps > max_weight_change_bps as u128 {
+            return Err(TipRouterError::WeightChangeExceedsLimit);
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -99,8 +126,15 @@ mod tests {
     #[test]
     fn test_weight_entry_new() {
         let mint = Pubkey::new_unique();
-        let mint_entry =
-            StMintEntry::new(&mint, NcnFeeGroup::default(), 0, &Pubkey::new_unique(), 0);
+        let mint_entry = StMintEntry::new(
+            &mint,
+            NcnFeeGroup::default(),
+            0,
+            &Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
         let weight_entry = WeightEntry::new(&mint_entry);
 
         assert_eq!(*weight_entry.st_mint(), mint);
@@ -112,8 +146,15 @@ mod tests {
     #[test]
     fn test_precise_weight() {
         let mint = Pubkey::new_unique();
-        let mint_entry =
-            StMintEntry::new(&mint, NcnFeeGroup::default(), 0, &Pubkey::new_unique(), 0);
+        let mint_entry = StMintEntry::new(
+            &mint,
+            NcnFeeGroup::default(),
+            0,
+            &Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
         let mut weight_entry = WeightEntry::new(&mint_entry);
 
         // Test 1: Zero weight should convert successfully
@@ -130,4 +171,47 @@ mod tests {
         let result = weight_entry.precise_weight().unwrap();
         assert_eq!(result.to_imprecise().unwrap(), u128::MAX);
     }
+
+    #[test]
+    fn test_check_weight_change() {
+        let mint = Pubkey::new_unique();
+
+        // No limit
+        let mint_entry = StMintEntry::new(
+            &mint,
+            NcnFeeGroup::default(),
+            0,
+            &Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        let weight_entry = WeightEntry::new(&mint_entry);
+        assert!(weight_entry.check_weight_change(100, 1_000).is_ok());
+
+        // 10% limit
+        let mint_entry = StMintEntry::new(
+            &mint,
+            NcnFeeGroup::default(),
+            0,
+            &Pubkey::new_unique(),
+            0,
+            1_000,
+            0,
+        );
+        let weight_entry = WeightEntry::new(&mint_entry);
+        assert!(weight_entry.check_weight_change(100, 110).is_ok());
+        assert!(weight_entry.check_weight_change(100, 90).is_ok());
+        assert_eq!(
+            weight_entry.check_weight_change(100, 111),
+            Err(TipRouterError::WeightChangeExceedsLimit)
+        );
+        assert_eq!(
+            weight_entry.check_weight_change(100, 89),
+            Err(TipRouterError::WeightChangeExceedsLimit)
+        );
+
+        // No previous weight
+        assert!(weight_entry.check_weight_change(0, 1_000).is_ok());
+    }
 }
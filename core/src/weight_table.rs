@@ -4,7 +4,7 @@ use std::mem::size_of;
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
@@ -100,21 +100,26 @@ impl WeightTable {
         self.reserved = [0; 128];
         self.vault_registry = [VaultEntry::default(); MAX_VAULTS];
         self.table = [WeightEntry::default(); MAX_ST_MINTS];
-        self.set_vault_entries(vault_entries)?;
-        self.set_mint_entries(mint_entries)?;
+        self.set_vault_entries(vault_entries, ncn_epoch)?;
+        self.set_mint_entries(mint_entries, ncn_epoch)?;
         Ok(())
     }
 
     fn set_vault_entries(
         &mut self,
         vault_entries: &[VaultEntry; MAX_VAULTS],
+        ncn_epoch: u64,
     ) -> Result<(), TipRouterError> {
         if self.vault_registry_initialized() {
             return Err(TipRouterError::WeightTableAlreadyInitialized);
         }
 
-        // Copy the entire slice into vault_registry
+        // Copy the entire slice into vault_registry, leaving out vaults retired by this epoch
         for (i, entry) in vault_entries.iter().enumerate() {
+            if entry.is_retired(ncn_epoch) {
+                continue;
+            }
+
             self.vault_registry[i] = *entry;
         }
 
@@ -126,13 +131,18 @@ impl WeightTable {
     fn set_mint_entries(
         &mut self,
         mint_entries: &[StMintEntry; MAX_ST_MINTS],
+        ncn_epoch: u64,
     ) -> Result<(), TipRouterError> {
         if self.table_initialized() {
             return Err(TipRouterError::WeightTableAlreadyInitialized);
         }
 
-        // Set table using iterator
+        // Set table using iterator, leaving out mints retired by this epoch
         for (i, entry) in mint_entries.iter().enumerate() {
+            if entry.is_retired(ncn_epoch) {
+                continue;
+            }
+
             self.table[i] = WeightEntry::new(entry)
         }
 
@@ -257,17 +267,69 @@ impl WeightTable {
     }
 
     pub fn check_registry_for_vault(&self, vault_index: u64) -> Result<(), TipRouterError> {
+        self.get_vault_entry(vault_index)?;
+        Ok(())
+    }
+
+    /// The vault's entry as snapshotted from the vault registry
+    pub fn get_vault_entry(&self, vault_index: u64) -> Result<&VaultEntry, TipRouterError> {
         if vault_index == VaultEntry::EMPTY_VAULT_INDEX {
             return Err(TipRouterError::VaultNotInRegistry);
         }
 
-        if !self
-            .vault_registry
+        self.vault_registry
             .iter()
-            .any(|entry| entry.vault_index().eq(&vault_index))
-        {
-            return Err(TipRouterError::VaultNotInRegistry);
+            .find(|entry| entry.vault_index().eq(&vault_index))
+            .ok_or(TipRouterError::VaultNotInRegistry)
+    }
+
+    /// Checks a new weight against the same mint in the previous epoch's weight table. The check
+    /// is skipped if that table was never created, has been closed or never set the mint's weight
+    pub fn check_weight_change_from_previous_epoch(
+        &self,
+        program_id: &Pubkey,
+        previous_weight_table: &AccountInfo,
+        mint: &Pubkey,
+        weight: u128,
+    ) -> Result<(), ProgramError> {
+        let weight_entry = self.get_weight_entry(mint)?;
+
+        let Some(previous_epoch) = self.epoch().checked_sub(1) else {
+            return Ok(());
+        };
+
+        let expected_pda = Self::find_program_address(program_id, &self.ncn, previous_epoch).0;
+        if previous_weight_table.key.ne(&expected_pda) {
+            msg!("Previous weight table is not at the correct PDA");
+            return Err(TipRouterError::InvalidPreviousWeightTable.into());
+        }
+
+        if previous_weight_table.owner.ne(program_id) || previous_weight_table.data_is_empty() {
+            return Ok(());
+        }
+
+        let previous_weight_table_data = previous_weight_table.data.borrow();
+        if previous_weight_table_data[0] != Self::DISCRIMINATOR {
+            return Ok(());
+        }
+
+        let previous_weight_table_account =
+            Self::try_from_slice_unchecked(&previous_weight_table_data)?;
+
+        let previous_weight = match previous_weight_table_account.get_weight_entry(mint) {
+            Ok(entry) if entry.is_set() => entry.weight(),
+            _ => return Ok(()),
+        };
+
+        if let Err(e) = weight_entry.check_weight_change(previous_weight, weight) {
+            msg!(
+                "Weight {} changed too much from the previous epoch's weight {}",
+                weight,
+                previous_weight
+            );
+            return Err(e.into());
         }
+
         Ok(())
     }
 
@@ -345,7 +407,7 @@ mod tests {
     use solana_program::pubkey::Pubkey;
 
     use super::*;
-    use crate::ncn_fee_group::NcnFeeGroup;
+    use crate::{ncn_fee_group::NcnFeeGroup, vault_registry::VaultRegistry};
 
     fn get_test_mint_entries(count: usize) -> [StMintEntry; 64] {
         let mut mints = [StMintEntry::default(); MAX_ST_MINTS];
@@ -357,6 +419,8 @@ mod tests {
                 0,
                 &Pubkey::new_unique(),
                 0,
+                0,
+                0,
             );
         }
 
@@ -406,7 +470,7 @@ mod tests {
         );
 
         // Initialize the table with vault entries
-        table.set_vault_entries(&vault_registry).unwrap();
+        table.set_vault_entries(&vault_registry, 0).unwrap();
 
         // Test 1: Check existing vault indices should succeed
         assert!(table.check_registry_for_vault(1).is_ok());
@@ -434,6 +498,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_initialize_leaves_out_retired_entries() {
+        let ncn = Pubkey::new_unique();
+        let mut vault_registry = VaultRegistry::new(&ncn, 0);
+
+        let mint = Pubkey::new_unique();
+        let retired_mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let retired_vault = Pubkey::new_unique();
+        for st_mint in [mint, retired_mint] {
+            vault_registry
+                .register_st_mint(
+                    &st_mint,
+                    NcnFeeGroup::lst(),
+                    0,
+                    &Pubkey::default(),
+                    1,
+                    0,
+                    0,
+                    0,
+                    10,
+                )
+                .unwrap();
+        }
+        vault_registry
+            .register_vault(&vault, &mint, 0, 0, 0, 10)
+            .unwrap();
+        vault_registry
+            .register_vault(&retired_vault, &retired_mint, 1, 0, 0, 10)
+            .unwrap();
+
+        vault_registry.retire_vault(&retired_vault, 2, 0).unwrap();
+        vault_registry.retire_st_mint(&retired_mint, 2, 0).unwrap();
+
+        // Still active the epoch before retirement
+        let mut table = WeightTable::new(&ncn, 1, 0, 0, 0);
+        table
+            .initialize(
+                &ncn,
+                1,
+                0,
+                vault_registry.active_vault_count(1),
+                0,
+                vault_registry.get_vault_entries(),
+                vault_registry.get_mint_entries(),
+            )
+            .unwrap();
+        assert_eq!(table.vault_count(), 2);
+        assert_eq!(table.mint_count(), 2);
+        assert!(table.check_registry_for_vault(1).is_ok());
+
+        let mut table = WeightTable::new(&ncn, 2, 0, 0, 0);
+        table
+            .initialize(
+                &ncn,
+                2,
+                0,
+                vault_registry.active_vault_count(2),
+                0,
+                vault_registry.get_vault_entries(),
+                vault_registry.get_mint_entries(),
+            )
+            .unwrap();
+        assert_eq!(table.vault_count(), 1);
+        assert_eq!(table.mint_count(), 1);
+        assert_eq!(table.get_mints(), vec![mint]);
+        assert!(table.check_registry_for_vault(0).is_ok());
+        assert_eq!(
+            table.check_registry_for_vault(1),
+            Err(TipRouterError::VaultNotInRegistry)
+        );
+    }
+
     #[test]
     fn test_initialize_table_success() {
         let ncn = Pubkey::new_unique();
@@ -442,7 +579,7 @@ mod tests {
 
         let mints = get_test_mint_entries(2);
 
-        table.set_mint_entries(&mints).unwrap();
+        table.set_mint_entries(&mints, 0).unwrap();
         assert_eq!(table.mint_count(), 2);
     }
 
@@ -451,7 +588,7 @@ mod tests {
         let ncn = Pubkey::new_unique();
         let mut table = WeightTable::new(&ncn, 0, 0, 0, 0);
         let max_mints = get_test_mint_entries(MAX_ST_MINTS);
-        table.set_mint_entries(&max_mints).unwrap();
+        table.set_mint_entries(&max_mints, 0).unwrap();
         assert_eq!(table.mint_count(), MAX_ST_MINTS);
     }
 
@@ -460,11 +597,11 @@ mod tests {
         let ncn = Pubkey::new_unique();
         let mut table = WeightTable::new(&ncn, 0, 0, 0, 0);
         let first_mints = get_test_mint_entries(2);
-        table.set_mint_entries(&first_mints).unwrap();
+        table.set_mint_entries(&first_mints, 0).unwrap();
         let second_mints = get_test_mint_entries(3);
 
         assert_eq!(
-            table.set_mint_entries(&second_mints),
+            table.set_mint_entries(&second_mints, 0),
             Err(TipRouterError::WeightTableAlreadyInitialized)
         );
     }
@@ -476,7 +613,7 @@ mod tests {
         let mints = get_test_mint_entries(2);
         let mint_entry = mints[0];
 
-        table.set_mint_entries(&mints).unwrap();
+        table.set_mint_entries(&mints, 0).unwrap();
 
         table.set_weight(&mint_entry.st_mint(), 100, 1).unwrap();
         assert_eq!(table.get_weight(&mint_entry.st_mint()).unwrap(), 100);
@@ -488,7 +625,7 @@ mod tests {
         let mut table = WeightTable::new(&ncn, 0, 0, 0, 0);
         let mints = get_test_mint_entries(2);
 
-        table.set_mint_entries(&mints).unwrap();
+        table.set_mint_entries(&mints, 0).unwrap();
 
         let invalid_mint = Pubkey::new_unique();
         assert_eq!(
@@ -504,7 +641,7 @@ mod tests {
         let mints = get_test_mint_entries(2);
         let mint = mints[0];
 
-        table.set_mint_entries(&mints).unwrap();
+        table.set_mint_entries(&mints, 0).unwrap();
 
         table.set_weight(&mint.st_mint(), 100, 1).unwrap();
         assert_eq!(table.get_weight(&mint.st_mint()).unwrap(), 100);
@@ -521,7 +658,7 @@ mod tests {
         let mint1 = mints[0];
         let mint2 = mints[1];
 
-        table.set_mint_entries(&mints).unwrap();
+        table.set_mint_entries(&mints, 0).unwrap();
 
         table.set_weight(&mint1.st_mint(), 100, 1).unwrap();
         table.set_weight(&mint2.st_mint(), 200, 1).unwrap();
@@ -537,7 +674,7 @@ mod tests {
         let mints = get_test_mint_entries(2);
         let mint = mints[0];
 
-        table.set_mint_entries(&mints).unwrap();
+        table.set_mint_entries(&mints, 0).unwrap();
 
         table.set_weight(&mint.st_mint(), 100, 1).unwrap();
         assert_eq!(table.get_weight(&mint.st_mint()).unwrap(), 100);
@@ -7,9 +7,9 @@ use jito_restaking_core::{config::Config, ncn_vault_ticket::NcnVaultTicket};
 use jito_tip_distribution_sdk::jito_tip_distribution;
 use jito_tip_router_core::{
     account_payer::AccountPayer,
-    ballot_box::BallotBox,
+    ballot_box::{BallotBox, BallotBoxPage},
     base_fee_group::BaseFeeGroup,
-    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter, BaseRewardRouterPage},
     constants::{JITOSOL_MINT, JTO_SOL_FEED},
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
@@ -592,6 +592,8 @@ impl TestBuilder {
                     10_000,
                     Some(JTO_SOL_FEED),
                     None,
+                    None,
+                    None,
                 )
                 .await?;
 
@@ -933,6 +935,10 @@ impl TestBuilder {
         tip_router_client.do_route_base_rewards(ncn, epoch).await?;
 
         let base_reward_router = tip_router_client.get_base_reward_router(ncn, epoch).await?;
+        let base_reward_router_pages = tip_router_client
+            .get_base_reward_router_pages(ncn, epoch)
+            .await?;
+        let base_reward_router_pages: Vec<_> = base_reward_router_pages.iter().collect();
 
         // Base Rewards
         for group in BaseFeeGroup::all_groups().iter() {
@@ -951,7 +957,8 @@ impl TestBuilder {
         for operator_root in test_ncn.operators.iter() {
             let operator = operator_root.operator_pubkey;
 
-            let operator_route = base_reward_router.ncn_fee_group_reward_route(&operator);
+            let operator_route =
+                base_reward_router.ncn_fee_group_reward_route(&base_reward_router_pages, &operator);
 
             if let Ok(operator_route) = operator_route {
                 for group in NcnFeeGroup::all_groups().iter() {
@@ -1182,6 +1189,23 @@ impl TestBuilder {
                 epoch_to_close,
             );
 
+            // Pages are closed before the router they are chained to
+            let page_count = tip_router_client
+                .get_base_reward_router(ncn, epoch_to_close)
+                .await?
+                .page_count();
+            for page in 1..=page_count {
+                let (base_reward_router_page, _, _) = BaseRewardRouterPage::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &base_reward_router,
+                    page,
+                );
+
+                tip_router_client
+                    .do_close_epoch_account(ncn, epoch_to_close, base_reward_router_page, None)
+                    .await?;
+            }
+
             tip_router_client
                 .airdrop(&base_reward_receiver, EXTRA_SOL_TO_AIRDROP)
                 .await?;
@@ -1236,38 +1260,54 @@ impl TestBuilder {
             assert!(result.is_none());
         }
 
-        // Ballot Box
-        {
-            let (ballot_box, _, _) = BallotBox::find_program_address(
+        // Operator Snapshots
+        for operator_root in test_ncn.operators.iter() {
+            let operator = operator_root.operator_pubkey;
+
+            let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
                 &jito_tip_router_program::id(),
+                &operator,
                 &ncn,
                 epoch_to_close,
             );
 
             tip_router_client
-                .do_close_epoch_account(ncn, epoch_to_close, ballot_box, None)
+                .do_close_epoch_account(ncn, epoch_to_close, operator_snapshot, None)
                 .await?;
 
-            let result = self.get_account(&ballot_box).await?;
+            let result = self.get_account(&operator_snapshot).await?;
             assert!(result.is_none());
         }
 
-        // Operator Snapshots
-        for operator_root in test_ncn.operators.iter() {
-            let operator = operator_root.operator_pubkey;
-
-            let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        // Ballot Box
+        {
+            let (ballot_box, _, _) = BallotBox::find_program_address(
                 &jito_tip_router_program::id(),
-                &operator,
                 &ncn,
                 epoch_to_close,
             );
 
+            let page_count = tip_router_client
+                .get_ballot_box(ncn, epoch_to_close)
+                .await?
+                .page_count();
+            for page in 1..=page_count {
+                let (ballot_box_page, _, _) = BallotBoxPage::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ballot_box,
+                    page,
+                );
+
+                tip_router_client
+                    .do_close_epoch_account(ncn, epoch_to_close, ballot_box_page, None)
+                    .await?;
+            }
+
             tip_router_client
-                .do_close_epoch_account(ncn, epoch_to_close, operator_snapshot, None)
+                .do_close_epoch_account(ncn, epoch_to_close, ballot_box, None)
                 .await?;
 
-            let result = self.get_account(&operator_snapshot).await?;
+            let result = self.get_account(&ballot_box).await?;
             assert!(result.is_none());
         }
 
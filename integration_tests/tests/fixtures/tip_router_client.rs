@@ -1,39 +1,55 @@
-use jito_bytemuck::AccountDeserialize;
+use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_restaking_core::{
     config::Config, ncn_operator_state::NcnOperatorState, ncn_vault_ticket::NcnVaultTicket,
 };
 use jito_tip_distribution_sdk::{derive_tip_distribution_account_address, jito_tip_distribution};
 use jito_tip_router_client::{
     instructions::{
-        AdminRegisterStMintBuilder, AdminSetConfigFeesBuilder, AdminSetNewAdminBuilder,
-        AdminSetParametersBuilder, AdminSetStMintBuilder, AdminSetTieBreakerBuilder,
-        AdminSetWeightBuilder, CastVoteBuilder, ClaimWithPayerBuilder, CloseEpochAccountBuilder,
+        AdminAcceptNewAdminBuilder, AdminCancelNewAdminBuilder,
+        AdminInitializeConfigExtensionBuilder, AdminInitializeFeeScheduleBuilder,
+        AdminInitializeTieBreakerCommitteeBuilder, AdminProposeNewAdminBuilder,
+        AdminRegisterStMintBuilder, AdminRetireStMintBuilder, AdminRetireVaultBuilder,
+        AdminSetConfigFeesBuilder, AdminSetParametersBuilder, AdminSetPauseBuilder,
+        AdminSetStMintBuilder, AdminSetStMintOracleFeedsBuilder, AdminSetTieBreakerBuilder,
+        AdminSetTieBreakerCommitteeBuilder, AdminSetVaultRewardAssetBuilder,
+        AdminSetVaultRewardMultiplierBuilder, AdminSetWeightBuilder, CastVoteBuilder,
+        ClaimWithPayerBuilder, CloseEpochAccountBuilder, CommitVoteBuilder,
         DistributeBaseNcnRewardRouteBuilder, DistributeBaseRewardsBuilder,
         DistributeNcnOperatorRewardsBuilder, DistributeNcnVaultRewardsBuilder,
         InitializeBallotBoxBuilder, InitializeBaseRewardRouterBuilder, InitializeConfigBuilder,
         InitializeEpochSnapshotBuilder, InitializeEpochStateBuilder,
-        InitializeNcnRewardRouterBuilder, InitializeOperatorSnapshotBuilder,
-        InitializeVaultRegistryBuilder, InitializeWeightTableBuilder, ReallocBallotBoxBuilder,
-        ReallocBaseRewardRouterBuilder, ReallocEpochStateBuilder, ReallocOperatorSnapshotBuilder,
-        ReallocVaultRegistryBuilder, ReallocWeightTableBuilder, RegisterVaultBuilder,
-        RouteBaseRewardsBuilder, RouteNcnRewardsBuilder, SetMerkleRootBuilder,
-        SnapshotVaultOperatorDelegationBuilder, SwitchboardSetWeightBuilder,
+        InitializeNcnRewardRouterBuilder, InitializeOperatorRecordBuilder,
+        InitializeOperatorSnapshotBuilder, InitializeVaultRegistryBuilder,
+        InitializeWeightTableBuilder, MedianSetWeightBuilder, ReallocBallotBoxBuilder,
+        ReallocBallotBoxPageBuilder, ReallocBaseRewardRouterBuilder,
+        ReallocBaseRewardRouterPageBuilder, ReallocEpochStateBuilder,
+        ReallocOperatorSnapshotBuilder, ReallocVaultRegistryBuilder, ReallocWeightTableBuilder,
+        RegisterVaultBuilder, RevealVoteBuilder, RouteBaseRewardsBuilder, RouteNcnRewardsBuilder,
+        SetMerkleRootBatchBuilder, SetMerkleRootBuilder, SnapshotVaultOperatorDelegationBuilder,
+        SweepDustBuilder, SwitchboardSetWeightBuilder, TieBreakerApproveBuilder,
     },
-    types::ConfigAdminRole,
+    types::{ConfigAdminRole, MerkleRootUpload},
 };
 use jito_tip_router_core::{
     account_payer::AccountPayer,
-    ballot_box::BallotBox,
+    ballot_box::{Ballot, BallotBox, BallotBoxPage},
     base_fee_group::BaseFeeGroup,
-    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter, BaseRewardRouterPage},
     config::Config as NcnConfig,
-    constants::{JITOSOL_MINT, MAX_REALLOC_BYTES},
+    config_extension::ConfigExtension,
+    constants::{
+        JITOSOL_MINT, MAX_ORACLE_FEEDS, MAX_REALLOC_BYTES, MAX_TIE_BREAKER_COMMITTEE_MEMBERS,
+    },
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
     error::TipRouterError,
+    fee_schedule::{FeeSchedule, FeeScheduleAction},
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    operator_record::OperatorRecord,
+    reward_asset::RewardAssetType,
+    tie_breaker_committee::TieBreakerCommittee,
     vault_registry::VaultRegistry,
     weight_table::WeightTable,
 };
@@ -41,7 +57,11 @@ use jito_vault_core::{
     vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
 };
 use solana_program::{
-    hash::Hash, instruction::InstructionError, native_token::sol_to_lamports, pubkey::Pubkey,
+    hash::Hash,
+    instruction::{AccountMeta, InstructionError},
+    native_token::sol_to_lamports,
+    program_error::ProgramError,
+    pubkey::Pubkey,
     system_instruction::transfer,
 };
 use solana_program_test::{BanksClient, ProgramTestBanksClientExt};
@@ -56,6 +76,7 @@ use spl_associated_token_account::{
     get_associated_token_address, instruction::create_associated_token_account_idempotent,
 };
 use spl_stake_pool::find_withdraw_authority_program_address;
+use spl_token::native_mint;
 
 use super::{restaking_client::NcnRoot, stake_pool_client::PoolRoot};
 use crate::fixtures::{TestError, TestResult};
@@ -165,6 +186,39 @@ impl TipRouterClient {
         Ok(*VaultRegistry::try_from_slice_unchecked(vault_registry.data.as_slice()).unwrap())
     }
 
+    pub async fn get_operator_record(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+    ) -> TestResult<OperatorRecord> {
+        let address =
+            OperatorRecord::find_program_address(&jito_tip_router_program::id(), &ncn, &operator).0;
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+        Ok(*OperatorRecord::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
+    }
+
+    pub async fn get_tie_breaker_committee(
+        &mut self,
+        ncn: Pubkey,
+    ) -> TestResult<TieBreakerCommittee> {
+        let address =
+            TieBreakerCommittee::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+        Ok(*TieBreakerCommittee::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
+    }
+
+    pub async fn get_config_extension(&mut self, ncn: Pubkey) -> TestResult<ConfigExtension> {
+        let address = ConfigExtension::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+        Ok(*ConfigExtension::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
+    }
+
+    pub async fn get_fee_schedule(&mut self, ncn: Pubkey) -> TestResult<FeeSchedule> {
+        let address = FeeSchedule::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+        Ok(*FeeSchedule::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
+    }
+
     pub async fn get_epoch_state(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<EpochState> {
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
@@ -249,6 +303,103 @@ impl TipRouterClient {
         Ok(*account)
     }
 
+    pub async fn get_ballot_box_pages(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<Vec<BallotBoxPage>> {
+        let ballot_box_page_metas = self
+            .ballot_box_page_metas(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+                false,
+            )
+            .await?;
+
+        let mut pages = Vec::with_capacity(ballot_box_page_metas.len());
+        for meta in ballot_box_page_metas {
+            let raw_account = self.banks_client.get_account(meta.pubkey).await?.unwrap();
+            pages.push(
+                *BallotBoxPage::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap(),
+            );
+        }
+
+        Ok(pages)
+    }
+
+    pub async fn get_base_reward_router_pages(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<Vec<BaseRewardRouterPage>> {
+        let base_reward_router_page_metas = self
+            .base_reward_router_page_metas(
+                BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch)
+                    .0,
+                false,
+            )
+            .await?;
+
+        let mut pages = Vec::with_capacity(base_reward_router_page_metas.len());
+        for meta in base_reward_router_page_metas {
+            let raw_account = self.banks_client.get_account(meta.pubkey).await?.unwrap();
+            pages.push(
+                *BaseRewardRouterPage::try_from_slice_unchecked(raw_account.data.as_slice())
+                    .unwrap(),
+            );
+        }
+
+        Ok(pages)
+    }
+
+    /// Pages chained to the ballot box, passed as remaining accounts
+    async fn ballot_box_page_metas(
+        &mut self,
+        ballot_box: Pubkey,
+        is_writable: bool,
+    ) -> TestResult<Vec<AccountMeta>> {
+        let page_count = self
+            .banks_client
+            .get_account(ballot_box)
+            .await?
+            .and_then(|account| {
+                BallotBox::try_from_slice_unchecked(account.data.as_slice())
+                    .ok()
+                    .map(|ballot_box| ballot_box.page_count())
+            })
+            .unwrap_or(0);
+
+        Ok(page_metas(page_count, is_writable, |page| {
+            BallotBoxPage::find_program_address(&jito_tip_router_program::id(), &ballot_box, page).0
+        }))
+    }
+
+    /// Pages chained to the base reward router, passed as remaining accounts
+    async fn base_reward_router_page_metas(
+        &mut self,
+        base_reward_router: Pubkey,
+        is_writable: bool,
+    ) -> TestResult<Vec<AccountMeta>> {
+        let page_count = self
+            .banks_client
+            .get_account(base_reward_router)
+            .await?
+            .and_then(|account| {
+                BaseRewardRouter::try_from_slice_unchecked(account.data.as_slice())
+                    .ok()
+                    .map(|base_reward_router| base_reward_router.page_count())
+            })
+            .unwrap_or(0);
+
+        Ok(page_metas(page_count, is_writable, |page| {
+            BaseRewardRouterPage::find_program_address(
+                &jito_tip_router_program::id(),
+                &base_reward_router,
+                page,
+            )
+            .0
+        }))
+    }
+
     pub async fn get_ncn_reward_router(
         &mut self,
         ncn_fee_group: NcnFeeGroup,
@@ -366,6 +517,36 @@ impl TipRouterClient {
             new_base_fee_bps,
             ncn_fee_group,
             new_ncn_fee_bps,
+            None,
+            None,
+            ncn_root,
+        )
+        .await
+    }
+
+    pub async fn do_set_fee_schedule(
+        &mut self,
+        fee_schedule_action: FeeScheduleAction,
+        activation_epoch: u64,
+        base_fee_group: Option<BaseFeeGroup>,
+        new_base_fee_bps: Option<u16>,
+        ncn_fee_group: Option<NcnFeeGroup>,
+        new_ncn_fee_bps: Option<u16>,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+        self.airdrop(&ncn_root.ncn_admin.pubkey(), 1.0).await?;
+        self.set_config_fees(
+            config_pda,
+            None,
+            base_fee_group,
+            None,
+            new_base_fee_bps,
+            ncn_fee_group,
+            new_ncn_fee_bps,
+            Some(fee_schedule_action),
+            Some(activation_epoch),
             ncn_root,
         )
         .await
@@ -380,14 +561,21 @@ impl TipRouterClient {
         new_base_fee_bps: Option<u16>,
         ncn_fee_group: Option<NcnFeeGroup>,
         new_ncn_fee_bps: Option<u16>,
+        fee_schedule_action: Option<FeeScheduleAction>,
+        activation_epoch: Option<u64>,
         ncn_root: &NcnRoot,
     ) -> TestResult<()> {
+        let fee_schedule =
+            FeeSchedule::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey)
+                .0;
+
         let ix = {
             let mut builder = AdminSetConfigFeesBuilder::new();
             builder
                 .config(config_pda)
                 .ncn(ncn_root.ncn_pubkey)
-                .ncn_admin(ncn_root.ncn_admin.pubkey());
+                .ncn_admin(ncn_root.ncn_admin.pubkey())
+                .fee_schedule(fee_schedule);
 
             if let Some(new_block_engine_fee_bps) = new_block_engine_fee_bps {
                 builder.new_block_engine_fee_bps(new_block_engine_fee_bps);
@@ -413,6 +601,14 @@ impl TipRouterClient {
                 builder.new_ncn_fee_bps(new_ncn_fee_bps);
             }
 
+            if let Some(fee_schedule_action) = fee_schedule_action {
+                builder.fee_schedule_action(fee_schedule_action as u8);
+            }
+
+            if let Some(activation_epoch) = activation_epoch {
+                builder.activation_epoch(activation_epoch);
+            }
+
             builder.instruction()
         };
 
@@ -426,7 +622,7 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_set_new_admin(
+    pub async fn do_propose_new_admin(
         &mut self,
         role: ConfigAdminRole,
         new_admin: Pubkey,
@@ -435,18 +631,18 @@ impl TipRouterClient {
         let config_pda =
             NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
         self.airdrop(&ncn_root.ncn_admin.pubkey(), 1.0).await?;
-        self.set_new_admin(config_pda, role, new_admin, ncn_root)
+        self.propose_new_admin(config_pda, role, new_admin, ncn_root)
             .await
     }
 
-    pub async fn set_new_admin(
+    pub async fn propose_new_admin(
         &mut self,
         config_pda: Pubkey,
         role: ConfigAdminRole,
         new_admin: Pubkey,
         ncn_root: &NcnRoot,
     ) -> TestResult<()> {
-        let ix = AdminSetNewAdminBuilder::new()
+        let ix = AdminProposeNewAdminBuilder::new()
             .config(config_pda)
             .ncn(ncn_root.ncn_pubkey)
             .ncn_admin(ncn_root.ncn_admin.pubkey())
@@ -464,6 +660,77 @@ impl TipRouterClient {
         .await
     }
 
+    pub async fn do_cancel_new_admin(&mut self, ncn_root: &NcnRoot) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+        self.airdrop(&ncn_root.ncn_admin.pubkey(), 1.0).await?;
+
+        let ix = AdminCancelNewAdminBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_accept_new_admin(
+        &mut self,
+        role: ConfigAdminRole,
+        new_admin: &Keypair,
+        ncn: Pubkey,
+    ) -> TestResult<()> {
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let ix = AdminAcceptNewAdminBuilder::new()
+            .config(config_pda)
+            .ncn(ncn)
+            .new_admin(new_admin.pubkey())
+            .role(role)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, new_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_pause(
+        &mut self,
+        paused: u8,
+        pause_admin: &Keypair,
+        ncn: Pubkey,
+    ) -> TestResult<()> {
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let ix = AdminSetPauseBuilder::new()
+            .config(config_pda)
+            .ncn(ncn)
+            .pause_admin(pause_admin.pubkey())
+            .paused(paused)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, pause_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_full_initialize_epoch_state(
         &mut self,
         ncn: Pubkey,
@@ -534,12 +801,16 @@ impl TipRouterClient {
         let (account_payer, _, _) =
             AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
+        let config_extension =
+            ConfigExtension::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
         let ix = ReallocEpochStateBuilder::new()
             .epoch_state(epoch_state)
             .config(config)
             .ncn(ncn)
             .account_payer(account_payer)
             .system_program(system_program::id())
+            .config_extension(config_extension)
             .epoch(epoch)
             .instruction();
 
@@ -605,6 +876,87 @@ impl TipRouterClient {
         .await
     }
 
+    /// Initializes the weight table with `cranker` signing for the crank bounty
+    pub async fn do_initialize_weight_table_with_bounty(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        cranker: &Keypair,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = InitializeWeightTableBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .vault_registry(vault_registry)
+            .ncn(ncn)
+            .weight_table(weight_table)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .add_remaining_accounts(&crank_bounty_metas(ncn, cranker.pubkey()))
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, cranker],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Reallocs the weight table once with `cranker` signing for the crank bounty
+    pub async fn do_realloc_weight_table_with_bounty(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        cranker: &Keypair,
+    ) -> TestResult<()> {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = ReallocWeightTableBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .weight_table(weight_table)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .epoch(epoch)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .add_remaining_accounts(&crank_bounty_metas(ncn, cranker.pubkey()))
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, cranker],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_admin_set_weight(
         &mut self,
         ncn: Pubkey,
@@ -673,11 +1025,18 @@ impl TipRouterClient {
             WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let previous_weight_table = WeightTable::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            epoch.saturating_sub(1),
+        )
+        .0;
 
         let ix = SwitchboardSetWeightBuilder::new()
             .epoch_state(epoch_state)
             .ncn(ncn)
             .weight_table(weight_table)
+            .previous_weight_table(previous_weight_table)
             .st_mint(st_mint)
             .switchboard_feed(switchboard_feed)
             .epoch(epoch)
@@ -693,36 +1052,98 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_full_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
-        self.do_initialize_vault_registry(ncn).await?;
-        let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
-        self.do_realloc_vault_registry(ncn, num_reallocs).await?;
-        Ok(())
-    }
+    pub async fn do_median_set_weight(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        st_mint: Pubkey,
+    ) -> TestResult<()> {
+        let vault_registry = self.get_vault_registry(ncn).await?;
 
-    pub async fn do_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
-        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let vault_registry =
-            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let mint_entry = vault_registry.get_mint_entry(&st_mint)?;
+        let oracle_feeds: Vec<Pubkey> = mint_entry
+            .registered_oracle_feeds()
+            .iter()
+            .map(|oracle_feed| *oracle_feed.feed())
+            .collect();
 
-        self.initialize_vault_registry(&ncn_config, &vault_registry, &ncn)
+        self.median_set_weight(ncn, epoch, st_mint, &oracle_feeds)
             .await
     }
 
-    pub async fn initialize_vault_registry(
+    pub async fn median_set_weight(
         &mut self,
-        ncn_config: &Pubkey,
-        vault_registry: &Pubkey,
-        ncn: &Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+        st_mint: Pubkey,
+        oracle_feeds: &[Pubkey],
     ) -> TestResult<()> {
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let previous_weight_table = WeightTable::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            epoch.saturating_sub(1),
+        )
+        .0;
 
-        let ix = InitializeVaultRegistryBuilder::new()
-            .config(*ncn_config)
-            .vault_registry(*vault_registry)
-            .ncn(*ncn)
-            .account_payer(account_payer)
+        let oracle_feed_metas: Vec<AccountMeta> = oracle_feeds
+            .iter()
+            .map(|oracle_feed| AccountMeta::new_readonly(*oracle_feed, false))
+            .collect();
+
+        let ix = MedianSetWeightBuilder::new()
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .weight_table(weight_table)
+            .previous_weight_table(previous_weight_table)
+            .st_mint(st_mint)
+            .epoch(epoch)
+            .add_remaining_accounts(&oracle_feed_metas)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_full_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
+        self.do_initialize_vault_registry(ncn).await?;
+        let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        self.do_realloc_vault_registry(ncn, num_reallocs).await?;
+        Ok(())
+    }
+
+    pub async fn do_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        self.initialize_vault_registry(&ncn_config, &vault_registry, &ncn)
+            .await
+    }
+
+    pub async fn initialize_vault_registry(
+        &mut self,
+        ncn_config: &Pubkey,
+        vault_registry: &Pubkey,
+        ncn: &Pubkey,
+    ) -> TestResult<()> {
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = InitializeVaultRegistryBuilder::new()
+            .config(*ncn_config)
+            .vault_registry(*vault_registry)
+            .ncn(*ncn)
+            .account_payer(account_payer)
             .system_program(system_program::id())
             .instruction();
 
@@ -827,6 +1248,8 @@ impl TipRouterClient {
         reward_multiplier_bps: u64,
         switchboard_feed: Option<Pubkey>,
         no_feed_weight: Option<u128>,
+        max_weight_change_bps: Option<u64>,
+        max_stale_slots: Option<u64>,
     ) -> TestResult<()> {
         let vault_registry =
             VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
@@ -846,6 +1269,8 @@ impl TipRouterClient {
             reward_multiplier_bps,
             switchboard_feed,
             no_feed_weight,
+            max_weight_change_bps,
+            max_stale_slots,
         )
         .await
     }
@@ -861,6 +1286,8 @@ impl TipRouterClient {
         reward_multiplier_bps: u64,
         switchboard_feed: Option<Pubkey>,
         no_feed_weight: Option<u128>,
+        max_weight_change_bps: Option<u64>,
+        max_stale_slots: Option<u64>,
     ) -> TestResult<()> {
         let ix = {
             let mut builder = AdminRegisterStMintBuilder::new();
@@ -881,6 +1308,14 @@ impl TipRouterClient {
                 builder.no_feed_weight(no_feed_weight);
             }
 
+            if let Some(max_weight_change_bps) = max_weight_change_bps {
+                builder.max_weight_change_bps(max_weight_change_bps);
+            }
+
+            if let Some(max_stale_slots) = max_stale_slots {
+                builder.max_stale_slots(max_stale_slots);
+            }
+
             builder.instruction()
         };
 
@@ -902,6 +1337,8 @@ impl TipRouterClient {
         reward_multiplier_bps: Option<u64>,
         switchboard_feed: Option<Pubkey>,
         no_feed_weight: Option<u128>,
+        max_weight_change_bps: Option<u64>,
+        max_stale_slots: Option<u64>,
     ) -> TestResult<()> {
         let vault_registry =
             VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
@@ -921,6 +1358,8 @@ impl TipRouterClient {
             reward_multiplier_bps,
             switchboard_feed,
             no_feed_weight,
+            max_weight_change_bps,
+            max_stale_slots,
         )
         .await
     }
@@ -936,6 +1375,8 @@ impl TipRouterClient {
         reward_multiplier_bps: Option<u64>,
         switchboard_feed: Option<Pubkey>,
         no_feed_weight: Option<u128>,
+        max_weight_change_bps: Option<u64>,
+        max_stale_slots: Option<u64>,
     ) -> TestResult<()> {
         let ix = {
             let mut builder = AdminSetStMintBuilder::new();
@@ -962,6 +1403,14 @@ impl TipRouterClient {
                 builder.no_feed_weight(no_feed_weight);
             }
 
+            if let Some(max_weight_change_bps) = max_weight_change_bps {
+                builder.max_weight_change_bps(max_weight_change_bps);
+            }
+
+            if let Some(max_stale_slots) = max_stale_slots {
+                builder.max_stale_slots(max_stale_slots);
+            }
+
             builder.instruction()
         };
 
@@ -975,6 +1424,167 @@ impl TipRouterClient {
         .await
     }
 
+    pub async fn do_admin_set_st_mint_oracle_feeds(
+        &mut self,
+        ncn: Pubkey,
+        st_mint: Pubkey,
+        oracle_feeds: [Pubkey; MAX_ORACLE_FEEDS],
+        oracle_feed_types: [u8; MAX_ORACLE_FEEDS],
+        max_feed_divergence_bps: u16,
+    ) -> TestResult<()> {
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = AdminSetStMintOracleFeedsBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .admin(self.payer.pubkey())
+            .st_mint(st_mint)
+            .oracle_feeds(oracle_feeds)
+            .oracle_feed_types(oracle_feed_types)
+            .max_feed_divergence_bps(max_feed_divergence_bps)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_retire_vault(
+        &mut self,
+        ncn: Pubkey,
+        vault: Pubkey,
+        retired_epoch: u64,
+    ) -> TestResult<()> {
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = AdminRetireVaultBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .vault(vault)
+            .admin(self.payer.pubkey())
+            .retired_epoch(retired_epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_set_vault_reward_asset(
+        &mut self,
+        ncn: Pubkey,
+        vault: Pubkey,
+        reward_asset_type: RewardAssetType,
+        stake_pool: Pubkey,
+    ) -> TestResult<()> {
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = AdminSetVaultRewardAssetBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .vault(vault)
+            .admin(self.payer.pubkey())
+            .reward_asset_type(reward_asset_type as u8)
+            .stake_pool(stake_pool)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_set_vault_reward_multiplier(
+        &mut self,
+        ncn: Pubkey,
+        vault: Pubkey,
+        reward_multiplier_bps: u64,
+    ) -> TestResult<()> {
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = AdminSetVaultRewardMultiplierBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .vault(vault)
+            .admin(self.payer.pubkey())
+            .reward_multiplier_bps(reward_multiplier_bps)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_retire_st_mint(
+        &mut self,
+        ncn: Pubkey,
+        st_mint: Pubkey,
+        retired_epoch: u64,
+    ) -> TestResult<()> {
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = AdminRetireStMintBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .st_mint(st_mint)
+            .admin(self.payer.pubkey())
+            .retired_epoch(retired_epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_initialize_epoch_snapshot(
         &mut self,
         ncn: Pubkey,
@@ -985,6 +1595,8 @@ impl TipRouterClient {
 
     pub async fn initialize_epoch_snapshot(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
         let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let fee_schedule =
+            FeeSchedule::find_program_address(&jito_tip_router_program::id(), &ncn).0;
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
         let epoch_state =
@@ -1001,6 +1613,7 @@ impl TipRouterClient {
             .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
             .config(config_pda)
+            .fee_schedule(fee_schedule)
             .ncn(ncn)
             .weight_table(weight_table)
             .epoch_snapshot(epoch_snapshot)
@@ -1025,6 +1638,17 @@ impl TipRouterClient {
         ncn: Pubkey,
         epoch: u64,
     ) -> TestResult<()> {
+        let operator_record =
+            OperatorRecord::find_program_address(&jito_tip_router_program::id(), &ncn, &operator).0;
+        if self
+            .banks_client
+            .get_account(operator_record)
+            .await?
+            .is_none()
+        {
+            self.do_initialize_operator_record(ncn, operator).await?;
+        }
+
         self.do_initialize_operator_snapshot(operator, ncn, epoch)
             .await?;
         let num_reallocs =
@@ -1034,6 +1658,40 @@ impl TipRouterClient {
         Ok(())
     }
 
+    pub async fn do_initialize_operator_record(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+    ) -> TestResult<()> {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let operator_record =
+            OperatorRecord::find_program_address(&jito_tip_router_program::id(), &ncn, &operator).0;
+        let ncn_operator_state =
+            NcnOperatorState::find_program_address(&jito_restaking_program::id(), &ncn, &operator)
+                .0;
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = InitializeOperatorRecordBuilder::new()
+            .config(config)
+            .operator_record(operator_record)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_initialize_operator_snapshot(
         &mut self,
         operator: Pubkey,
@@ -1145,6 +1803,9 @@ impl TipRouterClient {
         let weight_table =
             WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
+        let (config_extension, _, _) =
+            ConfigExtension::find_program_address(&jito_tip_router_program::id(), &ncn);
+
         let ix = SnapshotVaultOperatorDelegationBuilder::new()
             .epoch_state(epoch_state)
             .config(config_pda)
@@ -1158,6 +1819,7 @@ impl TipRouterClient {
             .weight_table(weight_table)
             .epoch_snapshot(epoch_snapshot)
             .operator_snapshot(operator_snapshot)
+            .config_extension(config_extension)
             .epoch(epoch)
             .instruction();
 
@@ -1179,6 +1841,16 @@ impl TipRouterClient {
         self.do_initialize_ballot_box(ncn, epoch).await?;
         let num_reallocs = (BallotBox::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
         self.do_realloc_ballot_box(ncn, epoch, num_reallocs).await?;
+
+        let epoch_state = self.get_epoch_state(ncn, epoch).await?;
+        let pages_required =
+            BallotBox::pages_required(epoch_state.operator_count()).map_err(ProgramError::from)?;
+        let num_page_reallocs =
+            (BallotBoxPage::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64;
+        for page in 1..=pages_required {
+            self.do_realloc_ballot_box_page(ncn, epoch, page, num_page_reallocs)
+                .await?;
+        }
         Ok(())
     }
 
@@ -1289,39 +1961,165 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_cast_vote(
+    pub async fn do_realloc_ballot_box_page(
         &mut self,
         ncn: Pubkey,
-        operator: Pubkey,
-        operator_admin: &Keypair,
-        meta_merkle_root: [u8; 32],
         epoch: u64,
+        page: u8,
+        num_reallocations: u64,
     ) -> Result<(), TestError> {
         let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
-        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
-            &jito_tip_router_program::id(),
-            &ncn,
-            epoch,
-        )
-        .0;
-
-        let epoch_snapshot =
-            jito_tip_router_core::epoch_snapshot::EpochSnapshot::find_program_address(
-                &jito_tip_router_program::id(),
-                &ncn,
-                epoch,
-            )
-            .0;
+        self.realloc_ballot_box_page(ncn_config, ballot_box, ncn, epoch, page, num_reallocations)
+            .await
+    }
 
-        let operator_snapshot =
-            jito_tip_router_core::epoch_snapshot::OperatorSnapshot::find_program_address(
-                &jito_tip_router_program::id(),
-                &operator,
-                &ncn,
-                epoch,
-            )
-            .0;
+    pub async fn realloc_ballot_box_page(
+        &mut self,
+        config: Pubkey,
+        ballot_box: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+        page: u8,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ballot_box_page =
+            BallotBoxPage::find_program_address(&jito_tip_router_program::id(), &ballot_box, page)
+                .0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = ReallocBallotBoxPageBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ballot_box(ballot_box)
+            .ballot_box_page(ballot_box_page)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .page(page)
+            .instruction();
+
+        let ixs = vec![ix; num_reallocations as usize];
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_cast_vote(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_admin: &Keypair,
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        self.do_cast_ballot(
+            ncn,
+            operator,
+            operator_admin,
+            &Ballot::new(&meta_merkle_root),
+            epoch,
+        )
+        .await
+    }
+
+    pub async fn do_cast_vote_with_snapshot(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_admin: &Keypair,
+        meta_merkle_root: [u8; 32],
+        snapshot_slot: u64,
+        bank_hash: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        self.do_cast_ballot(
+            ncn,
+            operator,
+            operator_admin,
+            &Ballot::new_with_snapshot(&meta_merkle_root, snapshot_slot, &bank_hash),
+            epoch,
+        )
+        .await
+    }
+
+    pub async fn do_cast_vote_with_tip_distribution_account_count(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_admin: &Keypair,
+        meta_merkle_root: [u8; 32],
+        tip_distribution_account_count: u64,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        self.do_cast_ballot(
+            ncn,
+            operator,
+            operator_admin,
+            &Ballot::new(&meta_merkle_root)
+                .with_tip_distribution_account_count(tip_distribution_account_count),
+            epoch,
+        )
+        .await
+    }
+
+    pub async fn do_cast_abstain_vote(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_admin: &Keypair,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        self.do_cast_ballot(ncn, operator, operator_admin, &Ballot::abstain(), epoch)
+            .await
+    }
+
+    async fn do_cast_ballot(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_admin: &Keypair,
+        ballot: &Ballot,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            epoch,
+        )
+        .0;
+
+        let epoch_snapshot =
+            jito_tip_router_core::epoch_snapshot::EpochSnapshot::find_program_address(
+                &jito_tip_router_program::id(),
+                &ncn,
+                epoch,
+            )
+            .0;
+
+        let operator_snapshot =
+            jito_tip_router_core::epoch_snapshot::OperatorSnapshot::find_program_address(
+                &jito_tip_router_program::id(),
+                &operator,
+                &ncn,
+                epoch,
+            )
+            .0;
 
         self.cast_vote(
             ncn_config,
@@ -1331,7 +2129,7 @@ impl TipRouterClient {
             operator_snapshot,
             operator,
             operator_admin,
-            meta_merkle_root,
+            ballot,
             epoch,
         )
         .await
@@ -1346,12 +2144,17 @@ impl TipRouterClient {
         operator_snapshot: Pubkey,
         operator: Pubkey,
         operator_voter: &Keypair,
-        meta_merkle_root: [u8; 32],
+        ballot: &Ballot,
         epoch: u64,
     ) -> Result<(), TestError> {
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
+        let operator_record =
+            OperatorRecord::find_program_address(&jito_tip_router_program::id(), &ncn, &operator).0;
+
+        let ballot_box_page_metas = self.ballot_box_page_metas(ballot_box, true).await?;
+
         let ix = CastVoteBuilder::new()
             .epoch_state(epoch_state)
             .config(ncn_config)
@@ -1361,8 +2164,130 @@ impl TipRouterClient {
             .operator_snapshot(operator_snapshot)
             .operator(operator)
             .operator_voter(operator_voter.pubkey())
+            .operator_record(operator_record)
+            .meta_merkle_root(ballot.root())
+            .epoch(epoch)
+            .abstain(ballot.is_abstain())
+            .snapshot_slot(ballot.snapshot_slot())
+            .bank_hash(*ballot.bank_hash())
+            .tip_distribution_account_count(ballot.tip_distribution_account_count())
+            .add_remaining_accounts(&ballot_box_page_metas)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, operator_voter],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_commit_vote(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_voter: &Keypair,
+        commitment: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let program_id = jito_tip_router_program::id();
+        let epoch_state = EpochState::find_program_address(&program_id, &ncn, epoch).0;
+        let ncn_config = NcnConfig::find_program_address(&program_id, &ncn).0;
+        let ballot_box = BallotBox::find_program_address(&program_id, &ncn, epoch).0;
+        let epoch_snapshot =
+            jito_tip_router_core::epoch_snapshot::EpochSnapshot::find_program_address(
+                &program_id,
+                &ncn,
+                epoch,
+            )
+            .0;
+        let operator_snapshot =
+            jito_tip_router_core::epoch_snapshot::OperatorSnapshot::find_program_address(
+                &program_id,
+                &operator,
+                &ncn,
+                epoch,
+            )
+            .0;
+
+        let ballot_box_page_metas = self.ballot_box_page_metas(ballot_box, true).await?;
+
+        let ix = CommitVoteBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ballot_box(ballot_box)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .operator_snapshot(operator_snapshot)
+            .operator(operator)
+            .operator_voter(operator_voter.pubkey())
+            .commitment(commitment)
+            .epoch(epoch)
+            .add_remaining_accounts(&ballot_box_page_metas)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, operator_voter],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_reveal_vote(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_voter: &Keypair,
+        meta_merkle_root: [u8; 32],
+        salt: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let program_id = jito_tip_router_program::id();
+        let epoch_state = EpochState::find_program_address(&program_id, &ncn, epoch).0;
+        let ncn_config = NcnConfig::find_program_address(&program_id, &ncn).0;
+        let ballot_box = BallotBox::find_program_address(&program_id, &ncn, epoch).0;
+        let epoch_snapshot =
+            jito_tip_router_core::epoch_snapshot::EpochSnapshot::find_program_address(
+                &program_id,
+                &ncn,
+                epoch,
+            )
+            .0;
+        let operator_snapshot =
+            jito_tip_router_core::epoch_snapshot::OperatorSnapshot::find_program_address(
+                &program_id,
+                &operator,
+                &ncn,
+                epoch,
+            )
+            .0;
+
+        let operator_record = OperatorRecord::find_program_address(&program_id, &ncn, &operator).0;
+
+        let ballot_box_page_metas = self.ballot_box_page_metas(ballot_box, true).await?;
+
+        let ix = RevealVoteBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ballot_box(ballot_box)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .operator_snapshot(operator_snapshot)
+            .operator(operator)
+            .operator_voter(operator_voter.pubkey())
+            .operator_record(operator_record)
             .meta_merkle_root(meta_merkle_root)
+            .salt(salt)
             .epoch(epoch)
+            .snapshot_slot(0)
+            .bank_hash([0; 32])
+            .tip_distribution_account_count(0)
+            .add_remaining_accounts(&ballot_box_page_metas)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -1432,23 +2357,268 @@ impl TipRouterClient {
         max_num_nodes: u64,
         epoch: u64,
     ) -> Result<(), TestError> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let ix = SetMerkleRootBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .ballot_box(ballot_box)
+            .vote_account(vote_account)
+            .tip_distribution_account(tip_distribution_account)
+            .tip_distribution_config(tip_distribution_config)
+            .tip_distribution_program(tip_distribution_program)
+            .proof(proof)
+            .merkle_root(merkle_root)
+            .max_total_claim(max_total_claim)
+            .max_num_nodes(max_num_nodes)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_merkle_root_batch(
+        &mut self,
+        ncn: Pubkey,
+        uploads: Vec<(Pubkey, MerkleRootUpload)>,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let tip_distribution_program = jito_tip_distribution::ID;
+        let tip_distribution_config =
+            jito_tip_distribution_sdk::derive_config_account_address(&tip_distribution_program).0;
+
+        let upload_accounts = uploads
+            .iter()
+            .flat_map(|(vote_account, _)| {
+                let tip_distribution_account = derive_tip_distribution_account_address(
+                    &tip_distribution_program,
+                    vote_account,
+                    epoch - 1,
+                )
+                .0;
+
+                [
+                    AccountMeta::new_readonly(*vote_account, false),
+                    AccountMeta::new(tip_distribution_account, false),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let ix = SetMerkleRootBatchBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .ballot_box(ballot_box)
+            .tip_distribution_config(tip_distribution_config)
+            .tip_distribution_program(tip_distribution_program)
+            .uploads(uploads.into_iter().map(|(_, upload)| upload).collect())
+            .epoch(epoch)
+            .add_remaining_accounts(&upload_accounts)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_set_tie_breaker(
+        &mut self,
+        ncn: Pubkey,
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let tie_breaker_admin = self.payer.pubkey();
+
+        self.admin_set_tie_breaker(
+            ncn_config,
+            ballot_box,
+            ncn,
+            tie_breaker_admin,
+            meta_merkle_root,
+            epoch,
+        )
+        .await
+    }
+
+    pub async fn admin_set_tie_breaker(
+        &mut self,
+        ncn_config: Pubkey,
+        ballot_box: Pubkey,
+        ncn: Pubkey,
+        tie_breaker_admin: Pubkey,
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let tie_breaker_committee =
+            TieBreakerCommittee::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let ballot_box_page_metas = self.ballot_box_page_metas(ballot_box, true).await?;
+
+        let ix = AdminSetTieBreakerBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ballot_box(ballot_box)
+            .ncn(ncn)
+            .tie_breaker_admin(tie_breaker_admin)
+            .tie_breaker_committee(tie_breaker_committee)
+            .meta_merkle_root(meta_merkle_root)
+            .epoch(epoch)
+            .add_remaining_accounts(&ballot_box_page_metas)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_finalize_stalled_ballot(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let ballot_box_page_metas = self.ballot_box_page_metas(ballot_box, false).await?;
+
+        let ix = FinalizeStalledBallotBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ballot_box(ballot_box)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .epoch(epoch)
+            .add_remaining_accounts(&ballot_box_page_metas)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_initialize_config_extension(
+        &mut self,
+        ncn_root: &NcnRoot,
+    ) -> Result<(), TestError> {
+        let ncn = ncn_root.ncn_pubkey;
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let config_extension =
+            ConfigExtension::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = AdminInitializeConfigExtensionBuilder::new()
+            .config(config)
+            .config_extension(config_extension)
+            .ncn(ncn)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_initialize_fee_schedule(
+        &mut self,
+        ncn_root: &NcnRoot,
+    ) -> Result<(), TestError> {
+        let ncn = ncn_root.ncn_pubkey;
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let fee_schedule =
+            FeeSchedule::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = AdminInitializeFeeScheduleBuilder::new()
+            .config(config)
+            .fee_schedule(fee_schedule)
+            .ncn(ncn)
+            .fee_admin(ncn_root.ncn_admin.pubkey())
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_initialize_tie_breaker_committee(
+        &mut self,
+        ncn: Pubkey,
+        members: [Pubkey; MAX_TIE_BREAKER_COMMITTEE_MEMBERS],
+        threshold: u8,
+    ) -> Result<(), TestError> {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let tie_breaker_committee =
+            TieBreakerCommittee::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        let ix = SetMerkleRootBuilder::new()
-            .epoch_state(epoch_state)
+        let ix = AdminInitializeTieBreakerCommitteeBuilder::new()
             .config(config)
+            .tie_breaker_committee(tie_breaker_committee)
             .ncn(ncn)
-            .ballot_box(ballot_box)
-            .vote_account(vote_account)
-            .tip_distribution_account(tip_distribution_account)
-            .tip_distribution_config(tip_distribution_config)
-            .tip_distribution_program(tip_distribution_program)
-            .proof(proof)
-            .merkle_root(merkle_root)
-            .max_total_claim(max_total_claim)
-            .max_num_nodes(max_num_nodes)
-            .epoch(epoch)
+            .tie_breaker_admin(self.payer.pubkey())
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .members(members)
+            .threshold(threshold)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -1461,56 +2631,69 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_admin_set_tie_breaker(
+    pub async fn do_admin_set_tie_breaker_committee(
         &mut self,
         ncn: Pubkey,
-        meta_merkle_root: [u8; 32],
-        epoch: u64,
+        members: [Pubkey; MAX_TIE_BREAKER_COMMITTEE_MEMBERS],
+        threshold: u8,
     ) -> Result<(), TestError> {
-        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let ballot_box =
-            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let tie_breaker_committee =
+            TieBreakerCommittee::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let tie_breaker_admin = self.payer.pubkey();
+        let ix = AdminSetTieBreakerCommitteeBuilder::new()
+            .config(config)
+            .tie_breaker_committee(tie_breaker_committee)
+            .ncn(ncn)
+            .tie_breaker_admin(self.payer.pubkey())
+            .members(members)
+            .threshold(threshold)
+            .instruction();
 
-        self.admin_set_tie_breaker(
-            ncn_config,
-            ballot_box,
-            ncn,
-            tie_breaker_admin,
-            meta_merkle_root,
-            epoch,
-        )
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
         .await
     }
 
-    pub async fn admin_set_tie_breaker(
+    pub async fn do_tie_breaker_approve(
         &mut self,
-        ncn_config: Pubkey,
-        ballot_box: Pubkey,
         ncn: Pubkey,
-        tie_breaker_admin: Pubkey,
+        member: &Keypair,
         meta_merkle_root: [u8; 32],
         epoch: u64,
     ) -> Result<(), TestError> {
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let tie_breaker_committee =
+            TieBreakerCommittee::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
-        let ix = AdminSetTieBreakerBuilder::new()
+        let ballot_box_page_metas = self.ballot_box_page_metas(ballot_box, false).await?;
+
+        let ix = TieBreakerApproveBuilder::new()
             .epoch_state(epoch_state)
-            .config(ncn_config)
+            .config(config)
+            .tie_breaker_committee(tie_breaker_committee)
             .ballot_box(ballot_box)
             .ncn(ncn)
-            .tie_breaker_admin(tie_breaker_admin)
+            .member(member.pubkey())
             .meta_merkle_root(meta_merkle_root)
             .epoch(epoch)
+            .add_remaining_accounts(&ballot_box_page_metas)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &[&self.payer, member],
             blockhash,
         ))
         .await
@@ -1526,6 +2709,16 @@ impl TipRouterClient {
             (BaseRewardRouter::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
         self.do_realloc_base_reward_router(ncn, epoch, num_reallocs)
             .await?;
+
+        let epoch_state = self.get_epoch_state(ncn, epoch).await?;
+        let pages_required = BaseRewardRouter::pages_required(epoch_state.operator_count())
+            .map_err(ProgramError::from)?;
+        let num_page_reallocs =
+            (BaseRewardRouterPage::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64;
+        for page in 1..=pages_required {
+            self.do_realloc_base_reward_router_page(ncn, epoch, page, num_page_reallocs)
+                .await?;
+        }
         Ok(())
     }
 
@@ -1717,6 +2910,18 @@ impl TipRouterClient {
 
         let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        let config_extension =
+            ConfigExtension::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        // Ballot box pages are followed by the base reward router pages
+        let mut page_metas = self.ballot_box_page_metas(ballot_box, false).await?;
+        page_metas.extend(
+            self.base_reward_router_page_metas(base_reward_router, true)
+                .await?,
+        );
+
         let ix = RouteBaseRewardsBuilder::new()
             .epoch_state(epoch_state)
             .config(config)
@@ -1725,8 +2930,12 @@ impl TipRouterClient {
             .ballot_box(ballot_box)
             .base_reward_router(base_reward_router)
             .base_reward_receiver(base_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .config_extension(config_extension)
             .max_iterations(max_iterations)
             .epoch(epoch)
+            .add_remaining_accounts(&page_metas)
             .instruction();
 
         let blockhash = self.get_best_latest_blockhash().await?;
@@ -1813,8 +3022,11 @@ impl TipRouterClient {
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
         let ix = RouteNcnRewardsBuilder::new()
             .epoch_state(epoch_state)
+            .config(config)
             .ncn(ncn)
             .operator(operator)
             .operator_snapshot(operator_snapshot)
@@ -1970,6 +3182,10 @@ impl TipRouterClient {
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
+        let base_reward_router_page_metas = self
+            .base_reward_router_page_metas(base_reward_router, true)
+            .await?;
+
         let ix = DistributeBaseNcnRewardRouteBuilder::new()
             .epoch_state(epoch_state)
             .config(ncn_config)
@@ -1982,6 +3198,7 @@ impl TipRouterClient {
             .system_program(system_program::id())
             .ncn_fee_group(ncn_fee_group.group)
             .epoch(epoch)
+            .add_remaining_accounts(&base_reward_router_page_metas)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -2121,12 +3338,28 @@ impl TipRouterClient {
         let manager_fee_account = pool_root.manager_fee_account;
         let referrer_pool_tokens_account = pool_root.referrer_pool_tokens_account;
 
-        let vault_ata = get_associated_token_address(&vault, &JITOSOL_MINT);
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        // The test stake pool mints JitoSOL, only SOL rewards go to a different token account
+        let reward_asset_type = self
+            .get_vault_registry(ncn)
+            .await?
+            .get_vault_entry(&vault)?
+            .reward_asset()
+            .asset_type()
+            .map_err(ProgramError::from)?;
+        let vault_mint = match reward_asset_type {
+            RewardAssetType::Sol => native_mint::id(),
+            RewardAssetType::JitoSol | RewardAssetType::StakePool => JITOSOL_MINT,
+        };
+
+        let vault_ata = get_associated_token_address(&vault, &vault_mint);
 
         let vault_ata_ix = create_associated_token_account_idempotent(
             &self.payer.pubkey(),
             &vault,
-            &JITOSOL_MINT,
+            &vault_mint,
             &spl_token::id(),
         );
         let epoch_state =
@@ -2151,6 +3384,7 @@ impl TipRouterClient {
             .pool_mint(JITOSOL_MINT)
             .token_program(spl_token::id())
             .system_program(system_program::id())
+            .vault_registry(vault_registry)
             .ncn_fee_group(ncn_fee_group.group)
             .epoch(epoch)
             .instruction();
@@ -2219,6 +3453,9 @@ impl TipRouterClient {
         let (account_payer, _, _) =
             AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
+        let (config_extension, _, _) =
+            ConfigExtension::find_program_address(&jito_tip_router_program::id(), &ncn);
+
         let ix = ReallocOperatorSnapshotBuilder::new()
             .epoch_state(epoch_state)
             .config(config)
@@ -2230,6 +3467,7 @@ impl TipRouterClient {
             .operator_snapshot(operator_snapshot)
             .account_payer(account_payer)
             .system_program(system_program::id())
+            .config_extension(config_extension)
             .epoch(epoch)
             .instruction();
 
@@ -2301,6 +3539,73 @@ impl TipRouterClient {
         .await
     }
 
+    pub async fn do_realloc_base_reward_router_page(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        page: u8,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let base_reward_router =
+            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        self.realloc_base_reward_router_page(
+            config,
+            base_reward_router,
+            ncn,
+            epoch,
+            page,
+            num_reallocations,
+        )
+        .await
+    }
+
+    pub async fn realloc_base_reward_router_page(
+        &mut self,
+        config: Pubkey,
+        base_reward_router: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+        page: u8,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let base_reward_router_page = BaseRewardRouterPage::find_program_address(
+            &jito_tip_router_program::id(),
+            &base_reward_router,
+            page,
+        )
+        .0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = ReallocBaseRewardRouterPageBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .base_reward_router(base_reward_router)
+            .base_reward_router_page(base_reward_router_page)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .page(page)
+            .instruction();
+
+        let ixs = vec![ix; num_reallocations as usize];
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_realloc_weight_table(
         &mut self,
         ncn: Pubkey,
@@ -2466,6 +3771,35 @@ impl TipRouterClient {
             .base_fee_wallet(BaseFeeGroup::dao())
             .expect("No DAO wallet ( do_close_epoch_account )");
 
+        // Operator snapshots record the operator's participation from the ballot box as they close
+        let mut receiver_to_close = receiver_to_close;
+        let mut remaining_accounts = vec![];
+        let operator = self
+            .banks_client
+            .get_account(account_to_close)
+            .await?
+            .filter(|account| account.data.first() == Some(&OperatorSnapshot::DISCRIMINATOR))
+            .and_then(|account| {
+                OperatorSnapshot::try_from_slice_unchecked(account.data.as_slice())
+                    .ok()
+                    .map(|operator_snapshot| *operator_snapshot.operator())
+            });
+        if let Some(operator) = operator {
+            let ballot_box =
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+            receiver_to_close = Some(
+                OperatorRecord::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    &operator,
+                )
+                .0,
+            );
+            remaining_accounts.push(AccountMeta::new_readonly(ballot_box, false));
+            remaining_accounts.extend(self.ballot_box_page_metas(ballot_box, false).await?);
+        }
+
         self.close_epoch_account(
             epoch_marker,
             epoch_state,
@@ -2476,6 +3810,7 @@ impl TipRouterClient {
             account_payer,
             dao_wallet,
             epoch,
+            &remaining_accounts,
         )
         .await
     }
@@ -2491,6 +3826,7 @@ impl TipRouterClient {
         account_payer: Pubkey,
         dao_wallet: Pubkey,
         epoch: u64,
+        remaining_accounts: &[AccountMeta],
     ) -> TestResult<()> {
         let mut ix = CloseEpochAccountBuilder::new();
 
@@ -2508,7 +3844,107 @@ impl TipRouterClient {
             ix.receiver_to_close(Some(receiver_to_close));
         }
 
-        let ix = ix.instruction();
+        let ix = ix.add_remaining_accounts(remaining_accounts).instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        self.process_transaction(&tx).await
+    }
+
+    pub async fn do_sweep_base_dust(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let (base_reward_router, _, _) =
+            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        self.do_sweep_dust(ncn, epoch, base_reward_router, base_reward_receiver)
+            .await
+    }
+
+    pub async fn do_sweep_ncn_dust(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        self.do_sweep_dust(ncn, epoch, ncn_reward_router, ncn_reward_receiver)
+            .await
+    }
+
+    pub async fn do_sweep_dust(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        reward_router: Pubkey,
+        reward_receiver: Pubkey,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (config, _, _) = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let config_account = self.get_ncn_config(ncn).await?;
+        let dao_wallet = *config_account
+            .fee_config
+            .base_fee_wallet(BaseFeeGroup::dao())
+            .expect("No DAO wallet ( do_sweep_dust )");
+
+        self.sweep_dust(
+            epoch_state,
+            config,
+            ncn,
+            reward_router,
+            reward_receiver,
+            dao_wallet,
+            epoch,
+        )
+        .await
+    }
+
+    pub async fn sweep_dust(
+        &mut self,
+        epoch_state: Pubkey,
+        config: Pubkey,
+        ncn: Pubkey,
+        reward_router: Pubkey,
+        reward_receiver: Pubkey,
+        dao_wallet: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let ix = SweepDustBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .reward_router(reward_router)
+            .reward_receiver(reward_receiver)
+            .dao_wallet(dao_wallet)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         let tx = Transaction::new_signed_with_payer(
@@ -2527,15 +3963,32 @@ impl TipRouterClient {
         epochs_before_stall: Option<u64>,
         epochs_after_consensus_before_close: Option<u64>,
         valid_slots_after_consensus: Option<u64>,
+        consensus_threshold_bps: Option<u16>,
+        commit_window_slots: Option<u64>,
+        late_vote_grace_slots: Option<u64>,
+        late_vote_max_decay_bps: Option<u16>,
+        plurality_fallback_epochs: Option<u64>,
+        plurality_min_stake_weight_bps: Option<u16>,
+        crank_bounty_lamports: Option<u64>,
+        max_crank_bounties_per_epoch: Option<u64>,
+        account_payer_target_balance: Option<u64>,
+        min_operator_stake_weight: Option<u128>,
+        max_operator_fee_bps: Option<u16>,
         ncn_root: &NcnRoot,
     ) -> TestResult<()> {
         let config_pda =
             NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+        let config_extension = ConfigExtension::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn_root.ncn_pubkey,
+        )
+        .0;
 
         let mut ix = AdminSetParametersBuilder::new();
         ix.config(config_pda)
             .ncn(ncn_root.ncn_pubkey)
-            .ncn_admin(ncn_root.ncn_admin.pubkey());
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .config_extension(config_extension);
 
         if let Some(epoch) = starting_valid_epoch {
             ix.starting_valid_epoch(epoch);
@@ -2553,6 +4006,50 @@ impl TipRouterClient {
             ix.valid_slots_after_consensus(slots);
         }
 
+        if let Some(bps) = consensus_threshold_bps {
+            ix.consensus_threshold_bps(bps);
+        }
+
+        if let Some(slots) = commit_window_slots {
+            ix.commit_window_slots(slots);
+        }
+
+        if let Some(slots) = late_vote_grace_slots {
+            ix.late_vote_grace_slots(slots);
+        }
+
+        if let Some(bps) = late_vote_max_decay_bps {
+            ix.late_vote_max_decay_bps(bps);
+        }
+
+        if let Some(epochs) = plurality_fallback_epochs {
+            ix.plurality_fallback_epochs(epochs);
+        }
+
+        if let Some(bps) = plurality_min_stake_weight_bps {
+            ix.plurality_min_stake_weight_bps(bps);
+        }
+
+        if let Some(lamports) = crank_bounty_lamports {
+            ix.crank_bounty_lamports(lamports);
+        }
+
+        if let Some(count) = max_crank_bounties_per_epoch {
+            ix.max_crank_bounties_per_epoch(count);
+        }
+
+        if let Some(lamports) = account_payer_target_balance {
+            ix.account_payer_target_balance(lamports);
+        }
+
+        if let Some(stake_weight) = min_operator_stake_weight {
+            ix.min_operator_stake_weight(stake_weight);
+        }
+
+        if let Some(bps) = max_operator_fee_bps {
+            ix.max_operator_fee_bps(bps);
+        }
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix.instruction()],
@@ -2564,6 +4061,36 @@ impl TipRouterClient {
     }
 }
 
+/// Builds the trailing `[account_payer, system_program, cranker]` metas that claim a crank bounty
+fn crank_bounty_metas(ncn: Pubkey, cranker: Pubkey) -> Vec<AccountMeta> {
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+    vec![
+        AccountMeta::new(account_payer, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(cranker, true),
+    ]
+}
+
+/// Builds the metas for pages `1..=page_count` of a paginated account
+fn page_metas(
+    page_count: u8,
+    is_writable: bool,
+    find_page_address: impl Fn(u8) -> Pubkey,
+) -> Vec<AccountMeta> {
+    (1..=page_count)
+        .map(|page| {
+            let address = find_page_address(page);
+            if is_writable {
+                AccountMeta::new(address, false)
+            } else {
+                AccountMeta::new_readonly(address, false)
+            }
+        })
+        .collect()
+}
+
 #[inline(always)]
 #[track_caller]
 pub fn assert_tip_router_error<T>(
@@ -1,6 +1,6 @@
 use jito_bytemuck::Discriminator;
 use jito_tip_router_core::{ballot_box::BallotBox, epoch_state::EpochState};
-use solana_sdk::{account::Account, native_token::LAMPORTS_PER_SOL};
+use solana_sdk::{account::Account, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
 
 pub fn serialized_epoch_state_account(epoch_state: &EpochState) -> Account {
     // TODO add AccountSerialize to jito_restaking::bytemuck?
@@ -31,3 +31,22 @@ pub fn serialized_ballot_box_account(ballot_box: &BallotBox) -> Account {
         rent_epoch: 0,
     }
 }
+
+/// A trading Pyth-style ( v2 ) price account
+pub fn serialized_pyth_price_account(price: i64, expo: i32, publish_slot: u64) -> Account {
+    let mut data = vec![0; 3312];
+    data[0..4].copy_from_slice(&0xa1b2_c3d4u32.to_le_bytes()); // magic
+    data[8..12].copy_from_slice(&3u32.to_le_bytes()); // price account type
+    data[20..24].copy_from_slice(&expo.to_le_bytes());
+    data[208..216].copy_from_slice(&price.to_le_bytes());
+    data[224..228].copy_from_slice(&1u32.to_le_bytes()); // trading status
+    data[232..240].copy_from_slice(&publish_slot.to_le_bytes());
+
+    Account {
+        lamports: LAMPORTS_PER_SOL * 5,
+        data,
+        owner: Pubkey::new_unique(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        account_payer::AccountPayer, base_fee_group::BaseFeeGroup,
+        base_reward_router::BaseRewardReceiver,
+    };
+    use solana_sdk::clock::DEFAULT_SLOTS_PER_EPOCH;
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    const REWARDS_TO_ROUTE: u64 = 1_000_000;
+    const TOP_UP: u64 = 1_000;
+
+    #[tokio::test]
+    async fn test_route_base_rewards_tops_up_account_payer() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture
+            .warp_slot_incremental(DEFAULT_SLOTS_PER_EPOCH * 2)
+            .await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+
+        let valid_slots_after_consensus = {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            config.valid_slots_after_consensus()
+        };
+
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        let account_payer_balance_before =
+            fixture.get_account(&account_payer).await?.unwrap().lamports;
+        let target_balance = account_payer_balance_before + TOP_UP;
+
+        tip_router_client
+            .do_admin_initialize_config_extension(&test_ncn.ncn_root)
+            .await?;
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(target_balance), // account_payer_target_balance
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, REWARDS_TO_ROUTE)
+            .await?;
+
+        tip_router_client.do_route_base_rewards(ncn, epoch).await?;
+
+        let account_payer_balance_after =
+            fixture.get_account(&account_payer).await?.unwrap().lamports;
+        assert_eq!(account_payer_balance_after, target_balance);
+
+        let base_reward_router = tip_router_client.get_base_reward_router(ncn, epoch).await?;
+        assert_eq!(base_reward_router.account_payer_top_up(), TOP_UP);
+        assert_eq!(
+            base_reward_router.rewards_processed(),
+            REWARDS_TO_ROUTE - TOP_UP
+        );
+        assert!(
+            base_reward_router
+                .base_fee_group_reward(BaseFeeGroup::dao())
+                .unwrap()
+                > 0
+        );
+
+        // The top up counts towards the epoch's distributed rewards
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.total_distribution_progress().tally(), TOP_UP);
+
+        // The account payer is at its target, new rewards are not diverted
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, REWARDS_TO_ROUTE)
+            .await?;
+        tip_router_client.do_route_base_rewards(ncn, epoch).await?;
+
+        let account_payer_balance_final =
+            fixture.get_account(&account_payer).await?.unwrap().lamports;
+        assert_eq!(account_payer_balance_final, target_balance);
+
+        let base_reward_router = tip_router_client.get_base_reward_router(ncn, epoch).await?;
+        assert_eq!(base_reward_router.account_payer_top_up(), TOP_UP);
+        assert_eq!(
+            base_reward_router.rewards_processed(),
+            REWARDS_TO_ROUTE * 2 - TOP_UP
+        );
+
+        Ok(())
+    }
+}
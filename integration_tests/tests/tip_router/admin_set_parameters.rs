@@ -1,9 +1,18 @@
 #[cfg(test)]
 mod tests {
-    use jito_tip_router_core::error::TipRouterError;
+    use jito_tip_router_core::{
+        constants::{
+            MAX_COMMIT_WINDOW_SLOTS, MAX_CRANK_BOUNTY_LAMPORTS, MAX_FEE_BPS,
+            MAX_LATE_VOTE_DECAY_BPS, MAX_PLURALITY_FALLBACK_EPOCHS,
+            MAX_PLURALITY_MIN_STAKE_WEIGHT_BPS,
+        },
+        error::TipRouterError,
+    };
+    use solana_program::instruction::InstructionError;
 
     use crate::fixtures::{
-        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+        assert_ix_error, test_builder::TestBuilder, tip_router_client::assert_tip_router_error,
+        TestResult,
     };
 
     #[tokio::test]
@@ -22,6 +31,17 @@ mod tests {
                 Some(5),    // epochs_before_stall
                 Some(10),   // epochs_after_consensus_before_close
                 Some(1000), // valid_slots_after_consensus
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
                 &ncn_root,
             )
             .await?;
@@ -41,6 +61,17 @@ mod tests {
                 Some(0), // Invalid - too low
                 None,
                 None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
                 &ncn_root,
             )
             .await;
@@ -53,6 +84,17 @@ mod tests {
                 None,
                 Some(0), // Invalid - too low
                 None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
                 &ncn_root,
             )
             .await;
@@ -65,11 +107,403 @@ mod tests {
                 None,
                 None,
                 Some(99), // Invalid - too low
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
                 &ncn_root,
             )
             .await;
         assert_tip_router_error(result, TipRouterError::InvalidSlotsAfterConsensus);
 
+        // Test setting a valid consensus threshold
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                Some(5_100),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await?;
+
+        let config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.consensus_threshold_bps(), 5_100);
+
+        // Test invalid consensus_threshold_bps
+        let result = tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                Some(5_000), // Invalid - too low
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidConsensusThreshold);
+
+        // Test valid commit_window_slots
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(100),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await?;
+
+        let config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.commit_window_slots(), 100);
+        assert!(config.is_commit_reveal_enabled());
+
+        // Test invalid commit_window_slots
+        let result = tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_COMMIT_WINDOW_SLOTS + 1), // Invalid - too high
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidCommitWindow);
+
+        // Test valid late vote decay
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(100),   // late_vote_grace_slots
+                Some(5_000), // late_vote_max_decay_bps
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await?;
+
+        let config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.late_vote_grace_slots(), 100);
+        assert_eq!(config.late_vote_max_decay_bps(), 5_000);
+        assert!(config.late_vote_decay().is_enabled());
+
+        // Test invalid late_vote_grace_slots
+        let result = tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(1001), // Invalid - longer than valid_slots_after_consensus
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidLateVoteDecay);
+
+        // Test invalid late_vote_max_decay_bps
+        let result = tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_LATE_VOTE_DECAY_BPS as u16 + 1), // Invalid - too high
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidLateVoteDecay);
+
+        // Test valid plurality fallback
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(5),     // plurality_fallback_epochs
+                Some(3_000), // plurality_min_stake_weight_bps
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await?;
+
+        let config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config.plurality_fallback_epochs(), 5);
+        assert_eq!(config.plurality_min_stake_weight_bps(), 3_000);
+        assert!(config.is_plurality_fallback_enabled());
+
+        // Test invalid plurality_fallback_epochs
+        let result = tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_PLURALITY_FALLBACK_EPOCHS + 1), // Invalid - too high
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidPluralityFallback);
+
+        // Test invalid plurality_min_stake_weight_bps
+        let result = tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_PLURALITY_MIN_STAKE_WEIGHT_BPS as u16 + 1), // Invalid - too high
+                None,
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidPluralityFallback);
+
+        // Crank bounties are kept in the config extension, which has to be created first
+        let result = tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(5_000), // crank_bounty_lamports
+                Some(20),    // max_crank_bounties_per_epoch
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_ix_error(result, InstructionError::UninitializedAccount);
+
+        tip_router_client
+            .do_admin_initialize_config_extension(&ncn_root)
+            .await?;
+
+        // Test valid crank bounty
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(5_000), // crank_bounty_lamports
+                Some(20),    // max_crank_bounties_per_epoch
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await?;
+
+        let config_extension = tip_router_client
+            .get_config_extension(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config_extension.crank_bounty_lamports(), 5_000);
+        assert_eq!(config_extension.max_crank_bounties_per_epoch(), 20);
+        assert!(config_extension.is_crank_bounty_enabled());
+
+        // Test invalid crank_bounty_lamports
+        let result = tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_CRANK_BOUNTY_LAMPORTS + 1), // Invalid - too high
+                None,
+                None,
+                None,
+                None,
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidCrankBounty);
+
+        // Test valid operator bounds
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(1_000), // min_operator_stake_weight
+                Some(1_000), // max_operator_fee_bps
+                &ncn_root,
+            )
+            .await?;
+
+        let config_extension = tip_router_client
+            .get_config_extension(ncn_root.ncn_pubkey)
+            .await?;
+        assert_eq!(config_extension.min_operator_stake_weight(), 1_000);
+        assert_eq!(config_extension.max_operator_fee_bps(), 1_000);
+
+        // Test invalid max_operator_fee_bps
+        let result = tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(MAX_FEE_BPS as u16 + 1), // Invalid - too high
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidMaxOperatorFee);
+
         Ok(())
     }
 }
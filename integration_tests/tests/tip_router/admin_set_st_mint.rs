@@ -27,6 +27,8 @@ mod tests {
         let reward_multiplier_bps = Some(10);
         let switchboard_feed = Some(JITOSOL_SOL_FEED);
         let no_feed_weight = Some(100);
+        let max_weight_change_bps = Some(500);
+        let max_stale_slots = Some(1_000);
 
         tip_router_client
             .do_admin_set_st_mint(
@@ -36,6 +38,8 @@ mod tests {
                 reward_multiplier_bps,
                 switchboard_feed,
                 no_feed_weight,
+                max_weight_change_bps,
+                max_stale_slots,
             )
             .await?;
 
@@ -51,9 +55,14 @@ mod tests {
         );
         assert_eq!(*mint_entry.switchboard_feed(), switchboard_feed.unwrap());
         assert_eq!(mint_entry.no_feed_weight(), no_feed_weight.unwrap());
+        assert_eq!(
+            mint_entry.max_weight_change_bps(),
+            max_weight_change_bps.unwrap()
+        );
+        assert_eq!(mint_entry.max_stale_slots(), max_stale_slots.unwrap());
 
         tip_router_client
-            .do_admin_set_st_mint(ncn, st_mint, None, None, None, None)
+            .do_admin_set_st_mint(ncn, st_mint, None, None, None, None, None, None)
             .await?;
 
         let mint_entry = vault_registry.get_mint_entry(&st_mint).unwrap();
@@ -66,6 +75,11 @@ mod tests {
         );
         assert_eq!(*mint_entry.switchboard_feed(), switchboard_feed.unwrap());
         assert_eq!(mint_entry.no_feed_weight(), no_feed_weight.unwrap());
+        assert_eq!(
+            mint_entry.max_weight_change_bps(),
+            max_weight_change_bps.unwrap()
+        );
+        assert_eq!(mint_entry.max_stale_slots(), max_stale_slots.unwrap());
 
         Ok(())
     }
@@ -3,6 +3,7 @@ mod set_merkle_root {
         derive_claim_status_account_address, derive_tip_distribution_account_address,
         jito_tip_distribution,
     };
+    use jito_tip_router_client::types::MerkleRootUpload;
     use jito_tip_router_core::{
         ballot_box::{Ballot, BallotBox},
         config::Config as NcnConfig,
@@ -364,6 +365,193 @@ mod set_merkle_root {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_set_merkle_root_batch_ok() -> TestResult<()> {
+        let mut fixture: TestBuilder = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut tip_distribution_client = fixture.tip_distribution_client();
+
+        fixture.warp_epoch_incremental(10).await?;
+
+        let test_ncn = fixture.create_test_ncn().await?;
+        let ncn_address = test_ncn.ncn_root.ncn_pubkey;
+        let ncn_config_address =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_address).0;
+
+        let epoch = fixture.clock().await.epoch;
+
+        tip_distribution_client
+            .do_initialize(ncn_config_address)
+            .await?;
+        let vote_keypair = tip_distribution_client.setup_vote_account().await?;
+        let vote_account = vote_keypair.pubkey();
+
+        tip_distribution_client
+            .do_initialize_tip_distribution_account(ncn_config_address, vote_keypair, epoch, 100)
+            .await?;
+        let (tip_distribution_account, _) = derive_tip_distribution_account_address(
+            &jito_tip_distribution::ID,
+            &vote_account,
+            epoch,
+        );
+        tip_router_client
+            .airdrop(&tip_distribution_account, 10.0)
+            .await?;
+
+        let meta_merkle_tree_fixture =
+            create_meta_merkle_tree(vote_account, ncn_config_address, ncn_address, epoch)?;
+        let winning_root = meta_merkle_tree_fixture.meta_merkle_tree.merkle_root;
+
+        fixture.warp_epoch_incremental(1).await?;
+        let epoch = fixture.clock().await.epoch;
+
+        let (ballot_box_address, bump, _) =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn_address, epoch);
+
+        let ballot_box_fixture = {
+            let mut ballot_box = BallotBox::new(&ncn_address, epoch, bump, 0);
+            let winning_ballot = Ballot::new(&winning_root);
+            ballot_box.set_winning_ballot(&winning_ballot);
+            ballot_box
+        };
+
+        let (epoch_state_address, bump, _) =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn_address, epoch);
+
+        let epoch_state_fixture = {
+            let mut epoch_state = EpochState::new(&ncn_address, epoch, bump, 0);
+            epoch_state._set_upload_progress();
+            epoch_state
+        };
+
+        let epoch_schedule: EpochSchedule = fixture.epoch_schedule().await;
+
+        // Must warp before .set_account
+        fixture
+            .warp_slot_incremental(epoch_schedule.get_slots_in_epoch(epoch))
+            .await?;
+
+        fixture
+            .set_account(
+                ballot_box_address,
+                serialized_ballot_box_account(&ballot_box_fixture),
+            )
+            .await;
+
+        fixture
+            .set_account(
+                epoch_state_address,
+                serialized_epoch_state_account(&epoch_state_fixture),
+            )
+            .await;
+
+        let tip_distribution_address = derive_tip_distribution_account_address(
+            &jito_tip_distribution::ID,
+            &vote_account,
+            epoch - 1,
+        )
+        .0;
+
+        // Get proof for vote_account
+        let node = meta_merkle_tree_fixture
+            .meta_merkle_tree
+            .get_node(&tip_distribution_address);
+        let proof = node.proof.clone().unwrap();
+
+        ballot_box_fixture
+            .verify_merkle_root(
+                &tip_distribution_address,
+                node.proof.unwrap(),
+                &node.validator_merkle_root,
+                node.max_total_claim,
+                node.max_num_nodes,
+            )
+            .unwrap();
+
+        // An empty batch has nothing to upload
+        let res = tip_router_client
+            .do_set_merkle_root_batch(ncn_address, vec![], epoch)
+            .await;
+        assert_tip_router_error(res, TipRouterError::InvalidMerkleRootBatch);
+
+        let upload = MerkleRootUpload {
+            proof,
+            merkle_root: node.validator_merkle_root,
+            max_total_claim: node.max_total_claim,
+            max_num_nodes: node.max_num_nodes,
+        };
+
+        // Test wrong proof
+        let res = tip_router_client
+            .do_set_merkle_root_batch(
+                ncn_address,
+                vec![(
+                    vote_account,
+                    MerkleRootUpload {
+                        proof: vec![[1; 32]],
+                        ..upload.clone()
+                    },
+                )],
+                epoch,
+            )
+            .await;
+        assert_tip_router_error(res, TipRouterError::InvalidMerkleProof);
+
+        // A tip distribution account can only appear once in a batch
+        let res = tip_router_client
+            .do_set_merkle_root_batch(
+                ncn_address,
+                vec![
+                    (vote_account, upload.clone()),
+                    (vote_account, upload.clone()),
+                ],
+                epoch,
+            )
+            .await;
+        assert_tip_router_error(res, TipRouterError::InvalidMerkleRootBatch);
+
+        tip_router_client
+            .do_set_merkle_root_batch(ncn_address, vec![(vote_account, upload.clone())], epoch)
+            .await?;
+
+        // Fetch the tip distribution account and check root
+        let tip_distribution_account = tip_distribution_client
+            .get_tip_distribution_account(vote_account, epoch - 1)
+            .await?;
+
+        let merkle_root = tip_distribution_account.merkle_root.unwrap();
+
+        assert_eq!(merkle_root.root, node.validator_merkle_root);
+        assert_eq!(merkle_root.max_num_nodes, node.max_num_nodes);
+        assert_eq!(merkle_root.max_total_claim, node.max_total_claim);
+
+        let epoch_state = tip_router_client
+            .get_epoch_state(ncn_address, epoch)
+            .await?;
+        assert_eq!(epoch_state.upload_progress().tally(), 1);
+
+        // Uploading to the same tip distribution account again is not counted twice
+        tip_router_client
+            .do_set_merkle_root(
+                ncn_address,
+                vote_account,
+                upload.proof,
+                upload.merkle_root,
+                upload.max_total_claim,
+                upload.max_num_nodes,
+                epoch,
+            )
+            .await?;
+
+        let epoch_state = tip_router_client
+            .get_epoch_state(ncn_address, epoch)
+            .await?;
+        assert_eq!(epoch_state.upload_progress().tally(), 1);
+        assert_eq!(epoch_state.upload_progress().total(), 1);
+
+        Ok(())
+    }
+
     // #[ignore = "code coverage"]
     #[tokio::test]
     async fn test_set_merkle_root_no_fixture() -> TestResult<()> {
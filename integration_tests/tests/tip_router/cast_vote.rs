@@ -42,7 +42,7 @@ mod tests {
 
         let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
 
-        assert!(ballot_box.has_ballot(&Ballot::new(&meta_merkle_root)));
+        assert!(ballot_box.has_ballot(&[], &Ballot::new(&meta_merkle_root)));
         assert_eq!(ballot_box.slot_consensus_reached(), slot);
         assert!(ballot_box.is_consensus_reached());
 
@@ -101,12 +101,12 @@ mod tests {
 
         let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
 
-        assert!(ballot_box.has_ballot(&Ballot::new(&winning_meta_merkle_root)));
+        assert!(ballot_box.has_ballot(&[], &Ballot::new(&winning_meta_merkle_root)));
         assert_eq!(ballot_box.slot_consensus_reached(), slot);
         assert_eq!(ballot_box.unique_ballots(), 1);
         assert_eq!(
             ballot_box
-                .get_winning_ballot_tally()
+                .get_winning_ballot_tally(&[])
                 .unwrap()
                 .stake_weights()
                 .stake_weight(),
@@ -117,6 +117,179 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_abstain_vote() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(3, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let clock = fixture.clock().await;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let epoch = clock.epoch;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+
+        tip_router_client
+            .do_cast_abstain_vote(ncn, operator, operator_admin, epoch)
+            .await?;
+
+        // Abstaining counts as voting, but adds no stake to any ballot
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert_eq!(ballot_box.operators_voted(), 1);
+        assert_eq!(ballot_box.operators_abstained(&[]), 1);
+        assert_eq!(ballot_box.unique_ballots(), 0);
+        assert!(!ballot_box.is_consensus_reached());
+
+        // The operator can still vote once it has data
+        let meta_merkle_root = [1u8; 32];
+        for operator in test_ncn.operators {
+            tip_router_client
+                .do_cast_vote(
+                    ncn,
+                    operator.operator_pubkey,
+                    &operator.operator_admin,
+                    meta_merkle_root,
+                    epoch,
+                )
+                .await?;
+        }
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert_eq!(ballot_box.operators_voted(), 3);
+        assert_eq!(ballot_box.operators_abstained(&[]), 0);
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(
+            ballot_box
+                .get_winning_ballot_tally(&[])
+                .unwrap()
+                .stake_weights()
+                .stake_weight(),
+            30_000
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cast_vote_with_snapshot() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(3, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let clock = fixture.clock().await;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = clock.epoch;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let meta_merkle_root = [1u8; 32];
+        let snapshots = [(100, [2u8; 32]), (200, [3u8; 32])];
+
+        for (operator, (snapshot_slot, bank_hash)) in test_ncn.operators.iter().zip(snapshots) {
+            tip_router_client
+                .do_cast_vote_with_snapshot(
+                    ncn,
+                    operator.operator_pubkey,
+                    &operator.operator_admin,
+                    meta_merkle_root,
+                    snapshot_slot,
+                    bank_hash,
+                    epoch,
+                )
+                .await?;
+        }
+
+        // Differing snapshots still count toward the same ballot
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert_eq!(ballot_box.unique_ballots(), 1);
+
+        for (operator, (snapshot_slot, bank_hash)) in test_ncn.operators.iter().zip(snapshots) {
+            let operator_snapshot = tip_router_client
+                .get_operator_snapshot(operator.operator_pubkey, ncn, epoch)
+                .await?;
+            assert_eq!(operator_snapshot.vote_snapshot_slot(), snapshot_slot);
+            assert_eq!(operator_snapshot.vote_bank_hash(), &bank_hash);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cast_vote_with_tip_distribution_account_count() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(4, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let clock = fixture.clock().await;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = clock.epoch;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let meta_merkle_root = [1u8; 32];
+        let tip_distribution_account_counts = [6, 5, 5, 5];
+
+        for (operator, tip_distribution_account_count) in test_ncn
+            .operators
+            .iter()
+            .zip(tip_distribution_account_counts)
+        {
+            tip_router_client
+                .do_cast_vote_with_tip_distribution_account_count(
+                    ncn,
+                    operator.operator_pubkey,
+                    &operator.operator_admin,
+                    meta_merkle_root,
+                    tip_distribution_account_count,
+                    epoch,
+                )
+                .await?;
+        }
+
+        // A differing count is still a vote for the same root, the staked majority count wins
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert_eq!(ballot_box.unique_ballots(), 1);
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.winning_tip_distribution_account_count(), 5);
+
+        // The winning count is the number of merkle roots expected to be uploaded
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.upload_progress().tally(), 0);
+        assert_eq!(epoch_state.upload_progress().total(), 5);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_bad_ballot() -> TestResult<()> {
         let mut fixture = TestBuilder::new().await;
@@ -152,6 +325,113 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_commit_reveal_vote() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let commit_window_slots = 100;
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(commit_window_slots),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        let clock = fixture.clock().await;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let epoch = clock.epoch;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let meta_merkle_root = [1u8; 32];
+        let salt = [2u8; 32];
+        let ballot = Ballot::new(&meta_merkle_root);
+
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+
+        // Plain votes are rejected while commit-reveal is enabled
+        let result = tip_router_client
+            .do_cast_vote(ncn, operator, operator_admin, meta_merkle_root, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::CommitRevealRequired);
+
+        tip_router_client
+            .do_commit_vote(
+                ncn,
+                operator,
+                operator_admin,
+                ballot.commitment(&operator, epoch, &salt),
+                epoch,
+            )
+            .await?;
+
+        // Reveals are rejected while the commit window is open
+        let result = tip_router_client
+            .do_reveal_vote(ncn, operator, operator_admin, meta_merkle_root, salt, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::CommitWindowOpen);
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert_eq!(ballot_box.operators_voted(), 0);
+        assert!(!ballot_box.has_ballot(&[], &ballot));
+
+        fixture.warp_slot_incremental(commit_window_slots).await?;
+
+        // Commits are rejected once the commit window closes
+        let result = tip_router_client
+            .do_commit_vote(
+                ncn,
+                operator,
+                operator_admin,
+                ballot.commitment(&operator, epoch, &salt),
+                epoch,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::CommitWindowClosed);
+
+        // Reveals must match the commitment
+        let result = tip_router_client
+            .do_reveal_vote(ncn, operator, operator_admin, [3u8; 32], salt, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidVoteCommitment);
+
+        tip_router_client
+            .do_reveal_vote(ncn, operator, operator_admin, meta_merkle_root, salt, epoch)
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.has_ballot(&[], &ballot));
+        assert!(ballot_box.is_consensus_reached());
+
+        Ok(())
+    }
+
     #[ignore = "long test"]
     #[tokio::test]
     async fn test_cast_vote_max_cu() -> TestResult<()> {
@@ -192,7 +472,7 @@ mod tests {
                 .await?;
 
             let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
-            assert!(ballot_box.has_ballot(&Ballot::new(&meta_merkle_root)));
+            assert!(ballot_box.has_ballot(&[], &Ballot::new(&meta_merkle_root)));
         }
 
         let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    const CRANK_BOUNTY_LAMPORTS: u64 = 10_000;
+
+    #[tokio::test]
+    async fn test_crank_bounty_paid_up_to_cap() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_admin_initialize_config_extension(&test_ncn.ncn_root)
+            .await?;
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(CRANK_BOUNTY_LAMPORTS), // crank_bounty_lamports
+                Some(1),                     // max_crank_bounties_per_epoch
+                None,
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        // Bounty settings are copied into the epoch state when it is created
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.crank_bounty_lamports(), CRANK_BOUNTY_LAMPORTS);
+        assert_eq!(epoch_state.max_crank_bounties(), 1);
+        assert_eq!(epoch_state.crank_bounties_paid(), 0);
+
+        let cranker = Keypair::new();
+        fixture.transfer(&cranker.pubkey(), 1.0).await?;
+        let cranker_balance_before = fixture
+            .get_account(&cranker.pubkey())
+            .await?
+            .unwrap()
+            .lamports;
+
+        tip_router_client
+            .do_initialize_weight_table_with_bounty(ncn, epoch, &cranker)
+            .await?;
+
+        let cranker_balance_after = fixture
+            .get_account(&cranker.pubkey())
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(
+            cranker_balance_after,
+            cranker_balance_before + CRANK_BOUNTY_LAMPORTS
+        );
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.crank_bounties_paid(), 1);
+        assert_eq!(
+            epoch_state.crank_bounty_lamports_paid(),
+            CRANK_BOUNTY_LAMPORTS
+        );
+        assert_eq!(epoch_state.next_crank_bounty(), 0);
+
+        // The epoch's cap is reached, the step still succeeds without a bounty
+        tip_router_client
+            .do_realloc_weight_table_with_bounty(ncn, epoch, &cranker)
+            .await?;
+
+        let cranker_balance_capped = fixture
+            .get_account(&cranker.pubkey())
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(cranker_balance_capped, cranker_balance_after);
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.crank_bounties_paid(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_crank_bounty_disabled() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        let cranker = Keypair::new();
+        fixture.transfer(&cranker.pubkey(), 1.0).await?;
+        let cranker_balance_before = fixture
+            .get_account(&cranker.pubkey())
+            .await?
+            .unwrap()
+            .lamports;
+
+        tip_router_client
+            .do_initialize_weight_table_with_bounty(ncn, epoch, &cranker)
+            .await?;
+
+        let cranker_balance_after = fixture
+            .get_account(&cranker.pubkey())
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(cranker_balance_after, cranker_balance_before);
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.crank_bounties_paid(), 0);
+
+        Ok(())
+    }
+}
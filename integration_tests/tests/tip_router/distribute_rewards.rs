@@ -134,6 +134,8 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
+                None,
             )
             .await?;
 
@@ -216,7 +218,7 @@ mod tests {
 
         let operator_1_rewards = {
             let reward_route = base_reward_router_account
-                .ncn_fee_group_reward_route(&test_ncn.operators[0].operator_pubkey)
+                .ncn_fee_group_reward_route(&[], &test_ncn.operators[0].operator_pubkey)
                 .unwrap();
             let lst_rewards = reward_route.rewards(NcnFeeGroup::default()).unwrap();
             let jto_rewards = reward_route
@@ -229,7 +231,7 @@ mod tests {
 
         let operator_2_rewards = {
             let reward_route = base_reward_router_account
-                .ncn_fee_group_reward_route(&test_ncn.operators[1].operator_pubkey)
+                .ncn_fee_group_reward_route(&[], &test_ncn.operators[1].operator_pubkey)
                 .unwrap();
             let lst_rewards = reward_route.rewards(NcnFeeGroup::default()).unwrap();
             let jto_rewards = reward_route
@@ -458,6 +460,8 @@ mod tests {
                     None,
                     None,
                     None,
+                    None,
+                    None,
                 )
                 .await?;
         }
@@ -138,11 +138,9 @@ mod tests {
             let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
 
             for i in 0..OPERATOR_COUNT {
-                assert_eq!(epoch_state.operator_snapshot_progress(i).tally(), 0);
-                assert_eq!(
-                    epoch_state.operator_snapshot_progress(i).total(),
-                    VAULT_COUNT as u64
-                );
+                let operator_snapshot_progress = epoch_state.operator_snapshot_progress(i).unwrap();
+                assert_eq!(operator_snapshot_progress.tally(), 0);
+                assert_eq!(operator_snapshot_progress.total(), VAULT_COUNT as u64);
             }
         }
 
@@ -187,15 +185,10 @@ mod tests {
             );
 
             for i in 0..OPERATOR_COUNT {
-                assert_eq!(
-                    epoch_state.operator_snapshot_progress(i).tally(),
-                    VAULT_COUNT as u64
-                );
-                assert_eq!(
-                    epoch_state.operator_snapshot_progress(i).total(),
-                    VAULT_COUNT as u64
-                );
-                assert!(epoch_state.operator_snapshot_progress(i).is_complete());
+                let operator_snapshot_progress = epoch_state.operator_snapshot_progress(i).unwrap();
+                assert_eq!(operator_snapshot_progress.tally(), VAULT_COUNT as u64);
+                assert_eq!(operator_snapshot_progress.total(), VAULT_COUNT as u64);
+                assert!(operator_snapshot_progress.is_complete());
             }
         }
 
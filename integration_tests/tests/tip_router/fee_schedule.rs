@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        base_fee_group::BaseFeeGroup, error::TipRouterError, fee_schedule::FeeScheduleAction,
+    };
+    use solana_program::instruction::InstructionError;
+
+    use crate::fixtures::{
+        assert_ix_error, test_builder::TestBuilder, tip_router_client::assert_tip_router_error,
+        TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_fee_schedule_ramp() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn_root = &test_ncn.ncn_root;
+        let ncn = ncn_root.ncn_pubkey;
+        let base_fee_group = BaseFeeGroup::dao();
+
+        tip_router_client
+            .do_admin_initialize_fee_schedule(ncn_root)
+            .await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let config_base_fee_bps = {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            config
+                .fee_config
+                .current_fees(None, epoch)
+                .base_fee_bps(base_fee_group)
+                .unwrap()
+        };
+
+        // Announce a three step ramp
+        for (epochs_ahead, base_fee_bps) in [(2, 150), (3, 200), (4, 300)] {
+            tip_router_client
+                .do_set_fee_schedule(
+                    FeeScheduleAction::Append,
+                    epoch + epochs_ahead,
+                    Some(base_fee_group),
+                    Some(base_fee_bps),
+                    None,
+                    None,
+                    ncn_root,
+                )
+                .await?;
+        }
+
+        let fee_schedule = tip_router_client.get_fee_schedule(ncn).await?;
+        assert_eq!(fee_schedule.entry_count(), 3);
+        assert_eq!(
+            fee_schedule
+                .scheduled_fees(epoch + 3)
+                .unwrap()
+                .base_fee_bps(base_fee_group)
+                .unwrap(),
+            200
+        );
+
+        // Rework the later steps
+        tip_router_client
+            .do_set_fee_schedule(
+                FeeScheduleAction::Replace,
+                epoch + 3,
+                Some(base_fee_group),
+                Some(250),
+                None,
+                None,
+                ncn_root,
+            )
+            .await?;
+        tip_router_client
+            .do_set_fee_schedule(
+                FeeScheduleAction::Cancel,
+                epoch + 4,
+                None,
+                None,
+                None,
+                None,
+                ncn_root,
+            )
+            .await?;
+
+        let fee_schedule = tip_router_client.get_fee_schedule(ncn).await?;
+        assert_eq!(fee_schedule.entry_count(), 2);
+        assert_eq!(
+            fee_schedule
+                .scheduled_fees(epoch + 3)
+                .unwrap()
+                .base_fee_bps(base_fee_group)
+                .unwrap(),
+            250
+        );
+        assert!(fee_schedule.scheduled_fees(epoch + 4).is_none());
+
+        // The active fees are untouched until the first step activates
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(
+            config
+                .fee_config
+                .current_fees(Some(&fee_schedule), epoch)
+                .base_fee_bps(base_fee_group)
+                .unwrap(),
+            config_base_fee_bps
+        );
+        assert_eq!(
+            config
+                .fee_config
+                .current_fees(Some(&fee_schedule), epoch + 2)
+                .base_fee_bps(base_fee_group)
+                .unwrap(),
+            150
+        );
+
+        // The epoch snapshot picks up the scheduled fees
+        fixture.warp_epoch_incremental(2).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+        fixture.add_admin_weights_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        tip_router_client
+            .do_initialize_epoch_snapshot(ncn, epoch)
+            .await?;
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, epoch).await?;
+        assert_eq!(
+            epoch_snapshot.fees().base_fee_bps(base_fee_group).unwrap(),
+            150
+        );
+
+        // Operators size merkle tree fees from the same schedule the snapshot used
+        let fee_schedule = tip_router_client.get_fee_schedule(ncn).await?;
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        let snapshot_total_fees_bps = epoch_snapshot.fees().total_fees_bps().unwrap();
+        assert_eq!(
+            config
+                .fee_config
+                .total_fees_bps(Some(&fee_schedule), epoch)
+                .unwrap(),
+            snapshot_total_fees_bps
+        );
+        assert_ne!(
+            config.fee_config.total_fees_bps(None, epoch).unwrap(),
+            snapshot_total_fees_bps
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fee_schedule_bad_entries() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn_root = &test_ncn.ncn_root;
+        let base_fee_group = BaseFeeGroup::dao();
+
+        let epoch = fixture.clock().await.epoch;
+
+        // The fee schedule has to be initialized first
+        let result = tip_router_client
+            .do_set_fee_schedule(
+                FeeScheduleAction::Append,
+                epoch + 2,
+                Some(base_fee_group),
+                Some(100),
+                None,
+                None,
+                ncn_root,
+            )
+            .await;
+        assert_ix_error(result, InstructionError::UninitializedAccount);
+
+        tip_router_client
+            .do_admin_initialize_fee_schedule(ncn_root)
+            .await?;
+
+        tip_router_client
+            .do_set_fee_schedule(
+                FeeScheduleAction::Append,
+                epoch + 2,
+                Some(base_fee_group),
+                Some(100),
+                None,
+                None,
+                ncn_root,
+            )
+            .await?;
+
+        // Entries are only for future epochs
+        let result = tip_router_client
+            .do_set_fee_schedule(
+                FeeScheduleAction::Append,
+                epoch,
+                Some(base_fee_group),
+                Some(200),
+                None,
+                None,
+                ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidFeeScheduleEpoch);
+
+        // Appended entries activate after the last one
+        let result = tip_router_client
+            .do_set_fee_schedule(
+                FeeScheduleAction::Append,
+                epoch + 2,
+                Some(base_fee_group),
+                Some(200),
+                None,
+                None,
+                ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidFeeScheduleEpoch);
+
+        let result = tip_router_client
+            .do_set_fee_schedule(
+                FeeScheduleAction::Replace,
+                epoch + 3,
+                Some(base_fee_group),
+                Some(200),
+                None,
+                None,
+                ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::FeeScheduleEntryNotFound);
+
+        let result = tip_router_client
+            .do_set_fee_schedule(
+                FeeScheduleAction::Cancel,
+                epoch + 3,
+                None,
+                None,
+                None,
+                None,
+                ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::FeeScheduleEntryNotFound);
+
+        Ok(())
+    }
+}
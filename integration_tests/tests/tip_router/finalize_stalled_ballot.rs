@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+
+    use jito_tip_router_core::{
+        ballot_box::Ballot, constants::DEFAULT_CONSENSUS_REACHED_SLOT, error::TipRouterError,
+    };
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_finalize_stalled_ballot() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        // Each operator gets 25% voting share
+        let test_ncn = fixture.create_initial_test_ncn(4, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let clock = fixture.clock().await;
+        let epoch = clock.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        // Fallback is disabled by default
+        let result = tip_router_client
+            .do_finalize_stalled_ballot(ncn, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::PluralityFallbackDisabled);
+
+        tip_router_client
+            .do_set_parameters(
+                None,
+                Some(1),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(1),     // plurality_fallback_epochs
+                Some(6_000), // plurality_min_stake_weight_bps
+                None,
+                None,
+                None,
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        let plurality_root = [1; 32];
+        let other_root = [2; 32];
+
+        tip_router_client
+            .do_cast_vote(
+                ncn,
+                test_ncn.operators[0].operator_pubkey,
+                &test_ncn.operators[0].operator_admin,
+                plurality_root,
+                epoch,
+            )
+            .await?;
+        tip_router_client
+            .do_cast_vote(
+                ncn,
+                test_ncn.operators[1].operator_pubkey,
+                &test_ncn.operators[1].operator_admin,
+                other_root,
+                epoch,
+            )
+            .await?;
+
+        // Stalled, but not yet past the fallback window
+        fixture.warp_epoch_incremental(1).await?;
+        let result = tip_router_client
+            .do_finalize_stalled_ballot(ncn, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::VotingNotFinalized);
+
+        // No single ballot leads
+        fixture.warp_epoch_incremental(1).await?;
+        let result = tip_router_client
+            .do_finalize_stalled_ballot(ncn, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::NoPluralityBallot);
+
+        // 50% of stake, short of consensus and of the 60% floor
+        tip_router_client
+            .do_cast_vote(
+                ncn,
+                test_ncn.operators[2].operator_pubkey,
+                &test_ncn.operators[2].operator_admin,
+                plurality_root,
+                epoch,
+            )
+            .await?;
+        let result = tip_router_client
+            .do_finalize_stalled_ballot(ncn, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::PluralityBelowStakeFloor);
+
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(5_000),
+                None,
+                None,
+                None,
+                None,
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        tip_router_client
+            .do_finalize_stalled_ballot(ncn, epoch)
+            .await?;
+
+        let ballot = Ballot::new(&plurality_root);
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(*ballot_box.get_winning_ballot().unwrap(), ballot);
+        // No official consensus reached so no slot set
+        assert_eq!(
+            ballot_box.slot_consensus_reached(),
+            DEFAULT_CONSENSUS_REACHED_SLOT
+        );
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert!(epoch_state.was_plurality_set());
+        assert!(!epoch_state.was_tie_breaker_set());
+        assert!(epoch_state.is_consensus_reached());
+
+        // Cannot finalize twice
+        let result = tip_router_client
+            .do_finalize_stalled_ballot(ncn, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::ConsensusAlreadyReached);
+
+        Ok(())
+    }
+}
@@ -4,9 +4,12 @@ mod tests {
     use jito_tip_router_core::{
         ballot_box::BallotBox,
         constants::{DEFAULT_CONSENSUS_REACHED_SLOT, MAX_REALLOC_BYTES},
+        error::TipRouterError,
     };
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
 
     #[tokio::test]
     async fn test_initialize_ballot_box() -> TestResult<()> {
@@ -53,7 +56,39 @@ mod tests {
             ballot_box.slot_consensus_reached(),
             DEFAULT_CONSENSUS_REACHED_SLOT
         );
-        assert!(ballot_box.get_winning_ballot_tally().is_err(),);
+        assert!(ballot_box.get_winning_ballot_tally(&[]).is_err(),);
+        assert_eq!(ballot_box.page_count(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_realloc_ballot_box_page_not_required() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        // A single operator fits in the ballot box itself
+        let result = tip_router_client
+            .do_realloc_ballot_box_page(ncn, epoch, 1, 1)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidPage);
+
+        // Pages are 1-indexed
+        let result = tip_router_client
+            .do_realloc_ballot_box_page(ncn, epoch, 0, 1)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidPage);
 
         Ok(())
     }
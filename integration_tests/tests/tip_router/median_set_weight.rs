@@ -0,0 +1,290 @@
+#[cfg(test)]
+mod tests {
+
+    use jito_tip_router_core::{
+        constants::WEIGHT_PRECISION, error::TipRouterError, oracle_feed::OracleFeedType,
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::{
+        fixtures::{
+            test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+        },
+        helpers::serialized_accounts::serialized_pyth_price_account,
+    };
+
+    #[tokio::test]
+    async fn test_median_set_weight() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let st_mint = *tip_router_client
+            .get_vault_registry(ncn)
+            .await?
+            .st_mint_list[0]
+            .st_mint();
+
+        let clock = fixture.clock().await;
+        let epoch = clock.epoch;
+
+        // 1.00, 1.01 and 1.02 - a 198 bps spread around the median
+        let oracle_feeds = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        for (oracle_feed, price) in oracle_feeds.iter().zip([102, 100, 101]) {
+            fixture
+                .set_account(
+                    *oracle_feed,
+                    serialized_pyth_price_account(price, -2, clock.slot),
+                )
+                .await;
+        }
+
+        tip_router_client
+            .do_admin_set_st_mint_oracle_feeds(
+                ncn,
+                st_mint,
+                oracle_feeds,
+                [OracleFeedType::Pyth as u8; 3],
+                200,
+            )
+            .await?;
+
+        tip_router_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        tip_router_client
+            .do_median_set_weight(ncn, epoch, st_mint)
+            .await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        assert_eq!(
+            weight_table.get_weight(&st_mint).unwrap(),
+            101 * WEIGHT_PRECISION / 100
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_median_set_weight_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let st_mint = *tip_router_client
+            .get_vault_registry(ncn)
+            .await?
+            .st_mint_list[0]
+            .st_mint();
+
+        let clock = fixture.clock().await;
+        let epoch = clock.epoch;
+
+        let oracle_feeds = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::default(),
+        ];
+        for (oracle_feed, price) in oracle_feeds[..2].iter().zip([100, 102]) {
+            fixture
+                .set_account(
+                    *oracle_feed,
+                    serialized_pyth_price_account(price, -2, clock.slot),
+                )
+                .await;
+        }
+
+        // Tolerance is tighter than the 198 bps spread
+        tip_router_client
+            .do_admin_set_st_mint_oracle_feeds(
+                ncn,
+                st_mint,
+                oracle_feeds,
+                [OracleFeedType::Pyth as u8; 3],
+                100,
+            )
+            .await?;
+
+        tip_router_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        let result = tip_router_client
+            .do_median_set_weight(ncn, epoch, st_mint)
+            .await;
+        assert_tip_router_error(result, TipRouterError::OracleFeedsDiverged);
+
+        // Feeds out of order
+        let result = tip_router_client
+            .median_set_weight(ncn, epoch, st_mint, &[oracle_feeds[1], oracle_feeds[0]])
+            .await;
+        assert_tip_router_error(result, TipRouterError::OracleFeedNotRegistered);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_median_set_weight_stale_feed() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let st_mint = *tip_router_client
+            .get_vault_registry(ncn)
+            .await?
+            .st_mint_list[0]
+            .st_mint();
+
+        let clock = fixture.clock().await;
+        let epoch = clock.epoch;
+
+        let oracle_feed = Pubkey::new_unique();
+        fixture
+            .set_account(
+                oracle_feed,
+                serialized_pyth_price_account(100, -2, clock.slot),
+            )
+            .await;
+
+        tip_router_client
+            .do_admin_set_st_mint_oracle_feeds(
+                ncn,
+                st_mint,
+                [oracle_feed, Pubkey::default(), Pubkey::default()],
+                [OracleFeedType::Pyth as u8; 3],
+                0,
+            )
+            .await?;
+
+        tip_router_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let result = tip_router_client
+            .do_median_set_weight(ncn, epoch, st_mint)
+            .await;
+        assert_tip_router_error(result, TipRouterError::StalePythFeed);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_median_set_weight_change_limit() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let st_mint = *tip_router_client
+            .get_vault_registry(ncn)
+            .await?
+            .st_mint_list[0]
+            .st_mint();
+
+        let clock = fixture.clock().await;
+        let epoch = clock.epoch;
+
+        let oracle_feed = Pubkey::new_unique();
+        fixture
+            .set_account(
+                oracle_feed,
+                serialized_pyth_price_account(100, -2, clock.slot),
+            )
+            .await;
+
+        tip_router_client
+            .do_admin_set_st_mint_oracle_feeds(
+                ncn,
+                st_mint,
+                [oracle_feed, Pubkey::default(), Pubkey::default()],
+                [OracleFeedType::Pyth as u8; 3],
+                0,
+            )
+            .await?;
+
+        // 5% limit from one epoch to the next
+        tip_router_client
+            .do_admin_set_st_mint(ncn, st_mint, None, None, None, None, Some(500), None)
+            .await?;
+
+        tip_router_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        tip_router_client
+            .do_median_set_weight(ncn, epoch, st_mint)
+            .await?;
+
+        fixture.warp_epoch_incremental(1).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let clock = fixture.clock().await;
+        let epoch = clock.epoch;
+
+        tip_router_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        // 1.00 -> 1.10 is a 1000 bps change
+        fixture
+            .set_account(
+                oracle_feed,
+                serialized_pyth_price_account(110, -2, clock.slot),
+            )
+            .await;
+
+        let result = tip_router_client
+            .do_median_set_weight(ncn, epoch, st_mint)
+            .await;
+        assert_tip_router_error(result, TipRouterError::WeightChangeExceedsLimit);
+
+        // 1.00 -> 1.05 is within the limit
+        fixture
+            .set_account(
+                oracle_feed,
+                serialized_pyth_price_account(105, -2, clock.slot),
+            )
+            .await;
+
+        tip_router_client
+            .do_median_set_weight(ncn, epoch, st_mint)
+            .await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        assert_eq!(
+            weight_table.get_weight(&st_mint).unwrap(),
+            105 * WEIGHT_PRECISION / 100
+        );
+
+        // The admin can always override the limit
+        tip_router_client
+            .do_admin_set_weight(ncn, epoch, st_mint, 2 * WEIGHT_PRECISION)
+            .await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        assert_eq!(
+            weight_table.get_weight(&st_mint).unwrap(),
+            2 * WEIGHT_PRECISION
+        );
+
+        Ok(())
+    }
+}
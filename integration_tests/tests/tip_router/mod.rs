@@ -1,11 +1,15 @@
+mod account_payer_top_up;
 mod admin_set_parameters;
 mod admin_set_st_mint;
 mod admin_update_weight_table;
 mod bpf;
 mod cast_vote;
 mod close_epoch_accounts;
+mod crank_bounty;
 mod distribute_rewards;
 mod epoch_state;
+mod fee_schedule;
+mod finalize_stalled_ballot;
 mod initialize_ballot_box;
 mod initialize_base_reward_router;
 mod initialize_config;
@@ -14,13 +18,22 @@ mod initialize_ncn_reward_router;
 mod initialize_operator_snapshot;
 mod initialize_vault_registry;
 mod initialize_weight_table;
+mod median_set_weight;
 mod meta_tests;
+mod operator_bounds;
+mod operator_record;
 mod register_vault;
 mod restaking_variations;
+mod retire_vault;
 mod set_config_fees;
 mod set_new_admin;
+mod set_pause;
 mod set_tie_breaker;
 mod set_tracked_mint_ncn_fee_group;
+mod set_vault_reward_asset;
+mod set_vault_reward_multiplier;
 mod simulation_tests;
 mod snapshot_vault_operator_delegation;
+mod sweep_dust;
 mod switchboard_set_weight;
+mod tie_breaker_committee;
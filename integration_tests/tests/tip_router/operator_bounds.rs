@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::epoch_snapshot::OperatorInactiveReason;
+
+    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+
+    const OPERATOR_FEE_BPS: u16 = 2_000;
+
+    #[tokio::test]
+    async fn test_operator_fee_above_maximum_is_inactive() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(2, 1, Some(OPERATOR_FEE_BPS))
+            .await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_admin_initialize_config_extension(&test_ncn.ncn_root)
+            .await?;
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(OPERATOR_FEE_BPS - 1), // max_operator_fee_bps
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        for operator_root in test_ncn.operators.iter() {
+            let operator_snapshot = tip_router_client
+                .get_operator_snapshot(operator_root.operator_pubkey, ncn, epoch)
+                .await?;
+            assert!(!operator_snapshot.is_active());
+            assert!(operator_snapshot.finalized());
+            assert_eq!(
+                operator_snapshot.inactive_reason().unwrap(),
+                OperatorInactiveReason::FeeAboveMaximum
+            );
+        }
+
+        // The operators are registered without stake
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, epoch).await?;
+        assert!(epoch_snapshot.finalized());
+        assert_eq!(epoch_snapshot.stake_weights().stake_weight(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_operator_stake_below_minimum_is_inactive() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 2, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_admin_initialize_config_extension(&test_ncn.ncn_root)
+            .await?;
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(u128::MAX), // min_operator_stake_weight
+                None,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        for operator_root in test_ncn.operators.iter() {
+            let operator_snapshot = tip_router_client
+                .get_operator_snapshot(operator_root.operator_pubkey, ncn, epoch)
+                .await?;
+            assert!(!operator_snapshot.is_active());
+            assert!(operator_snapshot.finalized());
+            assert_eq!(
+                operator_snapshot.inactive_reason().unwrap(),
+                OperatorInactiveReason::StakeBelowMinimum
+            );
+            assert_eq!(operator_snapshot.valid_operator_vault_delegations(), 0);
+            assert_eq!(operator_snapshot.stake_weights().stake_weight(), 0);
+        }
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, epoch).await?;
+        assert!(epoch_snapshot.finalized());
+        assert_eq!(epoch_snapshot.valid_operator_vault_delegations(), 0);
+        assert_eq!(epoch_snapshot.stake_weights().stake_weight(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_operators_within_bounds_are_active() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(2, 1, Some(OPERATOR_FEE_BPS))
+            .await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_admin_initialize_config_extension(&test_ncn.ncn_root)
+            .await?;
+        tip_router_client
+            .do_set_parameters(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(1),                // min_operator_stake_weight
+                Some(OPERATOR_FEE_BPS), // max_operator_fee_bps
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        for operator_root in test_ncn.operators.iter() {
+            let operator_snapshot = tip_router_client
+                .get_operator_snapshot(operator_root.operator_pubkey, ncn, epoch)
+                .await?;
+            assert!(operator_snapshot.is_active());
+            assert_eq!(
+                operator_snapshot.inactive_reason().unwrap(),
+                OperatorInactiveReason::None
+            );
+            assert_eq!(operator_snapshot.operator_fee_bps(), OPERATOR_FEE_BPS);
+        }
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, epoch).await?;
+        assert!(epoch_snapshot.stake_weights().stake_weight() > 0);
+
+        Ok(())
+    }
+}
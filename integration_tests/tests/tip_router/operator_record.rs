@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod tests {
+
+    use jito_tip_router_core::{
+        ballot_box::BallotBox, epoch_snapshot::OperatorSnapshot, error::TipRouterError,
+        operator_record::OperatorRecord,
+    };
+    use solana_sdk::account::Account;
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_operator_record() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        // Each operator gets 25% voting share
+        let test_ncn = fixture.create_initial_test_ncn(4, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        let operator_record = tip_router_client
+            .get_operator_record(ncn, test_ncn.operators[0].operator_pubkey)
+            .await?;
+        assert_eq!(operator_record.epochs_voted(), 0);
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let winning_root = [1; 32];
+        let losing_root = [2; 32];
+
+        // Operator 3 does not vote, no ballot reaches consensus
+        for (operator_root, meta_merkle_root) in
+            test_ncn
+                .operators
+                .iter()
+                .zip([losing_root, winning_root, winning_root])
+        {
+            tip_router_client
+                .do_cast_vote(
+                    ncn,
+                    operator_root.operator_pubkey,
+                    &operator_root.operator_admin,
+                    meta_merkle_root,
+                    epoch,
+                )
+                .await?;
+        }
+
+        // Changing a vote is not another epoch voted
+        fixture.warp_slot_incremental(1).await?;
+        tip_router_client
+            .do_cast_vote(
+                ncn,
+                test_ncn.operators[1].operator_pubkey,
+                &test_ncn.operators[1].operator_admin,
+                losing_root,
+                epoch,
+            )
+            .await?;
+        fixture.warp_slot_incremental(1).await?;
+        tip_router_client
+            .do_cast_vote(
+                ncn,
+                test_ncn.operators[1].operator_pubkey,
+                &test_ncn.operators[1].operator_admin,
+                winning_root,
+                epoch,
+            )
+            .await?;
+
+        let operator_record = tip_router_client
+            .get_operator_record(ncn, test_ncn.operators[1].operator_pubkey)
+            .await?;
+        assert_eq!(operator_record.epochs_voted(), 1);
+        assert_eq!(operator_record.last_voted_epoch(), epoch);
+
+        fixture.warp_slot_incremental(1000000).await?;
+        tip_router_client
+            .do_admin_set_tie_breaker(ncn, winning_root, epoch)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        fixture
+            .warp_epoch_incremental(config.epochs_after_consensus_before_close() + 1)
+            .await?;
+
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        // Operator snapshots are closed first, they need the ballot box
+        let result = tip_router_client
+            .do_close_epoch_account(ncn, epoch, ballot_box, None)
+            .await;
+        assert_tip_router_error(
+            result,
+            TipRouterError::CannotCloseBallotBoxOperatorSnapshotsOpen,
+        );
+
+        for operator_root in test_ncn.operators.iter() {
+            let operator_snapshot = OperatorSnapshot::find_program_address(
+                &jito_tip_router_program::id(),
+                &operator_root.operator_pubkey,
+                &ncn,
+                epoch,
+            )
+            .0;
+
+            tip_router_client
+                .do_close_epoch_account(ncn, epoch, operator_snapshot, None)
+                .await?;
+        }
+
+        tip_router_client
+            .do_close_epoch_account(ncn, epoch, ballot_box, None)
+            .await?;
+
+        let operator_records = [
+            tip_router_client
+                .get_operator_record(ncn, test_ncn.operators[0].operator_pubkey)
+                .await?,
+            tip_router_client
+                .get_operator_record(ncn, test_ncn.operators[1].operator_pubkey)
+                .await?,
+            tip_router_client
+                .get_operator_record(ncn, test_ncn.operators[3].operator_pubkey)
+                .await?,
+        ];
+
+        // Voted for the losing ballot
+        assert_eq!(operator_records[0].epochs_voted(), 1);
+        assert_eq!(operator_records[0].epochs_non_winning(), 1);
+        assert_eq!(operator_records[0].epochs_missed(), 0);
+
+        // Voted for the winning ballot
+        assert_eq!(operator_records[1].epochs_voted(), 1);
+        assert_eq!(operator_records[1].epochs_non_winning(), 0);
+        assert_eq!(operator_records[1].epochs_missed(), 0);
+        assert_eq!(operator_records[1].last_closed_epoch(), epoch);
+
+        // Did not vote
+        assert_eq!(operator_records[2].epochs_voted(), 0);
+        assert_eq!(operator_records[2].epochs_non_winning(), 0);
+        assert_eq!(operator_records[2].epochs_missed(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_operator_without_record() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(3, 1, None).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        // Operator 0 never initialized a record
+        let missing_operator_record = OperatorRecord::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            &test_ncn.operators[0].operator_pubkey,
+        )
+        .0;
+        fixture
+            .set_account(missing_operator_record, Account::default())
+            .await;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        // It still votes
+        for operator_root in test_ncn.operators.iter() {
+            tip_router_client
+                .do_cast_vote(
+                    ncn,
+                    operator_root.operator_pubkey,
+                    &operator_root.operator_admin,
+                    [1; 32],
+                    epoch,
+                )
+                .await?;
+        }
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert_eq!(ballot_box.operators_voted(), 3);
+        assert!(ballot_box.is_consensus_reached());
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        fixture
+            .warp_epoch_incremental(config.epochs_after_consensus_before_close() + 1)
+            .await?;
+
+        // And its snapshot closes without a record to update
+        for operator_root in test_ncn.operators.iter() {
+            let operator_snapshot = OperatorSnapshot::find_program_address(
+                &jito_tip_router_program::id(),
+                &operator_root.operator_pubkey,
+                &ncn,
+                epoch,
+            )
+            .0;
+
+            tip_router_client
+                .do_close_epoch_account(ncn, epoch, operator_snapshot, None)
+                .await?;
+        }
+
+        assert!(fixture
+            .get_account(&missing_operator_record)
+            .await?
+            .is_none());
+
+        let operator_record = tip_router_client
+            .get_operator_record(ncn, test_ncn.operators[1].operator_pubkey)
+            .await?;
+        assert_eq!(operator_record.epochs_voted(), 1);
+        assert_eq!(operator_record.last_closed_epoch(), epoch);
+
+        Ok(())
+    }
+}
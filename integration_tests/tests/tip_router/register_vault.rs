@@ -62,6 +62,8 @@ mod tests {
                 10_000,
                 Some(JTO_SOL_FEED),
                 None,
+                None,
+                None,
             )
             .await?;
 
@@ -160,6 +162,8 @@ mod tests {
                 10_000,
                 Some(JTO_SOL_FEED),
                 None,
+                None,
+                None,
             )
             .await?;
 
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::error::TipRouterError;
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_retire_vault() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 2, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let active_vault = test_ncn.vaults[0].vault_pubkey;
+        let retired_vault = test_ncn.vaults[1].vault_pubkey;
+
+        let epoch = fixture.clock().await.epoch;
+
+        // Retirement has to be scheduled for a future epoch
+        let result = tip_router_client
+            .do_admin_retire_vault(ncn, retired_vault, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidRetiredEpoch);
+
+        tip_router_client
+            .do_admin_retire_vault(ncn, retired_vault, epoch + 1)
+            .await?;
+
+        let result = tip_router_client
+            .do_admin_retire_vault(ncn, retired_vault, epoch + 1)
+            .await;
+        assert_tip_router_error(result, TipRouterError::VaultRetired);
+
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        let retired_st_mint = *vault_registry
+            .vault_list
+            .iter()
+            .find(|entry| entry.vault().eq(&retired_vault))
+            .unwrap()
+            .st_mint();
+        assert_eq!(vault_registry.active_vault_count(epoch), 2);
+        assert_eq!(vault_registry.active_vault_count(epoch + 1), 1);
+
+        // The st mint still backs the active vault until the retirement epoch
+        let result = tip_router_client
+            .do_admin_retire_st_mint(ncn, retired_st_mint, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidRetiredEpoch);
+
+        fixture.warp_epoch_incremental(1).await?;
+        let epoch = fixture.clock().await.epoch;
+
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+        fixture.add_admin_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        assert_eq!(weight_table.vault_count(), 1);
+
+        let result = tip_router_client
+            .do_snapshot_vault_operator_delegation(retired_vault, operator, ncn, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::VaultNotInRegistry);
+
+        tip_router_client
+            .do_snapshot_vault_operator_delegation(active_vault, operator, ncn, epoch)
+            .await?;
+
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, epoch)
+            .await?;
+        assert!(operator_snapshot.finalized());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retire_st_mint_with_active_vaults() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+        let st_mint = *tip_router_client
+            .get_vault_registry(ncn)
+            .await?
+            .st_mint_list[0]
+            .st_mint();
+
+        let epoch = fixture.clock().await.epoch;
+
+        let result = tip_router_client
+            .do_admin_retire_st_mint(ncn, st_mint, epoch + 1)
+            .await;
+        assert_tip_router_error(result, TipRouterError::StMintHasActiveVaults);
+
+        tip_router_client
+            .do_admin_retire_vault(ncn, vault, epoch + 1)
+            .await?;
+        tip_router_client
+            .do_admin_retire_st_mint(ncn, st_mint, epoch + 1)
+            .await?;
+
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        assert_eq!(vault_registry.active_st_mint_count(epoch), 1);
+        assert_eq!(vault_registry.active_st_mint_count(epoch + 1), 0);
+
+        Ok(())
+    }
+}
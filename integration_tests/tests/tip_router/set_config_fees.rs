@@ -57,7 +57,7 @@ mod tests {
             new_base_fee_wallet
         );
 
-        let current_fees = ncn_config.fee_config.current_fees(u64::MAX);
+        let current_fees = ncn_config.fee_config.current_fees(None, u64::MAX);
 
         assert_eq!(
             current_fees.base_fee_bps(base_fee_group).unwrap(),
@@ -142,7 +142,7 @@ mod tests {
             NEW_BLOCK_ENGINE_FEE
         );
 
-        let current_fees = ncn_config.fee_config.current_fees(u64::MAX);
+        let current_fees = ncn_config.fee_config.current_fees(None, u64::MAX);
 
         for group in BaseFeeGroup::all_groups().iter() {
             assert_eq!(
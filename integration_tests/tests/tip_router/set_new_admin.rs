@@ -1,8 +1,10 @@
 mod tests {
     use jito_tip_router_client::types::ConfigAdminRole;
-    use jito_tip_router_core::{config::Config as NcnConfig, error::TipRouterError};
+    use jito_tip_router_core::{
+        config::Config as NcnConfig, constants::PENDING_ADMIN_EXPIRY_EPOCHS, error::TipRouterError,
+    };
     use solana_program::pubkey::Pubkey;
-    use solana_sdk::{instruction::InstructionError, signature::Keypair};
+    use solana_sdk::{instruction::InstructionError, signature::Keypair, signer::Signer};
 
     use crate::fixtures::{
         assert_ix_error, restaking_client::NcnRoot, test_builder::TestBuilder,
@@ -19,27 +21,54 @@ mod tests {
             .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
             .await?;
 
-        let new_fee_admin = Pubkey::new_unique();
+        let new_fee_admin = Keypair::new();
+        tip_router_client
+            .do_propose_new_admin(ConfigAdminRole::FeeAdmin, new_fee_admin.pubkey(), &ncn_root)
+            .await?;
+
+        // Proposing does not hand over the role
+        let config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        assert_ne!(config.fee_admin, new_fee_admin.pubkey());
+        assert_eq!(config.pending_admin, new_fee_admin.pubkey());
+
         tip_router_client
-            .do_set_new_admin(ConfigAdminRole::FeeAdmin, new_fee_admin, &ncn_root)
+            .do_accept_new_admin(
+                ConfigAdminRole::FeeAdmin,
+                &new_fee_admin,
+                ncn_root.ncn_pubkey,
+            )
             .await?;
 
         let config = tip_router_client
             .get_ncn_config(ncn_root.ncn_pubkey)
             .await?;
-        assert_eq!(config.fee_admin, new_fee_admin);
+        assert_eq!(config.fee_admin, new_fee_admin.pubkey());
+        assert!(!config.has_pending_admin());
 
         fixture.warp_slot_incremental(1).await?;
 
-        let new_tie_breaker = Pubkey::new_unique();
+        let new_tie_breaker = Keypair::new();
         tip_router_client
-            .do_set_new_admin(ConfigAdminRole::TieBreakerAdmin, new_tie_breaker, &ncn_root)
+            .do_propose_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                new_tie_breaker.pubkey(),
+                &ncn_root,
+            )
+            .await?;
+        tip_router_client
+            .do_accept_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                &new_tie_breaker,
+                ncn_root.ncn_pubkey,
+            )
             .await?;
 
         let config = tip_router_client
             .get_ncn_config(ncn_root.ncn_pubkey)
             .await?;
-        assert_eq!(config.tie_breaker_admin, new_tie_breaker);
+        assert_eq!(config.tie_breaker_admin, new_tie_breaker.pubkey());
         Ok(())
     }
 
@@ -58,7 +87,7 @@ mod tests {
         let wrong_ncn_root = restaking_program_client.do_initialize_ncn(None).await?;
 
         let result = tip_router_client
-            .set_new_admin(
+            .propose_new_admin(
                 NcnConfig::find_program_address(
                     &jito_tip_router_program::id(),
                     &ncn_root.ncn_pubkey,
@@ -78,7 +107,7 @@ mod tests {
         };
 
         let result = tip_router_client
-            .do_set_new_admin(
+            .do_propose_new_admin(
                 ConfigAdminRole::FeeAdmin,
                 Pubkey::new_unique(),
                 &wrong_ncn_root,
@@ -88,4 +117,119 @@ mod tests {
         assert_tip_router_error(result, TipRouterError::IncorrectNcnAdmin);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_accept_new_admin_wrong_signer_or_role() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        tip_router_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let new_fee_admin = Keypair::new();
+        tip_router_client
+            .do_propose_new_admin(ConfigAdminRole::FeeAdmin, new_fee_admin.pubkey(), &ncn_root)
+            .await?;
+
+        let result = tip_router_client
+            .do_accept_new_admin(
+                ConfigAdminRole::FeeAdmin,
+                &Keypair::new(),
+                ncn_root.ncn_pubkey,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::PendingAdminMismatch);
+
+        let result = tip_router_client
+            .do_accept_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                &new_fee_admin,
+                ncn_root.ncn_pubkey,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::PendingAdminMismatch);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_new_admin() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        tip_router_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let new_fee_admin = Keypair::new();
+        tip_router_client
+            .do_propose_new_admin(ConfigAdminRole::FeeAdmin, new_fee_admin.pubkey(), &ncn_root)
+            .await?;
+
+        // The pending proposal has to be cancelled before another role is proposed
+        let new_tie_breaker = Keypair::new();
+        let result = tip_router_client
+            .do_propose_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                new_tie_breaker.pubkey(),
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::PendingAdminExists);
+
+        tip_router_client.do_cancel_new_admin(&ncn_root).await?;
+
+        let result = tip_router_client
+            .do_accept_new_admin(
+                ConfigAdminRole::FeeAdmin,
+                &new_fee_admin,
+                ncn_root.ncn_pubkey,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::NoPendingAdmin);
+
+        tip_router_client
+            .do_propose_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                new_tie_breaker.pubkey(),
+                &ncn_root,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_accept_new_admin_expired() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        tip_router_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let new_fee_admin = Keypair::new();
+        tip_router_client
+            .do_propose_new_admin(ConfigAdminRole::FeeAdmin, new_fee_admin.pubkey(), &ncn_root)
+            .await?;
+
+        fixture
+            .warp_epoch_incremental(PENDING_ADMIN_EXPIRY_EPOCHS + 1)
+            .await?;
+
+        let result = tip_router_client
+            .do_accept_new_admin(
+                ConfigAdminRole::FeeAdmin,
+                &new_fee_admin,
+                ncn_root.ncn_pubkey,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::PendingAdminExpired);
+
+        Ok(())
+    }
 }
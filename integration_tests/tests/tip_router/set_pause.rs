@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        ballot_box::Ballot,
+        constants::{PAUSE_ALL, PAUSE_SET_MERKLE_ROOT, PAUSE_VOTING},
+        error::TipRouterError,
+    };
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_set_pause() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        tip_router_client
+            .do_set_pause(
+                PAUSE_VOTING | PAUSE_SET_MERKLE_ROOT,
+                &ncn_root.ncn_admin,
+                ncn,
+            )
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.paused, PAUSE_VOTING | PAUSE_SET_MERKLE_ROOT);
+        assert!(config.is_paused(PAUSE_VOTING));
+        assert!(config.is_paused(PAUSE_SET_MERKLE_ROOT));
+
+        tip_router_client
+            .do_set_pause(0, &ncn_root.ncn_admin, ncn)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert!(!config.is_paused(PAUSE_ALL));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_pause_wrong_admin() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        let wrong_admin = Keypair::new();
+        tip_router_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await?;
+
+        let result = tip_router_client
+            .do_set_pause(PAUSE_VOTING, &wrong_admin, ncn)
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectPauseAdmin);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_pause_invalid_flags() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        let result = tip_router_client
+            .do_set_pause(!PAUSE_ALL, &ncn_root.ncn_admin, ncn)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidPauseFlags);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cast_vote_while_paused() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let clock = fixture.clock().await;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        let epoch = clock.epoch;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        tip_router_client
+            .do_set_pause(PAUSE_VOTING, &test_ncn.ncn_root.ncn_admin, ncn)
+            .await?;
+
+        let meta_merkle_root = [1u8; 32];
+
+        let result = tip_router_client
+            .do_cast_vote(ncn, operator, operator_admin, meta_merkle_root, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::VotingPaused);
+
+        tip_router_client
+            .do_set_pause(0, &test_ncn.ncn_root.ncn_admin, ncn)
+            .await?;
+
+        tip_router_client
+            .do_cast_vote(ncn, operator, operator_admin, meta_merkle_root, epoch)
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.has_ballot(&[], &Ballot::new(&meta_merkle_root)));
+
+        Ok(())
+    }
+}
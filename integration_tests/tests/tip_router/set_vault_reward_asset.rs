@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        constants::JITOSOL_MINT,
+        error::TipRouterError,
+        reward_asset::{RewardAsset, RewardAssetType},
+    };
+    use solana_sdk::{clock::DEFAULT_SLOTS_PER_EPOCH, pubkey::Pubkey};
+    use spl_token::native_mint;
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_set_vault_reward_asset() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+        let stake_pool = Pubkey::new_unique();
+
+        // Vaults are paid in JitoSOL until set otherwise
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        assert_eq!(
+            vault_registry.get_vault_entry(&vault)?.reward_asset(),
+            &RewardAsset::jito_sol()
+        );
+
+        tip_router_client
+            .do_admin_set_vault_reward_asset(ncn, vault, RewardAssetType::StakePool, stake_pool)
+            .await?;
+
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        let reward_asset = *vault_registry.get_vault_entry(&vault)?.reward_asset();
+        assert_eq!(reward_asset.asset_type(), Ok(RewardAssetType::StakePool));
+        assert_eq!(reward_asset.stake_pool(), &stake_pool);
+
+        // Only a stake pool reward asset names a stake pool
+        let result = tip_router_client
+            .do_admin_set_vault_reward_asset(
+                ncn,
+                vault,
+                RewardAssetType::StakePool,
+                Pubkey::default(),
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidRewardAsset);
+
+        let result = tip_router_client
+            .do_admin_set_vault_reward_asset(ncn, vault, RewardAssetType::Sol, stake_pool)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidRewardAsset);
+
+        let result = tip_router_client
+            .do_admin_set_vault_reward_asset(
+                ncn,
+                Pubkey::new_unique(),
+                RewardAssetType::Sol,
+                Pubkey::default(),
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::VaultNotInRegistry);
+
+        tip_router_client
+            .do_admin_set_vault_reward_asset(
+                ncn,
+                vault,
+                RewardAssetType::JitoSol,
+                Pubkey::default(),
+            )
+            .await?;
+
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        assert_eq!(
+            vault_registry.get_vault_entry(&vault)?.reward_asset(),
+            &RewardAsset::jito_sol()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_distribute_ncn_vault_rewards_as_sol() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        // 10% Operator fee
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, Some(1000)).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let vault = test_ncn.vaults[0].vault_pubkey;
+
+        tip_router_client
+            .do_admin_set_vault_reward_asset(ncn, vault, RewardAssetType::Sol, Pubkey::default())
+            .await?;
+
+        fixture
+            .warp_slot_incremental(DEFAULT_SLOTS_PER_EPOCH * 2)
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+        stake_pool_client
+            .update_stake_pool_balance(&pool_root)
+            .await?;
+        fixture
+            .route_in_base_rewards_for_test_ncn(&test_ncn, 10_000, &pool_root)
+            .await?;
+        fixture
+            .route_in_ncn_rewards_for_test_ncn(&test_ncn, &pool_root)
+            .await?;
+
+        // The vault is paid in wrapped SOL instead of JitoSOL
+        let vault_sol_balance = fixture
+            .get_associated_token_account(&vault, &native_mint::id())
+            .await?
+            .map_or(0, |account| account.amount);
+        assert!(vault_sol_balance > 0);
+
+        let vault_jitosol_balance = fixture
+            .get_associated_token_account(&vault, &JITOSOL_MINT)
+            .await?
+            .map_or(0, |account| account.amount);
+        assert_eq!(vault_jitosol_balance, 0);
+
+        Ok(())
+    }
+}
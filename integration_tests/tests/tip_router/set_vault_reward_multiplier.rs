@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::error::TipRouterError;
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    // The test NCN registers its ST mints with a 100% reward multiplier
+    const ST_MINT_REWARD_MULTIPLIER_BPS: u64 = 10_000;
+    const OVERRIDE_REWARD_MULTIPLIER_BPS: u64 = 20_000;
+
+    #[tokio::test]
+    async fn test_vault_reward_multiplier_override() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 2, None).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let boosted_vault = test_ncn.vaults[1].vault_pubkey;
+
+        let result = tip_router_client
+            .do_admin_set_vault_reward_multiplier(
+                ncn,
+                Pubkey::new_unique(),
+                OVERRIDE_REWARD_MULTIPLIER_BPS,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::VaultNotInRegistry);
+
+        tip_router_client
+            .do_admin_set_vault_reward_multiplier(
+                ncn,
+                boosted_vault,
+                OVERRIDE_REWARD_MULTIPLIER_BPS,
+            )
+            .await?;
+
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        assert_eq!(
+            vault_registry
+                .get_vault_entry(&boosted_vault)?
+                .reward_multiplier_bps_override(),
+            Some(OVERRIDE_REWARD_MULTIPLIER_BPS)
+        );
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, epoch)
+            .await?;
+
+        let vault_operator_stake_weights: Vec<_> = operator_snapshot
+            .vault_operator_stake_weight()
+            .iter()
+            .filter(|vault_operator_stake_weight| !vault_operator_stake_weight.is_empty())
+            .collect();
+        assert_eq!(vault_operator_stake_weights.len(), 2);
+
+        // Only the boosted vault's reward stake weight uses the override
+        for vault_operator_stake_weight in vault_operator_stake_weights {
+            let stake_weights = vault_operator_stake_weight.stake_weights();
+            let reward_multiplier_bps = if vault_operator_stake_weight.vault().eq(&boosted_vault) {
+                OVERRIDE_REWARD_MULTIPLIER_BPS
+            } else {
+                ST_MINT_REWARD_MULTIPLIER_BPS
+            };
+
+            assert!(stake_weights.stake_weight() > 0);
+            assert_eq!(
+                stake_weights
+                    .ncn_fee_group_stake_weight(vault_operator_stake_weight.ncn_fee_group())
+                    .unwrap(),
+                stake_weights.stake_weight() * reward_multiplier_bps as u128
+            );
+        }
+
+        // 0 removes the override
+        tip_router_client
+            .do_admin_set_vault_reward_multiplier(ncn, boosted_vault, 0)
+            .await?;
+
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        assert_eq!(
+            vault_registry
+                .get_vault_entry(&boosted_vault)?
+                .reward_multiplier_bps_override(),
+            None
+        );
+
+        Ok(())
+    }
+}
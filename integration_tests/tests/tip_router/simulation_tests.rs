@@ -176,6 +176,8 @@ mod tests {
                         *reward_multiplier_bps as u64,
                         *switchboard_feed,
                         *no_feed_weight,
+                        None,
+                        None,
                     )
                     .await?;
             }
@@ -525,6 +527,8 @@ mod fuzz_tests {
                         mint_config.reward_multiplier,
                         mint_config.switchboard_feed,
                         mint_config.no_feed_weight,
+                        None,
+                        None,
                     )
                     .await?;
             }
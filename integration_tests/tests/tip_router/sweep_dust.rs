@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        base_fee_group::BaseFeeGroup, base_reward_router::BaseRewardReceiver,
+        constants::MAX_DUST_LAMPORTS, error::TipRouterError, ncn_fee_group::NcnFeeGroup,
+        ncn_reward_router::NcnRewardReceiver,
+    };
+    use solana_sdk::{clock::DEFAULT_SLOTS_PER_EPOCH, pubkey::Pubkey};
+
+    use crate::fixtures::{
+        test_builder::TestBuilder,
+        tip_router_client::{assert_tip_router_error, TipRouterClient},
+        TestResult,
+    };
+
+    const DUST: u64 = 100;
+
+    async fn dao_wallet(tip_router_client: &mut TipRouterClient, ncn: Pubkey) -> Pubkey {
+        let config = tip_router_client.get_ncn_config(ncn).await.unwrap();
+        *config
+            .fee_config
+            .base_fee_wallet(BaseFeeGroup::dao())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sweep_base_dust() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture
+            .warp_slot_incremental(DEFAULT_SLOTS_PER_EPOCH * 2)
+            .await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let dao_wallet = dao_wallet(&mut tip_router_client, ncn).await;
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        // Before routing, anything in the receiver could be new rewards
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, DUST)
+            .await?;
+
+        let result = tip_router_client.do_sweep_base_dust(ncn, epoch).await;
+        assert_tip_router_error(result, TipRouterError::RewardsNotDistributed);
+
+        stake_pool_client
+            .update_stake_pool_balance(&pool_root)
+            .await?;
+        fixture
+            .route_in_base_rewards_for_test_ncn(&test_ncn, 10_000, &pool_root)
+            .await?;
+
+        // Nothing untracked once routed, the sweep is a no-op
+        tip_router_client.do_sweep_base_dust(ncn, epoch).await?;
+
+        let progress_before = tip_router_client
+            .get_epoch_state(ncn, epoch)
+            .await?
+            .total_distribution_progress();
+
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, DUST)
+            .await?;
+
+        let dao_wallet_balance_before = fixture
+            .get_account(&dao_wallet)
+            .await?
+            .map_or(0, |account| account.lamports);
+
+        tip_router_client.do_sweep_base_dust(ncn, epoch).await?;
+
+        let dao_wallet_balance_after = fixture
+            .get_account(&dao_wallet)
+            .await?
+            .map_or(0, |account| account.lamports);
+        assert_eq!(dao_wallet_balance_after, dao_wallet_balance_before + DUST);
+
+        // The swept dust is counted in both the tally and the total of the distribution progress
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.dust_swept(), DUST);
+        assert_eq!(
+            epoch_state.total_distribution_progress().tally(),
+            progress_before.tally() + DUST
+        );
+        assert_eq!(
+            epoch_state.total_distribution_progress().total(),
+            progress_before.total() + DUST
+        );
+
+        // Anything larger is new rewards, which have to be routed
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, MAX_DUST_LAMPORTS + 1)
+            .await?;
+
+        let result = tip_router_client.do_sweep_base_dust(ncn, epoch).await;
+        assert_tip_router_error(result, TipRouterError::UntrackedRewardsAboveDust);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sweep_ncn_dust() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let ncn_fee_group = NcnFeeGroup::default();
+
+        fixture
+            .warp_slot_incremental(DEFAULT_SLOTS_PER_EPOCH * 2)
+            .await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+        stake_pool_client
+            .update_stake_pool_balance(&pool_root)
+            .await?;
+        fixture
+            .route_in_base_rewards_for_test_ncn(&test_ncn, 10_000, &pool_root)
+            .await?;
+
+        // The NCN rewards arrived from the base router, but are not routed yet
+        let result = tip_router_client
+            .do_sweep_ncn_dust(ncn_fee_group, operator, ncn, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::RewardsNotDistributed);
+
+        fixture
+            .route_in_ncn_rewards_for_test_ncn(&test_ncn, &pool_root)
+            .await?;
+
+        let dao_wallet = dao_wallet(&mut tip_router_client, ncn).await;
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        tip_router_client
+            .airdrop_lamports(&ncn_reward_receiver, DUST)
+            .await?;
+
+        let dao_wallet_balance_before = fixture
+            .get_account(&dao_wallet)
+            .await?
+            .map_or(0, |account| account.lamports);
+
+        tip_router_client
+            .do_sweep_ncn_dust(ncn_fee_group, operator, ncn, epoch)
+            .await?;
+
+        let dao_wallet_balance_after = fixture
+            .get_account(&dao_wallet)
+            .await?
+            .map_or(0, |account| account.lamports);
+        assert_eq!(dao_wallet_balance_after, dao_wallet_balance_before + DUST);
+
+        Ok(())
+    }
+}
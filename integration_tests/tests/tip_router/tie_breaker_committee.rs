@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+
+    use jito_tip_router_core::{
+        ballot_box::Ballot,
+        constants::{DEFAULT_CONSENSUS_REACHED_SLOT, MAX_TIE_BREAKER_COMMITTEE_MEMBERS},
+        error::TipRouterError,
+    };
+    use solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+    };
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_tie_breaker_committee() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        // Each operator gets 50% voting share
+        let test_ncn = fixture.create_initial_test_ncn(2, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let clock = fixture.clock().await;
+        let epoch = clock.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        // 2 of 3 committee
+        let committee = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let mut members = [Pubkey::default(); MAX_TIE_BREAKER_COMMITTEE_MEMBERS];
+        for (seat, member) in members.iter_mut().zip(committee.iter()) {
+            *seat = member.pubkey();
+        }
+
+        tip_router_client
+            .do_admin_initialize_tie_breaker_committee(ncn, members, 2)
+            .await?;
+
+        let tie_breaker_committee = tip_router_client.get_tie_breaker_committee(ncn).await?;
+        assert_eq!(tie_breaker_committee.member_count(), 3);
+        assert_eq!(tie_breaker_committee.threshold(), 2);
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let meta_merkle_root = [1; 32];
+
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+
+        // Gets to 50% consensus weight
+        tip_router_client
+            .do_cast_vote(ncn, operator, operator_admin, meta_merkle_root, epoch)
+            .await?;
+
+        // Voting window still open
+        let result = tip_router_client
+            .do_tie_breaker_approve(ncn, &committee[0], meta_merkle_root, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::VotingNotFinalized);
+
+        fixture.warp_slot_incremental(1000000).await?;
+
+        // The single tie breaker admin is replaced by the committee
+        let result = tip_router_client
+            .do_admin_set_tie_breaker(ncn, meta_merkle_root, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::TieBreakerCommitteeActive);
+
+        // Only roots that were voted on can be approved
+        let result = tip_router_client
+            .do_tie_breaker_approve(ncn, &committee[0], [2; 32], epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::TieBreakerNotInPriorVotes);
+
+        let result = tip_router_client
+            .do_tie_breaker_approve(ncn, &Keypair::new(), meta_merkle_root, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::TieBreakerCommitteeMemberInvalid);
+
+        // First approval is below the threshold
+        tip_router_client
+            .do_tie_breaker_approve(ncn, &committee[0], meta_merkle_root, epoch)
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(!ballot_box.is_consensus_reached());
+
+        let tie_breaker_committee = tip_router_client.get_tie_breaker_committee(ncn).await?;
+        assert_eq!(tie_breaker_committee.approvals(epoch, &meta_merkle_root), 1);
+
+        // Second approval sets the tie breaker
+        tip_router_client
+            .do_tie_breaker_approve(ncn, &committee[2], meta_merkle_root, epoch)
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        let ballot = Ballot::new(&meta_merkle_root);
+        assert_eq!(
+            *ballot_box.get_winning_ballot_tally(&[]).unwrap().ballot(),
+            ballot
+        );
+        // No official consensus reached so no slot set
+        assert_eq!(
+            ballot_box.slot_consensus_reached(),
+            DEFAULT_CONSENSUS_REACHED_SLOT
+        );
+        assert!(ballot_box.is_consensus_reached());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_tie_breaker_committee() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        let mut members = [Pubkey::default(); MAX_TIE_BREAKER_COMMITTEE_MEMBERS];
+        members[0] = Pubkey::new_unique();
+
+        // Threshold larger than the committee
+        let result = tip_router_client
+            .do_admin_initialize_tie_breaker_committee(ncn, members, 2)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidTieBreakerCommittee);
+
+        tip_router_client
+            .do_admin_initialize_tie_breaker_committee(ncn, members, 1)
+            .await?;
+
+        members[1] = Pubkey::new_unique();
+        tip_router_client
+            .do_admin_set_tie_breaker_committee(ncn, members, 2)
+            .await?;
+
+        let tie_breaker_committee = tip_router_client.get_tie_breaker_committee(ncn).await?;
+        assert_eq!(tie_breaker_committee.member_count(), 2);
+        assert_eq!(tie_breaker_committee.threshold(), 2);
+        assert!(tie_breaker_committee.is_member(&members[1]));
+
+        // Duplicate members
+        members[2] = members[0];
+        let result = tip_router_client
+            .do_admin_set_tie_breaker_committee(ncn, members, 2)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidTieBreakerCommittee);
+
+        Ok(())
+    }
+}
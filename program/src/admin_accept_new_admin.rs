@@ -0,0 +1,41 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    config::{Config as NcnConfig, ConfigAdminRole},
+    error::TipRouterError,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+pub fn process_admin_accept_new_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: ConfigAdminRole,
+) -> ProgramResult {
+    let [config, ncn_account, new_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // The proposed admin must sign to prove they control the key
+    load_signer(new_admin, false)?;
+
+    NcnConfig::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    if config.ncn != *ncn_account.key {
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    let epoch = Clock::get()?.epoch;
+    config.accept_new_admin(role, new_admin.key, epoch)?;
+
+    msg!("{:?} set to {:?}", role, new_admin.key);
+
+    Ok(())
+}
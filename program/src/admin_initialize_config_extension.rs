@@ -0,0 +1,66 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_signer, load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer, config::Config as NcnConfig, config_extension::ConfigExtension,
+    error::TipRouterError,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_admin_initialize_config_extension(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [ncn_config, config_extension, ncn, ncn_admin, account_payer, system_program] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_account(config_extension, true)?;
+    load_system_program(system_program)?;
+    load_signer(ncn_admin, false)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+    {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        if ncn_account.admin != *ncn_admin.key {
+            msg!("NCN admin invalid");
+            return Err(TipRouterError::IncorrectNcnAdmin.into());
+        }
+    }
+
+    let (config_extension_pda, config_extension_bump, mut config_extension_seeds) =
+        ConfigExtension::find_program_address(program_id, ncn.key);
+    config_extension_seeds.push(vec![config_extension_bump]);
+
+    if config_extension_pda != *config_extension.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        config_extension,
+        system_program,
+        program_id,
+        ConfigExtension::SIZE,
+        &config_extension_seeds,
+    )?;
+
+    let mut config_extension_data = config_extension.try_borrow_mut_data()?;
+    config_extension_data[0] = ConfigExtension::DISCRIMINATOR;
+    let config_extension_account =
+        ConfigExtension::try_from_slice_unchecked_mut(&mut config_extension_data)?;
+
+    *config_extension_account = ConfigExtension::new(ncn.key, config_extension_bump);
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_signer, load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer, config::Config as NcnConfig, error::TipRouterError,
+    fee_schedule::FeeSchedule,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_admin_initialize_fee_schedule(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [ncn_config, fee_schedule, ncn, fee_admin, account_payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_account(fee_schedule, true)?;
+    load_system_program(system_program)?;
+    load_signer(fee_admin, false)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+    {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+
+        if ncn_config.fee_admin.ne(fee_admin.key) {
+            msg!("Fee admin invalid");
+            return Err(TipRouterError::IncorrectFeeAdmin.into());
+        }
+    }
+
+    let (fee_schedule_pda, fee_schedule_bump, mut fee_schedule_seeds) =
+        FeeSchedule::find_program_address(program_id, ncn.key);
+    fee_schedule_seeds.push(vec![fee_schedule_bump]);
+
+    if fee_schedule_pda != *fee_schedule.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        fee_schedule,
+        system_program,
+        program_id,
+        FeeSchedule::SIZE,
+        &fee_schedule_seeds,
+    )?;
+
+    let mut fee_schedule_data = fee_schedule.try_borrow_mut_data()?;
+    fee_schedule_data[0] = FeeSchedule::DISCRIMINATOR;
+    let fee_schedule_account = FeeSchedule::try_from_slice_unchecked_mut(&mut fee_schedule_data)?;
+
+    *fee_schedule_account = FeeSchedule::new(ncn.key, fee_schedule_bump);
+
+    Ok(())
+}
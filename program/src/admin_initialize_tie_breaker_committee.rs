@@ -0,0 +1,72 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_signer, load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer, config::Config as NcnConfig,
+    constants::MAX_TIE_BREAKER_COMMITTEE_MEMBERS, error::TipRouterError,
+    tie_breaker_committee::TieBreakerCommittee,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_admin_initialize_tie_breaker_committee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    members: &[Pubkey; MAX_TIE_BREAKER_COMMITTEE_MEMBERS],
+    threshold: u8,
+) -> ProgramResult {
+    let [ncn_config, tie_breaker_committee, ncn, tie_breaker_admin, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_account(tie_breaker_committee, true)?;
+    load_system_program(system_program)?;
+    load_signer(tie_breaker_admin, false)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+    {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+
+        if ncn_config.tie_breaker_admin.ne(tie_breaker_admin.key) {
+            msg!("Tie breaker admin invalid");
+            return Err(TipRouterError::TieBreakerAdminInvalid.into());
+        }
+    }
+
+    let (tie_breaker_committee_pda, tie_breaker_committee_bump, mut tie_breaker_committee_seeds) =
+        TieBreakerCommittee::find_program_address(program_id, ncn.key);
+    tie_breaker_committee_seeds.push(vec![tie_breaker_committee_bump]);
+
+    if tie_breaker_committee_pda != *tie_breaker_committee.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        tie_breaker_committee,
+        system_program,
+        program_id,
+        TieBreakerCommittee::SIZE,
+        &tie_breaker_committee_seeds,
+    )?;
+
+    let mut tie_breaker_committee_data = tie_breaker_committee.try_borrow_mut_data()?;
+    tie_breaker_committee_data[0] = TieBreakerCommittee::DISCRIMINATOR;
+    let tie_breaker_committee_account =
+        TieBreakerCommittee::try_from_slice_unchecked_mut(&mut tie_breaker_committee_data)?;
+
+    *tie_breaker_committee_account = TieBreakerCommittee::new(ncn.key, tie_breaker_committee_bump);
+    tie_breaker_committee_account.set_committee(members, threshold)?;
+
+    Ok(())
+}
@@ -6,11 +6,11 @@ use jito_tip_router_core::{
     error::TipRouterError,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
-pub fn process_admin_set_new_admin(
+pub fn process_admin_propose_new_admin(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     role: ConfigAdminRole,
@@ -39,16 +39,15 @@ pub fn process_admin_set_new_admin(
         return Err(TipRouterError::IncorrectNcnAdmin.into());
     }
 
-    match role {
-        ConfigAdminRole::FeeAdmin => {
-            config.fee_admin = *new_admin.key;
-            msg!("Fee admin set to {:?}", new_admin.key);
-        }
-        ConfigAdminRole::TieBreakerAdmin => {
-            config.tie_breaker_admin = *new_admin.key;
-            msg!("Tie breaker admin set to {:?}", new_admin.key);
-        }
-    }
+    let epoch = Clock::get()?.epoch;
+    config.propose_new_admin(role, new_admin.key, epoch)?;
+
+    msg!(
+        "Proposed {:?} {:?}, expires after epoch {}",
+        role,
+        new_admin.key,
+        config.pending_admin_expiry_epoch()
+    );
 
     Ok(())
 }
@@ -5,8 +5,8 @@ use jito_tip_router_core::{
     config::Config, ncn_fee_group::NcnFeeGroup, vault_registry::VaultRegistry,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 pub fn process_admin_register_st_mint(
@@ -16,6 +16,8 @@ pub fn process_admin_register_st_mint(
     reward_multiplier_bps: u64,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    max_weight_change_bps: Option<u64>,
+    max_stale_slots: Option<u64>,
 ) -> ProgramResult {
     let [config, ncn, st_mint, vault_registry, admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -39,6 +41,12 @@ pub fn process_admin_register_st_mint(
         }
     }
 
+    let epochs_after_consensus_before_close = {
+        let config_data = config.data.borrow();
+        let config = Config::try_from_slice_unchecked(&config_data)?;
+        config.epochs_after_consensus_before_close()
+    };
+
     let mut vault_registry_data = vault_registry.data.borrow_mut();
     let vault_registry_account =
         VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
@@ -59,6 +67,10 @@ pub fn process_admin_register_st_mint(
         reward_multiplier_bps,
         &switchboard_feed,
         no_feed_weight,
+        max_weight_change_bps.unwrap_or_default(),
+        max_stale_slots.unwrap_or_default(),
+        Clock::get()?.epoch,
+        epochs_after_consensus_before_close,
     )?;
 
     Ok(())
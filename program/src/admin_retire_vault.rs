@@ -0,0 +1,44 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{config::Config, vault_registry::VaultRegistry};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+pub fn process_admin_retire_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    retired_epoch: u64,
+) -> ProgramResult {
+    let [config, ncn, vault_registry, vault, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, ncn.key, false)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    load_signer(admin, false)?;
+
+    {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
+        if ncn_account.ncn_program_admin.ne(admin.key) {
+            msg!("Admin is not the NCN program admin");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+
+    let mut vault_registry_data = vault_registry.data.borrow_mut();
+    let vault_registry_account =
+        VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+
+    vault_registry_account.retire_vault(vault.key, retired_epoch, current_epoch)?;
+
+    Ok(())
+}
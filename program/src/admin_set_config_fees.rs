@@ -2,10 +2,14 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    base_fee_group::BaseFeeGroup, config::Config, error::TipRouterError, ncn_fee_group::NcnFeeGroup,
+    base_fee_group::BaseFeeGroup,
+    config::Config,
+    error::TipRouterError,
+    fee_schedule::{FeeSchedule, FeeScheduleAction},
+    ncn_fee_group::NcnFeeGroup,
 };
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
@@ -19,8 +23,10 @@ pub fn process_admin_set_config_fees(
     new_base_fee_bps: Option<u16>,
     ncn_fee_group: Option<u8>,
     new_ncn_fee_bps: Option<u16>,
+    fee_schedule_action: Option<u8>,
+    activation_epoch: Option<u64>,
 ) -> ProgramResult {
-    let [config, ncn_account, fee_admin] = accounts else {
+    let [config, ncn_account, fee_admin, fee_schedule] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -28,6 +34,8 @@ pub fn process_admin_set_config_fees(
 
     Config::load(program_id, config, ncn_account.key, true)?;
     Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+    let fee_schedule_initialized =
+        FeeSchedule::load_if_initialized(program_id, fee_schedule, ncn_account.key, true)?;
 
     let epoch = Clock::get()?.epoch;
 
@@ -45,16 +53,75 @@ pub fn process_admin_set_config_fees(
 
     let base_fee_group = base_fee_group.map(BaseFeeGroup::try_from).transpose()?;
     let ncn_fee_group = ncn_fee_group.map(NcnFeeGroup::try_from).transpose()?;
+    let fee_schedule_action = fee_schedule_action
+        .map(FeeScheduleAction::try_from)
+        .transpose()?;
 
-    config.fee_config.update_fee_config(
-        new_block_engine_fee_bps,
+    let Some(fee_schedule_action) = fee_schedule_action else {
+        let fee_schedule_data = fee_schedule.try_borrow_data()?;
+        let fee_schedule_account = if fee_schedule_initialized {
+            Some(FeeSchedule::try_from_slice_unchecked(&fee_schedule_data)?)
+        } else {
+            None
+        };
+
+        config.fee_config.update_fee_config(
+            new_block_engine_fee_bps,
+            base_fee_group,
+            new_base_fee_wallet,
+            new_base_fee_bps,
+            ncn_fee_group,
+            new_ncn_fee_bps,
+            fee_schedule_account,
+            epoch,
+        )?;
+
+        // A new block engine fee changes the adjusted fees of every scheduled entry
+        if let Some(fee_schedule_account) = fee_schedule_account {
+            config.fee_config.check_fee_schedule(fee_schedule_account)?;
+        }
+
+        return Ok(());
+    };
+
+    if !fee_schedule_initialized {
+        msg!("Fee schedule is not initialized");
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let Some(activation_epoch) = activation_epoch else {
+        msg!("Fee schedule actions need an activation epoch");
+        return Err(TipRouterError::InvalidFeeScheduleEpoch.into());
+    };
+
+    let mut fee_schedule_data = fee_schedule.try_borrow_mut_data()?;
+    let fee_schedule_account = FeeSchedule::try_from_slice_unchecked_mut(&mut fee_schedule_data)?;
+
+    // The fee bps go to the fee schedule, the block engine fee and wallets are not versioned
+    if new_block_engine_fee_bps.is_some() || new_base_fee_wallet.is_some() {
+        config.fee_config.update_fee_config(
+            new_block_engine_fee_bps,
+            base_fee_group,
+            new_base_fee_wallet,
+            None,
+            ncn_fee_group,
+            None,
+            Some(&*fee_schedule_account),
+            epoch,
+        )?;
+    }
+
+    config.fee_config.update_fee_schedule(
+        fee_schedule_account,
+        fee_schedule_action,
+        activation_epoch,
         base_fee_group,
-        new_base_fee_wallet,
         new_base_fee_bps,
         ncn_fee_group,
         new_ncn_fee_bps,
         epoch,
     )?;
+    config.fee_config.check_fee_schedule(fee_schedule_account)?;
 
     Ok(())
 }
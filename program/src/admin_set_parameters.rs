@@ -1,12 +1,19 @@
-use jito_bytemuck::{types::PodU64, AccountDeserialize};
+use jito_bytemuck::{
+    types::{PodU128, PodU16, PodU64},
+    AccountDeserialize,
+};
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     config::Config,
+    config_extension::ConfigExtension,
     constants::{
-        MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MAX_EPOCHS_BEFORE_STALL,
-        MAX_VALID_SLOTS_AFTER_CONSENSUS, MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE,
-        MIN_EPOCHS_BEFORE_STALL, MIN_VALID_SLOTS_AFTER_CONSENSUS,
+        MAX_COMMIT_WINDOW_SLOTS, MAX_CONSENSUS_THRESHOLD_BPS, MAX_CRANK_BOUNTY_LAMPORTS,
+        MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MAX_EPOCHS_BEFORE_STALL, MAX_FEE_BPS,
+        MAX_LATE_VOTE_DECAY_BPS, MAX_PLURALITY_FALLBACK_EPOCHS, MAX_PLURALITY_MIN_STAKE_WEIGHT_BPS,
+        MAX_VALID_SLOTS_AFTER_CONSENSUS, MIN_CONSENSUS_THRESHOLD_BPS,
+        MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MIN_EPOCHS_BEFORE_STALL,
+        MIN_VALID_SLOTS_AFTER_CONSENSUS,
     },
     error::TipRouterError,
 };
@@ -15,6 +22,7 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn process_admin_set_parameters(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -22,8 +30,19 @@ pub fn process_admin_set_parameters(
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
+    consensus_threshold_bps: Option<u16>,
+    commit_window_slots: Option<u64>,
+    late_vote_grace_slots: Option<u64>,
+    late_vote_max_decay_bps: Option<u16>,
+    plurality_fallback_epochs: Option<u64>,
+    plurality_min_stake_weight_bps: Option<u16>,
+    crank_bounty_lamports: Option<u64>,
+    max_crank_bounties_per_epoch: Option<u64>,
+    account_payer_target_balance: Option<u64>,
+    min_operator_stake_weight: Option<u128>,
+    max_operator_fee_bps: Option<u16>,
 ) -> ProgramResult {
-    let [config, ncn_account, ncn_admin] = accounts else {
+    let [config, ncn_account, ncn_admin, config_extension] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -32,6 +51,8 @@ pub fn process_admin_set_parameters(
     // Load and verify accounts
     Config::load(program_id, config, ncn_account.key, true)?;
     Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+    let config_extension_initialized =
+        ConfigExtension::load_if_initialized(program_id, config_extension, ncn_account.key, true)?;
 
     {
         let ncn_data = ncn_account.data.borrow();
@@ -79,5 +100,102 @@ pub fn process_admin_set_parameters(
         config.valid_slots_after_consensus = PodU64::from(slots);
     }
 
+    if let Some(bps) = consensus_threshold_bps {
+        if !(MIN_CONSENSUS_THRESHOLD_BPS..=MAX_CONSENSUS_THRESHOLD_BPS).contains(&(bps as u64)) {
+            return Err(TipRouterError::InvalidConsensusThreshold.into());
+        }
+        msg!("Updated consensus_threshold_bps to {}", bps);
+        config.consensus_threshold_bps = PodU16::from(bps);
+    }
+
+    if let Some(slots) = commit_window_slots {
+        if slots > MAX_COMMIT_WINDOW_SLOTS {
+            return Err(TipRouterError::InvalidCommitWindow.into());
+        }
+        msg!("Updated commit_window_slots to {}", slots);
+        config.commit_window_slots = PodU64::from(slots);
+    }
+
+    if let Some(slots) = late_vote_grace_slots {
+        if slots > config.valid_slots_after_consensus() {
+            return Err(TipRouterError::InvalidLateVoteDecay.into());
+        }
+        msg!("Updated late_vote_grace_slots to {}", slots);
+        config.late_vote_grace_slots = PodU64::from(slots);
+    }
+
+    if let Some(bps) = late_vote_max_decay_bps {
+        if bps as u64 > MAX_LATE_VOTE_DECAY_BPS {
+            return Err(TipRouterError::InvalidLateVoteDecay.into());
+        }
+        msg!("Updated late_vote_max_decay_bps to {}", bps);
+        config.late_vote_max_decay_bps = PodU16::from(bps);
+    }
+
+    if let Some(epochs) = plurality_fallback_epochs {
+        if epochs > MAX_PLURALITY_FALLBACK_EPOCHS {
+            return Err(TipRouterError::InvalidPluralityFallback.into());
+        }
+        msg!("Updated plurality_fallback_epochs to {}", epochs);
+        config.plurality_fallback_epochs = PodU64::from(epochs);
+    }
+
+    if let Some(bps) = plurality_min_stake_weight_bps {
+        if bps as u64 > MAX_PLURALITY_MIN_STAKE_WEIGHT_BPS {
+            return Err(TipRouterError::InvalidPluralityFallback.into());
+        }
+        msg!("Updated plurality_min_stake_weight_bps to {}", bps);
+        config.plurality_min_stake_weight_bps = PodU16::from(bps);
+    }
+
+    let sets_config_extension = crank_bounty_lamports.is_some()
+        || max_crank_bounties_per_epoch.is_some()
+        || account_payer_target_balance.is_some()
+        || min_operator_stake_weight.is_some()
+        || max_operator_fee_bps.is_some();
+    if !sets_config_extension {
+        return Ok(());
+    }
+
+    if !config_extension_initialized {
+        msg!("Config extension is not initialized");
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let mut config_extension_data = config_extension.try_borrow_mut_data()?;
+    let config_extension =
+        ConfigExtension::try_from_slice_unchecked_mut(&mut config_extension_data)?;
+
+    if let Some(lamports) = crank_bounty_lamports {
+        if lamports > MAX_CRANK_BOUNTY_LAMPORTS {
+            return Err(TipRouterError::InvalidCrankBounty.into());
+        }
+        msg!("Updated crank_bounty_lamports to {}", lamports);
+        config_extension.crank_bounty_lamports = PodU64::from(lamports);
+    }
+
+    if let Some(bounties) = max_crank_bounties_per_epoch {
+        msg!("Updated max_crank_bounties_per_epoch to {}", bounties);
+        config_extension.max_crank_bounties_per_epoch = PodU64::from(bounties);
+    }
+
+    if let Some(lamports) = account_payer_target_balance {
+        msg!("Updated account_payer_target_balance to {}", lamports);
+        config_extension.account_payer_target_balance = PodU64::from(lamports);
+    }
+
+    if let Some(stake_weight) = min_operator_stake_weight {
+        msg!("Updated min_operator_stake_weight to {}", stake_weight);
+        config_extension.min_operator_stake_weight = PodU128::from(stake_weight);
+    }
+
+    if let Some(bps) = max_operator_fee_bps {
+        if bps as u64 > MAX_FEE_BPS {
+            return Err(TipRouterError::InvalidMaxOperatorFee.into());
+        }
+        msg!("Updated max_operator_fee_bps to {}", bps);
+        config_extension.max_operator_fee_bps = PodU16::from(bps);
+    }
+
     Ok(())
 }
@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{config::Config as NcnConfig, error::TipRouterError};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_admin_set_pause(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused: u8,
+) -> ProgramResult {
+    let [config, ncn, pause_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(pause_admin, false)?;
+
+    NcnConfig::load(program_id, config, ncn.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    if config.ncn != *ncn.key {
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    if config.pause_admin() != *pause_admin.key {
+        return Err(TipRouterError::IncorrectPauseAdmin.into());
+    }
+
+    config.set_paused(paused)?;
+
+    msg!("Updated paused to {:#06b}", paused);
+
+    Ok(())
+}
@@ -15,6 +15,8 @@ pub fn process_admin_set_st_mint(
     reward_multiplier_bps: Option<u64>,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    max_weight_change_bps: Option<u64>,
+    max_stale_slots: Option<u64>,
 ) -> ProgramResult {
     let [config, ncn, vault_registry, admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -46,6 +48,8 @@ pub fn process_admin_set_st_mint(
         reward_multiplier_bps,
         switchboard_feed,
         no_feed_weight,
+        max_weight_change_bps,
+        max_stale_slots,
     )?;
 
     Ok(())
@@ -0,0 +1,58 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    config::Config,
+    constants::MAX_ORACLE_FEEDS,
+    oracle_feed::{OracleFeed, OracleFeedType},
+    vault_registry::VaultRegistry,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_admin_set_st_mint_oracle_feeds(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    st_mint: &Pubkey,
+    oracle_feeds: [Pubkey; MAX_ORACLE_FEEDS],
+    oracle_feed_types: [u8; MAX_ORACLE_FEEDS],
+    max_feed_divergence_bps: u16,
+) -> ProgramResult {
+    let [config, ncn, vault_registry, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, ncn.key, false)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    load_signer(admin, false)?;
+
+    {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
+        if ncn_account.ncn_program_admin.ne(admin.key) {
+            msg!("Admin is not the NCN program admin");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    let mut feeds = [OracleFeed::default(); MAX_ORACLE_FEEDS];
+    for (feed, (oracle_feed, oracle_feed_type)) in feeds
+        .iter_mut()
+        .zip(oracle_feeds.iter().zip(oracle_feed_types.iter()))
+    {
+        *feed = OracleFeed::new(oracle_feed, OracleFeedType::try_from(*oracle_feed_type)?);
+    }
+
+    let mut vault_registry_data = vault_registry.data.borrow_mut();
+    let vault_registry_account =
+        VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+
+    vault_registry_account.set_st_mint_oracle_feeds(st_mint, feeds, max_feed_divergence_bps)?;
+
+    Ok(())
+}
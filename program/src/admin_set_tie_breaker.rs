@@ -2,8 +2,12 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    ballot_box::BallotBox, config::Config as NcnConfig, epoch_state::EpochState,
+    ballot_box::{BallotBox, BallotBoxPage},
+    config::Config as NcnConfig,
+    epoch_state::EpochState,
     error::TipRouterError,
+    event::{TieBreakerSet, TipRouterEvent},
+    tie_breaker_committee::TieBreakerCommittee,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -16,7 +20,9 @@ pub fn process_admin_set_tie_breaker(
     meta_merkle_root: &[u8; 32],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, ballot_box, ncn, tie_breaker_admin] = accounts else {
+    let [epoch_state, ncn_config, ballot_box, ncn, tie_breaker_admin, tie_breaker_committee, ballot_box_pages @ ..] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -26,6 +32,18 @@ pub fn process_admin_set_tie_breaker(
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     load_signer(tie_breaker_admin, false)?;
 
+    // Once the NCN has a committee, only its threshold of members can set the tie breaker
+    let has_committee = TieBreakerCommittee::load_if_initialized(
+        program_id,
+        tie_breaker_committee,
+        ncn.key,
+        false,
+    )?;
+    if has_committee {
+        msg!("Tie breaker committee is initialized, use TieBreakerApprove");
+        return Err(TipRouterError::TieBreakerCommitteeActive.into());
+    }
+
     let ncn_config_data = ncn_config.data.borrow();
     let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
 
@@ -34,6 +52,27 @@ pub fn process_admin_set_tie_breaker(
         return Err(TipRouterError::TieBreakerAdminInvalid.into());
     }
 
+    let page_count = {
+        let ballot_box_data = ballot_box.data.borrow();
+        BallotBox::try_from_slice_unchecked(&ballot_box_data)?.page_count()
+    };
+
+    BallotBoxPage::load_pages(
+        program_id,
+        ballot_box_pages,
+        ballot_box.key,
+        page_count,
+        false,
+    )?;
+    let ballot_box_pages_data = ballot_box_pages[..page_count as usize]
+        .iter()
+        .map(|page| page.try_borrow_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let ballot_box_pages = ballot_box_pages_data
+        .iter()
+        .map(|page_data| BallotBoxPage::try_from_slice_unchecked(page_data))
+        .collect::<Result<Vec<_>, _>>()?;
+
     let mut ballot_box_data = ballot_box.data.borrow_mut();
     let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
 
@@ -41,18 +80,31 @@ pub fn process_admin_set_tie_breaker(
     let current_epoch = clock.epoch;
 
     ballot_box_account.set_tie_breaker_ballot(
+        &ballot_box_pages,
         meta_merkle_root,
         current_epoch,
         ncn_config.epochs_before_stall(),
     )?;
 
+    TipRouterEvent::TieBreakerSet(TieBreakerSet {
+        ncn: *ncn.key,
+        epoch,
+        meta_merkle_root: *meta_merkle_root,
+        tie_breaker_admin: *tie_breaker_admin.key,
+        slot: clock.slot,
+    })
+    .emit();
+
     // Update Epoch State
     {
         let slot = clock.slot;
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
-        epoch_state_account
-            .update_set_tie_breaker(ballot_box_account.is_consensus_reached(), slot)?;
+        epoch_state_account.update_set_tie_breaker(
+            ballot_box_account.is_consensus_reached(),
+            ballot_box_account.winning_tip_distribution_account_count(),
+            slot,
+        )?;
     }
 
     Ok(())
@@ -0,0 +1,45 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    config::Config as NcnConfig, constants::MAX_TIE_BREAKER_COMMITTEE_MEMBERS,
+    error::TipRouterError, tie_breaker_committee::TieBreakerCommittee,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_admin_set_tie_breaker_committee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    members: &[Pubkey; MAX_TIE_BREAKER_COMMITTEE_MEMBERS],
+    threshold: u8,
+) -> ProgramResult {
+    let [ncn_config, tie_breaker_committee, ncn, tie_breaker_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    TieBreakerCommittee::load(program_id, tie_breaker_committee, ncn.key, true)?;
+    load_signer(tie_breaker_admin, false)?;
+
+    {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+
+        if ncn_config.tie_breaker_admin.ne(tie_breaker_admin.key) {
+            msg!("Tie breaker admin invalid");
+            return Err(TipRouterError::TieBreakerAdminInvalid.into());
+        }
+    }
+
+    let mut tie_breaker_committee_data = tie_breaker_committee.try_borrow_mut_data()?;
+    let tie_breaker_committee_account =
+        TieBreakerCommittee::try_from_slice_unchecked_mut(&mut tie_breaker_committee_data)?;
+
+    tie_breaker_committee_account.set_committee(members, threshold)?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    config::Config,
+    reward_asset::{RewardAsset, RewardAssetType},
+    vault_registry::VaultRegistry,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_admin_set_vault_reward_asset(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reward_asset_type: u8,
+    stake_pool: &Pubkey,
+) -> ProgramResult {
+    let [config, ncn, vault_registry, vault, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, ncn.key, false)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    load_signer(admin, false)?;
+
+    {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
+        if ncn_account.ncn_program_admin.ne(admin.key) {
+            msg!("Admin is not the NCN program admin");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    let reward_asset_type = RewardAssetType::try_from(reward_asset_type)?;
+    let reward_asset = RewardAsset::new(reward_asset_type, stake_pool)?;
+
+    let mut vault_registry_data = vault_registry.data.borrow_mut();
+    let vault_registry_account =
+        VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+
+    vault_registry_account.set_vault_reward_asset(vault.key, reward_asset)?;
+
+    msg!(
+        "Vault {} rewards are paid out as {:?}",
+        vault.key,
+        reward_asset_type
+    );
+
+    Ok(())
+}
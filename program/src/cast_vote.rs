@@ -2,30 +2,57 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
-    ballot_box::{Ballot, BallotBox},
+    ballot_box::{Ballot, BallotBox, BallotBoxPage},
     config::Config as NcnConfig,
+    constants::PAUSE_VOTING,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
     error::TipRouterError,
+    event::{ConsensusReached, TipRouterEvent, VoteCast},
+    operator_record::OperatorRecord,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn process_cast_vote(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     meta_merkle_root: &[u8; 32],
     epoch: u64,
+    abstain: bool,
+    snapshot_slot: u64,
+    bank_hash: &[u8; 32],
+    tip_distribution_account_count: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, ballot_box, ncn, epoch_snapshot, operator_snapshot, operator, operator_admin] =
+    let ballot = if abstain {
+        Ballot::abstain()
+    } else {
+        Ballot::new_with_snapshot(meta_merkle_root, snapshot_slot, bank_hash)
+            .with_tip_distribution_account_count(tip_distribution_account_count)
+    };
+
+    process_operator_vote(program_id, accounts, &ballot, None, epoch)
+}
+
+/// Counts an operator's ballot, shared by CastVote and RevealVote. With a `salt` the ballot is
+/// revealed against the operator's commitment, otherwise it is cast directly.
+pub fn process_operator_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    ballot: &Ballot,
+    salt: Option<&[u8; 32]>,
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn_config, ballot_box, ncn, epoch_snapshot, operator_snapshot, operator, operator_admin, operator_record, ballot_box_pages @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    // Operator is casting the vote, needs to be signer
+    // Operator is casting or revealing the vote, needs to be signer
     load_signer(operator_admin, false)?;
 
     EpochState::load(program_id, epoch_state, ncn.key, epoch, false)?;
@@ -41,7 +68,14 @@ pub fn process_cast_vote(
         operator.key,
         ncn.key,
         epoch,
-        false,
+        true,
+    )?;
+    let has_operator_record = OperatorRecord::load_if_initialized(
+        program_id,
+        operator_record,
+        ncn.key,
+        operator.key,
+        true,
     )?;
     let operator_data = operator.data.borrow();
     let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
@@ -50,16 +84,53 @@ pub fn process_cast_vote(
         return Err(TipRouterError::InvalidOperatorVoter.into());
     }
 
-    let valid_slots_after_consensus = {
+    let (valid_slots_after_consensus, consensus_threshold_bps, commit_window_slots) = {
         let ncn_config_data = ncn_config.data.borrow();
         let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
-        ncn_config.valid_slots_after_consensus()
+        ncn_config.check_not_paused(PAUSE_VOTING)?;
+
+        if salt.is_some() && !ncn_config.is_commit_reveal_enabled() {
+            return Err(TipRouterError::CommitRevealNotEnabled.into());
+        }
+
+        // An abstain reveals nothing, so it does not need to be committed first
+        if salt.is_none() && ncn_config.is_commit_reveal_enabled() && !ballot.is_abstain() {
+            msg!("Commit-reveal is enabled, use CommitVote and RevealVote");
+            return Err(TipRouterError::CommitRevealRequired.into());
+        }
+
+        (
+            ncn_config.valid_slots_after_consensus(),
+            ncn_config.consensus_threshold_bps(),
+            ncn_config.commit_window_slots(),
+        )
     };
 
+    let page_count = {
+        let ballot_box_data = ballot_box.data.borrow();
+        BallotBox::try_from_slice_unchecked(&ballot_box_data)?.page_count()
+    };
+
+    BallotBoxPage::load_pages(
+        program_id,
+        ballot_box_pages,
+        ballot_box.key,
+        page_count,
+        true,
+    )?;
+    let mut ballot_box_pages_data = ballot_box_pages[..page_count as usize]
+        .iter()
+        .map(|page| page.try_borrow_mut_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut ballot_box_pages = ballot_box_pages_data
+        .iter_mut()
+        .map(|page_data| BallotBoxPage::try_from_slice_unchecked_mut(page_data))
+        .collect::<Result<Vec<_>, _>>()?;
+
     let mut ballot_box_data = ballot_box.data.borrow_mut();
     let ballot_box = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
 
-    let total_stake_weights = {
+    let (total_stake_weights, voting_opened_slot) = {
         let epoch_snapshot_data = epoch_snapshot.data.borrow();
         let epoch_snapshot = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
 
@@ -67,7 +138,10 @@ pub fn process_cast_vote(
             return Err(TipRouterError::EpochSnapshotNotFinalized.into());
         }
 
-        *epoch_snapshot.stake_weights()
+        (
+            *epoch_snapshot.stake_weights(),
+            epoch_snapshot.slot_finalized(),
+        )
     };
 
     let operator_stake_weights = {
@@ -85,24 +159,89 @@ pub fn process_cast_vote(
 
     let slot = Clock::get()?.slot;
 
-    let ballot = Ballot::new(meta_merkle_root);
+    let consensus_reached_before_vote = ballot_box.is_consensus_reached();
+    let operators_voted_before_vote = ballot_box.operators_voted();
 
-    ballot_box.cast_vote(
-        operator.key,
-        &ballot,
-        &operator_stake_weights,
+    match salt {
+        Some(salt) => ballot_box.reveal_vote(
+            &mut ballot_box_pages,
+            operator.key,
+            ballot,
+            salt,
+            &operator_stake_weights,
+            slot,
+            valid_slots_after_consensus,
+            voting_opened_slot,
+            commit_window_slots,
+        )?,
+        None => ballot_box.cast_vote(
+            &mut ballot_box_pages,
+            operator.key,
+            ballot,
+            &operator_stake_weights,
+            slot,
+            valid_slots_after_consensus,
+        )?,
+    }
+
+    {
+        let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
+        let operator_snapshot =
+            OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
+        operator_snapshot.set_vote_snapshot(ballot);
+    }
+
+    // Only the operator's first counted vote of the epoch is recorded, operators without a record
+    // still vote
+    if has_operator_record && ballot_box.operators_voted() > operators_voted_before_vote {
+        let slots_to_vote = slot.saturating_sub(ballot_box.slot_created());
+
+        let mut operator_record_data = operator_record.try_borrow_mut_data()?;
+        let operator_record_account =
+            OperatorRecord::try_from_slice_unchecked_mut(&mut operator_record_data)?;
+        operator_record_account.record_vote(epoch, slot, slots_to_vote)?;
+    }
+
+    let ballot_box_pages: Vec<&BallotBoxPage> =
+        ballot_box_pages.into_iter().map(|page| &*page).collect();
+
+    ballot_box.tally_votes(
+        &ballot_box_pages,
+        total_stake_weights.stake_weight(),
         slot,
-        valid_slots_after_consensus,
+        consensus_threshold_bps,
     )?;
 
-    ballot_box.tally_votes(total_stake_weights.stake_weight(), slot)?;
+    TipRouterEvent::VoteCast(VoteCast {
+        ncn: *ncn.key,
+        epoch,
+        operator: *operator.key,
+        meta_merkle_root: ballot.root(),
+        abstain: ballot.is_abstain(),
+        stake_weight: operator_stake_weights.stake_weight(),
+        slot,
+    })
+    .emit();
 
     if ballot_box.is_consensus_reached() {
+        let winning_ballot_tally = ballot_box.get_winning_ballot_tally(&ballot_box_pages)?;
         msg!(
             "Consensus reached for epoch {} with ballot {:?}",
             epoch,
-            ballot_box.get_winning_ballot_tally()?
+            winning_ballot_tally
         );
+
+        if !consensus_reached_before_vote {
+            TipRouterEvent::ConsensusReached(ConsensusReached {
+                ncn: *ncn.key,
+                epoch,
+                meta_merkle_root: winning_ballot_tally.ballot().root(),
+                stake_weight: winning_ballot_tally.stake_weights().stake_weight(),
+                total_stake_weight: total_stake_weights.stake_weight(),
+                slot,
+            })
+            .emit();
+        }
     }
 
     // Update Epoch State
@@ -112,6 +251,7 @@ pub fn process_cast_vote(
         epoch_state_account.update_cast_vote(
             ballot_box.operators_voted(),
             ballot_box.is_consensus_reached(),
+            ballot_box.winning_tip_distribution_account_count(),
             slot,
         )?;
     }
@@ -3,15 +3,18 @@ use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     account_payer::AccountPayer,
-    ballot_box::BallotBox,
+    ballot_box::{BallotBox, BallotBoxPage},
     base_fee_group::BaseFeeGroup,
-    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter, BaseRewardRouterPage},
     config::Config as NcnConfig,
+    crank_bounty::CrankBounty,
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
     error::TipRouterError,
+    event::{AccountClosed, TipRouterEvent},
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    operator_record::OperatorRecord,
     weight_table::WeightTable,
 };
 use solana_program::{
@@ -43,6 +46,9 @@ pub fn process_close_epoch_account(
 
     let closing_epoch_state = account_to_close.key.eq(epoch_state.key);
 
+    // Each account type takes its own optional accounts, the crank bounty accounts follow them
+    let mut optional_account_count = 0;
+
     // Empty Account Check
     if account_to_close.data_is_empty() {
         msg!("Account already closed");
@@ -120,15 +126,51 @@ pub fn process_close_epoch_account(
                     let account_to_close_struct =
                         OperatorSnapshot::try_from_slice_unchecked(&account_to_close_data)?;
                     let ncn_operator_index = account_to_close_struct.ncn_operator_index() as usize;
-                    epoch_state_account.close_operator_snapshot(ncn_operator_index);
+
+                    let [operator_record, ballot_box, ballot_box_pages @ ..] = optional_accounts
+                    else {
+                        msg!("Operator record or ballot box account is missing");
+                        return Err(
+                            TipRouterError::CannotCloseAccountNoOperatorRecordProvided.into()
+                        );
+                    };
+
+                    optional_account_count = record_operator_epoch_outcome(
+                        program_id,
+                        ncn.key,
+                        epoch,
+                        account_to_close_struct,
+                        operator_record,
+                        ballot_box,
+                        ballot_box_pages,
+                    )?;
+
+                    epoch_state_account.close_operator_snapshot(ncn_operator_index)?;
                 }
                 BallotBox::DISCRIMINATOR => {
                     BallotBox::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
+
+                    // Operator snapshots read the ballot box when they are closed
+                    if !epoch_state_account
+                        .account_status()
+                        .are_operator_snapshots_closed()
+                    {
+                        msg!("Operator snapshots need to be closed before the ballot box");
+                        return Err(
+                            TipRouterError::CannotCloseBallotBoxOperatorSnapshotsOpen.into()
+                        );
+                    }
+
                     epoch_state_account.close_ballot_box();
                 }
+                BallotBoxPage::DISCRIMINATOR => {
+                    BallotBoxPage::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
+                    epoch_state_account.close_page()?;
+                }
                 BaseRewardRouter::DISCRIMINATOR => {
                     BaseRewardRouter::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
-                    let [base_reward_receiver] = optional_accounts else {
+                    optional_account_count = 1;
+                    let [base_reward_receiver, ..] = optional_accounts else {
                         msg!("Base reward receiver account is missing");
                         return Err(TipRouterError::CannotCloseAccountNoReceiverProvided.into());
                     };
@@ -150,6 +192,15 @@ pub fn process_close_epoch_account(
 
                     epoch_state_account.close_base_reward_router();
                 }
+                BaseRewardRouterPage::DISCRIMINATOR => {
+                    BaseRewardRouterPage::load_to_close(
+                        program_id,
+                        account_to_close,
+                        ncn.key,
+                        epoch,
+                    )?;
+                    epoch_state_account.close_page()?;
+                }
                 NcnRewardRouter::DISCRIMINATOR => {
                     NcnRewardRouter::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
                     let account_to_close_data = account_to_close.try_borrow_data()?;
@@ -160,7 +211,8 @@ pub fn process_close_epoch_account(
                     let operator = ncn_reward_router.operator();
                     let ncn_fee_group = ncn_reward_router.ncn_fee_group();
 
-                    let [ncn_reward_receiver] = optional_accounts else {
+                    optional_account_count = 1;
+                    let [ncn_reward_receiver, ..] = optional_accounts else {
                         msg!("NCN reward receiver account is missing");
                         return Err(TipRouterError::CannotCloseAccountNoReceiverProvided.into());
                     };
@@ -193,6 +245,15 @@ pub fn process_close_epoch_account(
                     return Err(TipRouterError::InvalidAccountToCloseDiscriminator.into());
                 }
             }
+
+            TipRouterEvent::AccountClosed(AccountClosed {
+                ncn: *ncn.key,
+                epoch,
+                account: *account_to_close.key,
+                discriminator,
+                lamports: account_to_close.lamports(),
+            })
+            .emit();
         }
     }
 
@@ -224,5 +285,75 @@ pub fn process_close_epoch_account(
         *epoch_marker = EpochMarker::new(ncn.key, epoch, slot_closed);
     }
 
-    AccountPayer::close_account(program_id, account_payer, account_to_close)
+    AccountPayer::close_account(program_id, account_payer, account_to_close)?;
+
+    let (_, crank_bounty_accounts) =
+        CrankBounty::split_accounts(optional_accounts, optional_account_count)?;
+
+    // Closing the epoch state itself pays no bounty, there is nowhere left to track it
+    CrankBounty::pay(
+        program_id,
+        ncn.key,
+        epoch,
+        epoch_state,
+        crank_bounty_accounts,
+    )
+}
+
+/// Records the operator's participation while the ballot box is still around, returning how many
+/// of the optional accounts were used. Operators without a record and epochs without a ballot box
+/// have nothing to record, their snapshots still close
+fn record_operator_epoch_outcome(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+    operator_snapshot: &OperatorSnapshot,
+    operator_record: &AccountInfo,
+    ballot_box: &AccountInfo,
+    ballot_box_pages: &[AccountInfo],
+) -> Result<usize, ProgramError> {
+    let operator = operator_snapshot.operator();
+
+    let has_operator_record =
+        OperatorRecord::load_if_initialized(program_id, operator_record, ncn, operator, true)?;
+    if !BallotBox::load_if_initialized(program_id, ballot_box, ncn, epoch, false)? {
+        return Ok(2);
+    }
+
+    let ballot_box_data = ballot_box.data.borrow();
+    let ballot_box_account = BallotBox::try_from_slice_unchecked(&ballot_box_data)?;
+    let page_count = ballot_box_account.page_count();
+
+    BallotBoxPage::load_pages(
+        program_id,
+        ballot_box_pages,
+        ballot_box.key,
+        page_count,
+        false,
+    )?;
+
+    if has_operator_record {
+        let ballot_box_pages_data = ballot_box_pages[..page_count as usize]
+            .iter()
+            .map(|page| page.try_borrow_data())
+            .collect::<Result<Vec<_>, _>>()?;
+        let ballot_box_pages = ballot_box_pages_data
+            .iter()
+            .map(|page_data| BallotBoxPage::try_from_slice_unchecked(page_data))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut operator_record_data = operator_record.try_borrow_mut_data()?;
+        let operator_record_account =
+            OperatorRecord::try_from_slice_unchecked_mut(&mut operator_record_data)?;
+        let operator_vote = ballot_box_account.operator_vote(&ballot_box_pages, operator);
+        operator_record_account.record_epoch_outcome(
+            epoch,
+            operator_snapshot.is_active(),
+            operator_vote.is_some(),
+            operator_vote.is_some_and(|vote| vote.is_abstain()),
+            ballot_box_account.voted_for_winning_ballot(&ballot_box_pages, operator),
+        )?;
+    }
+
+    Ok(2 + page_count as usize)
 }
@@ -0,0 +1,119 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{
+    ballot_box::{BallotBox, BallotBoxPage},
+    config::Config as NcnConfig,
+    constants::PAUSE_VOTING,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    error::TipRouterError,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+pub fn process_commit_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    commitment: &[u8; 32],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn_config, ballot_box, ncn, epoch_snapshot, operator_snapshot, operator, operator_admin, ballot_box_pages @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Operator is committing the vote, needs to be signer
+    load_signer(operator_admin, false)?;
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, false)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Operator::load(&jito_restaking_program::id(), operator, false)?;
+
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, false)?;
+    OperatorSnapshot::load(
+        program_id,
+        operator_snapshot,
+        operator.key,
+        ncn.key,
+        epoch,
+        false,
+    )?;
+    let operator_data = operator.data.borrow();
+    let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+
+    if *operator_admin.key != operator_account.voter {
+        return Err(TipRouterError::InvalidOperatorVoter.into());
+    }
+
+    let commit_window_slots = {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.check_not_paused(PAUSE_VOTING)?;
+
+        if !ncn_config.is_commit_reveal_enabled() {
+            return Err(TipRouterError::CommitRevealNotEnabled.into());
+        }
+
+        ncn_config.commit_window_slots()
+    };
+
+    let voting_opened_slot = {
+        let epoch_snapshot_data = epoch_snapshot.data.borrow();
+        let epoch_snapshot = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
+
+        if !epoch_snapshot.finalized() {
+            return Err(TipRouterError::EpochSnapshotNotFinalized.into());
+        }
+
+        epoch_snapshot.slot_finalized()
+    };
+
+    let slot = Clock::get()?.slot;
+
+    let page_count = {
+        let ballot_box_data = ballot_box.data.borrow();
+        BallotBox::try_from_slice_unchecked(&ballot_box_data)?.page_count()
+    };
+
+    BallotBoxPage::load_pages(
+        program_id,
+        ballot_box_pages,
+        ballot_box.key,
+        page_count,
+        true,
+    )?;
+    let mut ballot_box_pages_data = ballot_box_pages[..page_count as usize]
+        .iter()
+        .map(|page| page.try_borrow_mut_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut ballot_box_pages = ballot_box_pages_data
+        .iter_mut()
+        .map(|page_data| BallotBoxPage::try_from_slice_unchecked_mut(page_data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut ballot_box_data = ballot_box.data.borrow_mut();
+    let ballot_box = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
+
+    ballot_box.commit_vote(
+        &mut ballot_box_pages,
+        operator.key,
+        commitment,
+        slot,
+        voting_opened_slot,
+        commit_window_slots,
+    )?;
+
+    msg!(
+        "Operator {} committed vote for epoch {}",
+        operator.key,
+        epoch
+    );
+
+    Ok(())
+}
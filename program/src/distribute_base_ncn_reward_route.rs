@@ -2,10 +2,13 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
-    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter, BaseRewardRouterPage},
     config::Config as NcnConfig,
+    constants::PAUSE_DISTRIBUTION,
+    crank_bounty::CrankBounty,
     epoch_state::EpochState,
     error::TipRouterError,
+    event::{RewardsDistributed, TipRouterEvent},
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
 };
@@ -21,7 +24,7 @@ pub fn process_distribute_base_ncn_reward_route(
     ncn_fee_group: u8,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, ncn, operator, base_reward_router, base_reward_receiver, ncn_reward_router, ncn_reward_receiver, system_program] =
+    let [epoch_state, ncn_config, ncn, operator, base_reward_router, base_reward_receiver, ncn_reward_router, ncn_reward_receiver, system_program, base_reward_router_pages @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -55,10 +58,40 @@ pub fn process_distribute_base_ncn_reward_route(
         false,
     )?;
 
+    {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.check_not_paused(PAUSE_DISTRIBUTION)?;
+    }
+
     load_system_program(system_program)?;
 
+    let page_count = {
+        let base_reward_router_data = base_reward_router.try_borrow_data()?;
+        BaseRewardRouter::try_from_slice_unchecked(&base_reward_router_data)?.page_count()
+    };
+    let (base_reward_router_pages, crank_bounty_accounts) =
+        CrankBounty::split_accounts(base_reward_router_pages, page_count as usize)?;
+
+    BaseRewardRouterPage::load_pages(
+        program_id,
+        base_reward_router_pages,
+        base_reward_router.key,
+        page_count,
+        true,
+    )?;
+
     // Get rewards and update state
     let rewards = {
+        let mut base_reward_router_pages_data = base_reward_router_pages[..page_count as usize]
+            .iter()
+            .map(|page| page.try_borrow_mut_data())
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut base_reward_router_pages = base_reward_router_pages_data
+            .iter_mut()
+            .map(|page_data| BaseRewardRouterPage::try_from_slice_unchecked_mut(page_data))
+            .collect::<Result<Vec<_>, _>>()?;
+
         let mut epoch_reward_router_data = base_reward_router.try_borrow_mut_data()?;
         let base_reward_router_account =
             BaseRewardRouter::try_from_slice_unchecked_mut(&mut epoch_reward_router_data)?;
@@ -68,8 +101,11 @@ pub fn process_distribute_base_ncn_reward_route(
             return Err(TipRouterError::RouterStillRouting.into());
         }
 
-        base_reward_router_account
-            .distribute_ncn_fee_group_reward_route(ncn_fee_group, operator.key)?
+        base_reward_router_account.distribute_ncn_fee_group_reward_route(
+            &mut base_reward_router_pages,
+            ncn_fee_group,
+            operator.key,
+        )?
     };
 
     // Send rewards
@@ -91,12 +127,33 @@ pub fn process_distribute_base_ncn_reward_route(
                 .collect::<Vec<&[u8]>>()
                 .as_slice()],
         )?;
+
+        TipRouterEvent::RewardsDistributed(RewardsDistributed {
+            ncn: *ncn.key,
+            epoch,
+            router: *base_reward_router.key,
+            destination: *ncn_reward_receiver.key,
+            rewards,
+        })
+        .emit();
     }
 
-    {
+    let crank_bounty_lamports = {
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
         epoch_state_account.update_distribute_base_ncn_rewards(rewards)?;
+        epoch_state_account.crank_bounty_lamports()
+    };
+
+    // As with base rewards, only a distribution larger than the bounty earns it
+    if rewards > crank_bounty_lamports {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
     }
 
     Ok(())
@@ -5,9 +5,11 @@ use jito_tip_router_core::{
     base_fee_group::BaseFeeGroup,
     base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
     config::Config as NcnConfig,
-    constants::JITOSOL_MINT,
+    constants::{JITOSOL_MINT, PAUSE_DISTRIBUTION},
+    crank_bounty::CrankBounty,
     epoch_state::EpochState,
     error::TipRouterError,
+    event::{RewardsDistributed, TipRouterEvent},
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
@@ -21,6 +23,7 @@ pub fn process_distribute_base_rewards(
     base_fee_group: u8,
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 16)?;
     let [epoch_state, ncn_config, ncn, base_reward_router, base_reward_receiver, base_fee_wallet, base_fee_wallet_ata, stake_pool_program, stake_pool, stake_pool_withdraw_authority, reserve_stake, manager_fee_account, referrer_pool_tokens_account, pool_mint, token_program, system_program] =
         accounts
     else {
@@ -34,6 +37,12 @@ pub fn process_distribute_base_rewards(
     BaseRewardReceiver::load(program_id, base_reward_receiver, ncn.key, epoch, true)?;
     load_associated_token_account(base_fee_wallet_ata, base_fee_wallet.key, &JITOSOL_MINT)?;
 
+    {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.check_not_paused(PAUSE_DISTRIBUTION)?;
+    }
+
     if stake_pool_program.key.ne(&spl_stake_pool::id()) {
         msg!("Incorrect stake pool program ID");
         return Err(ProgramError::InvalidAccountData);
@@ -106,12 +115,34 @@ pub fn process_distribute_base_rewards(
                 .collect::<Vec<&[u8]>>()
                 .as_slice()],
         )?;
+
+        TipRouterEvent::RewardsDistributed(RewardsDistributed {
+            ncn: *ncn.key,
+            epoch,
+            router: *base_reward_router.key,
+            destination: *base_fee_wallet_ata.key,
+            rewards,
+        })
+        .emit();
     }
 
-    {
+    let crank_bounty_lamports = {
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
         epoch_state_account.update_distribute_base_rewards(rewards)?;
+        epoch_state_account.crank_bounty_lamports()
+    };
+
+    // Distributing only earns the crank bounty when it moves more than the bounty pays, so small
+    // amounts sent to a receiver can't be split into bounties
+    if rewards > crank_bounty_lamports {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
     }
 
     Ok(())
@@ -3,10 +3,12 @@ use jito_jsm_core::loader::load_associated_token_account;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
     config::Config as NcnConfig,
-    constants::JITOSOL_MINT,
+    constants::{JITOSOL_MINT, PAUSE_DISTRIBUTION},
+    crank_bounty::CrankBounty,
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
     error::TipRouterError,
+    event::{RewardsDistributed, TipRouterEvent},
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
 };
@@ -23,6 +25,7 @@ pub fn process_distribute_ncn_operator_rewards(
     ncn_fee_group: u8,
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 17)?;
     let [epoch_state, ncn_config, ncn, operator, operator_ata, operator_snapshot, ncn_reward_router, ncn_reward_receiver, stake_pool_program, stake_pool, stake_pool_withdraw_authority, reserve_stake, manager_fee_account, referrer_pool_tokens_account, pool_mint, token_program, system_program] =
         accounts
     else {
@@ -64,6 +67,12 @@ pub fn process_distribute_ncn_operator_rewards(
     )?;
     load_associated_token_account(operator_ata, operator.key, &JITOSOL_MINT)?;
 
+    {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.check_not_paused(PAUSE_DISTRIBUTION)?;
+    }
+
     if stake_pool_program.key.ne(&spl_stake_pool::id()) {
         msg!("Incorrect stake pool program ID");
         return Err(ProgramError::InvalidAccountData);
@@ -129,9 +138,18 @@ pub fn process_distribute_ncn_operator_rewards(
                 .collect::<Vec<&[u8]>>()
                 .as_slice()],
         )?;
+
+        TipRouterEvent::RewardsDistributed(RewardsDistributed {
+            ncn: *ncn.key,
+            epoch,
+            router: *ncn_reward_router.key,
+            destination: *operator_ata.key,
+            rewards,
+        })
+        .emit();
     }
 
-    {
+    let crank_bounty_lamports = {
         let operator_snapshot_data = operator_snapshot.try_borrow_data()?;
         let operator_snapshot_account =
             OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?;
@@ -143,6 +161,18 @@ pub fn process_distribute_ncn_operator_rewards(
             ncn_fee_group,
             rewards,
         )?;
+        epoch_state_account.crank_bounty_lamports()
+    };
+
+    // Only a distribution larger than the bounty earns it
+    if rewards > crank_bounty_lamports {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
     }
 
     Ok(())
@@ -3,19 +3,28 @@ use jito_jsm_core::loader::load_associated_token_account;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
     config::Config as NcnConfig,
-    constants::JITOSOL_MINT,
+    constants::{JITOSOL_MINT, PAUSE_DISTRIBUTION},
+    crank_bounty::CrankBounty,
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
     error::TipRouterError,
+    event::{RewardsDistributed, TipRouterEvent},
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    reward_asset::RewardAssetType,
+    vault_registry::VaultRegistry,
 };
 use jito_vault_core::vault::Vault;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
 };
 use spl_stake_pool::instruction::deposit_sol;
+use spl_token::{instruction::sync_native, native_mint};
 
 /// Can be backfilled for previous epochs
 pub fn process_distribute_ncn_vault_rewards(
@@ -24,7 +33,8 @@ pub fn process_distribute_ncn_vault_rewards(
     ncn_fee_group: u8,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, ncn, operator, vault, vault_ata, operator_snapshot, ncn_reward_router, ncn_reward_receiver, stake_pool_program, stake_pool, stake_pool_withdraw_authority, reserve_stake, manager_fee_account, referrer_pool_tokens_account, pool_mint, token_program, system_program] =
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 19)?;
+    let [epoch_state, ncn_config, ncn, operator, vault, vault_ata, operator_snapshot, ncn_reward_router, ncn_reward_receiver, stake_pool_program, stake_pool, stake_pool_withdraw_authority, reserve_stake, manager_fee_account, referrer_pool_tokens_account, pool_mint, token_program, system_program, vault_registry] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -64,9 +74,46 @@ pub fn process_distribute_ncn_vault_rewards(
         epoch,
         true,
     )?;
-    load_associated_token_account(vault_ata, vault.key, &JITOSOL_MINT)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, false)?;
 
-    if stake_pool_program.key.ne(&spl_stake_pool::id()) {
+    {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.check_not_paused(PAUSE_DISTRIBUTION)?;
+    }
+
+    let reward_asset = {
+        let vault_registry_data = vault_registry.data.borrow();
+        let vault_registry_account = VaultRegistry::try_from_slice_unchecked(&vault_registry_data)?;
+        *vault_registry_account
+            .get_vault_entry(vault.key)?
+            .reward_asset()
+    };
+    let reward_asset_type = reward_asset.asset_type()?;
+
+    match reward_asset_type {
+        RewardAssetType::JitoSol => {
+            load_associated_token_account(vault_ata, vault.key, &JITOSOL_MINT)?;
+        }
+        RewardAssetType::StakePool => {
+            if stake_pool.key.ne(reward_asset.stake_pool()) {
+                msg!("Stake pool is not the vault's reward stake pool");
+                return Err(TipRouterError::InvalidRewardAsset.into());
+            }
+            // The stake pool program checks the pool mint belongs to the stake pool
+            load_associated_token_account(vault_ata, vault.key, pool_mint.key)?;
+        }
+        RewardAssetType::Sol => {
+            load_associated_token_account(vault_ata, vault.key, &native_mint::id())?;
+            if token_program.key.ne(&spl_token::id()) {
+                msg!("Incorrect token program ID");
+                return Err(ProgramError::IncorrectProgramId);
+            }
+        }
+    }
+
+    if reward_asset_type != RewardAssetType::Sol && stake_pool_program.key.ne(&spl_stake_pool::id())
+    {
         msg!("Incorrect stake pool program ID");
         return Err(ProgramError::InvalidAccountData);
     }
@@ -86,7 +133,24 @@ pub fn process_distribute_ncn_vault_rewards(
     };
 
     // Send rewards
-    if rewards > 0 {
+    if rewards > 0 && reward_asset_type == RewardAssetType::Sol {
+        NcnRewardReceiver::transfer(
+            program_id,
+            ncn_fee_group,
+            operator.key,
+            ncn.key,
+            epoch,
+            ncn_reward_receiver,
+            vault_ata,
+            rewards,
+        )?;
+
+        // Wrapped SOL only counts lamports once they are synced into the token amount
+        invoke(
+            &sync_native(token_program.key, vault_ata.key)?,
+            &[vault_ata.clone(), token_program.clone()],
+        )?;
+    } else if rewards > 0 {
         let (_, ncn_reward_receiver_bump, mut ncn_reward_receiver_seeds) =
             NcnRewardReceiver::find_program_address(
                 program_id,
@@ -135,7 +199,18 @@ pub fn process_distribute_ncn_vault_rewards(
         )?;
     }
 
-    {
+    if rewards > 0 {
+        TipRouterEvent::RewardsDistributed(RewardsDistributed {
+            ncn: *ncn.key,
+            epoch,
+            router: *ncn_reward_router.key,
+            destination: *vault_ata.key,
+            rewards,
+        })
+        .emit();
+    }
+
+    let crank_bounty_lamports = {
         let operator_snapshot_data = operator_snapshot.try_borrow_data()?;
         let operator_snapshot_account =
             OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?;
@@ -147,6 +222,18 @@ pub fn process_distribute_ncn_vault_rewards(
             ncn_fee_group,
             rewards,
         )?;
+        epoch_state_account.crank_bounty_lamports()
+    };
+
+    // Only a distribution larger than the bounty earns it
+    if rewards > crank_bounty_lamports {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
     }
 
     Ok(())
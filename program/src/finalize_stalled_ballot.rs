@@ -0,0 +1,113 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    ballot_box::{BallotBox, BallotBoxPage},
+    config::Config as NcnConfig,
+    epoch_snapshot::EpochSnapshot,
+    epoch_state::EpochState,
+    error::TipRouterError,
+    event::{StalledBallotFinalized, TipRouterEvent},
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Permissionless - finalizes a stalled ballot box with its plurality ballot
+pub fn process_finalize_stalled_ballot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn_config, ballot_box, ncn, epoch_snapshot, ballot_box_pages @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, false)?;
+
+    let ncn_config_data = ncn_config.data.borrow();
+    let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+
+    if !ncn_config.is_plurality_fallback_enabled() {
+        msg!("Plurality fallback is disabled");
+        return Err(TipRouterError::PluralityFallbackDisabled.into());
+    }
+
+    let total_stake_weight = {
+        let epoch_snapshot_data = epoch_snapshot.data.borrow();
+        EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?
+            .stake_weights()
+            .stake_weight()
+    };
+
+    let page_count = {
+        let ballot_box_data = ballot_box.data.borrow();
+        BallotBox::try_from_slice_unchecked(&ballot_box_data)?.page_count()
+    };
+
+    BallotBoxPage::load_pages(
+        program_id,
+        ballot_box_pages,
+        ballot_box.key,
+        page_count,
+        false,
+    )?;
+    let ballot_box_pages_data = ballot_box_pages[..page_count as usize]
+        .iter()
+        .map(|page| page.try_borrow_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let ballot_box_pages = ballot_box_pages_data
+        .iter()
+        .map(|page_data| BallotBoxPage::try_from_slice_unchecked(page_data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut ballot_box_data = ballot_box.data.borrow_mut();
+    let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
+
+    let clock = Clock::get()?;
+    let current_epoch = clock.epoch;
+
+    let plurality_tally = ballot_box_account.finalize_stalled_ballot(
+        &ballot_box_pages,
+        total_stake_weight,
+        current_epoch,
+        ncn_config.epochs_before_plurality_fallback()?,
+        ncn_config.plurality_min_stake_weight_bps(),
+    )?;
+
+    msg!(
+        "Finalized stalled ballot with {} of {} stake weight",
+        plurality_tally.stake_weights().stake_weight(),
+        total_stake_weight
+    );
+
+    TipRouterEvent::StalledBallotFinalized(StalledBallotFinalized {
+        ncn: *ncn.key,
+        epoch,
+        meta_merkle_root: plurality_tally.ballot().root(),
+        stake_weight: plurality_tally.stake_weights().stake_weight(),
+        total_stake_weight,
+        slot: clock.slot,
+    })
+    .emit();
+
+    // Update Epoch State
+    {
+        let slot = clock.slot;
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_finalize_stalled_ballot(
+            ballot_box_account.is_consensus_reached(),
+            ballot_box_account.winning_tip_distribution_account_count(),
+            slot,
+        )?;
+    }
+
+    Ok(())
+}
@@ -2,7 +2,8 @@ use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     account_payer::AccountPayer, ballot_box::BallotBox, config::Config as NcnConfig,
-    constants::MAX_REALLOC_BYTES, epoch_marker::EpochMarker, epoch_state::EpochState,
+    constants::MAX_REALLOC_BYTES, crank_bounty::CrankBounty, epoch_marker::EpochMarker,
+    epoch_state::EpochState,
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
@@ -14,6 +15,7 @@ pub fn process_initialize_ballot_box(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 7)?;
     let [epoch_marker, epoch_state, ncn_config, ballot_box, ncn, account_payer, system_program] =
         accounts
     else {
@@ -49,5 +51,11 @@ pub fn process_initialize_ballot_box(
         &ballot_box_seeds,
     )?;
 
-    Ok(())
+    CrankBounty::pay(
+        program_id,
+        ncn.key,
+        epoch,
+        epoch_state,
+        crank_bounty_accounts,
+    )
 }
@@ -4,6 +4,7 @@ use jito_tip_router_core::{
     account_payer::AccountPayer,
     base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
     constants::MAX_REALLOC_BYTES,
+    crank_bounty::CrankBounty,
     epoch_marker::EpochMarker,
     epoch_state::EpochState,
 };
@@ -18,6 +19,7 @@ pub fn process_initialize_base_reward_router(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 7)?;
     let [epoch_marker, epoch_state, ncn, base_reward_router, base_reward_receiver, account_payer, system_program] =
         accounts
     else {
@@ -73,5 +75,11 @@ pub fn process_initialize_base_reward_router(
         min_rent,
     )?;
 
-    Ok(())
+    CrankBounty::pay(
+        program_id,
+        ncn.key,
+        epoch,
+        epoch_state,
+        crank_bounty_accounts,
+    )
 }
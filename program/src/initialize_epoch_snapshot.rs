@@ -2,9 +2,9 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, config::Config, epoch_marker::EpochMarker,
-    epoch_snapshot::EpochSnapshot, epoch_state::EpochState, error::TipRouterError, fees,
-    weight_table::WeightTable,
+    account_payer::AccountPayer, config::Config, crank_bounty::CrankBounty,
+    epoch_marker::EpochMarker, epoch_snapshot::EpochSnapshot, epoch_state::EpochState,
+    error::TipRouterError, fee_schedule::FeeSchedule, fees, weight_table::WeightTable,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -17,7 +17,8 @@ pub fn process_initialize_epoch_snapshot(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_marker, epoch_state, config, ncn, weight_table, epoch_snapshot, account_payer, system_program] =
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 9)?;
+    let [epoch_marker, epoch_state, config, ncn, weight_table, epoch_snapshot, account_payer, system_program, fee_schedule] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -25,6 +26,8 @@ pub fn process_initialize_epoch_snapshot(
 
     EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, true)?;
     Config::load(program_id, config, ncn.key, false)?;
+    let fee_schedule_initialized =
+        FeeSchedule::load_if_initialized(program_id, fee_schedule, ncn.key, false)?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
     EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
@@ -79,7 +82,17 @@ pub fn process_initialize_epoch_snapshot(
     let ncn_fees: fees::Fees = {
         let ncn_config_data = config.data.borrow();
         let ncn_config_account = Config::try_from_slice_unchecked(&ncn_config_data)?;
-        *ncn_config_account.fee_config.current_fees(ncn_epoch)
+
+        let fee_schedule_data = fee_schedule.data.borrow();
+        let fee_schedule_account = if fee_schedule_initialized {
+            Some(FeeSchedule::try_from_slice_unchecked(&fee_schedule_data)?)
+        } else {
+            None
+        };
+
+        *ncn_config_account
+            .fee_config
+            .current_fees(fee_schedule_account, ncn_epoch)
     };
 
     let operator_count: u64 = {
@@ -116,5 +129,11 @@ pub fn process_initialize_epoch_snapshot(
         epoch_state_account.update_initialize_epoch_snapshot(operator_count);
     }
 
-    Ok(())
+    CrankBounty::pay(
+        program_id,
+        ncn.key,
+        epoch,
+        epoch_state,
+        crank_bounty_accounts,
+    )
 }
@@ -3,6 +3,7 @@ use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
     account_payer::AccountPayer,
+    crank_bounty::CrankBounty,
     epoch_marker::EpochMarker,
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
@@ -21,6 +22,7 @@ pub fn process_initialize_ncn_reward_router(
     ncn_fee_group: u8,
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 9)?;
     let [epoch_marker, epoch_state, ncn, operator, operator_snapshot, ncn_reward_router, ncn_reward_receiver, account_payer, system_program] =
         accounts
     else {
@@ -132,5 +134,11 @@ pub fn process_initialize_ncn_reward_router(
             .update_realloc_ncn_reward_router(operator_ncn_index as usize, ncn_fee_group)?;
     }
 
-    Ok(())
+    CrankBounty::pay(
+        program_id,
+        ncn.key,
+        epoch,
+        epoch_state,
+        crank_bounty_accounts,
+    )
 }
@@ -0,0 +1,66 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_system_account, load_system_program};
+use jito_restaking_core::{ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator};
+use jito_tip_router_core::{
+    account_payer::AccountPayer, config::Config as NcnConfig, operator_record::OperatorRecord,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Initializes an Operator Record, needed before the operator can vote
+pub fn process_initialize_operator_record(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [ncn_config, operator_record, ncn, operator, ncn_operator_state, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_account(operator_record, true)?;
+    load_system_program(system_program)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Operator::load(&jito_restaking_program::id(), operator, false)?;
+    NcnOperatorState::load(
+        &jito_restaking_program::id(),
+        ncn_operator_state,
+        ncn,
+        operator,
+        false,
+    )?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+    let (operator_record_pda, operator_record_bump, mut operator_record_seeds) =
+        OperatorRecord::find_program_address(program_id, ncn.key, operator.key);
+    operator_record_seeds.push(vec![operator_record_bump]);
+
+    if operator_record_pda != *operator_record.key {
+        msg!("Operator record account is not at the correct PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        operator_record,
+        system_program,
+        program_id,
+        OperatorRecord::SIZE,
+        &operator_record_seeds,
+    )?;
+
+    let mut operator_record_data = operator_record.try_borrow_mut_data()?;
+    operator_record_data[0] = OperatorRecord::DISCRIMINATOR;
+    let operator_record_account =
+        OperatorRecord::try_from_slice_unchecked_mut(&mut operator_record_data)?;
+
+    *operator_record_account = OperatorRecord::new(ncn.key, operator.key, operator_record_bump);
+
+    Ok(())
+}
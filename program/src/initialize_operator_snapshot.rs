@@ -5,6 +5,7 @@ use jito_tip_router_core::{
     account_payer::AccountPayer,
     config::Config,
     constants::MAX_REALLOC_BYTES,
+    crank_bounty::CrankBounty,
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
@@ -21,6 +22,7 @@ pub fn process_initialize_operator_snapshot(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 10)?;
     let [epoch_marker, epoch_state, config, ncn, operator, ncn_operator_state, epoch_snapshot, operator_snapshot, account_payer, system_program] =
         accounts
     else {
@@ -89,5 +91,11 @@ pub fn process_initialize_operator_snapshot(
         &operator_snapshot_seeds,
     )?;
 
-    Ok(())
+    CrankBounty::pay(
+        program_id,
+        ncn.key,
+        epoch,
+        epoch_state,
+        crank_bounty_accounts,
+    )
 }
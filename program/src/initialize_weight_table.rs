@@ -2,8 +2,9 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, constants::MAX_REALLOC_BYTES, epoch_marker::EpochMarker,
-    epoch_state::EpochState, vault_registry::VaultRegistry, weight_table::WeightTable,
+    account_payer::AccountPayer, constants::MAX_REALLOC_BYTES, crank_bounty::CrankBounty,
+    epoch_marker::EpochMarker, epoch_state::EpochState, error::TipRouterError,
+    vault_registry::VaultRegistry, weight_table::WeightTable,
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
@@ -17,6 +18,7 @@ pub fn process_initialize_weight_table(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 7)?;
     let [epoch_marker, epoch_state, vault_registry, ncn, weight_table, account_payer, system_program] =
         accounts
     else {
@@ -38,10 +40,14 @@ pub fn process_initialize_weight_table(
         ncn.vault_count()
     };
 
+    // Retired vaults that were freed from the registry are still counted by the NCN
     let vault_registry_count = {
         let vault_registry_data = vault_registry.data.borrow();
         let vault_registry = VaultRegistry::try_from_slice_unchecked(&vault_registry_data)?;
-        vault_registry.vault_count()
+        vault_registry
+            .vault_count()
+            .checked_add(vault_registry.removed_vault_count())
+            .ok_or(TipRouterError::ArithmeticOverflow)?
     };
 
     if vault_count != vault_registry_count {
@@ -75,5 +81,11 @@ pub fn process_initialize_weight_table(
         &weight_table_seeds,
     )?;
 
-    Ok(())
+    CrankBounty::pay(
+        program_id,
+        ncn.key,
+        epoch,
+        epoch_state,
+        crank_bounty_accounts,
+    )
 }
@@ -1,40 +1,61 @@
+mod admin_accept_new_admin;
+mod admin_cancel_new_admin;
 mod admin_initialize_config;
+mod admin_initialize_config_extension;
+mod admin_initialize_fee_schedule;
+mod admin_initialize_tie_breaker_committee;
+mod admin_propose_new_admin;
 mod admin_register_st_mint;
+mod admin_retire_st_mint;
+mod admin_retire_vault;
 mod admin_set_config_fees;
-mod admin_set_new_admin;
 mod admin_set_parameters;
+mod admin_set_pause;
 mod admin_set_st_mint;
+mod admin_set_st_mint_oracle_feeds;
 mod admin_set_tie_breaker;
+mod admin_set_tie_breaker_committee;
+mod admin_set_vault_reward_asset;
+mod admin_set_vault_reward_multiplier;
 mod admin_set_weight;
 mod cast_vote;
 mod claim_with_payer;
 mod close_epoch_account;
+mod commit_vote;
 mod distribute_base_ncn_reward_route;
 mod distribute_base_rewards;
 mod distribute_ncn_operator_rewards;
 mod distribute_ncn_vault_rewards;
+mod finalize_stalled_ballot;
 mod initialize_ballot_box;
 mod initialize_base_reward_router;
 mod initialize_epoch_snapshot;
 mod initialize_epoch_state;
 mod initialize_ncn_reward_router;
+mod initialize_operator_record;
 mod initialize_operator_snapshot;
 mod initialize_vault_registry;
 mod initialize_weight_table;
+mod median_set_weight;
 mod realloc_ballot_box;
+mod realloc_ballot_box_page;
 mod realloc_base_reward_router;
+mod realloc_base_reward_router_page;
 mod realloc_epoch_state;
 mod realloc_operator_snapshot;
 mod realloc_vault_registry;
 mod realloc_weight_table;
 mod register_vault;
+mod reveal_vote;
 mod route_base_rewards;
 mod route_ncn_rewards;
 mod set_merkle_root;
+mod set_merkle_root_batch;
 mod snapshot_vault_operator_delegation;
+mod sweep_dust;
 mod switchboard_set_weight;
+mod tie_breaker_approve;
 
-use admin_set_new_admin::process_admin_set_new_admin;
 use borsh::BorshDeserialize;
 use initialize_epoch_state::process_initialize_epoch_state;
 use jito_tip_router_core::instruction::TipRouterInstruction;
@@ -47,34 +68,53 @@ use solana_program::{
 use solana_security_txt::security_txt;
 
 use crate::{
+    admin_accept_new_admin::process_admin_accept_new_admin,
+    admin_cancel_new_admin::process_admin_cancel_new_admin,
     admin_initialize_config::process_admin_initialize_config,
+    admin_initialize_config_extension::process_admin_initialize_config_extension,
+    admin_initialize_fee_schedule::process_admin_initialize_fee_schedule,
+    admin_initialize_tie_breaker_committee::process_admin_initialize_tie_breaker_committee,
+    admin_propose_new_admin::process_admin_propose_new_admin,
     admin_register_st_mint::process_admin_register_st_mint,
+    admin_retire_st_mint::process_admin_retire_st_mint,
+    admin_retire_vault::process_admin_retire_vault,
     admin_set_config_fees::process_admin_set_config_fees,
-    admin_set_parameters::process_admin_set_parameters,
+    admin_set_parameters::process_admin_set_parameters, admin_set_pause::process_admin_set_pause,
     admin_set_st_mint::process_admin_set_st_mint,
+    admin_set_st_mint_oracle_feeds::process_admin_set_st_mint_oracle_feeds,
     admin_set_tie_breaker::process_admin_set_tie_breaker,
+    admin_set_tie_breaker_committee::process_admin_set_tie_breaker_committee,
+    admin_set_vault_reward_asset::process_admin_set_vault_reward_asset,
+    admin_set_vault_reward_multiplier::process_admin_set_vault_reward_multiplier,
     admin_set_weight::process_admin_set_weight, cast_vote::process_cast_vote,
     claim_with_payer::process_claim_with_payer, close_epoch_account::process_close_epoch_account,
+    commit_vote::process_commit_vote,
     distribute_base_ncn_reward_route::process_distribute_base_ncn_reward_route,
     distribute_base_rewards::process_distribute_base_rewards,
     distribute_ncn_operator_rewards::process_distribute_ncn_operator_rewards,
     distribute_ncn_vault_rewards::process_distribute_ncn_vault_rewards,
+    finalize_stalled_ballot::process_finalize_stalled_ballot,
     initialize_ballot_box::process_initialize_ballot_box,
     initialize_base_reward_router::process_initialize_base_reward_router,
     initialize_epoch_snapshot::process_initialize_epoch_snapshot,
     initialize_ncn_reward_router::process_initialize_ncn_reward_router,
+    initialize_operator_record::process_initialize_operator_record,
     initialize_operator_snapshot::process_initialize_operator_snapshot,
     initialize_vault_registry::process_initialize_vault_registry,
     initialize_weight_table::process_initialize_weight_table,
-    realloc_ballot_box::process_realloc_ballot_box,
+    median_set_weight::process_median_set_weight, realloc_ballot_box::process_realloc_ballot_box,
+    realloc_ballot_box_page::process_realloc_ballot_box_page,
     realloc_base_reward_router::process_realloc_base_reward_router,
+    realloc_base_reward_router_page::process_realloc_base_reward_router_page,
     realloc_operator_snapshot::process_realloc_operator_snapshot,
     realloc_vault_registry::process_realloc_vault_registry,
     realloc_weight_table::process_realloc_weight_table, register_vault::process_register_vault,
-    route_base_rewards::process_route_base_rewards, route_ncn_rewards::process_route_ncn_rewards,
-    set_merkle_root::process_set_merkle_root,
+    reveal_vote::process_reveal_vote, route_base_rewards::process_route_base_rewards,
+    route_ncn_rewards::process_route_ncn_rewards, set_merkle_root::process_set_merkle_root,
+    set_merkle_root_batch::process_set_merkle_root_batch,
     snapshot_vault_operator_delegation::process_snapshot_vault_operator_delegation,
-    switchboard_set_weight::process_switchboard_set_weight,
+    sweep_dust::process_sweep_dust, switchboard_set_weight::process_switchboard_set_weight,
+    tie_breaker_approve::process_tie_breaker_approve,
 };
 
 declare_id!(env!("TIP_ROUTER_PROGRAM_ID"));
@@ -165,6 +205,10 @@ pub fn process_instruction(
             msg!("Instruction: SwitchboardSetWeight");
             process_switchboard_set_weight(program_id, accounts, &st_mint, epoch)
         }
+        TipRouterInstruction::MedianSetWeight { epoch, st_mint } => {
+            msg!("Instruction: MedianSetWeight");
+            process_median_set_weight(program_id, accounts, &st_mint, epoch)
+        }
         TipRouterInstruction::InitializeEpochSnapshot { epoch } => {
             msg!("Instruction: InitializeEpochSnapshot");
             process_initialize_epoch_snapshot(program_id, accounts, epoch)
@@ -196,9 +240,46 @@ pub fn process_instruction(
         TipRouterInstruction::CastVote {
             meta_merkle_root,
             epoch,
+            abstain,
+            snapshot_slot,
+            bank_hash,
+            tip_distribution_account_count,
         } => {
             msg!("Instruction: CastVote");
-            process_cast_vote(program_id, accounts, &meta_merkle_root, epoch)
+            process_cast_vote(
+                program_id,
+                accounts,
+                &meta_merkle_root,
+                epoch,
+                abstain,
+                snapshot_slot,
+                &bank_hash,
+                tip_distribution_account_count,
+            )
+        }
+        TipRouterInstruction::CommitVote { commitment, epoch } => {
+            msg!("Instruction: CommitVote");
+            process_commit_vote(program_id, accounts, &commitment, epoch)
+        }
+        TipRouterInstruction::RevealVote {
+            meta_merkle_root,
+            salt,
+            epoch,
+            snapshot_slot,
+            bank_hash,
+            tip_distribution_account_count,
+        } => {
+            msg!("Instruction: RevealVote");
+            process_reveal_vote(
+                program_id,
+                accounts,
+                &meta_merkle_root,
+                &salt,
+                epoch,
+                snapshot_slot,
+                &bank_hash,
+                tip_distribution_account_count,
+            )
         }
         TipRouterInstruction::SetMerkleRoot {
             proof,
@@ -301,6 +382,17 @@ pub fn process_instruction(
             epochs_before_stall,
             epochs_after_consensus_before_close,
             valid_slots_after_consensus,
+            consensus_threshold_bps,
+            commit_window_slots,
+            late_vote_grace_slots,
+            late_vote_max_decay_bps,
+            plurality_fallback_epochs,
+            plurality_min_stake_weight_bps,
+            crank_bounty_lamports,
+            max_crank_bounties_per_epoch,
+            account_payer_target_balance,
+            min_operator_stake_weight,
+            max_operator_fee_bps,
         } => {
             msg!("Instruction: AdminSetParameters");
             process_admin_set_parameters(
@@ -310,6 +402,17 @@ pub fn process_instruction(
                 epochs_before_stall,
                 epochs_after_consensus_before_close,
                 valid_slots_after_consensus,
+                consensus_threshold_bps,
+                commit_window_slots,
+                late_vote_grace_slots,
+                late_vote_max_decay_bps,
+                plurality_fallback_epochs,
+                plurality_min_stake_weight_bps,
+                crank_bounty_lamports,
+                max_crank_bounties_per_epoch,
+                account_payer_target_balance,
+                min_operator_stake_weight,
+                max_operator_fee_bps,
             )
         }
         TipRouterInstruction::AdminSetConfigFees {
@@ -319,6 +422,8 @@ pub fn process_instruction(
             new_base_fee_bps,
             ncn_fee_group,
             new_ncn_fee_bps,
+            fee_schedule_action,
+            activation_epoch,
         } => {
             msg!("Instruction: AdminSetConfigFees");
             process_admin_set_config_fees(
@@ -330,11 +435,25 @@ pub fn process_instruction(
                 new_base_fee_bps,
                 ncn_fee_group,
                 new_ncn_fee_bps,
+                fee_schedule_action,
+                activation_epoch,
             )
         }
-        TipRouterInstruction::AdminSetNewAdmin { role } => {
-            msg!("Instruction: AdminSetNewAdmin");
-            process_admin_set_new_admin(program_id, accounts, role)
+        TipRouterInstruction::AdminProposeNewAdmin { role } => {
+            msg!("Instruction: AdminProposeNewAdmin");
+            process_admin_propose_new_admin(program_id, accounts, role)
+        }
+        TipRouterInstruction::AdminCancelNewAdmin => {
+            msg!("Instruction: AdminCancelNewAdmin");
+            process_admin_cancel_new_admin(program_id, accounts)
+        }
+        TipRouterInstruction::AdminAcceptNewAdmin { role } => {
+            msg!("Instruction: AdminAcceptNewAdmin");
+            process_admin_accept_new_admin(program_id, accounts, role)
+        }
+        TipRouterInstruction::AdminSetPause { paused } => {
+            msg!("Instruction: AdminSetPause");
+            process_admin_set_pause(program_id, accounts, paused)
         }
         TipRouterInstruction::AdminSetTieBreaker {
             meta_merkle_root,
@@ -356,6 +475,8 @@ pub fn process_instruction(
             reward_multiplier_bps,
             switchboard_feed,
             no_feed_weight,
+            max_weight_change_bps,
+            max_stale_slots,
         } => {
             msg!("Instruction: AdminRegisterStMint");
             process_admin_register_st_mint(
@@ -365,6 +486,8 @@ pub fn process_instruction(
                 reward_multiplier_bps,
                 switchboard_feed,
                 no_feed_weight,
+                max_weight_change_bps,
+                max_stale_slots,
             )
         }
         TipRouterInstruction::AdminSetStMint {
@@ -373,6 +496,8 @@ pub fn process_instruction(
             reward_multiplier_bps,
             switchboard_feed,
             no_feed_weight,
+            max_weight_change_bps,
+            max_stale_slots,
         } => {
             msg!("Instruction: AdminSetStMint");
             process_admin_set_st_mint(
@@ -383,7 +508,100 @@ pub fn process_instruction(
                 reward_multiplier_bps,
                 switchboard_feed,
                 no_feed_weight,
+                max_weight_change_bps,
+                max_stale_slots,
+            )
+        }
+        TipRouterInstruction::AdminSetStMintOracleFeeds {
+            st_mint,
+            oracle_feeds,
+            oracle_feed_types,
+            max_feed_divergence_bps,
+        } => {
+            msg!("Instruction: AdminSetStMintOracleFeeds");
+            process_admin_set_st_mint_oracle_feeds(
+                program_id,
+                accounts,
+                &st_mint,
+                oracle_feeds,
+                oracle_feed_types,
+                max_feed_divergence_bps,
             )
         }
+        TipRouterInstruction::AdminRetireVault { retired_epoch } => {
+            msg!("Instruction: AdminRetireVault");
+            process_admin_retire_vault(program_id, accounts, retired_epoch)
+        }
+        TipRouterInstruction::AdminRetireStMint { retired_epoch } => {
+            msg!("Instruction: AdminRetireStMint");
+            process_admin_retire_st_mint(program_id, accounts, retired_epoch)
+        }
+        TipRouterInstruction::ReallocBallotBoxPage { epoch, page } => {
+            msg!("Instruction: ReallocBallotBoxPage");
+            process_realloc_ballot_box_page(program_id, accounts, epoch, page)
+        }
+        TipRouterInstruction::ReallocBaseRewardRouterPage { epoch, page } => {
+            msg!("Instruction: ReallocBaseRewardRouterPage");
+            process_realloc_base_reward_router_page(program_id, accounts, epoch, page)
+        }
+        TipRouterInstruction::AdminInitializeTieBreakerCommittee { members, threshold } => {
+            msg!("Instruction: AdminInitializeTieBreakerCommittee");
+            process_admin_initialize_tie_breaker_committee(
+                program_id, accounts, &members, threshold,
+            )
+        }
+        TipRouterInstruction::AdminSetTieBreakerCommittee { members, threshold } => {
+            msg!("Instruction: AdminSetTieBreakerCommittee");
+            process_admin_set_tie_breaker_committee(program_id, accounts, &members, threshold)
+        }
+        TipRouterInstruction::TieBreakerApprove {
+            meta_merkle_root,
+            epoch,
+        } => {
+            msg!("Instruction: TieBreakerApprove");
+            process_tie_breaker_approve(program_id, accounts, &meta_merkle_root, epoch)
+        }
+        TipRouterInstruction::InitializeOperatorRecord => {
+            msg!("Instruction: InitializeOperatorRecord");
+            process_initialize_operator_record(program_id, accounts)
+        }
+        TipRouterInstruction::FinalizeStalledBallot { epoch } => {
+            msg!("Instruction: FinalizeStalledBallot");
+            process_finalize_stalled_ballot(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::SetMerkleRootBatch { uploads, epoch } => {
+            msg!("Instruction: SetMerkleRootBatch");
+            process_set_merkle_root_batch(program_id, accounts, uploads, epoch)
+        }
+        TipRouterInstruction::AdminSetVaultRewardAsset {
+            reward_asset_type,
+            stake_pool,
+        } => {
+            msg!("Instruction: AdminSetVaultRewardAsset");
+            process_admin_set_vault_reward_asset(
+                program_id,
+                accounts,
+                reward_asset_type,
+                &stake_pool,
+            )
+        }
+        TipRouterInstruction::AdminSetVaultRewardMultiplier {
+            reward_multiplier_bps,
+        } => {
+            msg!("Instruction: AdminSetVaultRewardMultiplier");
+            process_admin_set_vault_reward_multiplier(program_id, accounts, reward_multiplier_bps)
+        }
+        TipRouterInstruction::SweepDust { epoch } => {
+            msg!("Instruction: SweepDust");
+            process_sweep_dust(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::AdminInitializeFeeSchedule => {
+            msg!("Instruction: AdminInitializeFeeSchedule");
+            process_admin_initialize_fee_schedule(program_id, accounts)
+        }
+        TipRouterInstruction::AdminInitializeConfigExtension => {
+            msg!("Instruction: AdminInitializeConfigExtension");
+            process_admin_initialize_config_extension(program_id, accounts)
+        }
     }
 }
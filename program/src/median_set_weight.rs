@@ -0,0 +1,128 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    constants::PYTH_MAX_STALE_SLOTS,
+    epoch_state::EpochState,
+    error::TipRouterError,
+    oracle_feed::{median_weight, OracleFeedType, PythPrice},
+    weight_table::WeightTable,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::switchboard_set_weight::get_switchboard_weight;
+
+/// Reads the pyth-style price account, scaled by `WEIGHT_PRECISION`
+pub fn get_pyth_weight(pyth_feed: &AccountInfo, clock: &Clock) -> Result<u128, ProgramError> {
+    let price = PythPrice::parse(&pyth_feed.data.borrow())?;
+
+    let stale_slot = price
+        .publish_slot
+        .checked_add(PYTH_MAX_STALE_SLOTS)
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+    if clock.slot > stale_slot {
+        msg!("Stale feed");
+        return Err(TipRouterError::StalePythFeed.into());
+    }
+
+    let weight = price.weight()?;
+
+    msg!("Oracle Weight: {}", weight);
+    Ok(weight)
+}
+
+/// Updates weight table with the median of the registered oracle feeds
+pub fn process_median_set_weight(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    st_mint: &Pubkey,
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn, weight_table, previous_weight_table, oracle_feeds @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    WeightTable::load(program_id, weight_table, ncn.key, epoch, true)?;
+
+    let (registered_oracle_feeds, max_feed_divergence_bps, max_stale_slots) = {
+        let weight_table_data = weight_table.data.borrow();
+        let weight_table_account = WeightTable::try_from_slice_unchecked(&weight_table_data)?;
+
+        let weight_entry = weight_table_account.get_weight_entry(st_mint)?;
+
+        (
+            weight_entry.st_mint_entry().registered_oracle_feeds(),
+            weight_entry.st_mint_entry().max_feed_divergence_bps(),
+            weight_entry.st_mint_entry().max_stale_slots(),
+        )
+    };
+
+    if registered_oracle_feeds.is_empty() {
+        msg!("No oracle feeds registered");
+        return Err(TipRouterError::NoOracleFeeds.into());
+    }
+
+    if oracle_feeds.len() < registered_oracle_feeds.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let clock = Clock::get()?;
+    let mut weights = Vec::with_capacity(registered_oracle_feeds.len());
+    for (registered_oracle_feed, oracle_feed) in
+        registered_oracle_feeds.iter().zip(oracle_feeds.iter())
+    {
+        if registered_oracle_feed.feed().ne(oracle_feed.key) {
+            msg!("Oracle feed {} is not registered", oracle_feed.key);
+            return Err(TipRouterError::OracleFeedNotRegistered.into());
+        }
+
+        let weight = match registered_oracle_feed.feed_type()? {
+            OracleFeedType::Switchboard => {
+                get_switchboard_weight(oracle_feed, &clock, max_stale_slots)?
+            }
+            OracleFeedType::Pyth => get_pyth_weight(oracle_feed, &clock)?,
+        };
+
+        weights.push(weight);
+    }
+
+    let weight = median_weight(&mut weights, max_feed_divergence_bps)?;
+    msg!("Median Weight: {}", weight);
+
+    let mut weight_table_data = weight_table.try_borrow_mut_data()?;
+    let weight_table_account = WeightTable::try_from_slice_unchecked_mut(&mut weight_table_data)?;
+
+    weight_table_account.check_table_initialized()?;
+
+    if weight_table_account.finalized() {
+        msg!("Weight table is finalized");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    weight_table_account.check_weight_change_from_previous_epoch(
+        program_id,
+        previous_weight_table,
+        st_mint,
+        weight,
+    )?;
+
+    weight_table_account.set_weight(st_mint, weight, clock.slot)?;
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_set_weight(
+            weight_table_account.weight_count() as u64,
+            weight_table_account.st_mint_count() as u64,
+        );
+    }
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     account_payer::AccountPayer, ballot_box::BallotBox, config::Config as NcnConfig,
-    epoch_state::EpochState, utils::get_new_size,
+    crank_bounty::CrankBounty, epoch_state::EpochState, utils::get_new_size,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -17,6 +17,7 @@ pub fn process_realloc_ballot_box(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 6)?;
     let [epoch_state, ncn_config, ballot_box, ncn, account_payer, system_program] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -35,6 +36,9 @@ pub fn process_realloc_ballot_box(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // Calls on a fully sized account do no work and earn no crank bounty
+    let is_useful = ballot_box.data_len() < BallotBox::SIZE;
+
     if ballot_box.data_len() < BallotBox::SIZE {
         let new_size = get_new_size(ballot_box.data_len(), BallotBox::SIZE)?;
         msg!(
@@ -60,9 +64,20 @@ pub fn process_realloc_ballot_box(
             let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
             let epoch_state_account =
                 EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
-            epoch_state_account.update_realloc_ballot_box();
+            let pages_required = BallotBox::pages_required(epoch_state_account.operator_count())?;
+            epoch_state_account.update_realloc_ballot_box(pages_required);
         }
     }
 
+    if is_useful {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,153 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer,
+    ballot_box::{BallotBox, BallotBoxPage},
+    config::Config as NcnConfig,
+    constants::MAX_REALLOC_BYTES,
+    crank_bounty::CrankBounty,
+    epoch_state::EpochState,
+    error::TipRouterError,
+    utils::get_new_size,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Creates the next page chained to the ballot box and reallocates it to its full size.
+/// Pages are only created in order, and only when the operator count requires them.
+pub fn process_realloc_ballot_box_page(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    page: u8,
+) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 7)?;
+    let [epoch_state, ncn_config, ballot_box, ballot_box_page, ncn, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_program(system_program)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+    let (ballot_box_page_pda, ballot_box_page_bump, mut ballot_box_page_seeds) =
+        BallotBoxPage::find_program_address(program_id, ballot_box.key, page);
+    ballot_box_page_seeds.push(vec![ballot_box_page_bump]);
+
+    if ballot_box_page_pda != *ballot_box_page.key {
+        msg!("Ballot box page account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (pages_required, page_count) = {
+        let epoch_state_data = epoch_state.try_borrow_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked(&epoch_state_data)?;
+        let ballot_box_data = ballot_box.try_borrow_data()?;
+        let ballot_box_account = BallotBox::try_from_slice_unchecked(&ballot_box_data)?;
+
+        (
+            BallotBox::pages_required(epoch_state_account.operator_count())?,
+            ballot_box_account.page_count(),
+        )
+    };
+
+    if page == 0 || page > pages_required || page > page_count.saturating_add(1) {
+        msg!(
+            "Page {} is not the next required page ( {}/{} )",
+            page,
+            page_count,
+            pages_required
+        );
+        return Err(TipRouterError::InvalidPage.into());
+    }
+
+    // Already chained to the ballot box
+    if page <= page_count {
+        return Ok(());
+    }
+
+    if ballot_box_page.data_is_empty() {
+        load_system_account(ballot_box_page, true)?;
+
+        msg!("Creating ballot box page {}", page);
+        AccountPayer::pay_and_create_account(
+            program_id,
+            ncn.key,
+            account_payer,
+            ballot_box_page,
+            system_program,
+            program_id,
+            MAX_REALLOC_BYTES as usize,
+            &ballot_box_page_seeds,
+        )?;
+
+        return CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        );
+    }
+
+    if ballot_box_page.data_len() < BallotBoxPage::SIZE {
+        let new_size = get_new_size(ballot_box_page.data_len(), BallotBoxPage::SIZE)?;
+        msg!(
+            "Reallocating ballot box page from {} bytes to {} bytes",
+            ballot_box_page.data_len(),
+            new_size
+        );
+
+        AccountPayer::pay_and_realloc(
+            program_id,
+            ncn.key,
+            account_payer,
+            ballot_box_page,
+            new_size,
+        )?;
+    }
+
+    let should_initialize = ballot_box_page.data_len() >= BallotBoxPage::SIZE
+        && ballot_box_page.try_borrow_data()?[0] != BallotBoxPage::DISCRIMINATOR;
+
+    if should_initialize {
+        {
+            let mut ballot_box_page_data = ballot_box_page.try_borrow_mut_data()?;
+            ballot_box_page_data[0] = BallotBoxPage::DISCRIMINATOR;
+            let ballot_box_page_account =
+                BallotBoxPage::try_from_slice_unchecked_mut(&mut ballot_box_page_data)?;
+            ballot_box_page_account.initialize(ncn.key, epoch, page, ballot_box_page_bump);
+        }
+
+        // Chain the page to the ballot box
+        {
+            let mut ballot_box_data = ballot_box.try_borrow_mut_data()?;
+            let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
+            ballot_box_account.increment_page_count()?;
+        }
+
+        // Update Epoch State
+        {
+            let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+            let epoch_state_account =
+                EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+            epoch_state_account.update_realloc_ballot_box_page()?;
+        }
+    }
+
+    CrankBounty::pay(
+        program_id,
+        ncn.key,
+        epoch,
+        epoch_state,
+        crank_bounty_accounts,
+    )
+}
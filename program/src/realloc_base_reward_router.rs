@@ -3,7 +3,7 @@ use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     account_payer::AccountPayer, base_reward_router::BaseRewardRouter, config::Config as NcnConfig,
-    epoch_state::EpochState, utils::get_new_size,
+    crank_bounty::CrankBounty, epoch_state::EpochState, utils::get_new_size,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -15,6 +15,7 @@ pub fn process_realloc_base_reward_router(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 6)?;
     let [epoch_state, ncn_config, base_reward_router, ncn, account_payer, system_program] =
         accounts
     else {
@@ -35,6 +36,9 @@ pub fn process_realloc_base_reward_router(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // Calls on a fully sized account do no work and earn no crank bounty
+    let is_useful = base_reward_router.data_len() < BaseRewardRouter::SIZE;
+
     if base_reward_router.data_len() < BaseRewardRouter::SIZE {
         let new_size = get_new_size(base_reward_router.data_len(), BaseRewardRouter::SIZE)?;
         msg!(
@@ -72,9 +76,21 @@ pub fn process_realloc_base_reward_router(
             let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
             let epoch_state_account =
                 EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
-            epoch_state_account.update_realloc_base_reward_router();
+            let pages_required =
+                BaseRewardRouter::pages_required(epoch_state_account.operator_count())?;
+            epoch_state_account.update_realloc_base_reward_router(pages_required);
         }
     }
 
+    if is_useful {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
+    }
+
     Ok(())
 }
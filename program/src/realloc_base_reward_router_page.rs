@@ -0,0 +1,168 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer,
+    base_reward_router::{BaseRewardRouter, BaseRewardRouterPage},
+    config::Config as NcnConfig,
+    constants::MAX_REALLOC_BYTES,
+    crank_bounty::CrankBounty,
+    epoch_state::EpochState,
+    error::TipRouterError,
+    utils::get_new_size,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Creates the next page chained to the base reward router and reallocates it to its full size.
+/// Pages are only created in order, and only when the operator count requires them.
+pub fn process_realloc_base_reward_router_page(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    page: u8,
+) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 7)?;
+    let [epoch_state, ncn_config, base_reward_router, base_reward_router_page, ncn, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_program(system_program)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    BaseRewardRouter::load(program_id, base_reward_router, ncn.key, epoch, true)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+    let (
+        base_reward_router_page_pda,
+        base_reward_router_page_bump,
+        mut base_reward_router_page_seeds,
+    ) = BaseRewardRouterPage::find_program_address(program_id, base_reward_router.key, page);
+    base_reward_router_page_seeds.push(vec![base_reward_router_page_bump]);
+
+    if base_reward_router_page_pda != *base_reward_router_page.key {
+        msg!("Base reward router page account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (pages_required, page_count) = {
+        let epoch_state_data = epoch_state.try_borrow_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked(&epoch_state_data)?;
+        let base_reward_router_data = base_reward_router.try_borrow_data()?;
+        let base_reward_router_account =
+            BaseRewardRouter::try_from_slice_unchecked(&base_reward_router_data)?;
+
+        (
+            BaseRewardRouter::pages_required(epoch_state_account.operator_count())?,
+            base_reward_router_account.page_count(),
+        )
+    };
+
+    if page == 0 || page > pages_required || page > page_count.saturating_add(1) {
+        msg!(
+            "Page {} is not the next required page ( {}/{} )",
+            page,
+            page_count,
+            pages_required
+        );
+        return Err(TipRouterError::InvalidPage.into());
+    }
+
+    // Already chained to the base reward router
+    if page <= page_count {
+        return Ok(());
+    }
+
+    if base_reward_router_page.data_is_empty() {
+        load_system_account(base_reward_router_page, true)?;
+
+        msg!("Creating base reward router page {}", page);
+        AccountPayer::pay_and_create_account(
+            program_id,
+            ncn.key,
+            account_payer,
+            base_reward_router_page,
+            system_program,
+            program_id,
+            MAX_REALLOC_BYTES as usize,
+            &base_reward_router_page_seeds,
+        )?;
+
+        return CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        );
+    }
+
+    if base_reward_router_page.data_len() < BaseRewardRouterPage::SIZE {
+        let new_size = get_new_size(
+            base_reward_router_page.data_len(),
+            BaseRewardRouterPage::SIZE,
+        )?;
+        msg!(
+            "Reallocating base reward router page from {} bytes to {} bytes",
+            base_reward_router_page.data_len(),
+            new_size
+        );
+
+        AccountPayer::pay_and_realloc(
+            program_id,
+            ncn.key,
+            account_payer,
+            base_reward_router_page,
+            new_size,
+        )?;
+    }
+
+    let should_initialize = base_reward_router_page.data_len() >= BaseRewardRouterPage::SIZE
+        && base_reward_router_page.try_borrow_data()?[0] != BaseRewardRouterPage::DISCRIMINATOR;
+
+    if should_initialize {
+        {
+            let mut base_reward_router_page_data = base_reward_router_page.try_borrow_mut_data()?;
+            base_reward_router_page_data[0] = BaseRewardRouterPage::DISCRIMINATOR;
+            let base_reward_router_page_account =
+                BaseRewardRouterPage::try_from_slice_unchecked_mut(
+                    &mut base_reward_router_page_data,
+                )?;
+            base_reward_router_page_account.initialize(
+                ncn.key,
+                epoch,
+                page,
+                base_reward_router_page_bump,
+            );
+        }
+
+        // Chain the page to the base reward router
+        {
+            let mut base_reward_router_data = base_reward_router.try_borrow_mut_data()?;
+            let base_reward_router_account =
+                BaseRewardRouter::try_from_slice_unchecked_mut(&mut base_reward_router_data)?;
+            base_reward_router_account.increment_page_count()?;
+        }
+
+        // Update Epoch State
+        {
+            let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+            let epoch_state_account =
+                EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+            epoch_state_account.update_realloc_base_reward_router_page()?;
+        }
+    }
+
+    CrankBounty::pay(
+        program_id,
+        ncn.key,
+        epoch,
+        epoch_state,
+        crank_bounty_accounts,
+    )
+}
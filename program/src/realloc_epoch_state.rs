@@ -2,7 +2,8 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, config::Config, epoch_state::EpochState, utils::get_new_size,
+    account_payer::AccountPayer, config::Config, config_extension::ConfigExtension,
+    crank_bounty::CrankBounty, epoch_state::EpochState, utils::get_new_size,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -16,7 +17,9 @@ pub fn process_realloc_epoch_state(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, config, ncn, account_payer, system_program] = accounts else {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 6)?;
+    let [epoch_state, config, ncn, account_payer, system_program, config_extension] = accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -24,6 +27,8 @@ pub fn process_realloc_epoch_state(
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     Config::load(program_id, config, ncn.key, false)?;
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    let config_extension_initialized =
+        ConfigExtension::load_if_initialized(program_id, config_extension, ncn.key, false)?;
 
     let (epoch_state_pda, epoch_state_bump, _) =
         EpochState::find_program_address(program_id, ncn.key, epoch);
@@ -33,6 +38,9 @@ pub fn process_realloc_epoch_state(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // Calls on a fully sized account do no work and earn no crank bounty
+    let is_useful = epoch_state.data_len() < EpochState::SIZE;
+
     if epoch_state.data_len() < EpochState::SIZE {
         let new_size = get_new_size(epoch_state.data_len(), EpochState::SIZE)?;
         msg!(
@@ -52,8 +60,31 @@ pub fn process_realloc_epoch_state(
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
         epoch_state_account.initialize(ncn.key, epoch, epoch_state_bump, Clock::get()?.slot);
 
+        // Bounties are fixed for the epoch once it starts
+        if config_extension_initialized {
+            let config_extension_data = config_extension.try_borrow_data()?;
+            let config_extension_account =
+                ConfigExtension::try_from_slice_unchecked(&config_extension_data)?;
+            if config_extension_account.is_crank_bounty_enabled() {
+                epoch_state_account.set_crank_bounty(
+                    config_extension_account.crank_bounty_lamports(),
+                    config_extension_account.max_crank_bounties_per_epoch(),
+                );
+            }
+        }
+
         epoch_state_account.update_realloc_epoch_state();
     }
 
+    if is_useful {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
+    }
+
     Ok(())
 }
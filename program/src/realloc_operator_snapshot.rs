@@ -6,7 +6,9 @@ use jito_restaking_core::{
 use jito_tip_router_core::{
     account_payer::AccountPayer,
     config::Config as NcnConfig,
-    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    config_extension::ConfigExtension,
+    crank_bounty::CrankBounty,
+    epoch_snapshot::{EpochSnapshot, OperatorInactiveReason, OperatorSnapshot},
     epoch_state::EpochState,
     loaders::load_ncn_epoch,
     stake_weight::StakeWeights,
@@ -22,7 +24,8 @@ pub fn process_realloc_operator_snapshot(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, restaking_config, ncn, operator, ncn_operator_state, epoch_snapshot, operator_snapshot, account_payer, system_program] =
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 11)?;
+    let [epoch_state, ncn_config, restaking_config, ncn, operator, ncn_operator_state, epoch_snapshot, operator_snapshot, account_payer, system_program, config_extension] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -44,6 +47,8 @@ pub fn process_realloc_operator_snapshot(
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
 
     load_system_program(system_program)?;
+    let config_extension_initialized =
+        ConfigExtension::load_if_initialized(program_id, config_extension, ncn.key, false)?;
 
     let (operator_snapshot_pda, operator_snapshot_bump, _) =
         OperatorSnapshot::find_program_address(program_id, operator.key, ncn.key, epoch);
@@ -53,6 +58,9 @@ pub fn process_realloc_operator_snapshot(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // Calls on a fully sized account do no work and earn no crank bounty
+    let is_useful = operator_snapshot.data_len() < OperatorSnapshot::SIZE;
+
     if operator_snapshot.data_len() < OperatorSnapshot::SIZE {
         let new_size = get_new_size(operator_snapshot.data_len(), OperatorSnapshot::SIZE)?;
         msg!(
@@ -76,7 +84,7 @@ pub fn process_realloc_operator_snapshot(
         let current_slot = Clock::get()?.slot;
         let (_, ncn_epoch_length) = load_ncn_epoch(restaking_config, current_slot, None)?;
 
-        let (is_active, ncn_operator_index): (bool, u64) = {
+        let (is_opted_in, ncn_operator_index): (bool, u64) = {
             let ncn_operator_state_data = ncn_operator_state.data.borrow();
             let ncn_operator_state_account =
                 NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
@@ -113,6 +121,16 @@ pub fn process_realloc_operator_snapshot(
             )
         };
 
+        // An operator charging more than the config allows is left out of the snapshot
+        let fee_above_maximum = config_extension_initialized && {
+            let config_extension_data = config_extension.data.borrow();
+            let config_extension_account =
+                ConfigExtension::try_from_slice_unchecked(&config_extension_data)?;
+            config_extension_account.operator_fee_above_maximum(operator_fee_bps)
+        };
+
+        let is_active = is_opted_in && !fee_above_maximum;
+
         let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
         operator_snapshot_data[0] = OperatorSnapshot::DISCRIMINATOR;
         let operator_snapshot_account =
@@ -131,6 +149,14 @@ pub fn process_realloc_operator_snapshot(
             vault_count,
         )?;
 
+        if is_opted_in && fee_above_maximum {
+            msg!(
+                "Operator fee of {} bps is above the maximum, operator is inactive",
+                operator_fee_bps
+            );
+            operator_snapshot_account.deactivate(OperatorInactiveReason::FeeAboveMaximum);
+        }
+
         // Increment operator registration for an inactive operator
         if !is_active {
             let mut epoch_snapshot_data = epoch_snapshot.try_borrow_mut_data()?;
@@ -154,5 +180,15 @@ pub fn process_realloc_operator_snapshot(
         }
     }
 
+    if is_useful {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
+    }
+
     Ok(())
 }
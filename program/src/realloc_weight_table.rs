@@ -2,8 +2,9 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, config::Config as NcnConfig, epoch_state::EpochState,
-    utils::get_new_size, vault_registry::VaultRegistry, weight_table::WeightTable,
+    account_payer::AccountPayer, config::Config as NcnConfig, crank_bounty::CrankBounty,
+    epoch_state::EpochState, utils::get_new_size, vault_registry::VaultRegistry,
+    weight_table::WeightTable,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -15,6 +16,7 @@ pub fn process_realloc_weight_table(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 7)?;
     let [epoch_state, ncn_config, weight_table, ncn, vault_registry, account_payer, system_program] =
         accounts
     else {
@@ -36,6 +38,9 @@ pub fn process_realloc_weight_table(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // Calls on a fully sized account do no work and earn no crank bounty
+    let is_useful = weight_table.data_len() < WeightTable::SIZE;
+
     if weight_table.data_len() < WeightTable::SIZE {
         let new_size = get_new_size(weight_table.data_len(), WeightTable::SIZE)?;
         msg!(
@@ -53,8 +58,8 @@ pub fn process_realloc_weight_table(
         let vault_registry_data = vault_registry.data.borrow();
         let vault_registry = VaultRegistry::try_from_slice_unchecked(&vault_registry_data)?;
 
-        let vault_count = vault_registry.vault_count();
-        let st_mint_count = vault_registry.st_mint_count();
+        let vault_count = vault_registry.active_vault_count(epoch);
+        let st_mint_count = vault_registry.active_st_mint_count(epoch);
         let vault_entries = vault_registry.get_vault_entries();
         let mint_entries = vault_registry.get_mint_entries();
 
@@ -82,5 +87,15 @@ pub fn process_realloc_weight_table(
         }
     }
 
+    if is_useful {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
+    }
+
     Ok(())
 }
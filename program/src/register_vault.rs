@@ -28,8 +28,15 @@ pub fn process_register_vault(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         false,
     )?;
 
+    let epochs_after_consensus_before_close = {
+        let config_data = config.data.borrow();
+        let config = Config::try_from_slice_unchecked(&config_data)?;
+        config.epochs_after_consensus_before_close()
+    };
+
     let clock = Clock::get()?;
     let slot = clock.slot;
+    let epoch = clock.epoch;
 
     let mut vault_registry_data = vault_registry.try_borrow_mut_data()?;
     let vault_registry = VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
@@ -47,6 +54,8 @@ pub fn process_register_vault(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         &vault_account.supported_mint,
         vault_account.vault_index(),
         slot,
+        epoch,
+        epochs_after_consensus_before_close,
     )?;
 
     Ok(())
@@ -0,0 +1,21 @@
+use jito_tip_router_core::ballot_box::Ballot;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::cast_vote::process_operator_vote;
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_reveal_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    meta_merkle_root: &[u8; 32],
+    salt: &[u8; 32],
+    epoch: u64,
+    snapshot_slot: u64,
+    bank_hash: &[u8; 32],
+    tip_distribution_account_count: u64,
+) -> ProgramResult {
+    let ballot = Ballot::new_with_snapshot(meta_merkle_root, snapshot_slot, bank_hash)
+        .with_tip_distribution_account_count(tip_distribution_account_count);
+
+    process_operator_vote(program_id, accounts, &ballot, Some(salt), epoch)
+}
@@ -1,12 +1,18 @@
 use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    ballot_box::BallotBox,
-    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    account_payer::AccountPayer,
+    ballot_box::{BallotBox, BallotBoxPage},
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter, BaseRewardRouterPage},
     config::Config as NcnConfig,
+    config_extension::ConfigExtension,
+    constants::PAUSE_ROUTING,
+    crank_bounty::CrankBounty,
     epoch_snapshot::EpochSnapshot,
     epoch_state::EpochState,
     error::TipRouterError,
+    event::{RewardsDistributed, RewardsRouted, TipRouterEvent},
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -20,7 +26,7 @@ pub fn process_route_base_rewards(
     max_iterations: u16,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, config, ncn, epoch_snapshot, ballot_box, base_reward_router, base_reward_receiver] =
+    let [epoch_state, config, ncn, epoch_snapshot, ballot_box, base_reward_router, base_reward_receiver, account_payer, system_program, config_extension, pages @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -34,6 +40,61 @@ pub fn process_route_base_rewards(
     BaseRewardRouter::load(program_id, base_reward_router, ncn.key, epoch, true)?;
     BallotBox::load(program_id, ballot_box, ncn.key, epoch, false)?;
     BaseRewardReceiver::load(program_id, base_reward_receiver, ncn.key, epoch, true)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    load_system_program(system_program)?;
+    let config_extension_initialized =
+        ConfigExtension::load_if_initialized(program_id, config_extension, ncn.key, false)?;
+
+    // Ballot box pages come first, followed by the base reward router pages
+    let ballot_box_page_count = {
+        let ballot_box_data = ballot_box.try_borrow_data()?;
+        BallotBox::try_from_slice_unchecked(&ballot_box_data)?.page_count()
+    };
+    let base_reward_router_page_count = {
+        let base_reward_router_data = base_reward_router.try_borrow_data()?;
+        BaseRewardRouter::try_from_slice_unchecked(&base_reward_router_data)?.page_count()
+    };
+
+    let (pages, crank_bounty_accounts) = CrankBounty::split_accounts(
+        pages,
+        ballot_box_page_count as usize + base_reward_router_page_count as usize,
+    )?;
+
+    BallotBoxPage::load_pages(
+        program_id,
+        pages,
+        ballot_box.key,
+        ballot_box_page_count,
+        false,
+    )?;
+    let (ballot_box_pages, base_reward_router_pages) =
+        pages.split_at(ballot_box_page_count as usize);
+    BaseRewardRouterPage::load_pages(
+        program_id,
+        base_reward_router_pages,
+        base_reward_router.key,
+        base_reward_router_page_count,
+        true,
+    )?;
+
+    let ballot_box_pages_data = ballot_box_pages
+        .iter()
+        .map(|page| page.try_borrow_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let ballot_box_pages = ballot_box_pages_data
+        .iter()
+        .map(|page_data| BallotBoxPage::try_from_slice_unchecked(page_data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut base_reward_router_pages_data = base_reward_router_pages
+        [..base_reward_router_page_count as usize]
+        .iter()
+        .map(|page| page.try_borrow_mut_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut base_reward_router_pages = base_reward_router_pages_data
+        .iter_mut()
+        .map(|page_data| BaseRewardRouterPage::try_from_slice_unchecked_mut(page_data))
+        .collect::<Result<Vec<_>, _>>()?;
 
     let epoch_snapshot_data = epoch_snapshot.try_borrow_data()?;
     let epoch_snapshot_account = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
@@ -42,10 +103,23 @@ pub fn process_route_base_rewards(
     let ballot_box_account = BallotBox::try_from_slice_unchecked(&ballot_box_data)?;
 
     let current_slot = Clock::get()?.slot;
-    let valid_slots_after_consensus = {
+    let (valid_slots_after_consensus, late_vote_decay) = {
         let ncn_config_data = config.data.borrow();
         let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
-        ncn_config.valid_slots_after_consensus()
+        ncn_config.check_not_paused(PAUSE_ROUTING)?;
+        (
+            ncn_config.valid_slots_after_consensus(),
+            ncn_config.late_vote_decay(),
+        )
+    };
+
+    // Top-ups stay disabled until the NCN creates its config extension
+    let account_payer_target_balance = if config_extension_initialized {
+        let config_extension_data = config_extension.try_borrow_data()?;
+        ConfigExtension::try_from_slice_unchecked(&config_extension_data)?
+            .account_payer_target_balance()
+    } else {
+        0
     };
 
     // Do not route if voting is still ongoing
@@ -62,19 +136,84 @@ pub fn process_route_base_rewards(
 
     let rent_cost = Rent::get()?.minimum_balance(0);
 
+    // Routing earns the crank bounty for the epoch's first route or for continuing an unfinished
+    // route, so new lamports sent to the receiver can't claim it again
+    let was_still_routing = base_reward_router_account.still_routing();
+    let total_rewards_before = base_reward_router_account.total_rewards();
+
+    let mut account_payer_top_up = 0;
     if !base_reward_router_account.still_routing() {
         base_reward_router_account
             .route_incoming_rewards(rent_cost, base_reward_receiver_balance)?;
 
         base_reward_router_account.route_reward_pool(epoch_snapshot_account.fees())?;
+
+        // Refill the account payer from the DAO's share before it can be distributed
+        let account_payer_shortfall =
+            account_payer_target_balance.saturating_sub(account_payer.lamports());
+        account_payer_top_up =
+            base_reward_router_account.route_to_account_payer_top_up(account_payer_shortfall)?;
     }
 
-    base_reward_router_account.route_ncn_fee_group_rewards(ballot_box_account, max_iterations)?;
+    if account_payer_top_up > 0 {
+        BaseRewardReceiver::transfer(
+            program_id,
+            ncn.key,
+            epoch,
+            base_reward_receiver,
+            account_payer,
+            account_payer_top_up,
+        )?;
+
+        msg!(
+            "Topped up account payer with {} lamports",
+            account_payer_top_up
+        );
+
+        TipRouterEvent::RewardsDistributed(RewardsDistributed {
+            ncn: *ncn.key,
+            epoch,
+            router: *base_reward_router.key,
+            destination: *account_payer.key,
+            rewards: account_payer_top_up,
+        })
+        .emit();
+    }
+
+    base_reward_router_account.route_ncn_fee_group_rewards(
+        ballot_box_account,
+        &ballot_box_pages,
+        &late_vote_decay,
+        &mut base_reward_router_pages,
+        max_iterations,
+    )?;
+
+    TipRouterEvent::RewardsRouted(RewardsRouted {
+        ncn: *ncn.key,
+        epoch,
+        router: *base_reward_router.key,
+        total_rewards: base_reward_router_account.total_rewards(),
+        still_routing: base_reward_router_account.still_routing(),
+    })
+    .emit();
 
     {
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
-        epoch_state_account.update_route_base_rewards(base_reward_router_account.total_rewards());
+        epoch_state_account
+            .update_route_base_rewards(base_reward_router_account.total_rewards())?;
+        epoch_state_account.update_distribute_base_rewards(account_payer_top_up)?;
+    }
+
+    let first_route = total_rewards_before == 0 && base_reward_router_account.total_rewards() > 0;
+    if was_still_routing || first_route {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
     }
 
     Ok(())
@@ -1,8 +1,12 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
+    config::Config as NcnConfig,
+    constants::PAUSE_ROUTING,
+    crank_bounty::CrankBounty,
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
+    event::{RewardsRouted, TipRouterEvent},
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
 };
@@ -19,13 +23,15 @@ pub fn process_route_ncn_rewards(
     max_iterations: u16,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn, operator, operator_snapshot, ncn_reward_router, ncn_reward_receiver] =
+    let (accounts, crank_bounty_accounts) = CrankBounty::split_accounts(accounts, 7)?;
+    let [epoch_state, ncn, operator, operator_snapshot, ncn_reward_router, ncn_reward_receiver, config] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load(program_id, config, ncn.key, false)?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     Operator::load(&jito_restaking_program::id(), operator, false)?;
     NcnRewardReceiver::load(
@@ -58,6 +64,12 @@ pub fn process_route_ncn_rewards(
         true,
     )?;
 
+    {
+        let ncn_config_data = config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.check_not_paused(PAUSE_ROUTING)?;
+    }
+
     let operator_snapshot_data = operator_snapshot.try_borrow_data()?;
     let operator_snapshot_account =
         OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?;
@@ -70,6 +82,11 @@ pub fn process_route_ncn_rewards(
 
     let rent_cost = Rent::get()?.minimum_balance(0);
 
+    // Routing earns the crank bounty for the epoch's first route or for continuing an unfinished
+    // route, so new lamports sent to the receiver can't claim it again
+    let was_still_routing = ncn_reward_router_account.still_routing();
+    let total_rewards_before = ncn_reward_router_account.total_rewards();
+
     if !ncn_reward_router_account.still_routing() {
         ncn_reward_router_account.route_incoming_rewards(rent_cost, account_balance)?;
         ncn_reward_router_account.route_operator_rewards(operator_snapshot_account)?;
@@ -77,6 +94,15 @@ pub fn process_route_ncn_rewards(
 
     ncn_reward_router_account.route_reward_pool(operator_snapshot_account, max_iterations)?;
 
+    TipRouterEvent::RewardsRouted(RewardsRouted {
+        ncn: *ncn.key,
+        epoch,
+        router: *ncn_reward_router.key,
+        total_rewards: ncn_reward_router_account.total_rewards(),
+        still_routing: ncn_reward_router_account.still_routing(),
+    })
+    .emit();
+
     {
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
@@ -87,5 +113,16 @@ pub fn process_route_ncn_rewards(
         )?;
     }
 
+    let first_route = total_rewards_before == 0 && ncn_reward_router_account.total_rewards() > 0;
+    if was_still_routing || first_route {
+        CrankBounty::pay(
+            program_id,
+            ncn.key,
+            epoch,
+            epoch_state,
+            crank_bounty_accounts,
+        )?;
+    }
+
     Ok(())
 }
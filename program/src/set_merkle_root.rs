@@ -1,12 +1,16 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_distribution_sdk::{
-    derive_tip_distribution_account_address, instruction::upload_merkle_root_ix,
+    derive_tip_distribution_account_address, has_merkle_root, instruction::upload_merkle_root_ix,
     jito_tip_distribution,
 };
 use jito_tip_router_core::{
-    ballot_box::BallotBox, config::Config as NcnConfig, epoch_state::EpochState,
+    ballot_box::BallotBox,
+    config::Config as NcnConfig,
+    constants::PAUSE_SET_MERKLE_ROOT,
+    epoch_state::EpochState,
     error::TipRouterError,
+    event::{MerkleRootSet, TipRouterEvent},
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
@@ -28,16 +32,100 @@ pub fn process_set_merkle_root(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    load_set_merkle_root_accounts(
+        program_id,
+        epoch_state,
+        ncn_config,
+        ncn,
+        ballot_box,
+        tip_distribution_program,
+        epoch,
+    )?;
+
+    let ballot_box_data = ballot_box.data.borrow();
+    let ballot_box = BallotBox::try_from_slice_unchecked(&ballot_box_data)?;
+
+    if !ballot_box.is_consensus_reached() {
+        msg!("Ballot box not finalized");
+        return Err(TipRouterError::ConsensusNotReached.into());
+    }
+
+    let first_upload = upload_merkle_root(
+        program_id,
+        ncn,
+        ncn_config,
+        ballot_box,
+        vote_account,
+        tip_distribution_account,
+        tip_distribution_config,
+        tip_distribution_program,
+        proof,
+        merkle_root,
+        max_total_claim,
+        max_num_nodes,
+        epoch,
+    )?;
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_set_merkle_root(
+            u64::from(first_upload),
+            ballot_box.winning_tip_distribution_account_count(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Loads the accounts shared by `SetMerkleRoot` and `SetMerkleRootBatch`
+pub fn load_set_merkle_root_accounts(
+    program_id: &Pubkey,
+    epoch_state: &AccountInfo,
+    ncn_config: &AccountInfo,
+    ncn: &AccountInfo,
+    ballot_box: &AccountInfo,
+    tip_distribution_program: &AccountInfo,
+    epoch: u64,
+) -> ProgramResult {
     EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
     NcnConfig::load(program_id, ncn_config, ncn.key, true)?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     BallotBox::load(program_id, ballot_box, ncn.key, epoch, false)?;
 
+    {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.check_not_paused(PAUSE_SET_MERKLE_ROOT)?;
+    }
+
     if tip_distribution_program.key.ne(&jito_tip_distribution::ID) {
         msg!("Incorrect tip distribution program");
         return Err(ProgramError::InvalidAccountData);
     }
 
+    Ok(())
+}
+
+/// Verifies a tip distribution account's merkle root against the winning ballot and uploads it,
+/// returning whether the account had no merkle root before
+#[allow(clippy::too_many_arguments)]
+pub fn upload_merkle_root<'a, 'info>(
+    program_id: &Pubkey,
+    ncn: &'a AccountInfo<'info>,
+    ncn_config: &'a AccountInfo<'info>,
+    ballot_box: &BallotBox,
+    vote_account: &'a AccountInfo<'info>,
+    tip_distribution_account: &'a AccountInfo<'info>,
+    tip_distribution_config: &'a AccountInfo<'info>,
+    tip_distribution_program: &'a AccountInfo<'info>,
+    proof: Vec<[u8; 32]>,
+    merkle_root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+    epoch: u64,
+) -> Result<bool, ProgramError> {
     let tip_distribution_epoch = epoch
         .checked_sub(1)
         .ok_or(TipRouterError::ArithmeticUnderflowError)?;
@@ -52,14 +140,6 @@ pub fn process_set_merkle_root(
         return Err(ProgramError::InvalidAccountData);
     }
 
-    let ballot_box_data = ballot_box.data.borrow();
-    let ballot_box = BallotBox::try_from_slice_unchecked(&ballot_box_data)?;
-
-    if !ballot_box.is_consensus_reached() {
-        msg!("Ballot box not finalized");
-        return Err(TipRouterError::ConsensusNotReached.into());
-    }
-
     ballot_box.verify_merkle_root(
         &tip_distribution_address,
         proof,
@@ -68,6 +148,8 @@ pub fn process_set_merkle_root(
         max_num_nodes,
     )?;
 
+    let first_upload = !has_merkle_root(&tip_distribution_account.try_borrow_data()?);
+
     let (_, bump, mut ncn_config_seeds) = NcnConfig::find_program_address(program_id, ncn.key);
     ncn_config_seeds.push(vec![bump]);
 
@@ -92,12 +174,16 @@ pub fn process_set_merkle_root(
             .as_slice()],
     )?;
 
-    // Update Epoch State
-    {
-        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
-        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
-        epoch_state_account.update_set_merkle_root()?;
-    }
+    TipRouterEvent::MerkleRootSet(MerkleRootSet {
+        ncn: *ncn.key,
+        epoch,
+        vote_account: *vote_account.key,
+        tip_distribution_account: *tip_distribution_account.key,
+        merkle_root,
+        max_total_claim,
+        max_num_nodes,
+    })
+    .emit();
 
-    Ok(())
+    Ok(first_upload)
 }
@@ -0,0 +1,108 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_tip_router_core::{
+    ballot_box::BallotBox, epoch_state::EpochState, error::TipRouterError,
+    instruction::MerkleRootUpload,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::set_merkle_root::{load_set_merkle_root_accounts, upload_merkle_root};
+
+pub fn process_set_merkle_root_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    uploads: Vec<MerkleRootUpload>,
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn_config, ncn, ballot_box, tip_distribution_config, tip_distribution_program, upload_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_set_merkle_root_accounts(
+        program_id,
+        epoch_state,
+        ncn_config,
+        ncn,
+        ballot_box,
+        tip_distribution_program,
+        epoch,
+    )?;
+
+    // Each upload has a vote account and tip distribution account pair
+    if uploads.is_empty() || upload_accounts.len() != uploads.len() * 2 {
+        msg!(
+            "Expected {} upload accounts, got {}",
+            uploads.len() * 2,
+            upload_accounts.len()
+        );
+        return Err(TipRouterError::InvalidMerkleRootBatch.into());
+    }
+
+    // Each tip distribution account is uploaded to once per batch
+    for (index, upload_account_pair) in upload_accounts.chunks_exact(2).enumerate() {
+        let tip_distribution_account = upload_account_pair[1].key;
+        if upload_accounts
+            .chunks_exact(2)
+            .skip(index + 1)
+            .any(|other_pair| other_pair[1].key.eq(tip_distribution_account))
+        {
+            msg!(
+                "Duplicate tip distribution account {} in batch",
+                tip_distribution_account
+            );
+            return Err(TipRouterError::InvalidMerkleRootBatch.into());
+        }
+    }
+
+    let ballot_box_data = ballot_box.data.borrow();
+    let ballot_box = BallotBox::try_from_slice_unchecked(&ballot_box_data)?;
+
+    if !ballot_box.is_consensus_reached() {
+        msg!("Ballot box not finalized");
+        return Err(TipRouterError::ConsensusNotReached.into());
+    }
+
+    let mut merkle_roots_set: u64 = 0;
+    for (upload, upload_account_pair) in uploads.into_iter().zip(upload_accounts.chunks_exact(2)) {
+        let [vote_account, tip_distribution_account] = upload_account_pair else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let first_upload = upload_merkle_root(
+            program_id,
+            ncn,
+            ncn_config,
+            ballot_box,
+            vote_account,
+            tip_distribution_account,
+            tip_distribution_config,
+            tip_distribution_program,
+            upload.proof,
+            upload.merkle_root,
+            upload.max_total_claim,
+            upload.max_num_nodes,
+            epoch,
+        )?;
+        if first_upload {
+            merkle_roots_set = merkle_roots_set
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?;
+        }
+    }
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_set_merkle_root(
+            merkle_roots_set,
+            ballot_box.winning_tip_distribution_account_count(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -4,7 +4,8 @@ use jito_restaking_core::{
 };
 use jito_tip_router_core::{
     config::Config as NcnConfig,
-    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    config_extension::ConfigExtension,
+    epoch_snapshot::{EpochSnapshot, OperatorInactiveReason, OperatorSnapshot},
     epoch_state::EpochState,
     error::TipRouterError,
     loaders::load_ncn_epoch,
@@ -25,7 +26,7 @@ pub fn process_snapshot_vault_operator_delegation(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, restaking_config, ncn, operator, vault, vault_ncn_ticket, ncn_vault_ticket, vault_operator_delegation, weight_table, epoch_snapshot, operator_snapshot] =
+    let [epoch_state, ncn_config, restaking_config, ncn, operator, vault, vault_ncn_ticket, ncn_vault_ticket, vault_operator_delegation, weight_table, epoch_snapshot, operator_snapshot, config_extension] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -79,6 +80,8 @@ pub fn process_snapshot_vault_operator_delegation(
         epoch,
         true,
     )?;
+    let config_extension_initialized =
+        ConfigExtension::load_if_initialized(program_id, config_extension, ncn.key, false)?;
 
     // check vault is up to date
     let vault_needs_update = {
@@ -137,7 +140,7 @@ pub fn process_snapshot_vault_operator_delegation(
         let weight_table_account = WeightTable::try_from_slice_unchecked(&weight_table_data)?;
         let weight_entry = weight_table_account.get_weight_entry(&st_mint)?;
 
-        weight_table_account.check_registry_for_vault(vault_index)?;
+        let vault_entry = weight_table_account.get_vault_entry(vault_index)?;
 
         let total_stake_weight: u128 = if is_active {
             let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
@@ -155,7 +158,7 @@ pub fn process_snapshot_vault_operator_delegation(
 
         (
             weight_entry.st_mint_entry().ncn_fee_group(),
-            weight_entry.st_mint_entry().reward_multiplier_bps(),
+            vault_entry.reward_multiplier_bps(weight_entry.st_mint_entry().reward_multiplier_bps()),
             total_stake_weight,
         )
     };
@@ -178,6 +181,24 @@ pub fn process_snapshot_vault_operator_delegation(
 
     // If operator is finalized, increment operator registration
     if operator_snapshot_account.finalized() {
+        // An operator without enough stake is left out of the snapshot
+        let stake_below_minimum = config_extension_initialized && {
+            let config_extension_data = config_extension.data.borrow();
+            let config_extension_account =
+                ConfigExtension::try_from_slice_unchecked(&config_extension_data)?;
+            config_extension_account.operator_stake_below_minimum(
+                operator_snapshot_account.stake_weights().stake_weight(),
+            )
+        };
+
+        if operator_snapshot_account.is_active() && stake_below_minimum {
+            msg!(
+                "Operator stake weight of {} is below the minimum, operator is inactive",
+                operator_snapshot_account.stake_weights().stake_weight()
+            );
+            operator_snapshot_account.deactivate(OperatorInactiveReason::StakeBelowMinimum);
+        }
+
         let mut epoch_snapshot_data = epoch_snapshot.try_borrow_mut_data()?;
         let epoch_snapshot_account =
             EpochSnapshot::try_from_slice_unchecked_mut(&mut epoch_snapshot_data)?;
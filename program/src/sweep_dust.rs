@@ -0,0 +1,193 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::load_system_program;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    base_fee_group::BaseFeeGroup,
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    config::Config as NcnConfig,
+    constants::{MAX_DUST_LAMPORTS, PAUSE_DISTRIBUTION},
+    epoch_state::EpochState,
+    error::TipRouterError,
+    event::{RewardsDistributed, TipRouterEvent},
+    ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Sweeps the balance of a base or NCN reward receiver that its router does not track, left over
+/// from rounding, to the DAO wallet. Anything untracked before the router has routed and
+/// distributed its rewards may be new rewards, so the sweep waits for that.
+pub fn process_sweep_dust(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, config, ncn, reward_router, reward_receiver, dao_wallet, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_program(system_program)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load(program_id, config, ncn.key, false)?;
+
+    {
+        let config_data = config.try_borrow_data()?;
+        let config_account = NcnConfig::try_from_slice_unchecked(&config_data)?;
+        config_account.check_not_paused(PAUSE_DISTRIBUTION)?;
+
+        if config_account
+            .fee_config
+            .base_fee_wallet(BaseFeeGroup::dao())?
+            .ne(dao_wallet.key)
+        {
+            return Err(TipRouterError::InvalidDaoWallet.into());
+        }
+    }
+
+    if reward_router.data_is_empty() {
+        msg!("Reward router does not exist");
+        return Err(TipRouterError::InvalidRewardRouter.into());
+    }
+
+    let rent_cost = Rent::get()?.minimum_balance(0);
+    let account_balance = reward_receiver.lamports();
+
+    let discriminator = reward_router.try_borrow_data()?[0];
+    let dust = match discriminator {
+        BaseRewardRouter::DISCRIMINATOR => {
+            BaseRewardRouter::load(program_id, reward_router, ncn.key, epoch, false)?;
+            BaseRewardReceiver::load(program_id, reward_receiver, ncn.key, epoch, true)?;
+
+            let reward_router_data = reward_router.try_borrow_data()?;
+            let reward_router_account =
+                BaseRewardRouter::try_from_slice_unchecked(&reward_router_data)?;
+
+            // Only what is left once the epoch's rewards are routed and distributed is dust
+            if !reward_router_account.distribution_complete() {
+                msg!("Rewards are not fully routed and distributed");
+                return Err(TipRouterError::RewardsNotDistributed.into());
+            }
+
+            let dust = reward_router_account.untracked_rewards(rent_cost, account_balance)?;
+            check_dust(dust)?;
+
+            if dust > 0 {
+                BaseRewardReceiver::transfer(
+                    program_id,
+                    ncn.key,
+                    epoch,
+                    reward_receiver,
+                    dao_wallet,
+                    dust,
+                )?;
+            }
+
+            dust
+        }
+        NcnRewardRouter::DISCRIMINATOR => {
+            let (ncn_fee_group, operator) = {
+                let reward_router_data = reward_router.try_borrow_data()?;
+                let reward_router_account =
+                    NcnRewardRouter::try_from_slice_unchecked(&reward_router_data)?;
+                (
+                    reward_router_account.ncn_fee_group(),
+                    *reward_router_account.operator(),
+                )
+            };
+
+            NcnRewardRouter::load(
+                program_id,
+                reward_router,
+                ncn_fee_group,
+                &operator,
+                ncn.key,
+                epoch,
+                false,
+            )?;
+            NcnRewardReceiver::load(
+                program_id,
+                reward_receiver,
+                ncn_fee_group,
+                &operator,
+                ncn.key,
+                epoch,
+                true,
+            )?;
+
+            let reward_router_data = reward_router.try_borrow_data()?;
+            let reward_router_account =
+                NcnRewardRouter::try_from_slice_unchecked(&reward_router_data)?;
+
+            // Only what is left once the epoch's rewards are routed and distributed is dust
+            if !reward_router_account.distribution_complete() {
+                msg!("Rewards are not fully routed and distributed");
+                return Err(TipRouterError::RewardsNotDistributed.into());
+            }
+
+            let dust = reward_router_account.untracked_rewards(rent_cost, account_balance)?;
+            check_dust(dust)?;
+
+            if dust > 0 {
+                NcnRewardReceiver::transfer(
+                    program_id,
+                    ncn_fee_group,
+                    &operator,
+                    ncn.key,
+                    epoch,
+                    reward_receiver,
+                    dao_wallet,
+                    dust,
+                )?;
+            }
+
+            dust
+        }
+        _ => {
+            msg!("Account is not a reward router");
+            return Err(TipRouterError::InvalidRewardRouter.into());
+        }
+    };
+
+    if dust == 0 {
+        msg!("No dust to sweep");
+        return Ok(());
+    }
+
+    msg!("Swept {} lamports of dust to the DAO wallet", dust);
+
+    TipRouterEvent::RewardsDistributed(RewardsDistributed {
+        ncn: *ncn.key,
+        epoch,
+        router: *reward_router.key,
+        destination: *dao_wallet.key,
+        rewards: dust,
+    })
+    .emit();
+
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_sweep_dust(dust)?;
+    }
+
+    Ok(())
+}
+
+/// Larger untracked balances are new rewards, which are routed rather than swept
+fn check_dust(dust: u64) -> Result<(), TipRouterError> {
+    if dust > MAX_DUST_LAMPORTS {
+        msg!(
+            "Untracked rewards of {} lamports are above the dust threshold of {}",
+            dust,
+            MAX_DUST_LAMPORTS
+        );
+        return Err(TipRouterError::UntrackedRewardsAboveDust);
+    }
+
+    Ok(())
+}
@@ -1,9 +1,7 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    constants::{SWITCHBOARD_MAX_STALE_SLOTS, WEIGHT_PRECISION},
-    epoch_state::EpochState,
-    error::TipRouterError,
+    constants::WEIGHT_PRECISION, epoch_state::EpochState, error::TipRouterError,
     weight_table::WeightTable,
 };
 use solana_program::{
@@ -15,6 +13,42 @@ use switchboard_on_demand::{
     PullFeedAccountData,
 };
 
+/// Reads the switchboard feed price, scaled by `WEIGHT_PRECISION`
+pub fn get_switchboard_weight(
+    switchboard_feed: &AccountInfo,
+    clock: &Clock,
+    max_stale_slots: u64,
+) -> Result<u128, ProgramError> {
+    let feed = PullFeedAccountData::parse(switchboard_feed.data.borrow())
+        .map_err(|_| TipRouterError::BadSwitchboardFeed)?;
+
+    let price: Decimal = feed
+        .value(clock)
+        .map_err(|_| TipRouterError::BadSwitchboardValue)?;
+
+    let current_slot = clock.slot;
+    let stale_slot = {
+        feed.result
+            .slot
+            .checked_add(max_stale_slots)
+            .ok_or(TipRouterError::ArithmeticOverflow)?
+    };
+
+    if current_slot > stale_slot {
+        msg!("Stale feed");
+        return Err(TipRouterError::StaleSwitchboardFeed.into());
+    }
+
+    msg!("Oracle Price: {}", price);
+    let weight = price
+        .checked_mul(WEIGHT_PRECISION.into())
+        .ok_or(TipRouterError::ArithmeticOverflow)?
+        .round();
+
+    msg!("Oracle Weight: {}", weight);
+    Ok(weight.to_u128().ok_or(TipRouterError::CastToU128Error)?)
+}
+
 /// Updates weight table
 pub fn process_switchboard_set_weight(
     program_id: &Pubkey,
@@ -22,7 +56,7 @@ pub fn process_switchboard_set_weight(
     st_mint: &Pubkey,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn, weight_table, switchboard_feed] = accounts else {
+    let [epoch_state, ncn, weight_table, switchboard_feed, previous_weight_table] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -30,7 +64,7 @@ pub fn process_switchboard_set_weight(
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     WeightTable::load(program_id, weight_table, ncn.key, epoch, true)?;
 
-    let (registered_switchboard_feed, no_feed_weight) = {
+    let (registered_switchboard_feed, no_feed_weight, max_stale_slots) = {
         let weight_table_data = weight_table.data.borrow();
         let weight_table_account = WeightTable::try_from_slice_unchecked(&weight_table_data)?;
 
@@ -39,6 +73,7 @@ pub fn process_switchboard_set_weight(
         (
             *weight_entry.st_mint_entry().switchboard_feed(),
             weight_entry.st_mint_entry().no_feed_weight(),
+            weight_entry.st_mint_entry().max_stale_slots(),
         )
     };
 
@@ -56,35 +91,7 @@ pub fn process_switchboard_set_weight(
             return Err(TipRouterError::SwitchboardNotRegistered.into());
         }
 
-        let feed = PullFeedAccountData::parse(switchboard_feed.data.borrow())
-            .map_err(|_| TipRouterError::BadSwitchboardFeed)?;
-
-        let clock = Clock::get()?;
-        let price: Decimal = feed
-            .value(&clock)
-            .map_err(|_| TipRouterError::BadSwitchboardValue)?;
-
-        let current_slot = clock.slot;
-        let stale_slot = {
-            feed.result
-                .slot
-                .checked_add(SWITCHBOARD_MAX_STALE_SLOTS)
-                .ok_or(TipRouterError::ArithmeticOverflow)?
-        };
-
-        if current_slot > stale_slot {
-            msg!("Stale feed");
-            return Err(TipRouterError::StaleSwitchboardFeed.into());
-        }
-
-        msg!("Oracle Price: {}", price);
-        let weight = price
-            .checked_mul(WEIGHT_PRECISION.into())
-            .ok_or(TipRouterError::ArithmeticOverflow)?
-            .round();
-
-        msg!("Oracle Weight: {}", weight);
-        weight.to_u128().ok_or(TipRouterError::CastToU128Error)?
+        get_switchboard_weight(switchboard_feed, &Clock::get()?, max_stale_slots)?
     };
 
     let mut weight_table_data = weight_table.try_borrow_mut_data()?;
@@ -97,6 +104,13 @@ pub fn process_switchboard_set_weight(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    weight_table_account.check_weight_change_from_previous_epoch(
+        program_id,
+        previous_weight_table,
+        st_mint,
+        weight,
+    )?;
+
     weight_table_account.set_weight(st_mint, weight, Clock::get()?.slot)?;
 
     // Update Epoch State
@@ -0,0 +1,125 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    ballot_box::{BallotBox, BallotBoxPage},
+    config::Config as NcnConfig,
+    epoch_state::EpochState,
+    event::{TieBreakerSet, TipRouterEvent},
+    tie_breaker_committee::TieBreakerCommittee,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Records a committee member's approval of a tie breaker ballot. The ballot is set once
+/// the committee threshold approves the same meta merkle root.
+pub fn process_tie_breaker_approve(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    meta_merkle_root: &[u8; 32],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn_config, tie_breaker_committee, ballot_box, ncn, member, ballot_box_pages @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    TieBreakerCommittee::load(program_id, tie_breaker_committee, ncn.key, true)?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    load_signer(member, false)?;
+
+    let epochs_before_stall = {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.epochs_before_stall()
+    };
+
+    let page_count = {
+        let ballot_box_data = ballot_box.data.borrow();
+        BallotBox::try_from_slice_unchecked(&ballot_box_data)?.page_count()
+    };
+
+    BallotBoxPage::load_pages(
+        program_id,
+        ballot_box_pages,
+        ballot_box.key,
+        page_count,
+        false,
+    )?;
+    let ballot_box_pages_data = ballot_box_pages[..page_count as usize]
+        .iter()
+        .map(|page| page.try_borrow_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let ballot_box_pages = ballot_box_pages_data
+        .iter()
+        .map(|page_data| BallotBoxPage::try_from_slice_unchecked(page_data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut ballot_box_data = ballot_box.data.borrow_mut();
+    let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
+
+    let clock = Clock::get()?;
+
+    // Approvals are only recorded for ballots the tie breaker could set
+    ballot_box_account.check_tie_breaker_ballot(
+        &ballot_box_pages,
+        meta_merkle_root,
+        clock.epoch,
+        epochs_before_stall,
+    )?;
+
+    let threshold_met = {
+        let mut tie_breaker_committee_data = tie_breaker_committee.try_borrow_mut_data()?;
+        let tie_breaker_committee_account =
+            TieBreakerCommittee::try_from_slice_unchecked_mut(&mut tie_breaker_committee_data)?;
+
+        let approvals =
+            tie_breaker_committee_account.approve(member.key, epoch, meta_merkle_root)?;
+        msg!(
+            "{} of {} approvals for the tie breaker ballot",
+            approvals,
+            tie_breaker_committee_account.threshold()
+        );
+
+        tie_breaker_committee_account.is_threshold_met(epoch, meta_merkle_root)
+    };
+
+    if !threshold_met {
+        return Ok(());
+    }
+
+    ballot_box_account.set_tie_breaker_ballot(
+        &ballot_box_pages,
+        meta_merkle_root,
+        clock.epoch,
+        epochs_before_stall,
+    )?;
+
+    TipRouterEvent::TieBreakerSet(TieBreakerSet {
+        ncn: *ncn.key,
+        epoch,
+        meta_merkle_root: *meta_merkle_root,
+        tie_breaker_admin: *tie_breaker_committee.key,
+        slot: clock.slot,
+    })
+    .emit();
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_set_tie_breaker(
+            ballot_box_account.is_consensus_reached(),
+            ballot_box_account.winning_tip_distribution_account_count(),
+            clock.slot,
+        )?;
+    }
+
+    Ok(())
+}
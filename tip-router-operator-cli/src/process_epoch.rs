@@ -13,7 +13,10 @@ use solana_sdk::pubkey::Pubkey;
 use tokio::time;
 
 use crate::{
-    backup_snapshots::SnapshotInfo, get_meta_merkle_root, tip_router::get_ncn_config, Cli,
+    backup_snapshots::SnapshotInfo,
+    get_meta_merkle_root,
+    tip_router::{get_fee_schedule, get_ncn_config},
+    Cli,
 };
 
 const MAX_WAIT_FOR_INCREMENTAL_SNAPSHOT_TICKS: u64 = 1200; // Experimentally determined
@@ -109,14 +112,15 @@ pub async fn process_epoch(
     let operator_address = Pubkey::from_str(&cli_args.operator_address).unwrap();
     let meta_merkle_tree_dir = cli_args.meta_merkle_tree_dir.clone();
 
-    // Get the protocol fees
+    // Get the protocol fees, resolved through the fee schedule like the epoch snapshot's
     let ncn_config = get_ncn_config(client, tip_router_program_id, ncn_address).await?;
+    let fee_schedule = get_fee_schedule(client, tip_router_program_id, ncn_address).await?;
     let tip_router_target_epoch = target_epoch
         .checked_add(1)
         .ok_or_else(|| anyhow::anyhow!("tip_router_target_epoch overflow"))?;
     let adjusted_total_fees = ncn_config
         .fee_config
-        .adjusted_total_fees_bps(tip_router_target_epoch)?;
+        .adjusted_total_fees_bps(fee_schedule.as_ref(), tip_router_target_epoch)?;
 
     let account_paths = account_paths.map_or_else(|| vec![ledger_path.clone()], |paths| paths);
     let full_snapshots_path = full_snapshots_path.map_or(ledger_path, |path| path);
@@ -1,14 +1,22 @@
 use std::sync::Arc;
 use std::time::Duration;
-use std::{path::PathBuf, str::FromStr};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use anchor_lang::AccountDeserialize;
 use ellipsis_client::EllipsisClient;
 use jito_bytemuck::AccountDeserialize as JitoAccountDeserialize;
 use jito_tip_distribution_sdk::TipDistributionAccount;
-use jito_tip_router_core::{ballot_box::BallotBox, config::Config};
+use jito_tip_router_core::{
+    ballot_box::{Ballot, BallotBox, BallotBoxPage},
+    config::Config,
+};
 use log::{debug, error, info};
-use meta_merkle_tree::meta_merkle_tree::MetaMerkleTree;
+use meta_merkle_tree::{
+    generated_merkle_tree::GeneratedMerkleTreeCollection, meta_merkle_tree::MetaMerkleTree,
+};
 use solana_account_decoder::UiAccountEncoding;
 use solana_client::nonblocking::rpc_client::RpcClient as AsyncRpcClient;
 use solana_client::{
@@ -16,10 +24,13 @@ use solana_client::{
     rpc_filter::{Memcmp, RpcFilterType},
 };
 use solana_metrics::{datapoint_error, datapoint_info};
-use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Keypair};
 
 use crate::{
-    tip_router::{cast_vote, get_ncn_config, set_merkle_roots_batched},
+    tip_router::{
+        cast_vote, commit_vote, get_ballot_box_pages, get_epoch_snapshot, get_epoch_state,
+        get_ncn_config, reveal_vote, set_merkle_roots_batched, vote_salt,
+    },
     Cli,
 };
 
@@ -69,6 +80,39 @@ pub async fn submit_recent_epochs_to_ncn(
     Ok(())
 }
 
+/// The snapshot slot and bank hash the meta merkle tree was generated from, read from the
+/// generated merkle tree collection next to it. Reported as zero when the collection is missing
+fn get_snapshot_metadata(meta_merkle_tree_path: &Path, merkle_root_epoch: u64) -> (u64, [u8; 32]) {
+    let merkle_tree_coll_path = meta_merkle_tree_path
+        .with_file_name(format!("generated_merkle_tree_{}.json", merkle_root_epoch));
+
+    let merkle_tree_coll =
+        match GeneratedMerkleTreeCollection::new_from_file(&merkle_tree_coll_path) {
+            Ok(merkle_tree_coll) => merkle_tree_coll,
+            Err(e) => {
+                info!(
+                    "No snapshot metadata for epoch {} at {}: {:?}",
+                    merkle_root_epoch,
+                    merkle_tree_coll_path.display(),
+                    e
+                );
+                return (0, [0; 32]);
+            }
+        };
+
+    let bank_hash = Hash::from_str(&merkle_tree_coll.bank_hash)
+        .map(|hash| hash.to_bytes())
+        .unwrap_or_else(|e| {
+            error!(
+                "Invalid bank hash {} for epoch {}: {:?}",
+                merkle_tree_coll.bank_hash, merkle_root_epoch, e
+            );
+            [0; 32]
+        });
+
+    (merkle_tree_coll.slot, bank_hash)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn submit_to_ncn(
     client: &EllipsisClient,
@@ -84,6 +128,8 @@ pub async fn submit_to_ncn(
 ) -> Result<(), anyhow::Error> {
     let epoch_info = client.get_epoch_info().await?;
     let meta_merkle_tree = MetaMerkleTree::new_from_file(meta_merkle_tree_path)?;
+    let (snapshot_slot, bank_hash) =
+        get_snapshot_metadata(meta_merkle_tree_path, merkle_root_epoch);
     let config_pda = Config::find_program_address(tip_router_program_id, ncn_address).0;
     let config = get_ncn_config(client, tip_router_program_id, ncn_address).await?;
 
@@ -111,45 +157,132 @@ pub async fn submit_to_ncn(
     };
 
     let ballot_box = BallotBox::try_from_slice_unchecked(&ballot_box_account.data)?;
+    let ballot_box_pages = get_ballot_box_pages(
+        client,
+        tip_router_program_id,
+        &ballot_box_address,
+        ballot_box.page_count(),
+    )
+    .await?;
+    let ballot_box_pages: Vec<&BallotBoxPage> = ballot_box_pages.iter().collect();
 
     let is_voting_valid = ballot_box.is_voting_valid(
         epoch_info.absolute_slot,
         config.valid_slots_after_consensus(),
     )?;
 
+    // The ballot carries the number of tip distribution accounts expecting a merkle root
+    let ballot = Ballot::new(&meta_merkle_tree.merkle_root)
+        .with_tip_distribution_account_count(meta_merkle_tree.num_nodes);
+
     // If exists, look for vote from current operator
     let vote = ballot_box
-        .operator_votes()
-        .iter()
+        .all_operator_votes(&ballot_box_pages)
         .find(|vote| vote.operator() == operator_address);
 
     let should_cast_vote = match vote {
+        // Vote has only been committed, it still needs to be revealed
+        Some(vote) if !vote.has_voted() => true,
+        // Operator abstained without data, vote now that the meta merkle root exists
+        Some(vote) if vote.is_abstain() => true,
         Some(vote) => {
             // If vote exists, cast_vote if different from current meta_merkle_root
             let tally = ballot_box
-                .ballot_tallies()
-                .get(vote.ballot_index() as usize)
+                .all_ballot_tallies(&ballot_box_pages)
+                .nth(vote.ballot_index() as usize)
                 .ok_or_else(|| anyhow::anyhow!("Ballot tally not found"))?;
 
-            tally.ballot().root() != meta_merkle_tree.merkle_root
+            *tally.ballot() != ballot
         }
         None => true,
     };
 
-    if should_cast_vote && is_voting_valid {
-        let res = cast_vote(
+    let res = if !should_cast_vote || !is_voting_valid {
+        None
+    } else if submit_as_memo || !config.is_commit_reveal_enabled() {
+        Some(
+            cast_vote(
+                client,
+                keypair,
+                tip_router_program_id,
+                ncn_address,
+                operator_address,
+                keypair,
+                meta_merkle_tree.merkle_root,
+                snapshot_slot,
+                bank_hash,
+                meta_merkle_tree.num_nodes,
+                tip_router_target_epoch,
+                submit_as_memo,
+            )
+            .await,
+        )
+    } else {
+        let salt = vote_salt(keypair, ncn_address, tip_router_target_epoch);
+        // The commitment covers the snapshot metadata sent with the reveal
+        let commitment =
+            Ballot::new_with_snapshot(&meta_merkle_tree.merkle_root, snapshot_slot, &bank_hash)
+                .with_tip_distribution_account_count(meta_merkle_tree.num_nodes)
+                .commitment(operator_address, tip_router_target_epoch, &salt);
+        let voting_opened_slot = get_epoch_snapshot(
             client,
-            keypair,
             tip_router_program_id,
             ncn_address,
-            operator_address,
-            keypair,
-            meta_merkle_tree.merkle_root,
             tip_router_target_epoch,
-            submit_as_memo,
         )
-        .await;
+        .await?
+        .slot_finalized();
 
+        if ballot_box.is_commit_window_open(
+            epoch_info.absolute_slot,
+            voting_opened_slot,
+            config.commit_window_slots(),
+        )? {
+            if vote.is_some_and(|vote| *vote.commitment() == commitment) {
+                None
+            } else {
+                Some(
+                    commit_vote(
+                        client,
+                        keypair,
+                        tip_router_program_id,
+                        ncn_address,
+                        operator_address,
+                        keypair,
+                        commitment,
+                        tip_router_target_epoch,
+                    )
+                    .await,
+                )
+            }
+        } else if vote.is_some_and(|vote| vote.has_commitment()) {
+            Some(
+                reveal_vote(
+                    client,
+                    keypair,
+                    tip_router_program_id,
+                    ncn_address,
+                    operator_address,
+                    keypair,
+                    meta_merkle_tree.merkle_root,
+                    snapshot_slot,
+                    bank_hash,
+                    meta_merkle_tree.num_nodes,
+                    salt,
+                    tip_router_target_epoch,
+                )
+                .await,
+            )
+        } else {
+            info!(
+                "Commit window closed for epoch {} without a commitment",
+                tip_router_target_epoch
+            );
+            None
+        }
+    };
+
+    if let Some(res) = res {
         match res {
             Ok(signature) => {
                 datapoint_info!(
@@ -219,8 +352,16 @@ pub async fn submit_to_ncn(
         .await
         {
             Ok(res) => {
-                let num_success = res.iter().filter(|r| r.is_ok()).count();
-                let num_failed = res.iter().filter(|r| r.is_err()).count();
+                let num_success: usize = res
+                    .iter()
+                    .filter(|(_, r)| r.is_ok())
+                    .map(|(merkle_roots_set, _)| merkle_roots_set)
+                    .sum();
+                let num_failed: usize = res
+                    .iter()
+                    .filter(|(_, r)| r.is_err())
+                    .map(|(merkle_roots_set, _)| merkle_roots_set)
+                    .sum();
 
                 datapoint_info!(
                     "tip_router_cli.set_merkle_root",
@@ -245,6 +386,34 @@ pub async fn submit_to_ncn(
                 error!("Failed to set merkle roots: {:?}", e);
             }
         }
+
+        let upload_progress = get_epoch_state(
+            client,
+            tip_router_program_id,
+            ncn_address,
+            tip_router_target_epoch,
+        )
+        .await?
+        .upload_progress();
+        let merkle_roots_missing = upload_progress
+            .total()
+            .saturating_sub(upload_progress.tally());
+
+        datapoint_info!(
+            "tip_router_cli.upload_progress",
+            ("operator_address", operator_address.to_string(), String),
+            ("epoch", tip_router_target_epoch, i64),
+            ("merkle_roots_set", upload_progress.tally(), i64),
+            ("merkle_roots_expected", upload_progress.total(), i64),
+            ("merkle_roots_missing", merkle_roots_missing, i64)
+        );
+        info!(
+            "{} of {} merkle roots set for epoch {}, {} missing",
+            upload_progress.tally(),
+            upload_progress.total(),
+            tip_router_target_epoch,
+            merkle_roots_missing
+        );
     }
 
     Ok(())
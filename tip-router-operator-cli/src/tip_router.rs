@@ -4,16 +4,27 @@ use jito_bytemuck::AccountDeserialize;
 use jito_tip_distribution_sdk::{
     derive_config_account_address, jito_tip_distribution::accounts::TipDistributionAccount,
 };
-use jito_tip_router_client::instructions::{CastVoteBuilder, SetMerkleRootBuilder};
+use jito_tip_router_client::{
+    instructions::{
+        CastVoteBuilder, CommitVoteBuilder, RevealVoteBuilder, SetMerkleRootBatchBuilder,
+    },
+    types::MerkleRootUpload,
+};
 use jito_tip_router_core::{
-    ballot_box::BallotBox,
+    ballot_box::{BallotBox, BallotBoxPage},
     config::Config,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    fee_schedule::FeeSchedule,
+    operator_record::OperatorRecord,
 };
 use log::{error, info};
 use meta_merkle_tree::meta_merkle_tree::MetaMerkleTree;
 use solana_sdk::{
+    hash::hashv,
+    instruction::AccountMeta,
+    message::Message,
+    packet::PACKET_DATA_SIZE,
     pubkey::Pubkey,
     signature::{Keypair, Signature},
     signer::Signer,
@@ -31,6 +42,94 @@ pub async fn get_ncn_config(
     Ok(*Config::try_from_slice_unchecked(config.data.as_slice()).unwrap())
 }
 
+/// Fetch and deserialize, `None` when the NCN has no fee schedule
+pub async fn get_fee_schedule(
+    client: &EllipsisClient,
+    tip_router_program_id: &Pubkey,
+    ncn_pubkey: &Pubkey,
+) -> Result<Option<FeeSchedule>> {
+    let fee_schedule_pda = FeeSchedule::find_program_address(tip_router_program_id, ncn_pubkey).0;
+    let fee_schedule = client
+        .get_account_with_commitment(&fee_schedule_pda, client.commitment())
+        .await?
+        .value;
+
+    let Some(fee_schedule) = fee_schedule else {
+        return Ok(None);
+    };
+
+    Ok(Some(*FeeSchedule::try_from_slice_unchecked(
+        fee_schedule.data.as_slice(),
+    )?))
+}
+
+/// Fetch and deserialize
+pub async fn get_epoch_state(
+    client: &EllipsisClient,
+    tip_router_program_id: &Pubkey,
+    ncn_pubkey: &Pubkey,
+    epoch: u64,
+) -> Result<EpochState> {
+    let epoch_state_pda =
+        EpochState::find_program_address(tip_router_program_id, ncn_pubkey, epoch).0;
+    let epoch_state = client.get_account(&epoch_state_pda).await?;
+    Ok(*EpochState::try_from_slice_unchecked(
+        epoch_state.data.as_slice(),
+    )?)
+}
+
+pub async fn get_epoch_snapshot(
+    client: &EllipsisClient,
+    tip_router_program_id: &Pubkey,
+    ncn_pubkey: &Pubkey,
+    epoch: u64,
+) -> Result<EpochSnapshot> {
+    let epoch_snapshot_pda =
+        EpochSnapshot::find_program_address(tip_router_program_id, ncn_pubkey, epoch).0;
+    let epoch_snapshot = client.get_account(&epoch_snapshot_pda).await?;
+    Ok(*EpochSnapshot::try_from_slice_unchecked(
+        epoch_snapshot.data.as_slice(),
+    )?)
+}
+
+/// Fetch and deserialize the pages chained to the ballot box, in page order
+pub async fn get_ballot_box_pages(
+    client: &EllipsisClient,
+    tip_router_program_id: &Pubkey,
+    ballot_box: &Pubkey,
+    page_count: u8,
+) -> Result<Vec<BallotBoxPage>> {
+    let mut pages = Vec::with_capacity(page_count as usize);
+    for page in 1..=page_count {
+        let page_pda =
+            BallotBoxPage::find_program_address(tip_router_program_id, ballot_box, page).0;
+        let page_account = client.get_account(&page_pda).await?;
+        pages.push(*BallotBoxPage::try_from_slice_unchecked(page_account.data.as_slice()).unwrap());
+    }
+    Ok(pages)
+}
+
+/// Ballot box pages are passed to the voting instructions as remaining accounts
+async fn get_ballot_box_page_metas(
+    client: &EllipsisClient,
+    tip_router_program_id: &Pubkey,
+    ballot_box: &Pubkey,
+) -> EllipsisClientResult<Vec<AccountMeta>> {
+    let ballot_box_account = client.get_account(ballot_box).await?;
+    let page_count = BallotBox::try_from_slice_unchecked(ballot_box_account.data.as_slice())
+        .map_err(|e| EllipsisClientError::Other(anyhow::anyhow!("{:?}", e)))?
+        .page_count();
+
+    Ok((1..=page_count)
+        .map(|page| {
+            AccountMeta::new(
+                BallotBoxPage::find_program_address(tip_router_program_id, ballot_box, page).0,
+                false,
+            )
+        })
+        .collect())
+}
+
 /// Generate and send a CastVote instruction with the merkle root.
 #[allow(clippy::too_many_arguments)]
 pub async fn cast_vote(
@@ -41,6 +140,9 @@ pub async fn cast_vote(
     operator: &Pubkey,
     operator_voter: &Keypair,
     meta_merkle_root: [u8; 32],
+    snapshot_slot: u64,
+    bank_hash: [u8; 32],
+    tip_distribution_account_count: u64,
     tip_router_epoch: u64,
     submit_as_memo: bool,
 ) -> EllipsisClientResult<Signature> {
@@ -63,9 +165,15 @@ pub async fn cast_vote(
     )
     .0;
 
+    let operator_record =
+        OperatorRecord::find_program_address(tip_router_program_id, ncn, operator).0;
+
     let ix = if submit_as_memo {
         spl_memo::build_memo(meta_merkle_root.as_ref(), &[&operator_voter.pubkey()])
     } else {
+        let ballot_box_page_metas =
+            get_ballot_box_page_metas(client, tip_router_program_id, &ballot_box).await?;
+
         CastVoteBuilder::new()
             .epoch_state(epoch_state)
             .config(ncn_config)
@@ -75,8 +183,14 @@ pub async fn cast_vote(
             .operator_snapshot(operator_snapshot)
             .operator(*operator)
             .operator_voter(operator_voter.pubkey())
+            .operator_record(operator_record)
             .meta_merkle_root(meta_merkle_root)
             .epoch(tip_router_epoch)
+            .abstain(false)
+            .snapshot_slot(snapshot_slot)
+            .bank_hash(bank_hash)
+            .tip_distribution_account_count(tip_distribution_account_count)
+            .add_remaining_accounts(&ballot_box_page_metas)
             .instruction()
     };
 
@@ -88,7 +202,152 @@ pub async fn cast_vote(
         .await
 }
 
+/// Derive the salt used to commit to a vote. Signing is deterministic, so the same salt
+/// can be recomputed at reveal time without storing it.
+pub fn vote_salt(operator_voter: &Keypair, ncn: &Pubkey, tip_router_epoch: u64) -> [u8; 32] {
+    let message = [
+        b"vote_salt".as_ref(),
+        ncn.as_ref(),
+        &tip_router_epoch.to_le_bytes(),
+    ]
+    .concat();
+    let signature = operator_voter.sign_message(&message);
+    hashv(&[signature.as_ref()]).to_bytes()
+}
+
+/// Generate and send a CommitVote instruction with the commitment to the merkle root.
+#[allow(clippy::too_many_arguments)]
+pub async fn commit_vote(
+    client: &EllipsisClient,
+    payer: &Keypair,
+    tip_router_program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    operator_voter: &Keypair,
+    commitment: [u8; 32],
+    tip_router_epoch: u64,
+) -> EllipsisClientResult<Signature> {
+    let epoch_state =
+        EpochState::find_program_address(tip_router_program_id, ncn, tip_router_epoch).0;
+
+    let ncn_config = Config::find_program_address(tip_router_program_id, ncn).0;
+
+    let ballot_box =
+        BallotBox::find_program_address(tip_router_program_id, ncn, tip_router_epoch).0;
+
+    let epoch_snapshot =
+        EpochSnapshot::find_program_address(tip_router_program_id, ncn, tip_router_epoch).0;
+
+    let operator_snapshot = OperatorSnapshot::find_program_address(
+        tip_router_program_id,
+        operator,
+        ncn,
+        tip_router_epoch,
+    )
+    .0;
+
+    let ballot_box_page_metas =
+        get_ballot_box_page_metas(client, tip_router_program_id, &ballot_box).await?;
+
+    let ix = CommitVoteBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ballot_box(ballot_box)
+        .ncn(*ncn)
+        .epoch_snapshot(epoch_snapshot)
+        .operator_snapshot(operator_snapshot)
+        .operator(*operator)
+        .operator_voter(operator_voter.pubkey())
+        .commitment(commitment)
+        .epoch(tip_router_epoch)
+        .add_remaining_accounts(&ballot_box_page_metas)
+        .instruction();
+
+    info!("Submitting vote commitment {:?}", commitment);
+
+    let tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    client
+        .process_transaction(tx, &[payer, operator_voter])
+        .await
+}
+
+/// Generate and send a RevealVote instruction with the merkle root and salt.
+#[allow(clippy::too_many_arguments)]
+pub async fn reveal_vote(
+    client: &EllipsisClient,
+    payer: &Keypair,
+    tip_router_program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    operator_voter: &Keypair,
+    meta_merkle_root: [u8; 32],
+    snapshot_slot: u64,
+    bank_hash: [u8; 32],
+    tip_distribution_account_count: u64,
+    salt: [u8; 32],
+    tip_router_epoch: u64,
+) -> EllipsisClientResult<Signature> {
+    let epoch_state =
+        EpochState::find_program_address(tip_router_program_id, ncn, tip_router_epoch).0;
+
+    let ncn_config = Config::find_program_address(tip_router_program_id, ncn).0;
+
+    let ballot_box =
+        BallotBox::find_program_address(tip_router_program_id, ncn, tip_router_epoch).0;
+
+    let epoch_snapshot =
+        EpochSnapshot::find_program_address(tip_router_program_id, ncn, tip_router_epoch).0;
+
+    let operator_snapshot = OperatorSnapshot::find_program_address(
+        tip_router_program_id,
+        operator,
+        ncn,
+        tip_router_epoch,
+    )
+    .0;
+
+    let operator_record =
+        OperatorRecord::find_program_address(tip_router_program_id, ncn, operator).0;
+
+    let ballot_box_page_metas =
+        get_ballot_box_page_metas(client, tip_router_program_id, &ballot_box).await?;
+
+    let ix = RevealVoteBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ballot_box(ballot_box)
+        .ncn(*ncn)
+        .epoch_snapshot(epoch_snapshot)
+        .operator_snapshot(operator_snapshot)
+        .operator(*operator)
+        .operator_voter(operator_voter.pubkey())
+        .operator_record(operator_record)
+        .meta_merkle_root(meta_merkle_root)
+        .salt(salt)
+        .epoch(tip_router_epoch)
+        .snapshot_slot(snapshot_slot)
+        .bank_hash(bank_hash)
+        .tip_distribution_account_count(tip_distribution_account_count)
+        .add_remaining_accounts(&ballot_box_page_metas)
+        .instruction();
+
+    info!("Revealing meta merkle root {:?}", meta_merkle_root);
+
+    let tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    client
+        .process_transaction(tx, &[payer, operator_voter])
+        .await
+}
+
 #[allow(clippy::too_many_arguments)]
+/// Upper bound on the merkle roots set in one transaction, keeping the tip distribution CPIs
+/// within the default compute budget
+const MAX_MERKLE_ROOTS_PER_TRANSACTION: usize = 5;
+
+/// Sets the merkle roots of the given tip distribution accounts, packing as many uploads into each
+/// SetMerkleRootBatch transaction as fit in a packet.
+///
+/// Returns, per transaction, the number of merkle roots it carried and its result
 pub async fn set_merkle_roots_batched(
     client: &EllipsisClient,
     ncn_address: &Pubkey,
@@ -98,7 +357,7 @@ pub async fn set_merkle_roots_batched(
     epoch: u64,
     tip_distribution_accounts: Vec<(Pubkey, TipDistributionAccount)>,
     meta_merkle_tree: MetaMerkleTree,
-) -> Result<Vec<EllipsisClientResult<Signature>>> {
+) -> Result<Vec<(usize, EllipsisClientResult<Signature>)>> {
     let ballot_box = BallotBox::find_program_address(tip_router_program_id, ncn_address, epoch).0;
 
     let config = Config::find_program_address(tip_router_program_id, ncn_address).0;
@@ -107,8 +366,8 @@ pub async fn set_merkle_roots_batched(
 
     let tip_distribution_config = derive_config_account_address(tip_distribution_program).0;
 
-    // Given a list of target TipDistributionAccounts and a meta merkle tree, fetch each meta merkle root, create its instruction, and call set_merkle_root
-    let instructions = tip_distribution_accounts
+    // Given a list of target TipDistributionAccounts and a meta merkle tree, fetch each meta merkle root and its proof
+    let uploads = tip_distribution_accounts
         .iter()
         .filter_map(|(key, tip_distribution_account)| {
             let meta_merkle_node = meta_merkle_tree.get_node(key);
@@ -120,47 +379,89 @@ pub async fn set_merkle_roots_batched(
                 return None;
             };
 
-            let vote_account = tip_distribution_account.validator_vote_account;
-
-            let ix = SetMerkleRootBuilder::new()
-                .epoch_state(epoch_state)
-                .config(config)
-                .ncn(*ncn_address)
-                .ballot_box(ballot_box)
-                .vote_account(vote_account)
-                .tip_distribution_account(*key)
-                .tip_distribution_config(tip_distribution_config)
-                .tip_distribution_program(*tip_distribution_program)
-                .proof(proof)
-                .merkle_root(meta_merkle_node.validator_merkle_root)
-                .max_total_claim(meta_merkle_node.max_total_claim)
-                .max_num_nodes(meta_merkle_node.max_num_nodes)
-                .epoch(epoch)
-                .instruction();
-
-            Some(ix)
+            let upload = MerkleRootUpload {
+                proof,
+                merkle_root: meta_merkle_node.validator_merkle_root,
+                max_total_claim: meta_merkle_node.max_total_claim,
+                max_num_nodes: meta_merkle_node.max_num_nodes,
+            };
+            let upload_accounts = [
+                AccountMeta::new_readonly(tip_distribution_account.validator_vote_account, false),
+                AccountMeta::new(*key, false),
+            ];
+
+            Some((upload, upload_accounts))
         })
         .collect::<Vec<_>>();
 
-    let mut results = vec![];
-    for _ in 0..instructions.len() {
-        results.push(Err(EllipsisClientError::Other(anyhow::anyhow!(
-            "Default: Failed to submit instruction"
-        ))));
+    let build_instruction = |batch: &[(MerkleRootUpload, [AccountMeta; 2])]| {
+        let upload_accounts = batch
+            .iter()
+            .flat_map(|(_, upload_accounts)| upload_accounts.clone())
+            .collect::<Vec<_>>();
+
+        SetMerkleRootBatchBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(*ncn_address)
+            .ballot_box(ballot_box)
+            .tip_distribution_config(tip_distribution_config)
+            .tip_distribution_program(*tip_distribution_program)
+            .uploads(batch.iter().map(|(upload, _)| upload.clone()).collect())
+            .epoch(epoch)
+            .add_remaining_accounts(&upload_accounts)
+            .instruction()
+    };
+
+    // Greedily grow each batch while its transaction still fits in a packet
+    let mut instructions = vec![];
+    let mut batch_start = 0;
+    while batch_start < uploads.len() {
+        let mut batch_end = batch_start + 1;
+        while batch_end < uploads.len()
+            && batch_end - batch_start < MAX_MERKLE_ROOTS_PER_TRANSACTION
+        {
+            let ix = build_instruction(&uploads[batch_start..=batch_end]);
+            let message = Message::new(&[ix], Some(&keypair.pubkey()));
+            // Signature count prefix and the payer's signature
+            let transaction_size = message.serialize().len() + 1 + 64;
+            if transaction_size > PACKET_DATA_SIZE {
+                break;
+            }
+            batch_end += 1;
+        }
+
+        instructions.push((
+            batch_end - batch_start,
+            build_instruction(&uploads[batch_start..batch_end]),
+        ));
+        batch_start = batch_end;
     }
 
+    info!(
+        "Setting {} merkle roots in {} transactions",
+        uploads.len(),
+        instructions.len()
+    );
+
+    let mut results = vec![];
+
     // TODO Parallel submit instructions
-    for (i, ix) in instructions.into_iter().enumerate() {
+    for (merkle_roots_set, ix) in instructions {
+        let mut result = Err(EllipsisClientError::Other(anyhow::anyhow!(
+            "Default: Failed to submit instruction"
+        )));
         let mut tx = Transaction::new_with_payer(&[ix], Some(&keypair.pubkey()));
         // Simple retry logic
         for _ in 0..5 {
             let blockhash = client.fetch_latest_blockhash().await?;
             tx.sign(&[keypair], blockhash);
-            results[i] = client.process_transaction(tx.clone(), &[keypair]).await;
-            if results[i].is_ok() {
+            result = client.process_transaction(tx.clone(), &[keypair]).await;
+            if result.is_ok() {
                 break;
             }
         }
+        results.push((merkle_roots_set, result));
     }
 
     Ok(results)
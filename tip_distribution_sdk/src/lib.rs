@@ -1,5 +1,7 @@
 #![allow(clippy::redundant_pub_crate)]
-use anchor_lang::{declare_program, prelude::Pubkey, solana_program::clock::Epoch};
+use anchor_lang::{
+    declare_program, prelude::Pubkey, solana_program::clock::Epoch, AccountDeserialize,
+};
 
 declare_program!(jito_tip_distribution);
 pub use jito_tip_distribution::accounts::TipDistributionAccount;
@@ -18,6 +20,12 @@ pub const CLAIM_STATUS_SIZE: usize =
 pub const CONFIG_SIZE: usize =
     HEADER_SIZE + std::mem::size_of::<jito_tip_distribution::accounts::Config>();
 
+/// Whether a merkle root has already been uploaded to the tip distribution account
+pub fn has_merkle_root(mut tip_distribution_account_data: &[u8]) -> bool {
+    TipDistributionAccount::try_deserialize(&mut tip_distribution_account_data)
+        .is_ok_and(|tip_distribution_account| tip_distribution_account.merkle_root.is_some())
+}
+
 pub fn derive_tip_distribution_account_address(
     tip_distribution_program_id: &Pubkey,
     vote_pubkey: &Pubkey,